@@ -0,0 +1,340 @@
+//! Audit log handlers
+//!
+//! Every registry event is appended to a hash chain whose head is
+//! periodically signed as a checkpoint. `GET /v1/audit/verify` walks the
+//! chain and checks each checkpoint, `GET /v1/audit/checkpoints` lists the
+//! checkpoints and `POST /v1/audit/checkpoints` signs a new one.
+
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_service::{ChainCheckpoint, ChainVerification};
+use tracing::{debug, info, instrument};
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Verify the hash-chained audit log and its signed checkpoints
+///
+/// A tampered chain is reported in the response body, not as an error.
+/// Requires the `event:read` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/verify",
+    tag = "audit",
+    responses(
+        (status = 200, description = "Audit chain verification", body = ExecutionEnvelope<ChainVerification>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `event:read` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn verify_audit_chain(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<ChainVerification>>> {
+    debug!("Verifying audit chain");
+
+    let span_id = collector.begin_agent_span("AuditService");
+
+    let result = state.services.audit().verify_chain().await;
+
+    match result {
+        Ok(verification) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "chain_verification".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&verification).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(verification, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// List signed audit chain checkpoints
+///
+/// Requires the `event:read` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/checkpoints",
+    tag = "audit",
+    responses(
+        (status = 200, description = "Signed checkpoints", body = ExecutionEnvelope<Vec<ChainCheckpoint>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `event:read` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn list_audit_checkpoints(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<ChainCheckpoint>>>> {
+    debug!("Listing audit chain checkpoints");
+
+    let span_id = collector.begin_agent_span("AuditService");
+
+    let result = state.services.audit().list_checkpoints().await;
+
+    match result {
+        Ok(checkpoints) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "checkpoints".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": checkpoints.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(checkpoints, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Sign a checkpoint of the current audit chain head
+///
+/// Returns 201 with the new checkpoint, or 200 with no data if the chain
+/// is empty or its head is already checkpointed. Requires the `audit:admin`
+/// permission.
+#[utoipa::path(
+    post,
+    path = "/v1/audit/checkpoints",
+    tag = "audit",
+    responses(
+        (status = 201, description = "Checkpoint created", body = ExecutionEnvelope<Option<ChainCheckpoint>>),
+        (status = 200, description = "Chain is empty; no checkpoint created", body = ExecutionEnvelope<Option<ChainCheckpoint>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `audit:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn create_audit_checkpoint(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<Option<ChainCheckpoint>>>)> {
+    info!("Creating audit chain checkpoint");
+
+    let span_id = collector.begin_agent_span("AuditService");
+
+    let result = state.services.audit().create_checkpoint().await;
+
+    match result {
+        Ok(checkpoint) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "checkpoint".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&checkpoint).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            let status = if checkpoint.is_some() {
+                StatusCode::CREATED
+            } else {
+                StatusCode::OK
+            };
+            Ok((status, ok_with_execution(checkpoint, exec)))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::{auth_router, read_data, request};
+    use axum::body::Body;
+    use axum::http::Method;
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::ServiceRegistry;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_audit_checkpoints_and_verification() {
+        use llm_registry_db::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
+        use llm_registry_service::{
+            CheckpointSigner, InMemoryTrustedKeyStore, ServiceRegistryBuilder,
+        };
+
+        let signer =
+            CheckpointSigner::from_base64("audit", "BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=")
+                .unwrap();
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(event_store.clone())
+            .unit_of_work(Arc::new(InMemoryUnitOfWork::new(repository, event_store)))
+            .key_store(Arc::new(InMemoryTrustedKeyStore::with_keys([
+                signer.trusted_key()
+            ])))
+            .checkpoint_signer(signer)
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+        register_model(&state.services, "model", "1.0.0").await;
+        let app = build_router(state);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                "/v1/audit/checkpoints",
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let checkpoint = read_data(response).await;
+        assert_eq!(checkpoint["key_id"], "audit");
+
+        // Nothing new to checkpoint
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                "/v1/audit/checkpoints",
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(read_data(response).await.is_null());
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                "/v1/audit/checkpoints",
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(read_data(response).await.as_array().unwrap().len(), 1);
+
+        let response = app
+            .oneshot(request(
+                Method::GET,
+                "/v1/audit/verify",
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let verification = read_data(response).await;
+        assert_eq!(verification["valid"], true);
+        assert_eq!(verification["events_verified"], checkpoint["sequence"]);
+        assert_eq!(verification["checkpoints_verified"], 1);
+        assert!(verification["first_broken_link"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_audit_checkpoint_without_signer() {
+        let app = build_router(AppState::new(ServiceRegistry::in_memory()));
+        let response = app
+            .oneshot(request(
+                Method::POST,
+                "/v1/audit/checkpoints",
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_audit_routes_require_permission() {
+        let (app, with_role) = auth_router();
+        let send = |method: Method, uri: &str, role: Option<&str>| {
+            let mut request = request(method, uri, None, Body::empty());
+            if let Some(role) = role {
+                request = with_role(request, role);
+            }
+            app.clone().oneshot(request)
+        };
+
+        for uri in ["/v1/audit/verify", "/v1/audit/checkpoints"] {
+            let response = send(Method::GET, uri, None).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            let response = send(Method::GET, uri, Some("viewer")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+            let response = send(Method::GET, uri, Some("auditor")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let checkpoints = "/v1/audit/checkpoints";
+        let response = send(Method::POST, checkpoints, None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = send(Method::POST, checkpoints, Some("auditor"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        // Past authorization; fails only because no signer is configured
+        let response = send(Method::POST, checkpoints, Some("admin"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -4,19 +4,24 @@
 
 use axum::{
     body::Body,
-    extract::{Request, State},
+    extract::{Query, Request, State},
     http::{header::AUTHORIZATION, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
 use tracing::{debug, warn};
 
 use crate::{
     error::ErrorResponse,
     jwt::{Claims, JwtManager, TokenError},
+    rbac::{Permission, RbacPolicy},
 };
 
+/// Query parameter carrying an access token for clients that cannot set headers
+pub const ACCESS_TOKEN_PARAM: &str = "access_token";
+
 /// Extension for storing authenticated user claims in requests
 #[derive(Debug, Clone)]
 pub struct AuthUser {
@@ -56,24 +61,72 @@ impl AuthUser {
     }
 }
 
-/// Authentication state containing JWT manager
+/// Authentication state containing JWT manager and RBAC policy
 #[derive(Clone)]
 pub struct AuthState {
     jwt_manager: Arc<JwtManager>,
+    rbac_policy: Arc<Mutex<RbacPolicy>>,
 }
 
 impl AuthState {
-    /// Create new auth state
+    /// Create new auth state with the default RBAC roles
     pub fn new(jwt_manager: JwtManager) -> Self {
         Self {
             jwt_manager: Arc::new(jwt_manager),
+            rbac_policy: Arc::new(Mutex::new(RbacPolicy::new())),
         }
     }
 
+    /// Check permissions against a custom RBAC policy
+    pub fn with_rbac_policy(mut self, policy: RbacPolicy) -> Self {
+        self.rbac_policy = Arc::new(Mutex::new(policy));
+        self
+    }
+
     /// Get JWT manager reference
     pub fn jwt_manager(&self) -> &JwtManager {
         &self.jwt_manager
     }
+
+    /// Authenticate the value of an `Authorization` header
+    pub fn authenticate(&self, authorization: Option<&str>) -> Result<AuthUser, AuthError> {
+        let auth_header = authorization.ok_or(AuthError::MissingToken)?;
+
+        let token = JwtManager::extract_token_from_header(auth_header)
+            .map_err(|_| AuthError::InvalidToken)?;
+
+        let claims = self
+            .jwt_manager
+            .validate_token(token)
+            .map_err(|e| match e {
+                TokenError::Expired => AuthError::ExpiredToken,
+                _ => AuthError::InvalidToken,
+            })?;
+
+        Ok(AuthUser::new(claims))
+    }
+
+    /// Check that the RBAC policy grants a permission to one of a user's roles
+    pub fn authorize(
+        &self,
+        user: Option<&AuthUser>,
+        permission: &Permission,
+    ) -> Result<(), AuthError> {
+        let user = user.ok_or(AuthError::Unauthenticated)?;
+
+        let granted = self
+            .rbac_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .has_permission(&user.claims.roles, permission);
+
+        if !granted {
+            warn!("User {} lacks permission {}", user.user_id(), permission);
+            return Err(AuthError::InsufficientPermissions);
+        }
+
+        Ok(())
+    }
 }
 
 /// Required authentication middleware
@@ -92,7 +145,7 @@ impl AuthState {
 /// let jwt_manager = JwtManager::new(JwtConfig::default()).unwrap();
 /// let auth_state = AuthState::new(jwt_manager);
 ///
-/// let app = Router::new()
+/// let app: Router = Router::new()
 ///     .route("/protected", get(|| async { "Protected content" }))
 ///     .layer(middleware::from_fn_with_state(auth_state.clone(), require_auth));
 /// # }
@@ -112,8 +165,8 @@ pub async fn require_auth(
         .ok_or(AuthError::MissingToken)?;
 
     // Extract token from header
-    let token = JwtManager::extract_token_from_header(auth_header)
-        .map_err(|_| AuthError::InvalidToken)?;
+    let token =
+        JwtManager::extract_token_from_header(auth_header).map_err(|_| AuthError::InvalidToken)?;
 
     // Validate token
     let claims = auth_state
@@ -150,7 +203,7 @@ pub async fn require_auth(
 /// let jwt_manager = JwtManager::new(JwtConfig::default()).unwrap();
 /// let auth_state = AuthState::new(jwt_manager);
 ///
-/// let app = Router::new()
+/// let app: Router = Router::new()
 ///     .route("/public", get(|| async { "Public content" }))
 ///     .layer(middleware::from_fn_with_state(auth_state.clone(), optional_auth));
 /// # }
@@ -195,7 +248,7 @@ pub async fn optional_auth(
 ///
 /// let roles = vec!["admin".to_string(), "moderator".to_string()];
 ///
-/// let app = Router::new()
+/// let app: Router = Router::new()
 ///     .route("/admin", get(|| async { "Admin content" }))
 ///     .layer(middleware::from_fn_with_state(
 ///         (auth_state.clone(), roles),
@@ -217,8 +270,8 @@ pub async fn require_role(
         .and_then(|h| h.to_str().ok())
         .ok_or(AuthError::MissingToken)?;
 
-    let token = JwtManager::extract_token_from_header(auth_header)
-        .map_err(|_| AuthError::InvalidToken)?;
+    let token =
+        JwtManager::extract_token_from_header(auth_header).map_err(|_| AuthError::InvalidToken)?;
 
     let claims = auth_state
         .jwt_manager
@@ -242,6 +295,93 @@ pub async fn require_role(
     Ok(next.run(request).await)
 }
 
+/// Permission-based authentication middleware
+///
+/// This middleware requires authentication AND checks that the RBAC policy
+/// grants the permission to one of the user's roles.
+///
+/// # Usage
+///
+/// ```rust,no_run
+/// use axum::{Router, routing::get, middleware};
+/// use llm_registry_api::auth::{require_permission, AuthState};
+/// use llm_registry_api::jwt::{JwtConfig, JwtManager};
+/// use llm_registry_api::Permission;
+///
+/// # async fn example() {
+/// let jwt_manager = JwtManager::new(JwtConfig::default()).unwrap();
+/// let auth_state = AuthState::new(jwt_manager);
+///
+/// let app: Router = Router::new()
+///     .route("/events", get(|| async { "Event log" }))
+///     .layer(middleware::from_fn_with_state(
+///         (auth_state.clone(), Permission::read_events()),
+///         require_permission,
+///     ));
+/// # }
+/// ```
+pub async fn require_permission(
+    State((auth_state, permission)): State<(AuthState, Permission)>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    debug!("Authenticating request with permission check");
+
+    let auth_header = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|h| h.to_str().ok());
+
+    let user = auth_state.authenticate(auth_header)?;
+    auth_state.authorize(Some(&user), &permission)?;
+
+    debug!(
+        "User authenticated with permission {}: {}",
+        permission,
+        user.user_id()
+    );
+    request.extensions_mut().insert(user);
+
+    Ok(next.run(request).await)
+}
+
+/// Permission middleware that also accepts a token in the query string
+///
+/// Like [`require_permission`], but for routes opened by browser
+/// `EventSource` clients, which cannot set an `Authorization` header: the
+/// token may instead be passed in the `access_token` query parameter. The
+/// header wins when both are present.
+pub async fn require_permission_from_query(
+    State((auth_state, permission)): State<(AuthState, Permission)>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    debug!("Authenticating request with permission check");
+
+    let authorization = match request.headers().get(AUTHORIZATION) {
+        Some(value) => value.to_str().ok().map(str::to_string),
+        None => query_token(&request).map(|token| format!("Bearer {}", token)),
+    };
+
+    let user = auth_state.authenticate(authorization.as_deref())?;
+    auth_state.authorize(Some(&user), &permission)?;
+
+    debug!(
+        "User authenticated with permission {}: {}",
+        permission,
+        user.user_id()
+    );
+    request.extensions_mut().insert(user);
+
+    Ok(next.run(request).await)
+}
+
+/// The `access_token` query parameter of a request
+fn query_token(request: &Request) -> Option<String> {
+    let Query(mut params) = Query::<HashMap<String, String>>::try_from_uri(request.uri()).ok()?;
+    params.remove(ACCESS_TOKEN_PARAM)
+}
+
 /// Extract authenticated user from request
 ///
 /// This is a helper function to extract the AuthUser from request extensions.
@@ -286,26 +426,15 @@ pub enum AuthError {
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
         let (status, message) = match self {
-            AuthError::MissingToken => (
-                StatusCode::UNAUTHORIZED,
-                "Missing authentication token",
-            ),
-            AuthError::InvalidToken => (
-                StatusCode::UNAUTHORIZED,
-                "Invalid authentication token",
-            ),
-            AuthError::ExpiredToken => (
-                StatusCode::UNAUTHORIZED,
-                "Authentication token has expired",
-            ),
-            AuthError::Unauthenticated => (
-                StatusCode::UNAUTHORIZED,
-                "Authentication required",
-            ),
-            AuthError::InsufficientPermissions => (
-                StatusCode::FORBIDDEN,
-                "Insufficient permissions",
-            ),
+            AuthError::MissingToken => (StatusCode::UNAUTHORIZED, "Missing authentication token"),
+            AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid authentication token"),
+            AuthError::ExpiredToken => {
+                (StatusCode::UNAUTHORIZED, "Authentication token has expired")
+            }
+            AuthError::Unauthenticated => (StatusCode::UNAUTHORIZED, "Authentication required"),
+            AuthError::InsufficientPermissions => {
+                (StatusCode::FORBIDDEN, "Insufficient permissions")
+            }
         };
 
         let error_response = ErrorResponse {
@@ -313,6 +442,7 @@ impl IntoResponse for AuthError {
             error: message.to_string(),
             code: None,
             timestamp: chrono::Utc::now(),
+            execution: None,
         };
 
         (status, axum::Json(error_response)).into_response()
@@ -437,12 +567,13 @@ mod tests {
             }
         }
 
-        let app = Router::new()
-            .route("/public", get(handler))
-            .layer(middleware::from_fn_with_state(
-                auth_state.clone(),
-                optional_auth,
-            ));
+        let app =
+            Router::new()
+                .route("/public", get(handler))
+                .layer(middleware::from_fn_with_state(
+                    auth_state.clone(),
+                    optional_auth,
+                ));
 
         let request = Request::builder()
             .uri("/public")
@@ -463,12 +594,13 @@ mod tests {
             "Public content"
         }
 
-        let app = Router::new()
-            .route("/public", get(handler))
-            .layer(middleware::from_fn_with_state(
-                auth_state.clone(),
-                optional_auth,
-            ));
+        let app =
+            Router::new()
+                .route("/public", get(handler))
+                .layer(middleware::from_fn_with_state(
+                    auth_state.clone(),
+                    optional_auth,
+                ));
 
         let request = Request::builder()
             .uri("/public")
@@ -479,6 +611,42 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_require_permission() {
+        let jwt_manager = create_test_jwt_manager();
+        let token_with_role = |role: &str| {
+            let claims = crate::jwt::Claims::new("user123", "test", "test", 3600).with_role(role);
+            jwt_manager.generate_token_with_claims(claims).unwrap()
+        };
+        let auditor = token_with_role("auditor");
+        let viewer = token_with_role("viewer");
+        let auth_state = AuthState::new(jwt_manager);
+
+        let app = Router::new()
+            .route("/events", get(protected_handler))
+            .layer(middleware::from_fn_with_state(
+                (auth_state.clone(), Permission::read_events()),
+                require_permission,
+            ));
+
+        for (authorization, expected) in [
+            (None, StatusCode::UNAUTHORIZED),
+            (Some(viewer), StatusCode::FORBIDDEN),
+            (Some(auditor), StatusCode::OK),
+        ] {
+            let mut request = Request::builder().uri("/events");
+            if let Some(token) = authorization {
+                request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+            }
+            let response = app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), expected);
+        }
+    }
+
     #[test]
     fn test_auth_user() {
         let claims = crate::jwt::Claims::new("user123", "test", "test", 3600)
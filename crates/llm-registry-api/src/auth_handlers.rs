@@ -11,10 +11,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
+use utoipa::ToSchema;
 
 use crate::{
     auth::AuthUser,
-    error::{ApiError, ApiResult},
+    error::{ApiError, ApiResult, ErrorResponse},
     jwt::{Claims, JwtManager, TokenPair},
     responses::{ok, ApiResponse},
 };
@@ -40,7 +41,7 @@ impl AuthHandlerState {
 }
 
 /// Login request
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct LoginRequest {
     /// Username or email
     pub username: String,
@@ -50,7 +51,7 @@ pub struct LoginRequest {
 }
 
 /// Login response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LoginResponse {
     /// Token pair (access + refresh)
     #[serde(flatten)]
@@ -61,7 +62,7 @@ pub struct LoginResponse {
 }
 
 /// User information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserInfo {
     /// User ID
     pub id: String,
@@ -87,14 +88,14 @@ impl UserInfo {
 }
 
 /// Token refresh request
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct RefreshTokenRequest {
     /// Refresh token
     pub refresh_token: String,
 }
 
 /// Token refresh response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RefreshTokenResponse {
     /// New token pair
     #[serde(flatten)]
@@ -110,6 +111,16 @@ pub struct RefreshTokenResponse {
 /// 3. Implement rate limiting
 /// 4. Add audit logging
 /// 5. Handle MFA/2FA if required
+#[utoipa::path(
+    post,
+    path = "/v1/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Logged in", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Missing credentials", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, request))]
 pub async fn login(
     State(state): State<AuthHandlerState>,
@@ -149,6 +160,16 @@ pub async fn login(
 }
 
 /// Refresh token handler
+#[utoipa::path(
+    post,
+    path = "/v1/auth/refresh",
+    tag = "auth",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Token refreshed", body = ApiResponse<RefreshTokenResponse>),
+        (status = 401, description = "Refresh token invalid or expired", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, request))]
 pub async fn refresh_token(
     State(state): State<AuthHandlerState>,
@@ -161,9 +182,7 @@ pub async fn refresh_token(
         .jwt_manager()
         .refresh_access_token(&request.refresh_token)
         .map_err(|e| match e {
-            crate::jwt::TokenError::Expired => {
-                ApiError::unauthorized("Refresh token has expired")
-            }
+            crate::jwt::TokenError::Expired => ApiError::unauthorized("Refresh token has expired"),
             crate::jwt::TokenError::InvalidClaims(_) => {
                 ApiError::bad_request("Invalid refresh token")
             }
@@ -177,10 +196,18 @@ pub async fn refresh_token(
 }
 
 /// Get current user information
+#[utoipa::path(
+    get,
+    path = "/v1/auth/me",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Current user", body = ApiResponse<UserInfo>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(user))]
-pub async fn me(
-    Extension(user): Extension<AuthUser>,
-) -> ApiResult<Json<ApiResponse<UserInfo>>> {
+pub async fn me(Extension(user): Extension<AuthUser>) -> ApiResult<Json<ApiResponse<UserInfo>>> {
     debug!("Current user info requested");
 
     let user_info = UserInfo::from_claims(&user.claims);
@@ -195,6 +222,16 @@ pub async fn me(
 /// 1. Maintain a token blacklist in Redis
 /// 2. Use short-lived tokens with refresh tokens
 /// 3. Implement token revocation
+#[utoipa::path(
+    post,
+    path = "/v1/auth/logout",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Logged out", body = ApiResponse<LogoutResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(user))]
 pub async fn logout(
     Extension(user): Extension<AuthUser>,
@@ -212,13 +249,24 @@ pub async fn logout(
 }
 
 /// Logout response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LogoutResponse {
     /// Success message
     pub message: String,
 }
 
 /// Generate API key handler (example of protected endpoint)
+#[utoipa::path(
+    post,
+    path = "/v1/auth/api-keys",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "API key generated", body = ApiResponse<ApiKeyResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Requires the admin or developer role", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(user))]
 pub async fn generate_api_key(
     State(state): State<AuthHandlerState>,
@@ -246,7 +294,9 @@ pub async fn generate_api_key(
     let api_key = state
         .jwt_manager()
         .generate_token_with_claims(claims)
-        .map_err(|e| ApiError::internal_server_error(format!("Failed to generate API key: {}", e)))?;
+        .map_err(|e| {
+            ApiError::internal_server_error(format!("Failed to generate API key: {}", e))
+        })?;
 
     let response = ApiKeyResponse { api_key };
 
@@ -255,7 +305,7 @@ pub async fn generate_api_key(
 }
 
 /// API key response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ApiKeyResponse {
     /// Generated API key
     pub api_key: String,
@@ -0,0 +1,213 @@
+//! Conditional requests
+//!
+//! Assets carry a revision that increments on every update. REST responses
+//! expose it as a strong `ETag` (`"<revision>"`), and clients send it back in
+//! `If-Match` so that an update or delete only applies to the representation
+//! they last read. Weak or foreign entity tags never match, as required for
+//! `If-Match` by RFC 9110.
+
+use axum::http::{
+    header::{ETAG, IF_MATCH},
+    HeaderMap, HeaderName, HeaderValue,
+};
+use llm_registry_service::ServiceError;
+
+use crate::error::ApiError;
+
+/// Parsed `If-Match` header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IfMatch {
+    /// `*`, matching any current revision
+    Any,
+    /// Revisions named by the strong entity tags in the header
+    Revisions(Vec<u64>),
+}
+
+impl IfMatch {
+    /// Parse the `If-Match` header, if present
+    pub fn from_headers(headers: &HeaderMap) -> Result<Option<Self>, ApiError> {
+        let mut values = headers.get_all(IF_MATCH).iter().peekable();
+        if values.peek().is_none() {
+            return Ok(None);
+        }
+
+        let mut revisions = Vec::new();
+        for value in values {
+            let value = value
+                .to_str()
+                .map_err(|_| ApiError::bad_request("Invalid If-Match header"))?;
+            for tag in value
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+            {
+                if tag == "*" {
+                    return Ok(Some(Self::Any));
+                }
+                if let Some(revision) = parse_strong_etag(tag) {
+                    revisions.push(revision);
+                }
+            }
+        }
+
+        Ok(Some(Self::Revisions(revisions)))
+    }
+
+    /// Check whether the header matches an asset revision
+    pub fn matches(&self, revision: u64) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Revisions(revisions) => revisions.contains(&revision),
+        }
+    }
+
+    /// Resolve the revision an update or delete must be based on
+    ///
+    /// `current` is the revision the asset is at now. A header naming a
+    /// single revision needs no lookup, so `current` is only called when the
+    /// header lists several revisions. `*` places no constraint on the
+    /// revision.
+    pub async fn expected_revision<F, Fut>(&self, current: F) -> Result<Option<u64>, ApiError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<u64, ApiError>>,
+    {
+        match self {
+            Self::Any => Ok(None),
+            Self::Revisions(revisions) if revisions.len() == 1 => Ok(Some(revisions[0])),
+            Self::Revisions(revisions) if revisions.is_empty() => Err(precondition_failed()),
+            Self::Revisions(_) => {
+                let current = current().await?;
+                if self.matches(current) {
+                    Ok(Some(current))
+                } else {
+                    Err(precondition_failed())
+                }
+            }
+        }
+    }
+}
+
+/// Strong entity tag for an asset revision
+pub fn etag(revision: u64) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", revision)).expect("ETag is a valid header value")
+}
+
+/// Response body paired with an `ETag` header
+pub type WithEtag<T> = ([(HeaderName, HeaderValue); 1], T);
+
+/// Pair a response body with the `ETag` of an asset revision
+pub fn with_etag<T>(revision: u64, body: T) -> WithEtag<T> {
+    ([(ETAG, etag(revision))], body)
+}
+
+/// Combine the revision from `If-Match` with one from the request body
+///
+/// Both must agree when both are given.
+pub fn merge_expected_revision(
+    header: Option<u64>,
+    body: Option<u64>,
+) -> Result<Option<u64>, ApiError> {
+    match (header, body) {
+        (Some(header), Some(body)) if header != body => Err(precondition_failed()),
+        (header, body) => Ok(header.or(body)),
+    }
+}
+
+/// Convert a service error from a conditional request
+///
+/// A revision conflict means the `If-Match` precondition did not hold, which
+/// is reported as 412 rather than 409.
+pub fn conditional_error(err: ServiceError, if_match: Option<&IfMatch>) -> ApiError {
+    match err {
+        ServiceError::Conflict(msg) if if_match.is_some() => {
+            ApiError::precondition_failed(format!("Precondition failed: {}", msg))
+        }
+        err => ApiError::from(err),
+    }
+}
+
+fn precondition_failed() -> ApiError {
+    ApiError::precondition_failed("If-Match does not match the current asset revision")
+}
+
+/// Parse a strong entity tag issued by [`etag`]
+fn parse_strong_etag(tag: &str) -> Option<u64> {
+    tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(IF_MATCH, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_parse_if_match() {
+        assert_eq!(IfMatch::from_headers(&HeaderMap::new()).unwrap(), None);
+        assert_eq!(
+            IfMatch::from_headers(&headers(&["*"])).unwrap(),
+            Some(IfMatch::Any)
+        );
+        assert_eq!(
+            IfMatch::from_headers(&headers(&["\"3\", \"5\"", "\"7\""])).unwrap(),
+            Some(IfMatch::Revisions(vec![3, 5, 7]))
+        );
+
+        // Weak and foreign tags never match
+        let if_match = IfMatch::from_headers(&headers(&["W/\"3\", \"abc\", 4"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(if_match, IfMatch::Revisions(vec![]));
+        assert!(!if_match.matches(3));
+    }
+
+    #[tokio::test]
+    async fn test_expected_revision() {
+        let current = || async { Ok(5) };
+
+        assert_eq!(IfMatch::Any.expected_revision(current).await.unwrap(), None);
+        assert_eq!(
+            IfMatch::Revisions(vec![2])
+                .expected_revision(current)
+                .await
+                .unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            IfMatch::Revisions(vec![4, 5])
+                .expected_revision(current)
+                .await
+                .unwrap(),
+            Some(5)
+        );
+        assert!(IfMatch::Revisions(vec![1, 2])
+            .expected_revision(current)
+            .await
+            .is_err());
+        assert!(IfMatch::Revisions(vec![])
+            .expected_revision(current)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_merge_expected_revision() {
+        assert_eq!(merge_expected_revision(Some(2), None).unwrap(), Some(2));
+        assert_eq!(merge_expected_revision(None, Some(3)).unwrap(), Some(3));
+        assert_eq!(merge_expected_revision(Some(2), Some(2)).unwrap(), Some(2));
+        assert!(merge_expected_revision(Some(2), Some(3)).is_err());
+    }
+
+    #[test]
+    fn test_etag_round_trip() {
+        assert_eq!(etag(42), "\"42\"");
+        assert_eq!(parse_strong_etag(etag(42).to_str().unwrap()), Some(42));
+    }
+}
@@ -0,0 +1,510 @@
+//! Asset content upload and download
+//!
+//! `POST /v1/assets/{id}/content` takes the content of an asset as the
+//! `file` field of a `multipart/form-data` body and streams it into the
+//! registry's blob store, checking it against the asset's registered
+//! checksum on the way; content that does not match is rejected and not
+//! stored. `GET /v1/assets/{id}/content` serves it back, honouring a single
+//! `Range` so that interrupted downloads can resume.
+//!
+//! Content is streamed in both directions, so the request body limit does
+//! not apply to uploads and downloads carry no execution envelope.
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{multipart::Field, Multipart, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use futures::{stream, StreamExt};
+use llm_registry_core::{
+    execution::{SpanArtifact, SpanCollector, SpanStatus},
+    Asset, AssetId,
+};
+use llm_registry_service::{RangeRequest, ServiceError};
+use std::io;
+use tokio::sync::mpsc;
+use tracing::{debug, instrument};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::AuthUser,
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Name of the multipart field carrying the content
+pub const CONTENT_FIELD: &str = "file";
+
+/// Content type served when an asset does not declare one
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Chunks buffered between the request body and the blob store
+const UPLOAD_BUFFER_CHUNKS: usize = 8;
+
+/// Multipart form uploading the content of an asset
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct UploadContentForm {
+    /// Content of the asset; its part's `Content-Type` becomes the asset's
+    /// content type
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+/// Upload the content of an asset
+///
+/// The content must match the asset's registered checksum, which is
+/// computed as the upload streams through. On success the asset's storage
+/// location points at the registry's blob store and its size is set.
+/// Uploading again replaces the stored content.
+#[utoipa::path(
+    post,
+    path = "/v1/assets/{id}/content",
+    tag = "content",
+    params(("id" = String, Path, description = "Asset ID")),
+    request_body(content = UploadContentForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Content stored", body = ExecutionEnvelope<Asset>),
+        (status = 400, description = "Invalid asset ID or multipart body", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 422, description = "Content does not match the asset's checksum, or the signed size or content type", body = ErrorResponse),
+        (status = 501, description = "No blob store is configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, multipart))]
+pub async fn upload_asset_content(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    mut multipart: Multipart,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    debug!("Uploading content of asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let field = loop {
+        let message = match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some(CONTENT_FIELD) => break field,
+            Ok(Some(_)) => continue,
+            Ok(None) => format!("Missing multipart field `{}`", CONTENT_FIELD),
+            Err(e) => format!("Invalid multipart body: {}", e),
+        };
+        let exec = collector.finalize_failed("Invalid multipart body");
+        return Err(ApiError::bad_request(message).with_execution(exec));
+    };
+    let content_type = field.content_type().map(str::to_string);
+
+    let span_id = collector.begin_agent_span("ContentService");
+
+    // Hand the field's chunks to the service as they arrive, so the
+    // content is never held in memory as a whole
+    let (sender, receiver) = mpsc::channel(UPLOAD_BUFFER_CHUNKS);
+    let content = stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    })
+    .boxed();
+    let (result, read_error) = tokio::join!(
+        state
+            .services
+            .content()
+            .upload_content(&asset_id, content, content_type),
+        forward(field, sender)
+    );
+
+    match result {
+        Ok(asset) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "content_upload".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "size_bytes": asset.metadata.size_bytes,
+                        "checksum": asset.checksum.to_string(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(asset, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            // A body cut short by the client is its error, not the store's
+            let err = match read_error {
+                Some(read_error) => ApiError::bad_request(read_error),
+                None => ApiError::from(e),
+            };
+            Err(err.with_execution(exec))
+        }
+    }
+}
+
+/// Forward the chunks of a multipart field until it ends or the receiver
+/// goes away, returning the error that ended the field early, if any
+async fn forward(mut field: Field<'_>, sender: mpsc::Sender<io::Result<Bytes>>) -> Option<String> {
+    loop {
+        let (chunk, error) = match field.chunk().await {
+            Ok(Some(chunk)) => (Ok(chunk), None),
+            Ok(None) => return None,
+            Err(e) => {
+                let message = format!("Failed to read multipart field: {}", e);
+                (Err(io::Error::other(e)), Some(message))
+            }
+        };
+        if sender.send(chunk).await.is_err() || error.is_some() {
+            return error;
+        }
+    }
+}
+
+/// Parse a `Range` header
+///
+/// Only a single range of bytes is supported. Other units, multiple ranges
+/// and malformed values are ignored, which serves the whole content.
+fn parse_range(value: &str) -> Option<RangeRequest> {
+    let spec = value.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    match (start.trim(), end.trim()) {
+        ("", length) => Some(RangeRequest::Suffix(length.parse().ok()?)),
+        (start, "") => Some(RangeRequest::From {
+            start: start.parse().ok()?,
+            end: None,
+        }),
+        (start, end) => {
+            let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+            (start <= end).then_some(RangeRequest::From {
+                start,
+                end: Some(end),
+            })
+        }
+    }
+}
+
+/// Download the content of an asset
+///
+/// Send `Range: bytes=start-end`, `bytes=start-` or `bytes=-length` to read
+/// part of the content. Downloads from the first byte emit an
+/// `AssetDownloaded` event naming the authenticated user, if any.
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/content",
+    tag = "content",
+    params(
+        ("id" = String, Path, description = "Asset ID"),
+        ("Range" = Option<String>, Header, description = "Single byte range to read"),
+    ),
+    responses(
+        (status = 200, description = "Content of the asset", content((String = "application/octet-stream"))),
+        (status = 206, description = "Requested range of the content", content((String = "application/octet-stream"))),
+        (status = 400, description = "Invalid asset ID", body = ErrorResponse),
+        (status = 404, description = "Asset not found, or no content stored", body = ErrorResponse),
+        (status = 416, description = "Range lies outside the content", body = ErrorResponse),
+        (status = 501, description = "No blob store is configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, user, headers))]
+pub async fn download_asset_content(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    user: Option<Extension<AuthUser>>,
+    headers: HeaderMap,
+) -> ApiResult<Response> {
+    debug!("Downloading content of asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_range);
+    let downloader = user.map(|Extension(user)| user.user_id().to_string());
+
+    let span_id = collector.begin_agent_span("ContentService");
+
+    let result = state
+        .services
+        .content()
+        .download_content(&asset_id, range, downloader)
+        .await;
+
+    match result {
+        Ok(content) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "content_download".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "size_bytes": content.size,
+                        "range": content.range.map(|r| format!("{}-{}", r.start, r.end)),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            collector.finalize();
+
+            let content_type = content
+                .asset
+                .metadata
+                .content_type
+                .as_deref()
+                .and_then(|value| HeaderValue::from_str(value).ok())
+                .unwrap_or(HeaderValue::from_static(DEFAULT_CONTENT_TYPE));
+            let length = content.range.map_or(content.size, |range| range.length());
+
+            let mut response = Body::from_stream(content.body).into_response();
+            let response_headers = response.headers_mut();
+            response_headers.insert(header::CONTENT_TYPE, content_type);
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(length));
+            response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+            if let Some(range) = content.range {
+                let content_range = format!("bytes {}-{}/{}", range.start, range.end, content.size);
+                if let Ok(value) = HeaderValue::from_str(&content_range) {
+                    response_headers.insert(header::CONTENT_RANGE, value);
+                }
+                *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+            }
+            Ok(response)
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+
+            // Tell the client the size, so it can ask for a range that fits
+            let content_range = match e {
+                ServiceError::RangeNotSatisfiable { size } => Some(format!("bytes */{}", size)),
+                _ => None,
+            };
+            let err = ApiError::from(e).with_execution(exec);
+            Ok(match content_range {
+                Some(content_range) => {
+                    ([(header::CONTENT_RANGE, content_range)], err).into_response()
+                }
+                None => err.into_response(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_router;
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use axum::{body::to_bytes, http::Request, Router};
+    use llm_registry_core::{Checksum, HashAlgorithm};
+    use llm_registry_db::{
+        FileSystemBlobStore, InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork,
+    };
+    use llm_registry_service::test_support::register_request;
+    use llm_registry_service::{RegisterAssetRequest, ServiceRegistry, ServiceRegistryBuilder};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    const CONTENT: &[u8] = b"0123456789abcdef";
+    const BOUNDARY: &str = "content-boundary";
+
+    struct Fixture {
+        _dir: tempfile::TempDir,
+        services: Arc<ServiceRegistry>,
+        app: Router,
+        asset_id: AssetId,
+    }
+
+    async fn fixture() -> Fixture {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(event_store.clone())
+            .unit_of_work(Arc::new(InMemoryUnitOfWork::new(repository, event_store)))
+            .blob_store(Arc::new(FileSystemBlobStore::new(dir.path())))
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+
+        let asset_id = state
+            .services
+            .registration()
+            .register_asset(RegisterAssetRequest {
+                checksum: Checksum::new(
+                    HashAlgorithm::SHA256,
+                    HashAlgorithm::SHA256.digest(CONTENT),
+                )
+                .unwrap(),
+                ..register_request("content-model", "1.0.0")
+            })
+            .await
+            .unwrap()
+            .asset
+            .id;
+
+        Fixture {
+            _dir: dir,
+            services: state.services.clone(),
+            app: build_router(state),
+            asset_id,
+        }
+    }
+
+    fn request(method: &str, uri: &str) -> axum::http::request::Builder {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(HEADER_EXECUTION_ID, "exec-1")
+            .header(HEADER_PARENT_SPAN_ID, "01ARZ3NDEKTSV4RRFFQ69G5FAV")
+    }
+
+    async fn upload(fixture: &Fixture, content: &[u8]) -> (StatusCode, serde_json::Value) {
+        let mut body = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"{field}\"; filename=\"model.bin\"\r\n\
+             Content-Type: application/x-safetensors\r\n\r\n",
+            boundary = BOUNDARY,
+            field = CONTENT_FIELD
+        )
+        .into_bytes();
+        body.extend_from_slice(content);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+
+        let request = request("POST", &format!("/v1/assets/{}/content", fixture.asset_id))
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .body(Body::from(body))
+            .unwrap();
+        let response = fixture.app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn download(fixture: &Fixture, range: Option<&str>) -> (StatusCode, HeaderMap, Bytes) {
+        let mut request = request("GET", &format!("/v1/assets/{}/content", fixture.asset_id));
+        if let Some(range) = range {
+            request = request.header(header::RANGE, range);
+        }
+        let response = fixture
+            .app
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, headers, body)
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let from = |start, end| Some(RangeRequest::From { start, end });
+        assert_eq!(parse_range("bytes=0-4"), from(0, Some(4)));
+        assert_eq!(parse_range("bytes=5-"), from(5, None));
+        assert_eq!(parse_range("bytes=-3"), Some(RangeRequest::Suffix(3)));
+        for ignored in [
+            "bytes=4-0",
+            "bytes=0-1,4-5",
+            "items=0-4",
+            "bytes=-",
+            "bytes=a-b",
+        ] {
+            assert_eq!(parse_range(ignored), None, "{}", ignored);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_download_ranges() {
+        let fixture = fixture().await;
+
+        let (status, body) = upload(&fixture, CONTENT).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["metadata"]["size_bytes"], CONTENT.len());
+        assert_eq!(body["data"]["storage"]["backend"]["type"], "file_system");
+
+        let (status, headers, body) = download(&fixture, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, CONTENT);
+        assert_eq!(headers[header::CONTENT_TYPE], "application/x-safetensors");
+        assert_eq!(headers[header::ACCEPT_RANGES], "bytes");
+
+        let (status, headers, body) = download(&fixture, Some("bytes=10-")).await;
+        assert_eq!(status, StatusCode::PARTIAL_CONTENT);
+        assert_eq!(body, &CONTENT[10..]);
+        assert_eq!(headers[header::CONTENT_RANGE], "bytes 10-15/16");
+        assert_eq!(headers[header::CONTENT_LENGTH], "6");
+
+        let (status, headers, _) = download(&fixture, Some("bytes=16-20")).await;
+        assert_eq!(status, StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(headers[header::CONTENT_RANGE], "bytes */16");
+
+        let events = fixture
+            .services
+            .events()
+            .query_events(llm_registry_service::EventQuery::new().asset_id(fixture.asset_id))
+            .await
+            .unwrap();
+        let downloads = events
+            .events
+            .iter()
+            .filter(|e| e.event_name() == "asset_downloaded")
+            .count();
+        assert_eq!(downloads, 1);
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_checksum_mismatch() {
+        let fixture = fixture().await;
+
+        let (status, body) = upload(&fixture, b"not the registered content").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "CHECKSUM_MISMATCH");
+
+        let (status, _, _) = download(&fixture, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_without_blob_store() {
+        let app = build_router(AppState::new(ServiceRegistry::in_memory()));
+        let request = request("GET", &format!("/v1/assets/{}/content", AssetId::new()))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+}
@@ -12,6 +12,7 @@ use llm_registry_core::execution::ExecutionResult;
 use llm_registry_service::ServiceError;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
 
 /// API error type that can be converted to HTTP responses
 #[derive(Debug)]
@@ -70,6 +71,15 @@ impl ApiError {
         Self::new(StatusCode::CONFLICT, message)
     }
 
+    /// Create a precondition failed error (412)
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        Self::with_code(
+            StatusCode::PRECONDITION_FAILED,
+            message,
+            "PRECONDITION_FAILED",
+        )
+    }
+
     /// Create an unprocessable entity error (422)
     pub fn unprocessable_entity(message: impl Into<String>) -> Self {
         Self::new(StatusCode::UNPROCESSABLE_ENTITY, message)
@@ -100,7 +110,7 @@ impl fmt::Display for ApiError {
 impl std::error::Error for ApiError {}
 
 /// Error response JSON structure
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
     /// HTTP status code
     pub status: u16,
@@ -156,6 +166,11 @@ impl From<ServiceError> for ApiError {
                 format!("Checksum verification failed: {}", msg),
                 "CHECKSUM_MISMATCH",
             ),
+            ServiceError::SignatureVerificationFailed(msg) => ApiError::with_code(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Signature verification failed: {}", msg),
+                "SIGNATURE_INVALID",
+            ),
             ServiceError::CircularDependency(msg) => ApiError::with_code(
                 StatusCode::UNPROCESSABLE_ENTITY,
                 format!("Circular dependency detected: {}", msg),
@@ -171,6 +186,11 @@ impl From<ServiceError> for ApiError {
                 format!("Version conflict: {}", msg),
                 "VERSION_CONFLICT",
             ),
+            ServiceError::Conflict(msg) => ApiError::with_code(
+                StatusCode::CONFLICT,
+                format!("Conflict: {}", msg),
+                "CONFLICT",
+            ),
             ServiceError::PolicyValidationFailed {
                 policy_name,
                 message,
@@ -182,9 +202,22 @@ impl From<ServiceError> for ApiError {
             ServiceError::InvalidInput(msg) => {
                 ApiError::with_code(StatusCode::BAD_REQUEST, msg, "INVALID_INPUT")
             }
+            ServiceError::InvalidQuery(err) => ApiError::with_code(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid search query: {}", err),
+                "INVALID_QUERY",
+            ),
             ServiceError::NotPermitted(msg) => {
                 ApiError::with_code(StatusCode::FORBIDDEN, msg, "NOT_PERMITTED")
             }
+            ServiceError::RangeNotSatisfiable { size } => ApiError::with_code(
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                format!("Range not satisfiable for content of {} bytes", size),
+                "RANGE_NOT_SATISFIABLE",
+            ),
+            ServiceError::Unsupported(msg) => {
+                ApiError::with_code(StatusCode::NOT_IMPLEMENTED, msg, "NOT_SUPPORTED")
+            }
             ServiceError::Database(msg) => ApiError::with_code(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Database error: {}", msg),
@@ -233,6 +266,26 @@ mod tests {
         assert_eq!(api_err.status_code, StatusCode::NOT_FOUND);
     }
 
+    #[test]
+    fn test_conflict_conversion() {
+        let api_err: ApiError = ServiceError::Conflict("stale revision".to_string()).into();
+        assert_eq!(api_err.status_code, StatusCode::CONFLICT);
+        assert_eq!(api_err.error_code.as_deref(), Some("CONFLICT"));
+
+        let api_err = ApiError::precondition_failed("stale revision");
+        assert_eq!(api_err.status_code, StatusCode::PRECONDITION_FAILED);
+        assert_eq!(api_err.error_code.as_deref(), Some("PRECONDITION_FAILED"));
+    }
+
+    #[test]
+    fn test_invalid_query_conversion() {
+        let parse_err = llm_registry_service::parse_query("tag:a size>big").unwrap_err();
+        let api_err: ApiError = ServiceError::from(parse_err).into();
+        assert_eq!(api_err.status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(api_err.error_code.as_deref(), Some("INVALID_QUERY"));
+        assert!(api_err.message.contains("at position 11"));
+    }
+
     #[test]
     fn test_error_response_serialization() {
         let response = ErrorResponse {
@@ -0,0 +1,520 @@
+//! Event log queries and export
+//!
+//! `GET /v1/events` and `GET /v1/assets/{id}/events` page through the
+//! registry event log with the same filters as [`EventQuery`], following
+//! `next_cursor` from one page to the next. They return the usual paginated
+//! envelope, or an export when the `Accept` header asks for one:
+//!
+//! - `application/x-ndjson`: one JSON event per line
+//! - `text/csv`: one row per event, with the event details as JSON
+//!
+//! Exports stream every matching event from the requested position on,
+//! fetching pages of [`MAX_EVENT_PAGE_SIZE`] as the client reads, so `limit`
+//! and `include_total` do not apply to them.
+//!
+//! The event log records who did what to which asset, so both routes are
+//! gated on the `event:read` permission when authentication is enabled.
+//!
+//! [`EventQuery`]: llm_registry_service::EventQuery
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use futures::{stream, StreamExt};
+use llm_registry_core::{
+    execution::{SpanArtifact, SpanCollector, SpanStatus},
+    AssetId, RegistryEvent,
+};
+use llm_registry_service::{
+    ListEventsRequest, ListEventsResponse, ServiceRegistry, MAX_EVENT_PAGE_SIZE,
+};
+use tracing::{debug, instrument, warn};
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{PaginatedExecutionEnvelope, PaginationMeta},
+};
+
+/// Media type of newline-delimited JSON exports
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Media type of CSV exports
+pub const CSV_CONTENT_TYPE: &str = "text/csv";
+
+/// Header row of CSV exports
+const CSV_HEADER: &str =
+    "event_id,timestamp,event_type,asset_id,actor,source,correlation_id,details\n";
+
+/// Representation requested through the `Accept` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Paginated JSON envelope
+    Json,
+    /// Newline-delimited JSON export
+    Ndjson,
+    /// CSV export
+    Csv,
+}
+
+impl Format {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if accept.contains(NDJSON_CONTENT_TYPE) {
+            Format::Ndjson
+        } else if accept.contains(CSV_CONTENT_TYPE) {
+            Format::Csv
+        } else {
+            Format::Json
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Format::Json => "application/json",
+            Format::Ndjson => NDJSON_CONTENT_TYPE,
+            Format::Csv => CSV_CONTENT_TYPE,
+        }
+    }
+
+    /// Encode a page of events for an export
+    fn encode(self, events: &[RegistryEvent]) -> String {
+        let mut out = String::new();
+        for event in events {
+            match self {
+                Format::Json | Format::Ndjson => {
+                    out.push_str(&serde_json::to_string(event).unwrap_or_default());
+                    out.push('\n');
+                }
+                Format::Csv => out.push_str(&csv_row(event)),
+            }
+        }
+        out
+    }
+}
+
+/// Encode an event as a CSV row
+fn csv_row(event: &RegistryEvent) -> String {
+    let details = serde_json::to_string(&event.event_type).unwrap_or_default();
+    let fields = [
+        event.event_id.clone(),
+        event.timestamp.to_rfc3339(),
+        event.event_name().to_string(),
+        event
+            .asset_id()
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        event.actor.clone().unwrap_or_default(),
+        event.source.clone().unwrap_or_default(),
+        event.correlation_id.clone().unwrap_or_default(),
+        details,
+    ];
+
+    let mut row = fields
+        .iter()
+        .map(|f| csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// List registry events
+///
+/// Filters combine: `event_types` takes a comma-separated list, and
+/// `occurred_after`/`occurred_before` take RFC 3339 timestamps. Pass the
+/// returned `next_cursor` as `cursor` to fetch the next page. Send
+/// `Accept: application/x-ndjson` or `Accept: text/csv` to export every
+/// matching event instead. Requires the `event:read` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/events",
+    tag = "events",
+    params(ListEventsRequest),
+    responses(
+        (status = 200, description = "Page of matching events, or an export of all of them", content(
+            (PaginatedExecutionEnvelope<RegistryEvent> = "application/json"),
+            (String = "application/x-ndjson"),
+            (String = "text/csv"),
+        )),
+        (status = 400, description = "Invalid filter, limit or cursor", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `event:read` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector, headers))]
+pub async fn list_events(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    headers: HeaderMap,
+    Query(params): Query<ListEventsRequest>,
+) -> ApiResult<Response> {
+    debug!("Listing events with filters: {:?}", params);
+
+    query_events(state, collector, Format::from_headers(&headers), params).await
+}
+
+/// List the events of an asset
+///
+/// Takes the same filters and `Accept` types as `GET /v1/events`, scoped to
+/// one asset. Events of deleted assets remain listed. Requires the
+/// `event:read` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/events",
+    tag = "events",
+    params(("id" = String, Path, description = "Asset ID"), ListEventsRequest),
+    responses(
+        (status = 200, description = "Page of the asset's events, or an export of all of them", content(
+            (PaginatedExecutionEnvelope<RegistryEvent> = "application/json"),
+            (String = "application/x-ndjson"),
+            (String = "text/csv"),
+        )),
+        (status = 400, description = "Invalid asset ID, filter, limit or cursor", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `event:read` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector, headers))]
+pub async fn list_asset_events(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(mut params): Query<ListEventsRequest>,
+) -> ApiResult<Response> {
+    debug!("Listing events of asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+    params.asset_id = Some(asset_id);
+
+    query_events(state, collector, Format::from_headers(&headers), params).await
+}
+
+/// Fetch the first page under an agent span, then respond in `format`
+async fn query_events(
+    state: AppState,
+    collector: SpanCollector,
+    format: Format,
+    mut params: ListEventsRequest,
+) -> ApiResult<Response> {
+    if format != Format::Json {
+        params.limit = MAX_EVENT_PAGE_SIZE;
+        params.include_total = false;
+    }
+
+    let span_id = collector.begin_agent_span("EventService");
+
+    let result = state.services.events().list_events(params.clone()).await;
+
+    match result {
+        Ok(page) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "events".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "format": format.content_type(),
+                        "total": page.total,
+                        "count": page.events.len(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+
+            if format == Format::Json {
+                return Ok(Json(PaginatedExecutionEnvelope {
+                    items: page.events,
+                    pagination: PaginationMeta {
+                        total: page.total,
+                        offset: 0,
+                        limit: page.limit,
+                        has_more: page.has_more,
+                        next_cursor: page.next_cursor,
+                    },
+                    snippets: Default::default(),
+                    execution: exec,
+                })
+                .into_response());
+            }
+
+            Ok(export(state.services, format, params, page))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Request for the page after `page`, if there is one
+fn next_request(
+    request: &ListEventsRequest,
+    page: &ListEventsResponse,
+) -> Option<ListEventsRequest> {
+    match page.next_cursor {
+        Some(ref cursor) if page.has_more => Some(ListEventsRequest {
+            cursor: Some(cursor.clone()),
+            ..request.clone()
+        }),
+        _ => None,
+    }
+}
+
+/// Stream the first page and every page after it
+///
+/// Errors after the first page cannot change the status any more; they end
+/// the body early, which clients see as a truncated transfer.
+fn export(
+    services: Arc<ServiceRegistry>,
+    format: Format,
+    request: ListEventsRequest,
+    first: ListEventsResponse,
+) -> Response {
+    let mut head = String::new();
+    if format == Format::Csv {
+        head.push_str(CSV_HEADER);
+    }
+    head.push_str(&format.encode(&first.events));
+
+    let rest = stream::unfold(next_request(&request, &first), move |next| {
+        let services = services.clone();
+        async move {
+            let request = next?;
+            match services.events().list_events(request.clone()).await {
+                Ok(page) => {
+                    let next = next_request(&request, &page);
+                    Some((Ok(format.encode(&page.events)), next))
+                }
+                Err(e) => {
+                    warn!("Event export ended early: {}", e);
+                    Some((Err(std::io::Error::other(e.to_string())), None))
+                }
+            }
+        }
+    });
+
+    let body = Body::from_stream(stream::once(async move { Ok(head) }).chain(rest));
+
+    (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(format.content_type()),
+        )],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use crate::jwt::{Claims, JwtConfig, JwtManager};
+    use crate::{build_router, build_router_with_auth, AuthHandlerState, AuthState};
+    use axum::{
+        body::to_bytes,
+        http::{Request, StatusCode},
+        Router,
+    };
+    use llm_registry_service::test_support::register_request;
+    use llm_registry_service::RegisterAssetRequest;
+    use tower::ServiceExt;
+
+    async fn register(state: &AppState, name: &str) -> AssetId {
+        state
+            .services
+            .registration()
+            .register_asset(RegisterAssetRequest {
+                description: Some("a \"quoted\", multi-part description".to_string()),
+                ..register_request(name, "1.0.0")
+            })
+            .await
+            .unwrap()
+            .asset
+            .id
+    }
+
+    async fn get(
+        app: &Router,
+        uri: &str,
+        accept: Option<&str>,
+        token: Option<&str>,
+    ) -> (StatusCode, String) {
+        let mut request = Request::get(uri)
+            .header(HEADER_EXECUTION_ID, "exec-1")
+            .header(HEADER_PARENT_SPAN_ID, "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        if let Some(accept) = accept {
+            request = request.header(header::ACCEPT, accept);
+        }
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_events_pages_and_filters() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let first = register(&state, "first-model").await;
+        register(&state, "second-model").await;
+        let app = build_router(state);
+
+        let (status, body) = get(&app, "/v1/events?limit=1&include_total=true", None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["items"].as_array().unwrap().len(), 1);
+        assert_eq!(json["pagination"]["has_more"], true);
+        let total = json["pagination"]["total"].as_i64().unwrap();
+        assert!(total >= 2);
+
+        let cursor = json["pagination"]["next_cursor"].as_str().unwrap();
+        let (status, body) = get(
+            &app,
+            &format!("/v1/events?limit=1&cursor={}", cursor),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let next: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_ne!(next["items"][0]["event_id"], json["items"][0]["event_id"]);
+
+        let uri = format!(
+            "/v1/assets/{}/events?event_types=asset_registered,asset_deleted",
+            first
+        );
+        let (status, body) = get(&app, &uri, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let items = json["items"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["type"], "asset_registered");
+
+        let (status, _) = get(&app, "/v1/events?occurred_after=yesterday", None, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = get(&app, "/v1/events?limit=5000", None, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_export_streams_all_pages() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        register(&state, "first-model").await;
+        register(&state, "second-model").await;
+        let total = state
+            .services
+            .events()
+            .list_events(ListEventsRequest {
+                include_total: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .total
+            .unwrap() as usize;
+        let app = build_router(state);
+
+        let (status, body) = get(&app, "/v1/events?limit=1", Some(NDJSON_CONTENT_TYPE), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let lines: Vec<_> = body.lines().collect();
+        assert_eq!(lines.len(), total);
+        for line in lines {
+            let event: RegistryEvent = serde_json::from_str(line).unwrap();
+            assert!(!event.event_id.is_empty());
+        }
+
+        let (status, body) = get(
+            &app,
+            "/v1/events?event_types=asset_registered",
+            Some(CSV_CONTENT_TYPE),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with(CSV_HEADER));
+        let rows: Vec<_> = body.lines().skip(1).collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].contains(",asset_registered,"));
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[tokio::test]
+    async fn test_event_log_requires_permission() {
+        let jwt_manager = || {
+            JwtManager::new(
+                JwtConfig::new("test-secret-key")
+                    .with_issuer("test")
+                    .with_audience("test"),
+            )
+            .unwrap()
+        };
+        let token = |role: &str| {
+            let claims = Claims::new("user123", "test", "test", 3600).with_role(role);
+            jwt_manager().generate_token_with_claims(claims).unwrap()
+        };
+        let app = build_router_with_auth(
+            AppState::new(ServiceRegistry::in_memory()),
+            AuthHandlerState::new(jwt_manager()),
+            AuthState::new(jwt_manager()),
+        );
+
+        let (status, _) = get(&app, "/v1/events", None, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = get(&app, "/v1/events", None, Some(&token("viewer"))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = get(&app, "/v1/events", None, Some(&token("auditor"))).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = get(&app, "/v1/events", None, Some(&token("admin"))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
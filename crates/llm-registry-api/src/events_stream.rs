@@ -0,0 +1,303 @@
+//! Server-Sent Events change feed
+//!
+//! `GET /v1/events/stream` streams registry events to browsers and other
+//! SSE clients as they are appended to the event store. Each message carries
+//! the event ID as its SSE `id`, so clients that reconnect with the standard
+//! `Last-Event-ID` header resume without gaps. Idle connections receive
+//! periodic heartbeat comments to keep proxies from closing them.
+//!
+//! The feed sits outside the execution-context middleware because
+//! `EventSource` cannot set custom request headers. For the same reason,
+//! with authentication enabled, the access token may be passed in the
+//! `access_token` query parameter instead of the `Authorization` header.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::Stream;
+use llm_registry_core::{AssetId, RegistryEvent};
+use llm_registry_service::EventQuery;
+use serde::Deserialize;
+use tracing::{debug, instrument, warn};
+use utoipa::IntoParams;
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+};
+
+/// Header sent by SSE clients when reconnecting
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// Interval between heartbeat comments on an idle stream
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Query parameters for the event stream
+///
+/// Filters mirror [`EventQuery`]. `event_type` accepts a comma-separated
+/// list. `last_event_id` is a fallback for clients that cannot set the
+/// `Last-Event-ID` header; the header wins when both are present.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventStreamParams {
+    /// Only stream events for this asset
+    pub asset_id: Option<String>,
+
+    /// Only stream events of these types (comma-separated)
+    pub event_type: Option<String>,
+
+    /// Only stream events triggered by this actor
+    pub actor: Option<String>,
+
+    /// Resume after this event
+    pub last_event_id: Option<String>,
+}
+
+impl EventStreamParams {
+    /// Build the event filter from the parameters
+    fn to_query(&self) -> ApiResult<EventQuery> {
+        let mut query = EventQuery::new();
+
+        if let Some(ref id) = self.asset_id {
+            let asset_id = id
+                .parse::<AssetId>()
+                .map_err(|e| ApiError::bad_request(format!("Invalid asset ID: {}", e)))?;
+            query = query.asset_id(asset_id);
+        }
+
+        if let Some(ref types) = self.event_type {
+            for event_type in types.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                query = query.event_type(event_type);
+            }
+        }
+
+        if let Some(ref actor) = self.actor {
+            query = query.actor(actor.clone());
+        }
+
+        Ok(query)
+    }
+}
+
+/// Stream registry events as Server-Sent Events
+#[utoipa::path(
+    get,
+    path = "/v1/events/stream",
+    tag = "events",
+    params(
+        EventStreamParams,
+        ("Last-Event-ID" = Option<String>, Header, description = "Resume after this event"),
+        ("access_token" = Option<String>, Query, description = "Access token, for clients that cannot set the `Authorization` header"),
+    ),
+    responses(
+        (status = 200, description = "Stream of registry events", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Invalid filter or unknown `Last-Event-ID`", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `event:read` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, headers))]
+pub async fn stream_events(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<EventStreamParams>,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let filter = params.to_query()?;
+
+    let last_event_id = match headers.get(LAST_EVENT_ID_HEADER) {
+        Some(value) => Some(
+            value
+                .to_str()
+                .map_err(|_| ApiError::bad_request("Invalid Last-Event-ID header"))?
+                .to_string(),
+        ),
+        None => params.last_event_id.clone(),
+    }
+    .filter(|id| !id.is_empty());
+
+    debug!("Opening event stream, resuming after {:?}", last_event_id);
+
+    let subscription = state
+        .services
+        .events()
+        .subscribe(last_event_id.as_deref())
+        .await
+        .map_err(ApiError::from)?;
+
+    let stream = futures::stream::unfold(Some(subscription), move |state| {
+        let filter = filter.clone();
+        async move {
+            let mut subscription = state?;
+            loop {
+                match subscription.next().await {
+                    Ok(Some(event)) if filter.matches(&event) => {
+                        return Some((Ok(to_sse_event(&event)), Some(subscription)))
+                    }
+                    Ok(Some(_)) => continue,
+                    Ok(None) => return None,
+                    Err(e) => {
+                        // Ending the stream makes the client reconnect with
+                        // its last event ID and resume from the store.
+                        warn!("Event stream ended: {}", e);
+                        return None;
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(HEARTBEAT_INTERVAL)
+            .text("heartbeat"),
+    ))
+}
+
+/// Convert a registry event to an SSE message
+fn to_sse_event(event: &RegistryEvent) -> Event {
+    let data = serde_json::to_string(event).unwrap_or_default();
+    Event::default()
+        .id(event.event_id.clone())
+        .event(event.event_name())
+        .data(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::auth_router;
+    use axum::{
+        body::{Body, Bytes},
+        http::{header, Request, StatusCode},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+
+    async fn register(state: &AppState, name: &str) -> AssetId {
+        register_model(&state.services, name, "1.0.0").await.id
+    }
+
+    async fn next_chunk(body: &mut Body) -> String {
+        let frame = tokio::time::timeout(Duration::from_secs(5), body.frame())
+            .await
+            .expect("timed out waiting for event")
+            .unwrap()
+            .unwrap();
+        let data: Bytes = frame.into_data().unwrap();
+        String::from_utf8(data.to_vec()).unwrap()
+    }
+
+    fn stream_request(uri: &str, last_event_id: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().uri(uri);
+        if let Some(id) = last_event_id {
+            builder = builder.header(LAST_EVENT_ID_HEADER, id);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn app(state: &AppState) -> Router {
+        build_router(state.clone())
+    }
+
+    #[tokio::test]
+    async fn test_stream_resumes_with_filters() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let first = register(&state, "first-model").await;
+        let results = state
+            .services
+            .events()
+            .query_events(EventQuery::new())
+            .await
+            .unwrap();
+        let latest = results.events[0].event_id.clone();
+        let second = register(&state, "second-model").await;
+
+        // No execution headers, which EventSource cannot send
+        let uri = format!(
+            "/v1/events/stream?asset_id={}&event_type=asset_registered",
+            second
+        );
+        let response = app(&state)
+            .oneshot(stream_request(&uri, Some(&latest)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/event-stream"
+        );
+
+        let mut body = response.into_body();
+        let chunk = next_chunk(&mut body).await;
+        assert!(chunk.contains("event: asset_registered"), "{}", chunk);
+        assert!(chunk.contains(&second.to_string()), "{}", chunk);
+        assert!(!chunk.contains(&first.to_string()), "{}", chunk);
+    }
+
+    #[tokio::test]
+    async fn test_stream_rejects_unknown_last_event_id() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let response = app(&state)
+            .oneshot(stream_request("/v1/events/stream", Some("no-such-event")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app(&state)
+            .oneshot(stream_request("/v1/events/stream?asset_id=bogus", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_requires_permission() {
+        let (app, with_role) = auth_router();
+        let send = |role: Option<&str>| {
+            let mut request = stream_request("/v1/events/stream", None);
+            if let Some(role) = role {
+                request = with_role(request, role);
+            }
+            app.clone().oneshot(request)
+        };
+
+        let response = send(None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = send(Some("viewer")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = send(Some("auditor")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_accepts_query_token() {
+        let (app, with_role) = auth_router();
+        let token = |role: &str| {
+            let request = with_role(stream_request("/", None), role);
+            let header = request.headers()[header::AUTHORIZATION].to_str().unwrap();
+            header.trim_start_matches("Bearer ").to_string()
+        };
+        let send = |token: &str| {
+            let uri = format!("/v1/events/stream?access_token={}", token);
+            app.clone().oneshot(stream_request(&uri, None))
+        };
+
+        // EventSource cannot set headers, so the token goes in the query
+        let response = send(&token("auditor")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send(&token("viewer")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = send("invalid.token.here").await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
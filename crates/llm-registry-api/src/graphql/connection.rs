@@ -0,0 +1,428 @@
+//! Relay connections
+//!
+//! List fields return Relay `Connection` types, paged forward with
+//! `first`/`after` or backward with `last`/`before`. Asset searches and
+//! event histories page through the store with keyset cursors; short lists
+//! that are loaded in full, such as an asset's versions and dependents, are
+//! sliced in memory and use asset IDs as cursors.
+
+use async_graphql::connection::{Connection, Edge};
+use async_graphql::{Result, SimpleObject};
+use llm_registry_core::{Asset, RegistryEvent};
+use llm_registry_service::{Cursor, EventQuery, ServiceRegistry, SortField, SortOrder};
+
+use super::types::{GqlAsset, GqlAssetEvent};
+use crate::error::ApiError;
+
+/// Page size when neither `first` nor `last` is given
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Largest page a connection returns
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Fields added to every connection
+#[derive(SimpleObject, Default)]
+pub struct ConnectionFields {
+    /// Total number of items, computed only when selected
+    pub total_count: Option<i64>,
+}
+
+/// Paginated assets
+pub type GqlAssetConnection = Connection<String, GqlAsset, ConnectionFields>;
+
+/// Paginated registry events
+pub type GqlAssetEventConnection = Connection<String, GqlAssetEvent, ConnectionFields>;
+
+/// A requested page: its direction, cursor and size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRequest {
+    /// `first` items after the `after` cursor
+    Forward {
+        /// Cursor the page starts after
+        after: Option<String>,
+        /// Number of items
+        size: usize,
+    },
+    /// `last` items before the `before` cursor
+    Backward {
+        /// Cursor the page ends before
+        before: Option<String>,
+        /// Number of items
+        size: usize,
+    },
+}
+
+impl PageRequest {
+    /// Build a page request from connection arguments
+    ///
+    /// Sizes are capped at [`MAX_PAGE_SIZE`]. Paging needs a single
+    /// direction, so `first` with `last` and `after` with `before` are
+    /// rejected.
+    pub fn new(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Self> {
+        if first.is_some_and(|n| n < 0) || last.is_some_and(|n| n < 0) {
+            return Err(ApiError::bad_request("`first` and `last` must not be negative").into());
+        }
+        if first.is_some() && last.is_some() {
+            return Err(ApiError::bad_request("`first` and `last` cannot be combined").into());
+        }
+        if after.is_some() && before.is_some() {
+            return Err(ApiError::bad_request("`after` and `before` cannot be combined").into());
+        }
+
+        let size = page_size(first, last);
+        if last.is_some() || (before.is_some() && first.is_none()) {
+            Ok(Self::Backward { before, size })
+        } else {
+            Ok(Self::Forward { after, size })
+        }
+    }
+
+    /// Number of items in the page
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Forward { size, .. } | Self::Backward { size, .. } => *size,
+        }
+    }
+}
+
+/// Page size for connection arguments, also used as the complexity factor
+pub fn page_size(first: Option<i32>, last: Option<i32>) -> usize {
+    first.or(last).map_or(DEFAULT_PAGE_SIZE, |n| {
+        (n.max(0) as usize).min(MAX_PAGE_SIZE)
+    })
+}
+
+/// Decode a keyset cursor argument
+pub fn decode_cursor(token: &str) -> Result<Cursor> {
+    Cursor::decode(token).map_err(|_| ApiError::bad_request("Invalid cursor").into())
+}
+
+/// Cursor of an asset in the newest-first order used by `assets`
+pub fn asset_cursor(asset: &Asset) -> String {
+    Cursor::for_asset(
+        asset,
+        SortField::CreatedAt.into(),
+        SortOrder::Descending.into(),
+    )
+    .encode()
+}
+
+/// Cursor of an event in the newest-first order used by `events`
+pub fn event_cursor(event: &RegistryEvent) -> String {
+    Cursor::for_event(event).encode()
+}
+
+/// Build an asset connection from a page of assets
+pub fn asset_connection(
+    assets: Vec<Asset>,
+    has_previous_page: bool,
+    has_next_page: bool,
+    total_count: Option<i64>,
+    cursor: impl Fn(&Asset) -> String,
+) -> GqlAssetConnection {
+    let mut connection = Connection::with_additional_fields(
+        has_previous_page,
+        has_next_page,
+        ConnectionFields { total_count },
+    );
+    connection.edges.extend(
+        assets
+            .into_iter()
+            .map(|asset| Edge::new(cursor(&asset), GqlAsset(asset))),
+    );
+    connection
+}
+
+/// Build an event connection from a page of events
+pub fn event_connection(
+    events: Vec<RegistryEvent>,
+    has_previous_page: bool,
+    has_next_page: bool,
+    total_count: Option<i64>,
+) -> GqlAssetEventConnection {
+    let mut connection = Connection::with_additional_fields(
+        has_previous_page,
+        has_next_page,
+        ConnectionFields { total_count },
+    );
+    connection.edges.extend(
+        events
+            .into_iter()
+            .map(|event| Edge::new(event_cursor(&event), GqlAssetEvent(event))),
+    );
+    connection
+}
+
+/// Fetch a page of the events matching a filter from the event log
+pub async fn query_event_page(
+    services: &ServiceRegistry,
+    query: EventQuery,
+    page: PageRequest,
+) -> Result<GqlAssetEventConnection> {
+    let mut query = query.limit(page.size() as i64);
+
+    // Backward pages are read oldest first from the reversed cursor
+    query = match &page {
+        PageRequest::Forward { after, .. } => match after {
+            Some(after) => query.cursor(decode_cursor(after)?),
+            None => query,
+        },
+        PageRequest::Backward { before, .. } => {
+            query = query.sort_order(SortOrder::Ascending.into());
+            match before {
+                Some(before) => query.cursor(decode_cursor(before)?.reversed()),
+                None => query,
+            }
+        }
+    };
+
+    let results = services
+        .events()
+        .query_events(query)
+        .await
+        .map_err(ApiError::from)?;
+    let has_more = results.next_cursor.is_some();
+    let mut events = results.events;
+
+    Ok(match page {
+        PageRequest::Forward { after, .. } => {
+            event_connection(events, after.is_some(), has_more, results.total)
+        }
+        PageRequest::Backward { before, .. } => {
+            events.reverse();
+            event_connection(events, has_more, before.is_some(), results.total)
+        }
+    })
+}
+
+/// Page through a fully loaded list of assets, using asset IDs as cursors
+pub fn slice_assets(assets: Vec<Asset>, page: &PageRequest) -> Result<GqlAssetConnection> {
+    let len = assets.len();
+    let position = |cursor: &str| {
+        assets
+            .iter()
+            .position(|asset| asset.id.to_string() == cursor)
+            .ok_or_else(|| ApiError::bad_request(format!("Unknown cursor: {}", cursor)))
+    };
+
+    let (start, end) = match page {
+        PageRequest::Forward { after, size } => {
+            let start = match after {
+                Some(cursor) => position(cursor)? + 1,
+                None => 0,
+            };
+            (start, (start + size).min(len))
+        }
+        PageRequest::Backward { before, size } => {
+            let end = match before {
+                Some(cursor) => position(cursor)?,
+                None => len,
+            };
+            (end.saturating_sub(*size), end)
+        }
+    };
+
+    let page: Vec<Asset> = assets.into_iter().skip(start).take(end - start).collect();
+    Ok(asset_connection(
+        page,
+        start > 0,
+        end < len,
+        Some(len as i64),
+        |asset| asset.id.to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql::{build_schema, AppSchema};
+    use llm_registry_core::AssetId;
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::{EventQuery, ServiceRegistry, UpdateAssetRequest};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    async fn register(services: &ServiceRegistry, name: &str) -> AssetId {
+        // Keep creation times distinct so the expected order is unambiguous
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        register_model(services, name, "1.0.0").await.id
+    }
+
+    async fn update(services: &ServiceRegistry, asset_id: AssetId, description: &str) {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        services
+            .registration()
+            .update_asset(
+                serde_json::from_value::<UpdateAssetRequest>(serde_json::json!({
+                    "asset_id": asset_id,
+                    "description": description,
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn run(schema: &AppSchema, query: String) -> serde_json::Value {
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
+    fn names(connection: &serde_json::Value) -> Vec<&str> {
+        connection["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|edge| edge["node"]["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_page_request() {
+        assert_eq!(
+            PageRequest::new(None, None, None, None).unwrap(),
+            PageRequest::Forward {
+                after: None,
+                size: DEFAULT_PAGE_SIZE
+            }
+        );
+        assert_eq!(
+            PageRequest::new(Some(500), Some("a".into()), None, None).unwrap(),
+            PageRequest::Forward {
+                after: Some("a".into()),
+                size: MAX_PAGE_SIZE
+            }
+        );
+        assert_eq!(
+            PageRequest::new(None, None, None, Some("b".into())).unwrap(),
+            PageRequest::Backward {
+                before: Some("b".into()),
+                size: DEFAULT_PAGE_SIZE
+            }
+        );
+        assert_eq!(
+            PageRequest::new(None, None, Some(2), None).unwrap().size(),
+            2
+        );
+
+        assert!(PageRequest::new(Some(-1), None, None, None).is_err());
+        assert!(PageRequest::new(Some(1), None, Some(1), None).is_err());
+        assert!(PageRequest::new(None, Some("a".into()), None, Some("b".into())).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_assets_page_forward_and_backward() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        for name in ["a", "b", "c"] {
+            register(&services, name).await;
+        }
+        let schema = build_schema(services);
+        let fields = "totalCount edges { cursor node { name } } \
+                      pageInfo { hasPreviousPage hasNextPage startCursor endCursor }";
+
+        let data = run(&schema, format!("{{ assets(first: 2) {{ {} }} }}", fields)).await;
+        let page = &data["assets"];
+        assert_eq!(names(page), ["c", "b"]);
+        assert_eq!(page["totalCount"], 3);
+        assert_eq!(page["pageInfo"]["hasNextPage"], true);
+        assert_eq!(page["pageInfo"]["hasPreviousPage"], false);
+        let b = page["pageInfo"]["endCursor"].as_str().unwrap().to_string();
+
+        let data = run(
+            &schema,
+            format!("{{ assets(first: 2, after: \"{}\") {{ {} }} }}", b, fields),
+        )
+        .await;
+        assert_eq!(names(&data["assets"]), ["a"]);
+        assert_eq!(data["assets"]["pageInfo"]["hasNextPage"], false);
+        assert_eq!(data["assets"]["pageInfo"]["hasPreviousPage"], true);
+
+        let data = run(&schema, format!("{{ assets(last: 2) {{ {} }} }}", fields)).await;
+        assert_eq!(names(&data["assets"]), ["b", "a"]);
+        assert_eq!(data["assets"]["pageInfo"]["hasPreviousPage"], true);
+        assert_eq!(data["assets"]["pageInfo"]["startCursor"], b.as_str());
+
+        let data = run(
+            &schema,
+            format!("{{ assets(last: 2, before: \"{}\") {{ {} }} }}", b, fields),
+        )
+        .await;
+        assert_eq!(names(&data["assets"]), ["c"]);
+        assert_eq!(data["assets"]["pageInfo"]["hasPreviousPage"], false);
+        assert_eq!(data["assets"]["pageInfo"]["hasNextPage"], true);
+
+        // totalCount is not computed unless selected
+        let data = run(&schema, "{ assets { edges { cursor } } }".to_string()).await;
+        assert_eq!(data["assets"]["edges"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_asset_events_page_backward() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let asset_id = register(&services, "model").await;
+        update(&services, asset_id, "first").await;
+        update(&services, asset_id, "second").await;
+
+        let all: Vec<String> = services
+            .events()
+            .query_events(EventQuery::new().asset_id(asset_id))
+            .await
+            .unwrap()
+            .events
+            .into_iter()
+            .map(|event| event.event_id)
+            .collect();
+        let n = all.len();
+        assert!(n >= 3);
+        let schema = build_schema(services);
+
+        let event_ids = |connection: &serde_json::Value| -> Vec<String> {
+            connection["edges"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|edge| edge["node"]["eventId"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        let data = run(
+            &schema,
+            format!(
+                r#"{{ asset(id: "{}") {{ events(last: 2) {{
+                    edges {{ node {{ eventId }} }}
+                    pageInfo {{ hasPreviousPage startCursor }}
+                }} }} }}"#,
+                asset_id
+            ),
+        )
+        .await;
+        let events = &data["asset"]["events"];
+        assert_eq!(event_ids(events), all[n - 2..]);
+        assert_eq!(events["pageInfo"]["hasPreviousPage"], true);
+        let cursor = events["pageInfo"]["startCursor"].as_str().unwrap();
+
+        let data = run(
+            &schema,
+            format!(
+                r#"{{ asset(id: "{}") {{ events(last: {}, before: "{}") {{
+                    totalCount
+                    edges {{ node {{ eventId }} }}
+                    pageInfo {{ hasPreviousPage hasNextPage }}
+                }} }} }}"#,
+                asset_id, n, cursor
+            ),
+        )
+        .await;
+        let events = &data["asset"]["events"];
+        assert_eq!(events["totalCount"], n);
+        assert_eq!(event_ids(events), all[..n - 2]);
+        assert_eq!(events["pageInfo"]["hasPreviousPage"], false);
+        assert_eq!(events["pageInfo"]["hasNextPage"], true);
+    }
+}
@@ -0,0 +1,310 @@
+//! GraphQL DataLoaders
+//!
+//! Relationship fields on `Asset` resolve through these loaders, so a query
+//! touching many assets issues one batched lookup per relationship instead
+//! of one per asset. Loaders are registered without a cache: they batch the
+//! keys requested while a query executes, and never serve stale results
+//! across queries.
+
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::Error;
+use futures::future::try_join_all;
+use llm_registry_core::{Asset, AssetId, RegistryEvent};
+use llm_registry_service::{EventQuery, ServiceRegistry};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::connection::MAX_PAGE_SIZE;
+use crate::error::ApiError;
+
+/// Loads assets by ID
+pub struct AssetLoader {
+    services: Arc<ServiceRegistry>,
+}
+
+impl Loader<AssetId> for AssetLoader {
+    type Value = Asset;
+    type Error = Error;
+
+    async fn load(&self, keys: &[AssetId]) -> Result<HashMap<AssetId, Asset>, Error> {
+        let assets = self
+            .services
+            .search()
+            .get_assets(keys)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(assets.into_iter().map(|asset| (asset.id, asset)).collect())
+    }
+}
+
+/// Loads the IDs of an asset's direct dependencies
+pub struct DependencyLoader {
+    services: Arc<ServiceRegistry>,
+}
+
+impl Loader<AssetId> for DependencyLoader {
+    type Value = Vec<AssetId>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[AssetId]) -> Result<HashMap<AssetId, Vec<AssetId>>, Error> {
+        let links = self
+            .services
+            .search()
+            .get_dependency_links(keys)
+            .await
+            .map_err(ApiError::from)?;
+
+        let mut dependencies: HashMap<AssetId, Vec<AssetId>> = HashMap::new();
+        for link in links {
+            dependencies
+                .entry(link.asset_id)
+                .or_default()
+                .push(link.dependency_id);
+        }
+        Ok(dependencies)
+    }
+}
+
+/// Loads the IDs of assets directly depending on an asset
+pub struct DependentLoader {
+    services: Arc<ServiceRegistry>,
+}
+
+impl Loader<AssetId> for DependentLoader {
+    type Value = Vec<AssetId>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[AssetId]) -> Result<HashMap<AssetId, Vec<AssetId>>, Error> {
+        let links = self
+            .services
+            .search()
+            .get_reverse_dependency_links(keys)
+            .await
+            .map_err(ApiError::from)?;
+
+        let mut dependents: HashMap<AssetId, Vec<AssetId>> = HashMap::new();
+        for link in links {
+            dependents
+                .entry(link.dependency_id)
+                .or_default()
+                .push(link.asset_id);
+        }
+        Ok(dependents)
+    }
+}
+
+/// Loads all versions of an asset by name, highest version first
+pub struct VersionsLoader {
+    services: Arc<ServiceRegistry>,
+}
+
+impl Loader<String> for VersionsLoader {
+    type Value = Vec<Asset>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[String]) -> Result<HashMap<String, Vec<Asset>>, Error> {
+        let assets = self
+            .services
+            .search()
+            .get_assets_by_names(keys)
+            .await
+            .map_err(ApiError::from)?;
+
+        let mut versions: HashMap<String, Vec<Asset>> = HashMap::new();
+        for asset in assets {
+            versions
+                .entry(asset.metadata.name.clone())
+                .or_default()
+                .push(asset);
+        }
+        for assets in versions.values_mut() {
+            assets.sort_by(|a, b| b.metadata.version.cmp(&a.metadata.version));
+        }
+        Ok(versions)
+    }
+}
+
+/// Loads the first page of an asset's events, newest first
+///
+/// One event more than the largest page is loaded, so any first page can
+/// tell whether another follows. The event log is queried per asset,
+/// concurrently; the loader still collapses repeated requests for the same
+/// asset within a query.
+pub struct EventsLoader {
+    services: Arc<ServiceRegistry>,
+}
+
+impl Loader<AssetId> for EventsLoader {
+    type Value = Vec<RegistryEvent>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[AssetId]) -> Result<HashMap<AssetId, Vec<RegistryEvent>>, Error> {
+        let pages = try_join_all(keys.iter().map(|id| {
+            let query = EventQuery::new()
+                .asset_id(*id)
+                .limit(MAX_PAGE_SIZE as i64 + 1)
+                .include_total(false);
+            async move {
+                let results = self.services.events().query_events(query).await?;
+                Ok::<_, llm_registry_service::ServiceError>((*id, results.events))
+            }
+        }))
+        .await
+        .map_err(ApiError::from)?;
+
+        Ok(pages.into_iter().collect())
+    }
+}
+
+/// DataLoaders shared by a schema
+pub struct Loaders {
+    /// Assets by ID
+    pub assets: DataLoader<AssetLoader>,
+    /// Dependency IDs by asset ID
+    pub dependencies: DataLoader<DependencyLoader>,
+    /// Dependent IDs by asset ID
+    pub dependents: DataLoader<DependentLoader>,
+    /// Versions by asset name
+    pub versions: DataLoader<VersionsLoader>,
+    /// First page of events by asset ID
+    pub events: DataLoader<EventsLoader>,
+}
+
+impl Loaders {
+    /// Create loaders over a service registry
+    pub fn new(services: Arc<ServiceRegistry>) -> Self {
+        Self {
+            assets: DataLoader::new(
+                AssetLoader {
+                    services: services.clone(),
+                },
+                tokio::spawn,
+            ),
+            dependencies: DataLoader::new(
+                DependencyLoader {
+                    services: services.clone(),
+                },
+                tokio::spawn,
+            ),
+            dependents: DataLoader::new(
+                DependentLoader {
+                    services: services.clone(),
+                },
+                tokio::spawn,
+            ),
+            versions: DataLoader::new(
+                VersionsLoader {
+                    services: services.clone(),
+                },
+                tokio::spawn,
+            ),
+            events: DataLoader::new(EventsLoader { services }, tokio::spawn),
+        }
+    }
+
+    /// Load assets by ID, keeping the order of `ids` and skipping missing ones
+    pub async fn load_assets(&self, ids: Vec<AssetId>) -> Result<Vec<Asset>, Error> {
+        let mut found = self.assets.load_many(ids.iter().copied()).await?;
+        Ok(ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graphql::{build_schema, build_schema_with_limits, SchemaLimits};
+    use llm_registry_core::AssetReference;
+    use llm_registry_service::test_support::register_request;
+    use llm_registry_service::{RegisterAssetRequest, ServiceRegistry};
+    use std::sync::Arc;
+
+    async fn register(
+        services: &ServiceRegistry,
+        name: &str,
+        version: &str,
+        dependencies: Vec<AssetReference>,
+    ) -> String {
+        services
+            .registration()
+            .register_asset(RegisterAssetRequest {
+                dependencies,
+                ..register_request(name, version)
+            })
+            .await
+            .unwrap()
+            .asset
+            .id
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_nested_relationship_fields() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let base = register(&services, "base", "1.0.0", vec![]).await;
+        register(&services, "base", "1.1.0", vec![]).await;
+        let app = register(
+            &services,
+            "app",
+            "1.0.0",
+            vec![AssetReference::by_id(base.parse().unwrap())],
+        )
+        .await;
+
+        let schema = build_schema(services);
+        let query = format!(
+            r#"{{
+                asset(id: "{}") {{
+                    dependencies {{
+                        name
+                        version
+                        latestVersion {{ version }}
+                        versions {{ totalCount nodes {{ version }} }}
+                        dependents {{ nodes {{ id }} }}
+                        events(first: 1) {{
+                            edges {{ node {{ eventType }} }}
+                            pageInfo {{ hasNextPage }}
+                        }}
+                    }}
+                }}
+            }}"#,
+            app
+        );
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let data = response.data.into_json().unwrap();
+        let dependency = &data["asset"]["dependencies"][0];
+        assert_eq!(dependency["name"], "base");
+        assert_eq!(dependency["version"], "1.0.0");
+        assert_eq!(dependency["latestVersion"]["version"], "1.1.0");
+        assert_eq!(dependency["versions"]["totalCount"], 2);
+        assert_eq!(dependency["versions"]["nodes"][1]["version"], "1.0.0");
+        assert_eq!(dependency["dependents"]["nodes"][0]["id"], app.as_str());
+        let events = &dependency["events"];
+        assert_eq!(events["edges"].as_array().unwrap().len(), 1);
+        assert_eq!(events["pageInfo"]["hasNextPage"], true);
+    }
+
+    #[tokio::test]
+    async fn test_depth_and_complexity_limits() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let schema = build_schema_with_limits(
+            services,
+            SchemaLimits {
+                max_depth: 3,
+                max_complexity: 100,
+            },
+        );
+
+        let deep = r#"{ assets { nodes { dependencies { dependencies { id } } } } }"#;
+        let response = schema.execute(deep).await;
+        assert!(response.errors[0].message.contains("nested too deep"));
+
+        let costly = r#"{ assets(first: 500) { nodes { id } } }"#;
+        let response = schema.execute(costly).await;
+        assert!(response.errors[0].message.contains("too complex"));
+
+        let response = schema.execute(r#"{ assets { nodes { id } } }"#).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+}
@@ -1,33 +1,115 @@
 //! GraphQL API implementation
 //!
 //! This module provides a complete GraphQL API for the LLM Registry using async-graphql.
-//! It supports queries, mutations, authentication, and includes a GraphQL Playground.
+//! It supports queries, mutations, subscriptions over WebSocket, authentication,
+//! and includes a GraphQL Playground.
 
+pub mod connection;
+pub mod loaders;
 pub mod mutation;
 pub mod query;
+pub mod subscription;
 pub mod types;
+pub mod ws;
 
-use async_graphql::{http::GraphiQLSource, EmptySubscription, Schema};
+use async_graphql::{http::GraphiQLSource, Context, Schema, SchemaBuilder};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
-use axum::{extract::State, response::{Html, IntoResponse}, Extension};
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+    Extension,
+};
 use llm_registry_service::ServiceRegistry;
 use std::sync::Arc;
 
-use crate::auth::AuthUser;
+use crate::auth::{AuthError, AuthState, AuthUser};
+use crate::error::ApiError;
+use crate::rbac::Permission;
 
+pub use loaders::Loaders;
 pub use mutation::Mutation;
 pub use query::Query;
+pub use subscription::Subscription;
+pub use ws::{graphql_ws_handler, GraphQLWsState};
 
 /// GraphQL schema type
-pub type AppSchema = Schema<Query, Mutation, EmptySubscription>;
+pub type AppSchema = Schema<Query, Mutation, Subscription>;
+
+/// Default maximum query depth
+pub const DEFAULT_MAX_DEPTH: usize = 10;
+
+/// Default maximum query complexity
+pub const DEFAULT_MAX_COMPLEXITY: usize = 5000;
+
+/// Limits rejecting expensive queries before they execute
+///
+/// Each selected field costs one unit; connection fields multiply the cost
+/// of their selection by the page size.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaLimits {
+    /// Maximum nesting depth of a query
+    pub max_depth: usize,
+
+    /// Maximum total complexity of a query
+    pub max_complexity: usize,
+}
+
+impl Default for SchemaLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_complexity: DEFAULT_MAX_COMPLEXITY,
+        }
+    }
+}
 
 /// Build the GraphQL schema
 pub fn build_schema(services: Arc<ServiceRegistry>) -> AppSchema {
-    Schema::build(Query, Mutation, EmptySubscription)
-        .data(services)
+    build_schema_with_limits(services, SchemaLimits::default())
+}
+
+/// Build the GraphQL schema with custom query limits
+pub fn build_schema_with_limits(services: Arc<ServiceRegistry>, limits: SchemaLimits) -> AppSchema {
+    schema_builder(services, limits).finish()
+}
+
+/// Build the GraphQL schema with permission checks
+///
+/// Permission-gated fields, such as the event log, then require a request
+/// user whose roles the RBAC policy of `auth_state` grants the permission.
+/// Schemas built without it leave those fields open.
+pub fn build_schema_with_auth(services: Arc<ServiceRegistry>, auth_state: AuthState) -> AppSchema {
+    schema_builder(services, SchemaLimits::default())
+        .data(auth_state)
         .finish()
 }
 
+fn schema_builder(
+    services: Arc<ServiceRegistry>,
+    limits: SchemaLimits,
+) -> SchemaBuilder<Query, Mutation, Subscription> {
+    Schema::build(Query, Mutation, Subscription)
+        .data(Loaders::new(services.clone()))
+        .data(services)
+        .limit_depth(limits.max_depth)
+        .limit_complexity(limits.max_complexity)
+}
+
+/// Check that the request user holds a permission, if the schema has auth
+pub(crate) fn authorize(ctx: &Context<'_>, permission: &Permission) -> async_graphql::Result<()> {
+    let Some(auth_state) = ctx.data_opt::<AuthState>() else {
+        return Ok(());
+    };
+
+    auth_state
+        .authorize(ctx.data_opt::<AuthUser>(), permission)
+        .map_err(|e| match e {
+            AuthError::InsufficientPermissions => ApiError::forbidden(e.to_string()),
+            _ => ApiError::unauthorized(e.to_string()),
+        })?;
+    Ok(())
+}
+
 /// GraphQL handler with optional authentication
 pub async fn graphql_handler(
     State(schema): State<AppSchema>,
@@ -49,6 +131,7 @@ pub async fn graphql_playground() -> impl IntoResponse {
     Html(
         GraphiQLSource::build()
             .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
             .title("LLM Registry GraphQL Playground")
             .finish(),
     )
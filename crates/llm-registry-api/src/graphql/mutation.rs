@@ -4,7 +4,7 @@
 
 use async_graphql::{Context, InputObject, Object, Result};
 use llm_registry_core::{
-    AssetId, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+    AssetId, AssetSignature, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
 };
 use llm_registry_service::{RegisterAssetRequest, ServiceRegistry, UpdateAssetRequest};
 use semver::Version;
@@ -54,6 +54,10 @@ pub struct RegisterAssetInput {
     pub size_bytes: Option<u64>,
     /// Content type
     pub content_type: Option<String>,
+    /// Signing key ID (required when a signature is provided)
+    pub signature_key_id: Option<String>,
+    /// Base64-encoded Ed25519 signature over the asset's signing payload
+    pub signature_value: Option<String>,
 }
 
 /// Input for updating an asset
@@ -79,6 +83,9 @@ pub struct UpdateAssetInput {
     /// Annotation keys to remove
     #[graphql(default)]
     pub remove_annotations: Vec<String>,
+    /// Revision the update is based on; the update fails if the asset has
+    /// been modified since
+    pub expected_revision: Option<u64>,
 }
 
 /// Annotation key-value pair
@@ -112,7 +119,7 @@ impl Mutation {
             "SHA256" => HashAlgorithm::SHA256,
             "SHA3_256" | "SHA3-256" => HashAlgorithm::SHA3_256,
             "BLAKE3" => HashAlgorithm::BLAKE3,
-            _ => return Err(ApiError::bad_request("Invalid checksum algorithm"))?
+            _ => return Err(ApiError::bad_request("Invalid checksum algorithm"))?,
         };
 
         // Create storage backend
@@ -138,6 +145,20 @@ impl Mutation {
             value: input.checksum,
         };
 
+        // Create signature
+        let signature = match (input.signature_key_id, input.signature_value) {
+            (Some(key_id), Some(value)) => Some(
+                AssetSignature::ed25519(key_id, value)
+                    .map_err(|e| ApiError::bad_request(format!("Invalid signature: {}", e)))?,
+            ),
+            (None, None) => None,
+            _ => {
+                return Err(ApiError::bad_request(
+                    "signatureKeyId and signatureValue must be provided together",
+                ))?
+            }
+        };
+
         // Build registration request
         let request = RegisterAssetRequest {
             asset_type: input.asset_type.to_core(),
@@ -157,6 +178,7 @@ impl Mutation {
             dependencies: vec![],
             size_bytes: input.size_bytes,
             content_type: input.content_type,
+            signature,
         };
 
         let response = services
@@ -202,6 +224,7 @@ impl Mutation {
                 .map(|a| (a.key, a.value))
                 .collect(),
             remove_annotations: input.remove_annotations,
+            expected_revision: input.expected_revision,
         };
 
         let response = services
@@ -216,8 +239,53 @@ impl Mutation {
         })
     }
 
+    /// Deprecate an asset version
+    ///
+    /// The alternative must be another registered version of the same asset.
+    async fn deprecate_asset(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        reason: Option<String>,
+        #[graphql(desc = "Version of the same asset to use instead")] alternative: Option<String>,
+    ) -> Result<GqlUpdateResult> {
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+
+        // Check authentication (optional - can be made required)
+        let _user = ctx.data_opt::<AuthUser>();
+
+        // Parse asset ID
+        let asset_id = id
+            .parse::<AssetId>()
+            .map_err(|e| ApiError::bad_request(format!("Invalid asset ID: {}", e)))?;
+
+        let alternative = alternative
+            .map(|v| Version::parse(&v))
+            .transpose()
+            .map_err(|e| ApiError::bad_request(format!("Invalid alternative version: {}", e)))?;
+
+        let asset = services
+            .versioning()
+            .deprecate_version(&asset_id, reason, alternative)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(GqlUpdateResult {
+            asset: GqlAsset(asset),
+            message: "Asset deprecated successfully".to_string(),
+        })
+    }
+
     /// Delete an asset
-    async fn delete_asset(&self, ctx: &Context<'_>, id: String) -> Result<GqlDeleteResult> {
+    ///
+    /// With an expected revision, the deletion fails if the asset has been
+    /// modified since.
+    async fn delete_asset(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        expected_revision: Option<u64>,
+    ) -> Result<GqlDeleteResult> {
         let services = ctx.data::<Arc<ServiceRegistry>>()?;
 
         // Check authentication (optional - can be made required)
@@ -230,7 +298,7 @@ impl Mutation {
 
         services
             .registration()
-            .delete_asset(&asset_id)
+            .delete_asset(&asset_id, expected_revision)
             .await
             .map_err(|e| ApiError::from(e))?;
 
@@ -3,12 +3,23 @@
 //! This module implements all GraphQL query operations.
 
 use async_graphql::{Context, Object, Result};
+use chrono::{DateTime, Utc};
 use llm_registry_core::AssetId;
-use llm_registry_service::{SearchAssetsRequest, ServiceRegistry, SortField, SortOrder};
+use llm_registry_service::{
+    EventQuery, ListVersionsRequest, SearchAssetsRequest, ServiceRegistry, SortField, SortOrder,
+};
+use semver::VersionReq;
 use std::sync::Arc;
 
-use super::types::{GqlAsset, GqlAssetConnection, GqlAssetFilter, GqlDependencyNode};
+use super::authorize;
+use super::connection::{
+    asset_connection, asset_cursor, decode_cursor, page_size, query_event_page, slice_assets,
+    GqlAssetConnection, GqlAssetEventConnection, PageRequest,
+};
+use super::loaders::Loaders;
+use super::types::{GqlAsset, GqlAssetFilter, GqlDependencyNode, GqlEventFilter};
 use crate::error::ApiError;
+use crate::rbac::Permission;
 
 /// Root Query type for GraphQL
 pub struct Query;
@@ -32,15 +43,33 @@ impl Query {
         Ok(asset.map(GqlAsset))
     }
 
-    /// Search and list assets with optional filters
+    /// Search and list assets with optional filters, newest first
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    #[allow(clippy::too_many_arguments)]
     async fn assets(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "Filter criteria", default)] filter: Option<GqlAssetFilter>,
-        #[graphql(desc = "Number of items to return", default = 20)] limit: i64,
-        #[graphql(desc = "Number of items to skip", default = 0)] offset: i64,
+        #[graphql(desc = "Structured query, e.g. `tag:llama size>2GB`")] query: Option<String>,
+        #[graphql(desc = "Number of assets from the start of the page")] first: Option<i32>,
+        #[graphql(desc = "Return assets after this cursor")] after: Option<String>,
+        #[graphql(desc = "Number of assets from the end of the page")] last: Option<i32>,
+        #[graphql(desc = "Return assets before this cursor")] before: Option<String>,
     ) -> Result<GqlAssetConnection> {
         let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let page = PageRequest::new(first, after, last, before)?;
+
+        // Backward pages are read oldest first from the reversed cursor
+        let (sort_order, cursor) = match &page {
+            PageRequest::Forward { after, .. } => (SortOrder::Descending, after.clone()),
+            PageRequest::Backward { before, .. } => (
+                SortOrder::Ascending,
+                before
+                    .as_deref()
+                    .map(|before| decode_cursor(before).map(|c| c.reversed().encode()))
+                    .transpose()?,
+            ),
+        };
 
         // Build search request
         let mut search_request = SearchAssetsRequest {
@@ -49,11 +78,16 @@ impl Query {
             tags: vec![],
             author: None,
             storage_backend: None,
+            query,
             exclude_deprecated: true,
-            limit,
-            offset,
+            limit: page.size() as i64,
+            offset: 0,
+            cursor,
+            // Only count matches when the total is selected
+            include_total: ctx.look_ahead().field("totalCount").exists(),
             sort_by: SortField::CreatedAt,
-            sort_order: SortOrder::Descending,
+            sort_order,
+            ..Default::default()
         };
 
         // Apply filters if provided
@@ -78,11 +112,27 @@ impl Query {
             .search_assets(search_request)
             .await
             .map_err(|e| ApiError::from(e))?;
+        let has_more = response.next_cursor.is_some();
+        let mut assets = response.assets;
 
-        Ok(GqlAssetConnection {
-            nodes: response.assets.into_iter().map(GqlAsset).collect(),
-            total_count: response.total,
-            has_next_page: (response.offset + response.limit) < response.total,
+        Ok(match page {
+            PageRequest::Forward { after, .. } => asset_connection(
+                assets,
+                after.is_some(),
+                has_more,
+                response.total,
+                asset_cursor,
+            ),
+            PageRequest::Backward { before, .. } => {
+                assets.reverse();
+                asset_connection(
+                    assets,
+                    has_more,
+                    before.is_some(),
+                    response.total,
+                    asset_cursor,
+                )
+            }
         })
     }
 
@@ -119,12 +169,18 @@ impl Query {
     }
 
     /// Get all assets that depend on this asset (reverse dependencies)
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
     async fn dependents(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "Asset ID")] id: String,
-    ) -> Result<Vec<GqlAsset>> {
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<GqlAssetConnection> {
         let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let page = PageRequest::new(first, after, last, before)?;
 
         let asset_id = id
             .parse::<AssetId>()
@@ -136,7 +192,124 @@ impl Query {
             .await
             .map_err(|e| ApiError::from(e))?;
 
-        Ok(dependents.into_iter().map(GqlAsset).collect())
+        slice_assets(dependents, &page)
+    }
+
+    /// All versions of an asset by name, highest first
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    #[allow(clippy::too_many_arguments)]
+    async fn asset_versions(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset name")] name: String,
+        #[graphql(desc = "Include deprecated versions", default)] include_deprecated: bool,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<GqlAssetConnection> {
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let page = PageRequest::new(first, after, last, before)?;
+
+        let versions = services
+            .versioning()
+            .list_versions(ListVersionsRequest {
+                name,
+                include_deprecated,
+            })
+            .await
+            .map_err(ApiError::from)?;
+
+        let ids = versions.versions.iter().map(|v| v.asset_id).collect();
+        let assets = ctx.data::<Loaders>()?.load_assets(ids).await?;
+        slice_assets(assets, &page)
+    }
+
+    /// Resolve a SemVer requirement to the highest matching version
+    ///
+    /// Active versions are preferred; deprecated ones are only considered
+    /// with `includeDeprecated`.
+    async fn resolve_version(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset name")] name: String,
+        #[graphql(desc = "SemVer requirement, e.g. `^1.2`")] requirement: String,
+        #[graphql(desc = "Consider deprecated versions", default)] include_deprecated: bool,
+    ) -> Result<Option<GqlAsset>> {
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+
+        let requirement = VersionReq::parse(&requirement)
+            .map_err(|e| ApiError::bad_request(format!("Invalid version requirement: {}", e)))?;
+
+        let asset = services
+            .versioning()
+            .resolve_version(&name, &requirement, include_deprecated)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(asset.map(GqlAsset))
+    }
+
+    /// The latest active version of an asset by name
+    async fn latest_version(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset name")] name: String,
+    ) -> Result<Option<GqlAsset>> {
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+
+        let asset = services
+            .versioning()
+            .get_latest_version(&name)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(asset.map(GqlAsset))
+    }
+
+    /// Query the registry event log, newest first
+    ///
+    /// Requires the `event:read` permission when authentication is enabled.
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    #[allow(clippy::too_many_arguments)]
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Filter criteria", default)] filter: GqlEventFilter,
+        #[graphql(desc = "Only events that occurred after this time")] occurred_after: Option<
+            DateTime<Utc>,
+        >,
+        #[graphql(desc = "Only events that occurred before this time")] occurred_before: Option<
+            DateTime<Utc>,
+        >,
+        #[graphql(desc = "Number of events from the start of the page")] first: Option<i32>,
+        #[graphql(desc = "Return events after this cursor")] after: Option<String>,
+        #[graphql(desc = "Number of events from the end of the page")] last: Option<i32>,
+        #[graphql(desc = "Return events before this cursor")] before: Option<String>,
+    ) -> Result<GqlAssetEventConnection> {
+        authorize(ctx, &Permission::read_events())?;
+
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let page = PageRequest::new(first, after, last, before)?;
+
+        let mut query =
+            EventQuery::new().include_total(ctx.look_ahead().field("totalCount").exists());
+        if let Some(id) = filter.asset_id {
+            let asset_id = id
+                .parse::<AssetId>()
+                .map_err(|e| ApiError::bad_request(format!("Invalid asset ID: {}", e)))?;
+            query = query.asset_id(asset_id);
+        }
+        for event_type in filter.event_types.unwrap_or_default() {
+            query = query.event_type(event_type);
+        }
+        if let Some(actor) = filter.actor {
+            query = query.actor(actor);
+        }
+        query.after = occurred_after;
+        query.before = occurred_before;
+
+        query_event_page(services, query, page).await
     }
 
     /// Get all unique tags across all assets
@@ -168,3 +341,127 @@ impl Query {
         env!("CARGO_PKG_VERSION").to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::{AuthState, AuthUser};
+    use crate::graphql::{build_schema, build_schema_with_auth};
+    use crate::jwt::{Claims, JwtConfig, JwtManager};
+    use async_graphql::Request;
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::ServiceRegistry;
+    use std::sync::Arc;
+
+    async fn register(services: &ServiceRegistry, version: &str) -> String {
+        register_model(services, "llama", version)
+            .await
+            .id
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_versioning_fields() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        register(&services, "1.2.0").await;
+        let v1_3 = register(&services, "1.3.0").await;
+        register(&services, "2.0.0").await;
+        let schema = build_schema(services);
+
+        let mutation = format!(
+            r#"mutation {{
+                deprecateAsset(id: "{}", reason: "Broken", alternative: "2.0.0") {{
+                    asset {{ status }}
+                }}
+            }}"#,
+            v1_3
+        );
+        let response = schema.execute(mutation).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["deprecateAsset"]["asset"]["status"], "DEPRECATED");
+
+        let query = r#"{
+            assetVersions(name: "llama", includeDeprecated: true) { totalCount nodes { version } }
+            active: resolveVersion(name: "llama", requirement: "^1.2") { version }
+            any: resolveVersion(name: "llama", requirement: "^1.2", includeDeprecated: true) { version }
+            latestVersion(name: "llama") { version }
+        }"#;
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["assetVersions"]["totalCount"], 3);
+        assert_eq!(data["assetVersions"]["nodes"][0]["version"], "2.0.0");
+        assert_eq!(data["active"]["version"], "1.2.0");
+        assert_eq!(data["any"]["version"], "1.3.0");
+        assert_eq!(data["latestVersion"]["version"], "2.0.0");
+
+        let response = schema
+            .execute(r#"{ resolveVersion(name: "llama", requirement: "bogus") { id } }"#)
+            .await;
+        assert!(!response.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_events_field() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let first = register(&services, "1.0.0").await;
+        register(&services, "1.1.0").await;
+        let schema = build_schema(services.clone());
+
+        let query = r#"{
+            events(filter: { eventTypes: ["asset_registered"] }, first: 1) {
+                totalCount
+                edges { cursor node { eventType assetId } }
+                pageInfo { hasNextPage }
+            }
+        }"#;
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["events"]["totalCount"], 2);
+        assert_eq!(data["events"]["pageInfo"]["hasNextPage"], true);
+        assert_eq!(
+            data["events"]["edges"][0]["node"]["eventType"],
+            "asset_registered"
+        );
+        assert_ne!(
+            data["events"]["edges"][0]["node"]["assetId"],
+            first.as_str()
+        );
+
+        let cursor = data["events"]["edges"][0]["cursor"].as_str().unwrap();
+        let query = format!(
+            r#"{{ events(filter: {{ eventTypes: ["asset_registered"] }}, after: "{}") {{
+                nodes {{ assetId }}
+            }} }}"#,
+            cursor
+        );
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["events"]["nodes"][0]["assetId"], first.as_str());
+
+        let query = r#"{ events(occurredAfter: "2999-01-01T00:00:00Z") { nodes { eventId } } }"#;
+        let data = schema.execute(query).await.data.into_json().unwrap();
+        assert!(data["events"]["nodes"].as_array().unwrap().is_empty());
+
+        // With auth, the event log needs the `event:read` permission
+        let jwt_manager = JwtManager::new(JwtConfig::new("test-secret-key")).unwrap();
+        let schema = build_schema_with_auth(services, AuthState::new(jwt_manager));
+        let query = "{ events { nodes { eventId } } }";
+        let user = |role: &str| {
+            AuthUser::new(Claims::new("user123", "test", "test", 3600).with_role(role))
+        };
+
+        let response = schema.execute(query).await;
+        assert_eq!(response.errors[0].message, "Authentication required");
+        let response = schema
+            .execute(Request::new(query).data(user("viewer")))
+            .await;
+        assert_eq!(response.errors[0].message, "Insufficient permissions");
+        let response = schema
+            .execute(Request::new(query).data(user("auditor")))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+}
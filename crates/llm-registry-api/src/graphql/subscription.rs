@@ -0,0 +1,323 @@
+//! GraphQL subscription resolvers
+//!
+//! This module implements live subscriptions over registry events. Each
+//! subscription follows the event service's broadcast, so slow clients are
+//! caught up from the event store rather than dropped. Subscribing requires
+//! the `event:read` permission when the schema is built with auth.
+
+use async_graphql::{Context, Result, Subscription as SubscriptionObject};
+use futures::{future, Stream, StreamExt};
+use llm_registry_core::{AssetId, EventType, RegistryEvent};
+use llm_registry_service::{EventQuery, EventSubscription, ServiceRegistry};
+use std::sync::Arc;
+
+use super::authorize;
+use super::types::{GqlAsset, GqlAssetEvent, GqlEventFilter};
+use crate::error::ApiError;
+use crate::rbac::Permission;
+
+/// Root Subscription type for GraphQL
+pub struct Subscription;
+
+#[SubscriptionObject]
+impl Subscription {
+    /// Registry events matching a filter
+    async fn asset_events(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Filter criteria", default)] filter: GqlEventFilter,
+        #[graphql(desc = "Replay events after this event before live ones")] last_event_id: Option<
+            String,
+        >,
+    ) -> Result<impl Stream<Item = Result<GqlAssetEvent>>> {
+        authorize(ctx, &Permission::read_events())?;
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+
+        let mut query = EventQuery::new();
+        if let Some(id) = filter.asset_id {
+            query = query.asset_id(parse_asset_id(&id)?);
+        }
+        for event_type in filter.event_types.unwrap_or_default() {
+            query = query.event_type(event_type);
+        }
+        if let Some(actor) = filter.actor {
+            query = query.actor(actor);
+        }
+
+        let subscription = services
+            .events()
+            .subscribe(last_event_id.as_deref())
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(registry_events(subscription)
+            .filter(move |event| future::ready(passes(event, |e| query.matches(e))))
+            .map(|event| event.map(GqlAssetEvent)))
+    }
+
+    /// Changes to a single asset: updates, status changes and deletion
+    async fn asset_changed(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset ID")] id: String,
+    ) -> Result<impl Stream<Item = Result<GqlAssetEvent>>> {
+        authorize(ctx, &Permission::read_events())?;
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let asset_id = parse_asset_id(&id)?;
+
+        let subscription = services
+            .events()
+            .subscribe(None)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(registry_events(subscription)
+            .filter(move |event| {
+                future::ready(passes(event, |e| {
+                    e.asset_id() == Some(asset_id) && is_change(&e.event_type)
+                }))
+            })
+            .map(|event| event.map(GqlAssetEvent)))
+    }
+
+    /// Newly registered versions of an asset
+    async fn new_versions(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset name")] name: String,
+    ) -> Result<impl Stream<Item = Result<GqlAsset>>> {
+        authorize(ctx, &Permission::read_events())?;
+        let services = ctx.data::<Arc<ServiceRegistry>>()?.clone();
+
+        let subscription = services
+            .events()
+            .subscribe(None)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(registry_events(subscription).filter_map(move |event| {
+            let services = services.clone();
+            let name = name.clone();
+            async move {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => return Some(Err(e)),
+                };
+                let asset_id = match &event.event_type {
+                    EventType::AssetRegistered {
+                        asset_id,
+                        asset_name,
+                        ..
+                    } if *asset_name == name => *asset_id,
+                    _ => return None,
+                };
+
+                // Skip versions deleted before the event was delivered
+                match services.search().get_asset(&asset_id).await {
+                    Ok(asset) => asset.map(|a| Ok(GqlAsset(a))),
+                    Err(e) => Some(Err(ApiError::from(e).into())),
+                }
+            }
+        }))
+    }
+}
+
+/// Stream events from a subscription, ending after the first error
+fn registry_events(subscription: EventSubscription) -> impl Stream<Item = Result<RegistryEvent>> {
+    futures::stream::unfold(Some(subscription), |state| async move {
+        let mut subscription = state?;
+        match subscription.next().await {
+            Ok(Some(event)) => Some((Ok(event), Some(subscription))),
+            Ok(None) => None,
+            Err(e) => Some((Err(ApiError::from(e).into()), None)),
+        }
+    })
+}
+
+/// Check an event against a predicate, letting errors through
+fn passes(event: &Result<RegistryEvent>, predicate: impl Fn(&RegistryEvent) -> bool) -> bool {
+    event.as_ref().map_or(true, predicate)
+}
+
+/// Whether an event changes an existing asset
+fn is_change(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::AssetUpdated { .. }
+            | EventType::AssetStatusChanged { .. }
+            | EventType::AssetDeleted { .. }
+    )
+}
+
+fn parse_asset_id(id: &str) -> Result<AssetId> {
+    id.parse::<AssetId>()
+        .map_err(|e| ApiError::bad_request(format!("Invalid asset ID: {}", e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthState;
+    use crate::graphql::ws::authenticate_connection;
+    use crate::graphql::{build_schema, build_schema_with_auth};
+    use crate::jwt::{Claims, JwtConfig, JwtManager};
+    use async_graphql::{Request, Response};
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::UpdateAssetRequest;
+    use std::time::Duration;
+
+    async fn register(services: &ServiceRegistry, name: &str, version: &str) -> AssetId {
+        register_model(services, name, version).await.id
+    }
+
+    async fn next(stream: &mut (impl Stream<Item = Response> + Unpin)) -> serde_json::Value {
+        let response = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("timed out waiting for event")
+            .unwrap();
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
+    /// Poll once so the resolver subscribes before events are produced
+    async fn start(stream: &mut (impl Stream<Item = Response> + Unpin)) {
+        let pending = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+        assert!(
+            pending.is_err(),
+            "unexpected event before any were produced"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_new_versions_and_asset_changed() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let schema = build_schema(services.clone());
+
+        let mut versions = schema.execute_stream(Request::new(
+            r#"subscription { newVersions(name: "llama") { name version } }"#,
+        ));
+        start(&mut versions).await;
+
+        register(&services, "other", "1.0.0").await;
+        let asset_id = register(&services, "llama", "2.0.0").await;
+        let data = next(&mut versions).await;
+        assert_eq!(data["newVersions"]["version"], "2.0.0");
+
+        let mut changes = schema.execute_stream(Request::new(format!(
+            r#"subscription {{ assetChanged(id: "{}") {{ eventType asset {{ description }} }} }}"#,
+            asset_id
+        )));
+        start(&mut changes).await;
+
+        services
+            .registration()
+            .update_asset(
+                serde_json::from_value::<UpdateAssetRequest>(serde_json::json!({
+                    "asset_id": asset_id,
+                    "description": "updated",
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let data = next(&mut changes).await;
+        assert_eq!(data["assetChanged"]["eventType"], "asset_updated");
+        assert_eq!(data["assetChanged"]["asset"]["description"], "updated");
+    }
+
+    #[tokio::test]
+    async fn test_asset_events_replays_with_filter() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let schema = build_schema(services.clone());
+
+        let first = register(&services, "first", "1.0.0").await;
+        let results = services
+            .events()
+            .query_events(EventQuery::new())
+            .await
+            .unwrap();
+        let latest = results.events[0].event_id.clone();
+        register(&services, "second", "1.0.0").await;
+
+        let mut stream = schema.execute_stream(Request::new(format!(
+            r#"subscription {{
+                assetEvents(
+                    filter: {{ eventTypes: ["asset_registered"] }},
+                    lastEventId: "{}"
+                ) {{ eventType assetName assetId }}
+            }}"#,
+            latest
+        )));
+        let data = next(&mut stream).await;
+        assert_eq!(data["assetEvents"]["eventType"], "asset_registered");
+        assert_eq!(data["assetEvents"]["assetName"], "second");
+        assert_ne!(data["assetEvents"]["assetId"], first.to_string());
+
+        let response = schema
+            .execute_stream(Request::new(
+                r#"subscription { assetEvents(lastEventId: "missing") { eventId } }"#,
+            ))
+            .next()
+            .await
+            .unwrap();
+        assert!(!response.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_require_permission() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let jwt_manager = JwtManager::new(
+            JwtConfig::new("test-secret-key")
+                .with_issuer("test")
+                .with_audience("test"),
+        )
+        .unwrap();
+        let auth_state = AuthState::new(jwt_manager);
+        let schema = build_schema_with_auth(services.clone(), auth_state.clone());
+        let jwt_manager = auth_state.jwt_manager();
+        // Subscribers are identified by their `connection_init` payload
+        let session = |role: Option<&str>| {
+            let payload = match role {
+                Some(role) => {
+                    let claims = Claims::new("user123", "test", "test", 3600).with_role(role);
+                    let token = jwt_manager.generate_token_with_claims(claims).unwrap();
+                    serde_json::json!({ "Authorization": format!("Bearer {}", token) })
+                }
+                None => serde_json::json!({}),
+            };
+            Arc::new(authenticate_connection(jwt_manager, &payload).unwrap())
+        };
+
+        for subscription in [
+            r#"subscription { assetEvents { eventId } }"#,
+            r#"subscription { assetChanged(id: "01HQXK7Y8Z9A0B1C2D3E4F5G6H") { eventId } }"#,
+            r#"subscription { newVersions(name: "llama") { name } }"#,
+        ] {
+            let response = schema
+                .execute_stream_with_session_data(Request::new(subscription), session(None))
+                .next()
+                .await
+                .unwrap();
+            assert_eq!(response.errors[0].message, "Authentication required");
+
+            let response = schema
+                .execute_stream_with_session_data(
+                    Request::new(subscription),
+                    session(Some("viewer")),
+                )
+                .next()
+                .await
+                .unwrap();
+            assert_eq!(response.errors[0].message, "Insufficient permissions");
+        }
+
+        let mut stream = schema.execute_stream_with_session_data(
+            Request::new(r#"subscription { newVersions(name: "llama") { version } }"#),
+            session(Some("auditor")),
+        );
+        start(&mut stream).await;
+        register(&services, "llama", "1.0.0").await;
+        let data = next(&mut stream).await;
+        assert_eq!(data["newVersions"]["version"], "1.0.0");
+    }
+}
@@ -2,10 +2,20 @@
 //!
 //! This module defines GraphQL types that wrap the core domain types.
 
-use async_graphql::{Enum, Object, SimpleObject};
+use async_graphql::{Context, Enum, Json, Object, Result, SimpleObject};
 use chrono::{DateTime, Utc};
-use llm_registry_core::{Asset, AssetStatus, AssetType};
-use llm_registry_service::DependencyNode;
+use llm_registry_core::{Asset, AssetStatus, AssetType, EventType, RegistryEvent};
+use llm_registry_service::{DependencyNode, EventQuery, ServiceRegistry};
+use std::sync::Arc;
+
+use super::authorize;
+use super::connection::{
+    event_connection, page_size, query_event_page, slice_assets, GqlAssetConnection,
+    GqlAssetEventConnection, PageRequest,
+};
+use super::loaders::Loaders;
+use crate::error::ApiError;
+use crate::rbac::Permission;
 
 /// GraphQL representation of an Asset
 #[derive(Clone)]
@@ -96,6 +106,16 @@ impl GqlAsset {
         &self.0.checksum.value
     }
 
+    /// Signature algorithm (if signed)
+    async fn signature_algorithm(&self) -> Option<String> {
+        self.0.signature.as_ref().map(|s| s.algorithm.to_string())
+    }
+
+    /// Signing key ID (if signed)
+    async fn signature_key_id(&self) -> Option<&str> {
+        self.0.signature.as_ref().map(|s| s.key_id.as_str())
+    }
+
     /// Number of dependencies
     async fn dependency_count(&self) -> usize {
         self.0.dependencies.len()
@@ -115,6 +135,181 @@ impl GqlAsset {
     async fn deprecated_at(&self) -> Option<DateTime<Utc>> {
         self.0.deprecated_at
     }
+
+    /// Revision, incremented on every update
+    async fn revision(&self) -> u64 {
+        self.0.revision
+    }
+
+    /// Assets this asset directly depends on
+    async fn dependencies(&self, ctx: &Context<'_>) -> Result<Vec<GqlAsset>> {
+        let loaders = ctx.data::<Loaders>()?;
+        let ids = loaders.dependencies.load_one(self.0.id).await?;
+        let assets = loaders.load_assets(ids.unwrap_or_default()).await?;
+        Ok(assets.into_iter().map(GqlAsset).collect())
+    }
+
+    /// Assets directly depending on this asset
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    async fn dependents(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<GqlAssetConnection> {
+        let page = PageRequest::new(first, after, last, before)?;
+        let loaders = ctx.data::<Loaders>()?;
+        let ids = loaders.dependents.load_one(self.0.id).await?;
+        let assets = loaders.load_assets(ids.unwrap_or_default()).await?;
+        slice_assets(assets, &page)
+    }
+
+    /// All versions of this asset, highest first
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    async fn versions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<GqlAssetConnection> {
+        let page = PageRequest::new(first, after, last, before)?;
+        let loaders = ctx.data::<Loaders>()?;
+        let versions = loaders
+            .versions
+            .load_one(self.0.metadata.name.clone())
+            .await?;
+        slice_assets(versions.unwrap_or_default(), &page)
+    }
+
+    /// The highest version of this asset
+    async fn latest_version(&self, ctx: &Context<'_>) -> Result<Option<GqlAsset>> {
+        let loaders = ctx.data::<Loaders>()?;
+        let versions = loaders
+            .versions
+            .load_one(self.0.metadata.name.clone())
+            .await?;
+        Ok(versions
+            .and_then(|versions| versions.into_iter().next())
+            .map(GqlAsset))
+    }
+
+    /// Events for this asset, newest first
+    #[graphql(complexity = "page_size(first, last) * child_complexity")]
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<GqlAssetEventConnection> {
+        authorize(ctx, &Permission::read_events())?;
+
+        let page = PageRequest::new(first, after, last, before)?;
+        let include_total = ctx.look_ahead().field("totalCount").exists();
+
+        // First pages come from the batched loader
+        if let PageRequest::Forward { after: None, size } = page {
+            if !include_total {
+                let loaders = ctx.data::<Loaders>()?;
+                let mut events = loaders
+                    .events
+                    .load_one(self.0.id)
+                    .await?
+                    .unwrap_or_default();
+                let has_next_page = events.len() > size;
+                events.truncate(size);
+                return Ok(event_connection(events, false, has_next_page, None));
+            }
+        }
+
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+        let query = EventQuery::new()
+            .asset_id(self.0.id)
+            .include_total(include_total);
+
+        query_event_page(services, query, page).await
+    }
+}
+
+/// GraphQL representation of a registry event
+#[derive(Clone)]
+pub struct GqlAssetEvent(pub RegistryEvent);
+
+#[Object]
+impl GqlAssetEvent {
+    /// Event ID, usable as `lastEventId` to resume a subscription
+    async fn event_id(&self) -> &str {
+        &self.0.event_id
+    }
+
+    /// Event type, e.g. `asset_registered`
+    async fn event_type(&self) -> &str {
+        self.0.event_name()
+    }
+
+    /// ID of the asset the event concerns, if any
+    async fn asset_id(&self) -> Option<String> {
+        self.0.asset_id().map(|id| id.to_string())
+    }
+
+    /// Name of the asset the event concerns, if the event carries it
+    async fn asset_name(&self) -> Option<&str> {
+        match &self.0.event_type {
+            EventType::AssetRegistered { asset_name, .. }
+            | EventType::AssetUpdated { asset_name, .. }
+            | EventType::AssetDeleted { asset_name, .. }
+            | EventType::AssetStatusChanged { asset_name, .. }
+            | EventType::AssetDownloaded { asset_name, .. } => Some(asset_name),
+            _ => None,
+        }
+    }
+
+    /// User or service that triggered the event
+    async fn actor(&self) -> Option<&str> {
+        self.0.actor.as_deref()
+    }
+
+    /// When the event occurred
+    async fn timestamp(&self) -> DateTime<Utc> {
+        self.0.timestamp
+    }
+
+    /// The full event as JSON
+    async fn payload(&self) -> Json<RegistryEvent> {
+        Json(self.0.clone())
+    }
+
+    /// Current state of the asset, if it still exists
+    async fn asset(&self, ctx: &Context<'_>) -> Result<Option<GqlAsset>> {
+        let Some(asset_id) = self.0.asset_id() else {
+            return Ok(None);
+        };
+        let services = ctx.data::<Arc<ServiceRegistry>>()?;
+
+        let asset = services
+            .search()
+            .get_asset(&asset_id)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(asset.map(GqlAsset))
+    }
+}
+
+/// Registry event filters
+#[derive(async_graphql::InputObject, Default)]
+pub struct GqlEventFilter {
+    /// Only events for this asset
+    pub asset_id: Option<String>,
+    /// Only events of these types, e.g. `asset_updated`
+    pub event_types: Option<Vec<String>>,
+    /// Only events triggered by this actor
+    pub actor: Option<String>,
 }
 
 /// GraphQL representation of a dependency node
@@ -237,17 +432,6 @@ pub struct GqlAnnotation {
     pub value: String,
 }
 
-/// Paginated assets response
-#[derive(SimpleObject)]
-pub struct GqlAssetConnection {
-    /// List of assets
-    pub nodes: Vec<GqlAsset>,
-    /// Total count
-    pub total_count: i64,
-    /// Whether there are more results
-    pub has_next_page: bool,
-}
-
 /// Asset search filters
 #[derive(async_graphql::InputObject)]
 pub struct GqlAssetFilter {
@@ -0,0 +1,117 @@
+//! GraphQL over WebSocket
+//!
+//! Serves subscriptions on `/graphql/ws` using the `graphql-transport-ws`
+//! protocol (and the legacy `graphql-ws` one). Browsers cannot set an
+//! `Authorization` header on a WebSocket handshake, so clients authenticate
+//! in the `connection_init` payload instead:
+//!
+//! ```json
+//! { "type": "connection_init", "payload": { "Authorization": "Bearer <jwt>" } }
+//! ```
+//!
+//! A bare `token` field is accepted as well. Connections without credentials
+//! are anonymous; connections with invalid credentials are rejected.
+
+use async_graphql::{Data, Result};
+use async_graphql_axum::{GraphQLProtocol, GraphQLWebSocket};
+use axum::{
+    extract::{State, WebSocketUpgrade},
+    response::Response,
+};
+use serde_json::Value;
+use tracing::debug;
+
+use super::AppSchema;
+use crate::auth::{AuthState, AuthUser};
+use crate::jwt::JwtManager;
+
+/// State for the GraphQL WebSocket endpoint
+#[derive(Clone)]
+pub struct GraphQLWsState {
+    /// GraphQL schema
+    pub schema: AppSchema,
+
+    /// Authentication state used to validate init payload tokens
+    pub auth_state: AuthState,
+}
+
+/// GraphQL WebSocket handler
+pub async fn graphql_ws_handler(
+    State(state): State<GraphQLWsState>,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    upgrade
+        .protocols(async_graphql::http::ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            let auth_state = state.auth_state;
+            GraphQLWebSocket::new(stream, state.schema, protocol)
+                .on_connection_init(move |payload| async move {
+                    authenticate_connection(auth_state.jwt_manager(), &payload)
+                })
+                .serve()
+        })
+}
+
+/// Authenticate a connection from its `connection_init` payload
+///
+/// Returns connection data holding the [`AuthUser`] when the payload carries
+/// a valid token, and empty data when it carries none.
+pub fn authenticate_connection(jwt_manager: &JwtManager, payload: &Value) -> Result<Data> {
+    let mut data = Data::default();
+
+    let token = match (
+        payload
+            .get("Authorization")
+            .or_else(|| payload.get("authorization"))
+            .and_then(Value::as_str),
+        payload.get("token").and_then(Value::as_str),
+    ) {
+        (Some(header), _) => JwtManager::extract_token_from_header(header)
+            .map_err(|_| async_graphql::Error::new("Invalid authorization header"))?,
+        (None, Some(token)) => token,
+        (None, None) => return Ok(data),
+    };
+
+    let claims = jwt_manager
+        .validate_token(token)
+        .map_err(|_| async_graphql::Error::new("Invalid or expired token"))?;
+    debug!("GraphQL WebSocket authenticated: {}", claims.sub);
+
+    data.insert(AuthUser::new(claims));
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jwt::JwtConfig;
+    use serde_json::json;
+
+    fn jwt_manager() -> JwtManager {
+        JwtManager::new(
+            JwtConfig::new("test-secret-key")
+                .with_issuer("test")
+                .with_audience("test"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_authenticate_connection() {
+        let manager = jwt_manager();
+        let token = manager.generate_token("user123").unwrap();
+
+        assert!(authenticate_connection(&manager, &json!({})).is_ok());
+        assert!(authenticate_connection(&manager, &Value::Null).is_ok());
+
+        let bearer = json!({ "Authorization": format!("Bearer {}", token) });
+        assert!(authenticate_connection(&manager, &bearer).is_ok());
+        assert!(authenticate_connection(&manager, &json!({ "token": token })).is_ok());
+
+        assert!(authenticate_connection(&manager, &json!({ "token": "garbage" })).is_err());
+        assert!(
+            authenticate_connection(&manager, &json!({ "authorization": "Basic abc" })).is_err()
+        );
+    }
+}
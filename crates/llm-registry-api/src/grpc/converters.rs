@@ -6,10 +6,15 @@
 use super::proto;
 use crate::error::ApiError;
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, AssetReference, AssetStatus, AssetType, Checksum,
-    HashAlgorithm, Provenance, StorageBackend, StorageLocation,
+    Asset, AssetId, AssetMetadata, AssetReference, AssetSignature, AssetStatus, AssetType,
+    Checksum, HashAlgorithm, Provenance, RegistryEvent, SignatureAlgorithm, StorageBackend,
+    StorageLocation,
+};
+use llm_registry_service::{
+    BatchItemError, BatchRegisterResponse, DependencyNode, IntegrityVerificationResult,
+    ListEventsRequest, ListEventsResponse, ListVersionsResponse, RegisterAssetRequest, SortField,
+    SortOrder, ValidationError, ValidationResult, ValidationWarning, VersionInfo,
 };
-use llm_registry_service::{DependencyNode, SortField, SortOrder};
 use semver::Version;
 
 // ============================================================================
@@ -89,6 +94,24 @@ pub fn hash_algorithm_from_i32(value: i32) -> Result<HashAlgorithm, ApiError> {
     }
 }
 
+impl From<SignatureAlgorithm> for proto::SignatureAlgorithm {
+    fn from(alg: SignatureAlgorithm) -> Self {
+        match alg {
+            SignatureAlgorithm::Ed25519 => proto::SignatureAlgorithm::Ed25519,
+        }
+    }
+}
+
+/// Convert i32 to SignatureAlgorithm (helper function to avoid orphan rule violations)
+pub fn signature_algorithm_from_i32(value: i32) -> Result<SignatureAlgorithm, ApiError> {
+    match proto::SignatureAlgorithm::try_from(value) {
+        Ok(proto::SignatureAlgorithm::Unspecified) | Ok(proto::SignatureAlgorithm::Ed25519) => {
+            Ok(SignatureAlgorithm::Ed25519)
+        }
+        Err(_) => Err(ApiError::bad_request("Invalid signature algorithm")),
+    }
+}
+
 impl From<SortField> for proto::SortField {
     fn from(field: SortField) -> Self {
         match field {
@@ -97,6 +120,7 @@ impl From<SortField> for proto::SortField {
             SortField::Name => proto::SortField::Name,
             SortField::Version => proto::SortField::Version,
             SortField::SizeBytes => proto::SortField::SizeBytes,
+            SortField::Relevance => proto::SortField::Relevance,
         }
     }
 }
@@ -111,6 +135,7 @@ pub fn sort_field_from_i32(value: i32) -> Result<SortField, ApiError> {
         Ok(proto::SortField::Name) => Ok(SortField::Name),
         Ok(proto::SortField::Version) => Ok(SortField::Version),
         Ok(proto::SortField::SizeBytes) => Ok(SortField::SizeBytes),
+        Ok(proto::SortField::Relevance) => Ok(SortField::Relevance),
         Err(_) => Err(ApiError::bad_request("Invalid sort field")),
     }
 }
@@ -158,6 +183,8 @@ impl From<Asset> for proto::Asset {
             created_at: asset.created_at.to_rfc3339(),
             updated_at: asset.updated_at.to_rfc3339(),
             deprecated_at: asset.deprecated_at.map(|dt| dt.to_rfc3339()),
+            signature: asset.signature.map(proto::AssetSignature::from),
+            revision: asset.revision,
         }
     }
 }
@@ -182,7 +209,11 @@ impl From<AssetMetadata> for proto::AssetMetadata {
 impl From<StorageLocation> for proto::StorageLocation {
     fn from(storage: StorageLocation) -> Self {
         let (backend_type, config) = match storage.backend {
-            StorageBackend::S3 { bucket, region, endpoint } => (
+            StorageBackend::S3 {
+                bucket,
+                region,
+                endpoint,
+            } => (
                 proto::StorageBackend::S3 as i32,
                 Some(proto::storage_config::Config::S3(proto::S3Config {
                     bucket,
@@ -197,12 +228,17 @@ impl From<StorageLocation> for proto::StorageLocation {
                     project_id,
                 })),
             ),
-            StorageBackend::AzureBlob { account_name, container } => (
+            StorageBackend::AzureBlob {
+                account_name,
+                container,
+            } => (
                 proto::StorageBackend::AzureBlob as i32,
-                Some(proto::storage_config::Config::Azure(proto::AzureBlobConfig {
-                    account_name,
-                    container,
-                })),
+                Some(proto::storage_config::Config::Azure(
+                    proto::AzureBlobConfig {
+                        account_name,
+                        container,
+                    },
+                )),
             ),
             StorageBackend::MinIO { bucket, endpoint } => (
                 proto::StorageBackend::Minio as i32,
@@ -293,6 +329,31 @@ impl TryFrom<proto::Checksum> for Checksum {
     }
 }
 
+/// Convert domain AssetSignature to proto
+impl From<AssetSignature> for proto::AssetSignature {
+    fn from(sig: AssetSignature) -> Self {
+        proto::AssetSignature {
+            algorithm: proto::SignatureAlgorithm::from(sig.algorithm) as i32,
+            key_id: sig.key_id,
+            value: sig.value,
+        }
+    }
+}
+
+/// Convert proto AssetSignature to domain
+impl TryFrom<proto::AssetSignature> for AssetSignature {
+    type Error = ApiError;
+
+    fn try_from(proto: proto::AssetSignature) -> Result<Self, Self::Error> {
+        AssetSignature::new(
+            signature_algorithm_from_i32(proto.algorithm)?,
+            proto.key_id,
+            proto.value,
+        )
+        .map_err(|e| ApiError::bad_request(format!("Invalid signature: {}", e)))
+    }
+}
+
 /// Convert domain Provenance to proto
 impl From<Provenance> for proto::Provenance {
     fn from(prov: Provenance) -> Self {
@@ -389,6 +450,223 @@ impl From<DependencyNode> for proto::DependencyNode {
     }
 }
 
+/// Convert domain VersionInfo to proto
+impl From<VersionInfo> for proto::VersionInfo {
+    fn from(info: VersionInfo) -> Self {
+        proto::VersionInfo {
+            version: info.version.to_string(),
+            asset_id: info.asset_id.to_string(),
+            status: proto::AssetStatus::from(info.status) as i32,
+            created_at: info.created_at.to_rfc3339(),
+            deprecated_at: info.deprecated_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
+/// Convert domain ListVersionsResponse to proto
+impl From<ListVersionsResponse> for proto::ListVersionsResponse {
+    fn from(response: ListVersionsResponse) -> Self {
+        proto::ListVersionsResponse {
+            name: response.name,
+            versions: response
+                .versions
+                .into_iter()
+                .map(proto::VersionInfo::from)
+                .collect(),
+            latest: response.latest.map(|v| v.to_string()),
+        }
+    }
+}
+
+/// Convert domain ValidationResult to proto
+impl From<ValidationResult> for proto::ValidationResult {
+    fn from(result: ValidationResult) -> Self {
+        proto::ValidationResult {
+            valid: result.valid,
+            errors: result
+                .errors
+                .into_iter()
+                .map(proto::ValidationIssue::from)
+                .collect(),
+            warnings: result
+                .warnings
+                .into_iter()
+                .map(proto::ValidationIssue::from)
+                .collect(),
+        }
+    }
+}
+
+/// Convert domain ValidationError to proto
+impl From<ValidationError> for proto::ValidationIssue {
+    fn from(error: ValidationError) -> Self {
+        proto::ValidationIssue {
+            field: error.field,
+            message: error.message,
+            code: error.code,
+        }
+    }
+}
+
+/// Convert domain ValidationWarning to proto
+impl From<ValidationWarning> for proto::ValidationIssue {
+    fn from(warning: ValidationWarning) -> Self {
+        proto::ValidationIssue {
+            field: warning.field,
+            message: warning.message,
+            code: None,
+        }
+    }
+}
+
+/// Convert domain IntegrityVerificationResult to proto
+impl From<IntegrityVerificationResult> for proto::VerifyIntegrityResponse {
+    fn from(result: IntegrityVerificationResult) -> Self {
+        proto::VerifyIntegrityResponse {
+            verified: result.verified,
+            expected_checksum: Some(result.expected_checksum.into()),
+            actual_checksum: result.actual_checksum.map(Into::into),
+            error: result.error,
+        }
+    }
+}
+
+/// Convert domain RegistryEvent to proto
+impl From<RegistryEvent> for proto::RegistryEvent {
+    fn from(event: RegistryEvent) -> Self {
+        Self {
+            event_type: event.event_name().to_string(),
+            asset_id: event.asset_id().map(|id| id.to_string()),
+            timestamp: event.timestamp.to_rfc3339(),
+            details_json: serde_json::to_string(&event.event_type).unwrap_or_default(),
+            event_id: event.event_id,
+            actor: event.actor,
+            source: event.source,
+            correlation_id: event.correlation_id,
+        }
+    }
+}
+
+/// Convert domain ListEventsResponse to proto
+impl From<ListEventsResponse> for proto::ListEventsResponse {
+    fn from(response: ListEventsResponse) -> Self {
+        Self {
+            events: response.events.into_iter().map(Into::into).collect(),
+            total: response.total,
+            limit: response.limit,
+            has_more: response.has_more,
+            next_cursor: response.next_cursor,
+        }
+    }
+}
+
+/// Convert domain BatchItemError to proto
+impl From<BatchItemError> for proto::BatchItemError {
+    fn from(error: BatchItemError) -> Self {
+        proto::BatchItemError {
+            index: error.index as u32,
+            name: error.name,
+            version: error.version,
+            message: error.message,
+        }
+    }
+}
+
+/// Convert domain BatchRegisterResponse to proto
+impl From<BatchRegisterResponse> for proto::BatchRegisterResponse {
+    fn from(response: BatchRegisterResponse) -> Self {
+        proto::BatchRegisterResponse {
+            total_registered: response.assets.len() as u32,
+            total_failed: response.errors.len() as u32,
+            error_messages: response
+                .errors
+                .iter()
+                .map(|e| format!("item {} ({}@{}): {}", e.index, e.name, e.version, e.message))
+                .collect(),
+            assets: response
+                .assets
+                .into_iter()
+                .map(proto::Asset::from)
+                .collect(),
+            errors: response
+                .errors
+                .into_iter()
+                .map(proto::BatchItemError::from)
+                .collect(),
+            warnings: response.warnings,
+        }
+    }
+}
+
+/// Convert a proto registration request to the service request
+pub fn register_request_from_proto(
+    req: proto::RegisterAssetRequest,
+) -> Result<RegisterAssetRequest, ApiError> {
+    let storage = req
+        .storage
+        .ok_or_else(|| ApiError::bad_request("Storage location is required"))?
+        .try_into()?;
+
+    let checksum = req
+        .checksum
+        .ok_or_else(|| ApiError::bad_request("Checksum is required"))?
+        .try_into()?;
+
+    Ok(RegisterAssetRequest {
+        asset_type: asset_type_from_i32(req.asset_type)?,
+        name: req.name,
+        version: parse_version(&req.version)?,
+        description: req.description,
+        license: req.license,
+        tags: req.tags,
+        annotations: req.annotations,
+        storage,
+        checksum,
+        provenance: req.provenance.map(|p| p.try_into()).transpose()?,
+        dependencies: req
+            .dependencies
+            .into_iter()
+            .map(|d| d.try_into())
+            .collect::<Result<Vec<_>, _>>()?,
+        size_bytes: req.size_bytes,
+        content_type: req.content_type,
+        signature: req.signature.map(|s| s.try_into()).transpose()?,
+    })
+}
+
+/// Convert proto ListEventsRequest to domain
+pub fn list_events_request_from_proto(
+    proto: proto::ListEventsRequest,
+) -> Result<ListEventsRequest, ApiError> {
+    let asset_id = proto
+        .asset_id
+        .map(|id| {
+            id.parse::<AssetId>()
+                .map_err(|e| ApiError::bad_request(format!("Invalid asset ID: {}", e)))
+        })
+        .transpose()?;
+
+    Ok(ListEventsRequest {
+        asset_id,
+        event_types: proto.event_types,
+        actor: proto.actor,
+        occurred_after: proto
+            .occurred_after
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        occurred_before: proto
+            .occurred_before
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        limit: proto.limit.unwrap_or(ListEventsRequest::default().limit),
+        cursor: proto.cursor,
+        include_total: proto.include_total,
+        sort_order: sort_order_from_i32(proto.sort_order)?,
+    })
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -5,6 +5,7 @@
 
 pub mod converters;
 pub mod service;
+pub mod watch;
 
 // Include the generated protobuf code
 pub mod proto {
@@ -14,13 +15,11 @@ pub mod proto {
 pub use proto::registry_service_server::{RegistryService, RegistryServiceServer};
 pub use service::RegistryServiceImpl;
 
-use tonic::transport::Server;
 use std::net::SocketAddr;
+use tonic::transport::Server;
 
 /// Build a gRPC server with the registry service
-pub fn build_grpc_server(
-    service: RegistryServiceImpl,
-) -> tonic::transport::server::Router {
+pub fn build_grpc_server(service: RegistryServiceImpl) -> tonic::transport::server::Router {
     Server::builder().add_service(RegistryServiceServer::new(service))
 }
 
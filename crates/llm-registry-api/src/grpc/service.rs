@@ -4,12 +4,17 @@
 
 use super::converters::*;
 use super::proto::{self, registry_service_server::RegistryService};
+use super::watch::{stream_error_status, AssetWatch};
+use crate::auth::{AuthError, AuthState};
 use crate::error::ApiError;
-use llm_registry_core::{AssetId, AssetReference};
+use crate::rbac::Permission;
+use llm_registry_core::AssetId;
 use llm_registry_service::{
-    GetDependencyGraphRequest, RegisterAssetRequest, SearchAssetsRequest, ServiceRegistry,
-    UpdateAssetRequest,
+    registration::MAX_BATCH_SIZE, BatchItemError, BatchRegisterResponse, ComputeChecksumRequest,
+    GetDependencyGraphRequest, ListVersionsRequest, SearchAssetsRequest, ServiceError,
+    ServiceRegistry, UpdateAssetRequest, VerifyIntegrityRequest,
 };
+use semver::VersionReq;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 
@@ -17,12 +22,38 @@ use tonic::{Request, Response, Status};
 #[derive(Clone)]
 pub struct RegistryServiceImpl {
     services: Arc<ServiceRegistry>,
+    auth: Option<AuthState>,
 }
 
 impl RegistryServiceImpl {
     /// Create a new gRPC service instance
     pub fn new(services: Arc<ServiceRegistry>) -> Self {
-        Self { services }
+        Self {
+            services,
+            auth: None,
+        }
+    }
+
+    /// Require a bearer token in the `authorization` metadata for
+    /// permission-gated calls such as `ListEvents`
+    pub fn with_auth(mut self, auth_state: AuthState) -> Self {
+        self.auth = Some(auth_state);
+        self
+    }
+
+    /// Check a request's token for a permission, if authentication is enabled
+    fn authorize<T>(&self, request: &Request<T>, permission: &Permission) -> Result<(), AuthError> {
+        let Some(ref auth) = self.auth else {
+            return Ok(());
+        };
+
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+
+        let user = auth.authenticate(authorization)?;
+        auth.authorize(Some(&user), permission)
     }
 }
 
@@ -37,56 +68,10 @@ impl RegistryService for RegistryServiceImpl {
         &self,
         request: Request<proto::RegisterAssetRequest>,
     ) -> Result<Response<proto::RegisterAssetResponse>, Status> {
-        let req = request.into_inner();
-
         // Convert proto request to domain request
-        let asset_type = asset_type_from_i32(req.asset_type)
-            .map_err(|e| Status::invalid_argument(e.to_string()))?;
-
-        let version = parse_version(&req.version)
+        let domain_request = register_request_from_proto(request.into_inner())
             .map_err(|e| Status::invalid_argument(e.to_string()))?;
 
-        let storage = req
-            .storage
-            .ok_or_else(|| Status::invalid_argument("Storage location is required"))?
-            .try_into()
-            .map_err(|e: ApiError| Status::invalid_argument(e.to_string()))?;
-
-        let checksum = req
-            .checksum
-            .ok_or_else(|| Status::invalid_argument("Checksum is required"))?
-            .try_into()
-            .map_err(|e: ApiError| Status::invalid_argument(e.to_string()))?;
-
-        let provenance = req
-            .provenance
-            .map(|p| p.try_into())
-            .transpose()
-            .map_err(|e: ApiError| Status::invalid_argument(e.to_string()))?;
-
-        let dependencies: Result<Vec<AssetReference>, ApiError> = req
-            .dependencies
-            .into_iter()
-            .map(|d| d.try_into())
-            .collect();
-        let dependencies = dependencies.map_err(|e| Status::invalid_argument(e.to_string()))?;
-
-        let domain_request = RegisterAssetRequest {
-            asset_type,
-            name: req.name,
-            version,
-            description: req.description,
-            license: req.license,
-            tags: req.tags,
-            annotations: req.annotations,
-            storage,
-            checksum,
-            provenance,
-            dependencies,
-            size_bytes: req.size_bytes,
-            content_type: req.content_type,
-        };
-
         // Execute registration
         let response = self
             .services
@@ -151,11 +136,15 @@ impl RegistryService for RegistryServiceImpl {
             tags: req.tags,
             author: req.author,
             storage_backend: req.storage_backend,
+            query: req.query,
             exclude_deprecated: req.exclude_deprecated,
             limit: req.limit,
             offset: req.offset,
+            cursor: req.cursor,
+            include_total: req.include_total.unwrap_or(true),
             sort_by,
             sort_order,
+            ..Default::default()
         };
 
         let response = self
@@ -163,14 +152,25 @@ impl RegistryService for RegistryServiceImpl {
             .search()
             .search_assets(search_request)
             .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .map_err(|e| match e {
+                ServiceError::InvalidQuery(_) | ServiceError::InvalidInput(_) => {
+                    Status::invalid_argument(e.to_string())
+                }
+                e => Status::internal(e.to_string()),
+            })?;
 
         Ok(Response::new(proto::SearchAssetsResponse {
             assets: response.assets.into_iter().map(|a| a.into()).collect(),
             total: response.total,
             offset: response.offset,
             limit: response.limit,
-            has_more: (response.offset + response.limit) < response.total,
+            has_more: response.has_more,
+            next_cursor: response.next_cursor,
+            snippets: response
+                .snippets
+                .into_iter()
+                .map(|(id, snippet)| (id.to_string(), snippet))
+                .collect(),
         }))
     }
 
@@ -201,6 +201,7 @@ impl RegistryService for RegistryServiceImpl {
             remove_tags: req.remove_tags,
             add_annotations: req.add_annotations,
             remove_annotations: req.remove_annotations,
+            expected_revision: req.expected_revision,
         };
 
         let response = self
@@ -208,7 +209,10 @@ impl RegistryService for RegistryServiceImpl {
             .registration()
             .update_asset(update_request)
             .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .map_err(|e| match e {
+                ServiceError::Conflict(_) => Status::aborted(e.to_string()),
+                e => Status::internal(e.to_string()),
+            })?;
 
         Ok(Response::new(proto::UpdateAssetResponse {
             asset: Some(response.asset.into()),
@@ -230,9 +234,12 @@ impl RegistryService for RegistryServiceImpl {
 
         self.services
             .registration()
-            .delete_asset(&asset_id)
+            .delete_asset(&asset_id, req.expected_revision)
             .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .map_err(|e| match e {
+                ServiceError::Conflict(_) => Status::aborted(e.to_string()),
+                e => Status::internal(e.to_string()),
+            })?;
 
         Ok(Response::new(proto::DeleteAssetResponse {
             asset_id: req.asset_id,
@@ -240,6 +247,97 @@ impl RegistryService for RegistryServiceImpl {
         }))
     }
 
+    /// List the versions of an asset
+    async fn list_versions(
+        &self,
+        request: Request<proto::ListVersionsRequest>,
+    ) -> Result<Response<proto::ListVersionsResponse>, Status> {
+        let req = request.into_inner();
+
+        let response = self
+            .services
+            .versioning()
+            .list_versions(ListVersionsRequest {
+                name: req.name,
+                include_deprecated: req.include_deprecated,
+            })
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(response.into()))
+    }
+
+    /// Resolve a version requirement to the highest matching version
+    async fn resolve_version(
+        &self,
+        request: Request<proto::ResolveVersionRequest>,
+    ) -> Result<Response<proto::ResolveVersionResponse>, Status> {
+        let req = request.into_inner();
+
+        let requirement = VersionReq::parse(&req.requirement)
+            .map_err(|e| Status::invalid_argument(format!("Invalid version requirement: {}", e)))?;
+
+        let asset = self
+            .services
+            .versioning()
+            .resolve_version(&req.name, &requirement, req.include_deprecated)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(proto::ResolveVersionResponse {
+            asset: asset.map(|a| a.into()),
+        }))
+    }
+
+    /// Get the latest active version of an asset
+    async fn get_latest_version(
+        &self,
+        request: Request<proto::GetLatestVersionRequest>,
+    ) -> Result<Response<proto::GetLatestVersionResponse>, Status> {
+        let req = request.into_inner();
+
+        let asset = self
+            .services
+            .versioning()
+            .get_latest_version(&req.name)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(proto::GetLatestVersionResponse {
+            asset: asset.map(|a| a.into()),
+        }))
+    }
+
+    /// Deprecate an asset version
+    async fn deprecate_asset(
+        &self,
+        request: Request<proto::DeprecateAssetRequest>,
+    ) -> Result<Response<proto::DeprecateAssetResponse>, Status> {
+        let req = request.into_inner();
+
+        let asset_id = req
+            .asset_id
+            .parse::<AssetId>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid asset ID: {}", e)))?;
+        let alternative = req
+            .alternative
+            .as_deref()
+            .map(parse_version)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let asset = self
+            .services
+            .versioning()
+            .deprecate_version(&asset_id, req.reason, alternative)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(proto::DeprecateAssetResponse {
+            asset: Some(asset.into()),
+        }))
+    }
+
     /// Get dependency graph for an asset
     async fn get_dependencies(
         &self,
@@ -297,6 +395,176 @@ impl RegistryService for RegistryServiceImpl {
         }))
     }
 
+    /// Get the signature attached to an asset
+    async fn get_asset_signature(
+        &self,
+        request: Request<proto::GetAssetSignatureRequest>,
+    ) -> Result<Response<proto::GetAssetSignatureResponse>, Status> {
+        let req = request.into_inner();
+
+        let asset_id = req
+            .asset_id
+            .parse::<AssetId>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid asset ID: {}", e)))?;
+
+        let signature = self
+            .services
+            .signing()
+            .get_signature(&asset_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(proto::GetAssetSignatureResponse {
+            signature: signature.map(|s| s.into()),
+        }))
+    }
+
+    /// Verify an asset's signature
+    async fn verify_asset_signature(
+        &self,
+        request: Request<proto::VerifyAssetSignatureRequest>,
+    ) -> Result<Response<proto::VerifyAssetSignatureResponse>, Status> {
+        let req = request.into_inner();
+
+        let asset_id = req
+            .asset_id
+            .parse::<AssetId>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid asset ID: {}", e)))?;
+
+        let result = self
+            .services
+            .signing()
+            .verify_asset_signature(&asset_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(proto::VerifyAssetSignatureResponse {
+            asset_id: result.asset_id.to_string(),
+            verified: result.verified,
+            key_id: result.key_id,
+            error: result.error,
+            verified_at: result.verified_at.to_rfc3339(),
+        }))
+    }
+
+    /// Check a registration request without registering anything
+    async fn validate_registration(
+        &self,
+        request: Request<proto::RegisterAssetRequest>,
+    ) -> Result<Response<proto::ValidationResult>, Status> {
+        let domain_request = register_request_from_proto(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let result = self
+            .services
+            .registration()
+            .validate_registration(domain_request)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(result.into()))
+    }
+
+    /// Validate a registered asset against policies
+    async fn validate_asset(
+        &self,
+        request: Request<proto::ValidateAssetRequest>,
+    ) -> Result<Response<proto::ValidationResult>, Status> {
+        let req = request.into_inner();
+
+        let asset_id = req
+            .asset_id
+            .parse::<AssetId>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid asset ID: {}", e)))?;
+
+        let result = self
+            .services
+            .validation()
+            .validate_registered_asset(&asset_id, req.policies, req.deep)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(result.into()))
+    }
+
+    /// Verify a registered asset against a computed checksum
+    async fn verify_integrity(
+        &self,
+        request: Request<proto::VerifyIntegrityRequest>,
+    ) -> Result<Response<proto::VerifyIntegrityResponse>, Status> {
+        let req = request.into_inner();
+
+        let asset_id = req
+            .asset_id
+            .parse::<AssetId>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid asset ID: {}", e)))?;
+        let checksum = req
+            .checksum
+            .ok_or_else(|| Status::invalid_argument("Checksum is required"))?
+            .try_into()
+            .map_err(|e: ApiError| Status::invalid_argument(e.to_string()))?;
+
+        let result = self
+            .services
+            .integrity()
+            .verify_integrity(VerifyIntegrityRequest {
+                asset_id,
+                computed_checksum: Some(checksum),
+            })
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(result.into()))
+    }
+
+    /// Compute the checksum of some content
+    async fn compute_checksum(
+        &self,
+        request: Request<proto::ComputeChecksumRequest>,
+    ) -> Result<Response<proto::ComputeChecksumResponse>, Status> {
+        use base64::Engine;
+
+        let req = request.into_inner();
+
+        let algorithm = hash_algorithm_from_i32(req.algorithm)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let response = self
+            .services
+            .integrity()
+            .compute_checksum(ComputeChecksumRequest {
+                data: base64::engine::general_purpose::STANDARD.encode(&req.data),
+                algorithm,
+            })
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(proto::ComputeChecksumResponse {
+            checksum: Some(response.checksum.into()),
+        }))
+    }
+
+    /// List registry events
+    async fn list_events(
+        &self,
+        request: Request<proto::ListEventsRequest>,
+    ) -> Result<Response<proto::ListEventsResponse>, Status> {
+        self.authorize(&request, &Permission::read_events())
+            .map_err(auth_status)?;
+
+        let list_request = list_events_request_from_proto(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let response = self
+            .services
+            .events()
+            .list_events(list_request)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(response.into()))
+    }
+
     /// List all unique tags
     async fn list_tags(
         &self,
@@ -351,26 +619,276 @@ impl RegistryService for RegistryServiceImpl {
     }
 
     /// Watch assets (server streaming)
+    ///
+    /// Streams asset lifecycle events as they are appended to the event store.
+    /// When `last_event_id` is set, events missed since that event are replayed
+    /// first. Slow consumers that fall behind the live broadcast are caught up
+    /// from the event store rather than dropped.
     async fn watch_assets(
         &self,
-        _request: Request<proto::WatchAssetsRequest>,
+        request: Request<proto::WatchAssetsRequest>,
     ) -> Result<Response<Self::WatchAssetsStream>, Status> {
-        // This would be implemented with actual event streaming
-        // For now, return unimplemented
-        Err(Status::unimplemented(
-            "Asset watching not yet implemented - requires event streaming infrastructure",
-        ))
+        let req = request.into_inner();
+
+        let watch = AssetWatch::new(self.services.clone(), &req)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let subscription = self
+            .services
+            .events()
+            .subscribe(req.last_event_id.as_deref())
+            .await
+            .map_err(stream_error_status)?;
+
+        let stream = futures::stream::unfold(Some((subscription, watch)), |state| async move {
+            let (mut subscription, mut watch) = state?;
+            loop {
+                let event = match subscription.next().await {
+                    Ok(Some(event)) => event,
+                    Ok(None) => return None,
+                    Err(e) => return Some((Err(stream_error_status(e)), None)),
+                };
+
+                match watch.to_asset_event(&event).await {
+                    Ok(Some(asset_event)) => {
+                        return Some((Ok(asset_event), Some((subscription, watch))))
+                    }
+                    Ok(None) => continue,
+                    Err(status) => return Some((Err(status), None)),
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(stream)))
     }
 
     /// Batch register assets (client streaming)
+    ///
+    /// Collects the whole request stream, then registers it atomically. Items
+    /// may depend on items earlier in the stream by name and version. Invalid
+    /// items are reported per item and cause the whole batch to be rejected.
     async fn batch_register(
         &self,
-        _request: Request<tonic::Streaming<proto::RegisterAssetRequest>>,
+        request: Request<tonic::Streaming<proto::RegisterAssetRequest>>,
     ) -> Result<Response<proto::BatchRegisterResponse>, Status> {
-        // This would be implemented with actual batch processing
-        // For now, return unimplemented
-        Err(Status::unimplemented(
-            "Batch registration not yet implemented - use individual registration",
-        ))
+        let mut stream = request.into_inner();
+
+        let mut requests = Vec::new();
+        let mut conversion_errors = Vec::new();
+
+        while let Some(item) = stream.message().await? {
+            if requests.len() + conversion_errors.len() >= MAX_BATCH_SIZE {
+                return Err(Status::invalid_argument(format!(
+                    "Batch exceeds the maximum of {} assets",
+                    MAX_BATCH_SIZE
+                )));
+            }
+
+            let index = requests.len() + conversion_errors.len();
+            let (name, version) = (item.name.clone(), item.version.clone());
+            match register_request_from_proto(item) {
+                Ok(domain_request) => requests.push(domain_request),
+                Err(e) => conversion_errors.push(BatchItemError {
+                    index,
+                    name,
+                    version,
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        // Malformed items reject the batch before any registration work
+        if !conversion_errors.is_empty() {
+            let response = BatchRegisterResponse {
+                errors: conversion_errors,
+                ..Default::default()
+            };
+            return Ok(Response::new(response.into()));
+        }
+
+        let response = self
+            .services
+            .registration()
+            .register_batch(requests)
+            .await
+            .map_err(service_status)?;
+
+        Ok(Response::new(response.into()))
+    }
+}
+
+/// Map an authentication or authorization failure to a gRPC status
+fn auth_status(err: AuthError) -> Status {
+    match err {
+        AuthError::InsufficientPermissions => Status::permission_denied(err.to_string()),
+        _ => Status::unauthenticated(err.to_string()),
+    }
+}
+
+/// Map a service error to a gRPC status
+fn service_status(err: ServiceError) -> Status {
+    match err {
+        ServiceError::NotFound(msg) => Status::not_found(msg),
+        ServiceError::InvalidInput(msg) => Status::invalid_argument(msg),
+        ServiceError::AlreadyExists { .. } => Status::already_exists(err.to_string()),
+        ServiceError::ValidationFailed(_)
+        | ServiceError::ChecksumVerificationFailed(_)
+        | ServiceError::SignatureVerificationFailed(_)
+        | ServiceError::CircularDependency(_)
+        | ServiceError::DependencyNotFound(_)
+        | ServiceError::PolicyValidationFailed { .. }
+        | ServiceError::InvalidQuery(_) => Status::invalid_argument(err.to_string()),
+        ServiceError::Conflict(_) => Status::aborted(err.to_string()),
+        ServiceError::VersionConflict(_) => Status::failed_precondition(err.to_string()),
+        ServiceError::NotPermitted(msg) => Status::permission_denied(msg),
+        ServiceError::Unsupported(msg) => Status::unimplemented(msg),
+        other => Status::internal(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc::proto::registry_service_client::RegistryServiceClient;
+    use crate::grpc::RegistryServiceServer;
+    use crate::jwt::{Claims, JwtConfig, JwtManager};
+    use llm_registry_service::test_support::{register_model, register_request};
+    use tonic::transport::server::TcpIncoming;
+    use tonic::transport::{Channel, Server};
+
+    fn jwt_manager() -> JwtManager {
+        JwtManager::new(
+            JwtConfig::new("test-secret-key")
+                .with_issuer("test")
+                .with_audience("test"),
+        )
+        .unwrap()
+    }
+
+    fn list_request(token: Option<&str>) -> Request<proto::ListEventsRequest> {
+        let mut request = Request::new(proto::ListEventsRequest {
+            event_types: vec!["asset_registered".to_string()],
+            include_total: true,
+            ..Default::default()
+        });
+        if let Some(token) = token {
+            request.metadata_mut().insert(
+                "authorization",
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+        }
+        request
+    }
+
+    #[tokio::test]
+    async fn test_list_events_requires_permission_with_auth() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        register_model(&services, "model", "1.0.0").await;
+
+        let open = RegistryServiceImpl::new(services.clone());
+        let response = open
+            .list_events(list_request(None))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.total, Some(1));
+        assert_eq!(response.events[0].event_type, "asset_registered");
+        assert!(response.events[0].asset_id.is_some());
+
+        let token = |role: &str| {
+            let claims = Claims::new("user123", "test", "test", 3600).with_role(role);
+            jwt_manager().generate_token_with_claims(claims).unwrap()
+        };
+        let gated = RegistryServiceImpl::new(services).with_auth(AuthState::new(jwt_manager()));
+
+        let status = gated.list_events(list_request(None)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        let status = gated
+            .list_events(list_request(Some(&token("viewer"))))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        let response = gated
+            .list_events(list_request(Some(&token("auditor"))))
+            .await
+            .unwrap();
+        assert_eq!(response.into_inner().events.len(), 1);
+    }
+
+    /// Serve the service on a local port and connect a client to it
+    async fn connect(service: RegistryServiceImpl) -> RegistryServiceClient<Channel> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(RegistryServiceServer::new(service))
+                .serve_with_incoming(incoming),
+        );
+        RegistryServiceClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap()
+    }
+
+    fn proto_request(name: &str, version: &str) -> proto::RegisterAssetRequest {
+        let request = register_request(name, version);
+        proto::RegisterAssetRequest {
+            asset_type: proto::AssetType::from(request.asset_type) as i32,
+            name: request.name,
+            version: request.version.to_string(),
+            storage: Some(request.storage.into()),
+            checksum: Some(request.checksum.into()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_register_reports_invalid_entry() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        register_model(&services, "existing", "1.0.0").await;
+        let mut client = connect(RegistryServiceImpl::new(services.clone())).await;
+
+        let batch = vec![
+            proto_request("fresh", "1.0.0"),
+            proto_request("existing", "1.0.0"),
+        ];
+        let response = client
+            .batch_register(futures::stream::iter(batch))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.assets.is_empty());
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].index, 1);
+        assert!(response.errors[0].message.contains("already exists"));
+        assert!(services
+            .search()
+            .get_asset_by_name_version("fresh", "1.0.0")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_service_status_codes() {
+        let already_exists = ServiceError::AlreadyExists {
+            name: "model".to_string(),
+            version: "1.0.0".to_string(),
+        };
+        assert_eq!(
+            service_status(already_exists).code(),
+            tonic::Code::AlreadyExists
+        );
+        assert_eq!(
+            service_status(ServiceError::ValidationFailed("bad".to_string())).code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            service_status(ServiceError::Conflict("stale".to_string())).code(),
+            tonic::Code::Aborted
+        );
+        assert_eq!(
+            service_status(ServiceError::Database("down".to_string())).code(),
+            tonic::Code::Internal
+        );
     }
 }
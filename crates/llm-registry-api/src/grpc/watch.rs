@@ -0,0 +1,275 @@
+//! Asset watch streams
+//!
+//! This module turns registry events into `AssetEvent` messages for the
+//! `WatchAssets` RPC, applying the asset type and tag filters from the request.
+
+use super::converters::asset_type_from_i32;
+use super::proto;
+use crate::error::ApiError;
+use llm_registry_core::{Asset, AssetId, AssetType, EventType, RegistryEvent};
+use llm_registry_service::{ServiceError, ServiceRegistry};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tonic::Status;
+
+/// Filter and conversion state for a single `WatchAssets` stream
+pub struct AssetWatch {
+    services: Arc<ServiceRegistry>,
+    asset_types: Vec<AssetType>,
+    tags: Vec<String>,
+    /// Last seen state of matching assets, used to filter deletions
+    watched: HashMap<AssetId, Asset>,
+}
+
+impl AssetWatch {
+    /// Create a watch from the request filters
+    pub fn new(
+        services: Arc<ServiceRegistry>,
+        request: &proto::WatchAssetsRequest,
+    ) -> Result<Self, ApiError> {
+        let asset_types = request
+            .asset_types
+            .iter()
+            .map(|t| asset_type_from_i32(*t))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            services,
+            asset_types,
+            tags: request.tags.clone(),
+            watched: HashMap::new(),
+        })
+    }
+
+    fn has_filters(&self) -> bool {
+        !self.asset_types.is_empty() || !self.tags.is_empty()
+    }
+
+    /// Check whether an asset passes the type and tag filters
+    ///
+    /// An asset must have one of the requested types and all requested tags.
+    fn matches(&self, asset: &Asset) -> bool {
+        let type_matches =
+            self.asset_types.is_empty() || self.asset_types.contains(&asset.asset_type);
+        let tags_match = self
+            .tags
+            .iter()
+            .all(|tag| asset.metadata.tags.contains(tag));
+
+        type_matches && tags_match
+    }
+
+    /// Convert a registry event into an `AssetEvent`
+    ///
+    /// Returns `None` for events that are not asset lifecycle events or that do
+    /// not pass the filters. Deletions are matched against the last state seen
+    /// on this stream, since the asset can no longer be loaded.
+    pub async fn to_asset_event(
+        &mut self,
+        event: &RegistryEvent,
+    ) -> Result<Option<proto::AssetEvent>, Status> {
+        let (event_type, asset) = match &event.event_type {
+            EventType::AssetRegistered { asset_id, .. } => (
+                proto::asset_event::EventType::Created,
+                self.load(asset_id).await?,
+            ),
+            EventType::AssetUpdated { asset_id, .. }
+            | EventType::AssetStatusChanged { asset_id, .. } => (
+                proto::asset_event::EventType::Updated,
+                self.load(asset_id).await?,
+            ),
+            EventType::AssetDeleted {
+                asset_id,
+                asset_name,
+                asset_version,
+            } => {
+                let asset = match self.watched.remove(asset_id) {
+                    Some(asset) => Some(proto::Asset::from(asset)),
+                    None if !self.has_filters() => {
+                        Some(deleted_asset(asset_id, asset_name, asset_version))
+                    }
+                    None => None,
+                };
+                (proto::asset_event::EventType::Deleted, asset)
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(asset.map(|asset| proto::AssetEvent {
+            event_type: event_type as i32,
+            asset: Some(asset),
+            timestamp: event.timestamp.to_rfc3339(),
+            event_id: event.event_id.clone(),
+        }))
+    }
+
+    /// Load the current state of an asset if it passes the filters
+    async fn load(&mut self, asset_id: &AssetId) -> Result<Option<proto::Asset>, Status> {
+        let asset = self
+            .services
+            .search()
+            .get_asset(asset_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        match asset {
+            Some(asset) if self.matches(&asset) => {
+                if self.has_filters() {
+                    self.watched.insert(asset.id, asset.clone());
+                }
+                Ok(Some(asset.into()))
+            }
+            _ => {
+                self.watched.remove(asset_id);
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Build the minimal asset sent for a deletion of an asset never seen on the stream
+fn deleted_asset(asset_id: &AssetId, name: &str, version: &str) -> proto::Asset {
+    proto::Asset {
+        id: asset_id.to_string(),
+        metadata: Some(proto::AssetMetadata {
+            name: name.to_string(),
+            version: version.to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Map a service error raised while streaming to a gRPC status
+pub fn stream_error_status(err: ServiceError) -> Status {
+    match err {
+        ServiceError::InvalidInput(msg) => Status::invalid_argument(msg),
+        other => Status::internal(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_service::{test_support, EventQuery, RegisterAssetRequest};
+
+    fn register_request(name: &str, asset_type: AssetType, tags: &[&str]) -> RegisterAssetRequest {
+        RegisterAssetRequest {
+            asset_type,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..test_support::register_request(name, "1.0.0")
+        }
+    }
+
+    fn deleted_event(asset: &Asset) -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: asset.id,
+            asset_name: asset.metadata.name.clone(),
+            asset_version: asset.metadata.version.to_string(),
+        })
+    }
+
+    /// Convert every event appended so far, oldest first
+    async fn convert_all(
+        services: &ServiceRegistry,
+        watch: &mut AssetWatch,
+    ) -> Vec<proto::AssetEvent> {
+        let mut events = services
+            .events()
+            .query_events(EventQuery::new())
+            .await
+            .unwrap()
+            .events;
+        events.reverse();
+
+        let mut converted = Vec::new();
+        for event in &events {
+            if let Some(asset_event) = watch.to_asset_event(event).await.unwrap() {
+                converted.push(asset_event);
+            }
+        }
+        converted
+    }
+
+    #[tokio::test]
+    async fn test_watch_filters_by_type_and_tags() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let request = proto::WatchAssetsRequest {
+            asset_types: vec![proto::AssetType::Model as i32],
+            tags: vec!["nlp".to_string()],
+            last_event_id: None,
+        };
+        let mut watch = AssetWatch::new(services.clone(), &request).unwrap();
+
+        let registration = services.registration();
+        let matching = registration
+            .register_asset(register_request("bert", AssetType::Model, &["nlp", "base"]))
+            .await
+            .unwrap()
+            .asset;
+        registration
+            .register_asset(register_request("etl", AssetType::Pipeline, &["nlp"]))
+            .await
+            .unwrap();
+        registration
+            .register_asset(register_request("resnet", AssetType::Model, &["vision"]))
+            .await
+            .unwrap();
+
+        let converted = convert_all(&services, &mut watch).await;
+        assert_eq!(converted.len(), 1);
+        assert_eq!(
+            converted[0].event_type,
+            proto::asset_event::EventType::Created as i32
+        );
+        assert_eq!(
+            converted[0].asset.as_ref().unwrap().id,
+            matching.id.to_string()
+        );
+
+        // Deletions are matched against the state seen earlier on the stream
+        let deleted = watch
+            .to_asset_event(&deleted_event(&matching))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            deleted.event_type,
+            proto::asset_event::EventType::Deleted as i32
+        );
+        assert_eq!(deleted.asset.unwrap().metadata.unwrap().name, "bert");
+    }
+
+    #[tokio::test]
+    async fn test_watch_without_filters_reports_unseen_deletions() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let mut watch =
+            AssetWatch::new(services.clone(), &proto::WatchAssetsRequest::default()).unwrap();
+
+        let asset = services
+            .registration()
+            .register_asset(register_request("gpt", AssetType::Model, &[]))
+            .await
+            .unwrap()
+            .asset;
+
+        let deleted = watch
+            .to_asset_event(&deleted_event(&asset))
+            .await
+            .unwrap()
+            .unwrap();
+        let deleted_asset = deleted.asset.unwrap();
+        assert_eq!(deleted_asset.id, asset.id.to_string());
+        assert_eq!(deleted_asset.metadata.unwrap().version, "1.0.0");
+    }
+
+    #[test]
+    fn test_watch_rejects_invalid_asset_type() {
+        let services = Arc::new(ServiceRegistry::in_memory());
+        let request = proto::WatchAssetsRequest {
+            asset_types: vec![99],
+            ..Default::default()
+        };
+        assert!(AssetWatch::new(services, &request).is_err());
+    }
+}
@@ -1,30 +1,36 @@
 //! API request handlers
 //!
-//! This module implements HTTP request handlers for all API endpoints.
-//! Every `/v1/*` handler extracts the [`SpanCollector`] injected by the
+//! This module implements the asset, search, dependency and system
+//! handlers; larger features such as versioning, webhooks and the audit log
+//! have their own modules that follow the same conventions. Every `/v1/*` handler extracts the [`SpanCollector`] injected by the
 //! execution middleware, creates agent-level spans for each service
 //! invocation, attaches artifacts, and returns an [`ExecutionEnvelope`].
 
 use axum::{
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     Json,
 };
 use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
-use llm_registry_core::AssetId;
+use llm_registry_core::{Asset, AssetId};
 use llm_registry_service::{
-    GetDependencyGraphRequest, RegisterAssetRequest, SearchAssetsRequest, ServiceRegistry,
-    UpdateAssetRequest,
+    DependencyGraphResponse, GetDependencyGraphRequest, RegisterAssetRequest,
+    RegisterAssetResponse, SearchAssetsRequest, SearchFacets, ServiceRegistry, UpdateAssetRequest,
+    UpdateAssetResponse,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
-    error::{ApiError, ApiResult},
+    conditional::{conditional_error, merge_expected_revision, with_etag, IfMatch, WithEtag},
+    error::{ApiError, ApiResult, ErrorResponse},
+    history::parse_as_of,
     responses::{
-        created_with_execution, deleted_with_execution, ok_with_execution, ComponentHealth,
-        ExecutionEnvelope, HealthResponse, PaginatedExecutionEnvelope, PaginationMeta,
+        created_with_execution, deleted_with_execution, ok_with_execution, ApiResponse,
+        ComponentHealth, EmptyResponse, ExecutionEnvelope, HealthResponse,
+        PaginatedExecutionEnvelope, PaginationMeta,
     },
 };
 
@@ -49,24 +55,31 @@ impl AppState {
 // ============================================================================
 
 /// Register a new asset
+#[utoipa::path(
+    post,
+    path = "/v1/assets",
+    tag = "assets",
+    request_body = RegisterAssetRequest,
+    responses(
+        (status = 201, description = "Asset registered", body = ExecutionEnvelope<RegisterAssetResponse>),
+        (status = 400, description = "Invalid asset", body = ErrorResponse),
+        (status = 409, description = "Asset version already exists", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, collector))]
 pub async fn register_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Json(request): Json<RegisterAssetRequest>,
-) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<llm_registry_service::RegisterAssetResponse>>)> {
-    info!(
-        "Registering asset: {}@{}",
-        request.name, request.version
-    );
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<llm_registry_service::RegisterAssetResponse>>,
+)> {
+    info!("Registering asset: {}@{}", request.name, request.version);
 
     let span_id = collector.begin_agent_span("RegistrationService");
 
-    let result = state
-        .services
-        .registration()
-        .register_asset(request)
-        .await;
+    let result = state.services.registration().register_asset(request).await;
 
     match result {
         Ok(response) => {
@@ -98,13 +111,41 @@ pub async fn register_asset(
     }
 }
 
+/// Query parameters for fetching an asset
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetAssetParams {
+    /// Rebuild the asset as of an RFC 3339 timestamp or an event ID
+    pub as_of: Option<String>,
+}
+
 /// Get asset by ID
-#[instrument(skip(state, collector))]
+///
+/// The response carries the asset revision as its `ETag`. An `If-Match`
+/// header that does not match the current revision fails with 412.
+///
+/// With `as_of`, the asset is rebuilt from its events as it was at that
+/// timestamp or right after that event, and 404 is returned if it did not
+/// exist then.
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}",
+    tag = "assets",
+    params(("id" = String, Path, description = "Asset ID"), GetAssetParams),
+    responses(
+        (status = 200, description = "Asset, with its revision as `ETag`", body = ExecutionEnvelope<Asset>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 412, description = "`If-Match` does not match the current revision", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector, headers))]
 pub async fn get_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Path(id): Path<String>,
-) -> ApiResult<Json<ExecutionEnvelope<llm_registry_core::Asset>>> {
+    Query(params): Query<GetAssetParams>,
+    headers: HeaderMap,
+) -> ApiResult<WithEtag<Json<ExecutionEnvelope<llm_registry_core::Asset>>>> {
     debug!("Getting asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
@@ -113,13 +154,28 @@ pub async fn get_asset(
         err.with_execution(exec)
     })?;
 
-    let span_id = collector.begin_agent_span("SearchService");
-
-    let result = state
-        .services
-        .search()
-        .get_asset(&asset_id)
-        .await;
+    let as_of = params
+        .as_of
+        .as_deref()
+        .map(parse_as_of)
+        .transpose()
+        .map_err(|e| e.with_execution(collector.finalize_failed("Invalid as_of")))?;
+
+    let if_match = IfMatch::from_headers(&headers)
+        .map_err(|e| e.with_execution(collector.finalize_failed("Invalid If-Match header")))?;
+
+    let (span_id, result) = match as_of {
+        Some(ref as_of) => {
+            let span_id = collector.begin_agent_span("EventService");
+            let result = state.services.events().asset_as_of(&asset_id, as_of).await;
+            (span_id, result)
+        }
+        None => {
+            let span_id = collector.begin_agent_span("SearchService");
+            let result = state.services.search().get_asset(&asset_id).await;
+            (span_id, result)
+        }
+    };
 
     match result {
         Ok(Some(asset)) => {
@@ -132,8 +188,17 @@ pub async fn get_asset(
                 },
             );
             collector.end_agent_span(span_id, SpanStatus::Ok);
+
+            if if_match.is_some_and(|if_match| !if_match.matches(asset.revision)) {
+                let exec = collector.finalize_failed("If-Match precondition failed");
+                return Err(ApiError::precondition_failed(
+                    "If-Match does not match the current asset revision",
+                )
+                .with_execution(exec));
+            }
+
             let exec = collector.finalize();
-            Ok(ok_with_execution(asset, exec))
+            Ok(with_etag(asset.revision, ok_with_execution(asset, exec)))
         }
         Ok(None) => {
             let _ = collector.attach_artifact(
@@ -165,6 +230,21 @@ pub async fn get_asset(
 }
 
 /// List/search assets with pagination
+///
+/// The `q` parameter takes a structured query such as
+/// `type:model tag:llama size>2GB "exact phrase"`, combined with the other
+/// filter parameters. Pass the returned `next_cursor` as `cursor` to fetch
+/// the next page, and `include_total=false` to skip counting.
+#[utoipa::path(
+    get,
+    path = "/v1/assets",
+    tag = "assets",
+    params(SearchAssetsRequest),
+    responses(
+        (status = 200, description = "Page of matching assets", body = PaginatedExecutionEnvelope<Asset>),
+        (status = 400, description = "Invalid query or cursor", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, collector))]
 pub async fn list_assets(
     State(state): State<AppState>,
@@ -175,11 +255,7 @@ pub async fn list_assets(
 
     let span_id = collector.begin_agent_span("SearchService");
 
-    let result = state
-        .services
-        .search()
-        .search_assets(params)
-        .await;
+    let result = state.services.search().search_assets(params).await;
 
     match result {
         Ok(response) => {
@@ -197,17 +273,20 @@ pub async fn list_assets(
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
 
-            let has_more = response.offset + response.assets.len() as i64
-                > response.total.min(response.offset + response.limit);
-
             Ok(Json(PaginatedExecutionEnvelope {
                 items: response.assets,
                 pagination: PaginationMeta {
                     total: response.total,
                     offset: response.offset,
                     limit: response.limit,
-                    has_more,
+                    has_more: response.has_more,
+                    next_cursor: response.next_cursor,
                 },
+                snippets: response
+                    .snippets
+                    .into_iter()
+                    .map(|(id, snippet)| (id.to_string(), snippet))
+                    .collect(),
                 execution: exec,
             }))
         }
@@ -228,13 +307,31 @@ pub async fn list_assets(
 }
 
 /// Update asset metadata
-#[instrument(skip(state, collector))]
+///
+/// The expected revision comes from `If-Match` or the body's
+/// `expected_revision`. A stale `If-Match` fails with 412, a stale body
+/// revision with 409. The response carries the new revision as its `ETag`.
+#[utoipa::path(
+    patch,
+    path = "/v1/assets/{id}",
+    tag = "assets",
+    params(("id" = String, Path, description = "Asset ID")),
+    request_body = UpdateAssetRequest,
+    responses(
+        (status = 200, description = "Asset updated, with its new revision as `ETag`", body = ExecutionEnvelope<UpdateAssetResponse>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "`expected_revision` is stale", body = ErrorResponse),
+        (status = 412, description = "`If-Match` is stale", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector, headers))]
 pub async fn update_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Path(id): Path<String>,
+    headers: HeaderMap,
     Json(mut request): Json<UpdateAssetRequest>,
-) -> ApiResult<Json<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
+) -> ApiResult<WithEtag<Json<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>>> {
     info!("Updating asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
@@ -246,13 +343,20 @@ pub async fn update_asset(
     // Set asset ID from path
     request.asset_id = asset_id;
 
+    let if_match = IfMatch::from_headers(&headers)
+        .map_err(|e| e.with_execution(collector.finalize_failed("Invalid If-Match header")))?;
+    request.expected_revision = expected_revision(
+        &state,
+        &asset_id,
+        if_match.as_ref(),
+        request.expected_revision,
+    )
+    .await
+    .map_err(|e| e.with_execution(collector.finalize_failed("If-Match precondition failed")))?;
+
     let span_id = collector.begin_agent_span("RegistrationService");
 
-    let result = state
-        .services
-        .registration()
-        .update_asset(request)
-        .await;
+    let result = state.services.registration().update_asset(request).await;
 
     match result {
         Ok(response) => {
@@ -266,7 +370,10 @@ pub async fn update_asset(
             );
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
-            Ok(ok_with_execution(response, exec))
+            Ok(with_etag(
+                response.asset.revision,
+                ok_with_execution(response, exec),
+            ))
         }
         Err(e) => {
             let _ = collector.attach_artifact(
@@ -279,18 +386,36 @@ pub async fn update_asset(
             );
             collector.end_agent_span(span_id, SpanStatus::Failed);
             let exec = collector.finalize();
-            Err(ApiError::from(e).with_execution(exec))
+            Err(conditional_error(e, if_match.as_ref()).with_execution(exec))
         }
     }
 }
 
 /// Delete asset
-#[instrument(skip(state, collector))]
+///
+/// With an `If-Match` header, the asset is only deleted while its revision
+/// matches, and fails with 412 otherwise.
+#[utoipa::path(
+    delete,
+    path = "/v1/assets/{id}",
+    tag = "assets",
+    params(("id" = String, Path, description = "Asset ID")),
+    responses(
+        (status = 200, description = "Asset deleted", body = ExecutionEnvelope<EmptyResponse>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 412, description = "`If-Match` is stale", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector, headers))]
 pub async fn delete_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Path(id): Path<String>,
-) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<crate::responses::EmptyResponse>>)> {
+    headers: HeaderMap,
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<crate::responses::EmptyResponse>>,
+)> {
     info!("Deleting asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
@@ -299,12 +424,18 @@ pub async fn delete_asset(
         err.with_execution(exec)
     })?;
 
+    let if_match = IfMatch::from_headers(&headers)
+        .map_err(|e| e.with_execution(collector.finalize_failed("Invalid If-Match header")))?;
+    let expected_revision = expected_revision(&state, &asset_id, if_match.as_ref(), None)
+        .await
+        .map_err(|e| e.with_execution(collector.finalize_failed("If-Match precondition failed")))?;
+
     let span_id = collector.begin_agent_span("RegistrationService");
 
     let result = state
         .services
         .registration()
-        .delete_asset(&asset_id)
+        .delete_asset(&asset_id, expected_revision)
         .await;
 
     match result {
@@ -321,6 +452,170 @@ pub async fn delete_asset(
             let exec = collector.finalize();
             Ok(deleted_with_execution(exec))
         }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(conditional_error(e, if_match.as_ref()).with_execution(exec))
+        }
+    }
+}
+
+/// Resolve the revision a write must be based on from `If-Match` and the body
+async fn expected_revision(
+    state: &AppState,
+    asset_id: &AssetId,
+    if_match: Option<&IfMatch>,
+    body: Option<u64>,
+) -> ApiResult<Option<u64>> {
+    let header = match if_match {
+        Some(if_match) => {
+            if_match
+                .expected_revision(|| async {
+                    state
+                        .services
+                        .search()
+                        .get_asset(asset_id)
+                        .await?
+                        .map(|asset| asset.revision)
+                        .ok_or_else(|| {
+                            ApiError::not_found(format!("Asset not found: {}", asset_id))
+                        })
+                })
+                .await?
+        }
+        None => None,
+    };
+
+    merge_expected_revision(header, body)
+}
+
+// ============================================================================
+// Search Handlers
+// ============================================================================
+
+/// Suggest asset names completing a prefix
+#[utoipa::path(
+    get,
+    path = "/v1/assets/suggest",
+    tag = "search",
+    params(SuggestParams),
+    responses(
+        (status = 200, description = "Asset names starting with the prefix", body = ExecutionEnvelope<Vec<String>>),
+    ),
+)]
+#[instrument(skip(state, collector))]
+pub async fn suggest_assets(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Query(params): Query<SuggestParams>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<String>>>> {
+    debug!("Suggesting assets for prefix: {}", params.prefix);
+
+    let span_id = collector.begin_agent_span("SearchService");
+
+    let result = state
+        .services
+        .search()
+        .autocomplete(&params.prefix, params.limit)
+        .await;
+
+    match result {
+        Ok(suggestions) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "suggestions".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": suggestions.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(suggestions, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Query parameters for name suggestions
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SuggestParams {
+    /// Prefix to complete
+    pub prefix: String,
+
+    /// Maximum number of suggestions
+    #[serde(default = "default_suggest_limit")]
+    pub limit: usize,
+}
+
+fn default_suggest_limit() -> usize {
+    10
+}
+
+/// Count matching assets by type, license, author, tag and storage backend
+#[utoipa::path(
+    get,
+    path = "/v1/assets/facets",
+    tag = "search",
+    params(SearchAssetsRequest),
+    responses(
+        (status = 200, description = "Facet counts of matching assets", body = ExecutionEnvelope<SearchFacets>),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector))]
+pub async fn asset_facets(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Query(params): Query<SearchAssetsRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<SearchFacets>>> {
+    debug!("Counting facets with filters: {:?}", params);
+
+    let span_id = collector.begin_agent_span("SearchService");
+
+    let result = state.services.search().facets(params).await;
+
+    match result {
+        Ok(facets) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "facets".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_types": facets.asset_types.len(),
+                        "licenses": facets.licenses.len(),
+                        "authors": facets.authors.len(),
+                        "tags": facets.tags.len(),
+                        "storage_backends": facets.storage_backends.len(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(facets, exec))
+        }
         Err(e) => {
             let _ = collector.attach_artifact(
                 span_id,
@@ -342,6 +637,16 @@ pub async fn delete_asset(
 // ============================================================================
 
 /// Get dependency graph for an asset
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/dependencies",
+    tag = "dependencies",
+    params(("id" = String, Path, description = "Asset ID"), DependencyGraphParams),
+    responses(
+        (status = 200, description = "Dependency graph", body = ExecutionEnvelope<DependencyGraphResponse>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, collector))]
 pub async fn get_dependencies(
     State(state): State<AppState>,
@@ -364,11 +669,7 @@ pub async fn get_dependencies(
 
     let span_id = collector.begin_agent_span("SearchService");
 
-    let result = state
-        .services
-        .search()
-        .get_dependency_graph(request)
-        .await;
+    let result = state.services.search().get_dependency_graph(request).await;
 
     match result {
         Ok(response) => {
@@ -401,13 +702,24 @@ pub async fn get_dependencies(
 }
 
 /// Query parameters for dependency graph
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct DependencyGraphParams {
     /// Maximum depth to traverse (-1 for unlimited)
     pub max_depth: Option<i32>,
 }
 
 /// Get reverse dependencies (dependents)
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/dependents",
+    tag = "dependencies",
+    params(("id" = String, Path, description = "Asset ID")),
+    responses(
+        (status = 200, description = "Assets directly depending on the asset", body = ExecutionEnvelope<Vec<Asset>>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    ),
+)]
 #[instrument(skip(state, collector))]
 pub async fn get_dependents(
     State(state): State<AppState>,
@@ -466,14 +778,21 @@ pub async fn get_dependents(
 // ============================================================================
 
 /// Health check endpoint
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "system",
+    responses(
+        (status = 200, description = "Service health", body = HealthResponse),
+    ),
+)]
 #[instrument(skip(state))]
 pub async fn health_check(State(state): State<AppState>) -> ApiResult<HealthResponse> {
     debug!("Health check requested");
 
     // For now, simple health check
     // In production, you'd check database connectivity, etc.
-    let mut response = HealthResponse::healthy()
-        .with_version(env!("CARGO_PKG_VERSION"));
+    let mut response = HealthResponse::healthy().with_version(env!("CARGO_PKG_VERSION"));
 
     // Add database health check
     // Try to perform a simple database operation
@@ -494,6 +813,14 @@ pub async fn health_check(State(state): State<AppState>) -> ApiResult<HealthResp
 ///
 /// This endpoint exposes Prometheus metrics for monitoring.
 /// Metrics are collected throughout the application lifecycle.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "Prometheus metrics", body = String, content_type = "text/plain"),
+    ),
+)]
 #[instrument]
 pub async fn metrics() -> ApiResult<String> {
     debug!("Metrics requested");
@@ -515,6 +842,14 @@ pub async fn metrics() -> ApiResult<String> {
 // ============================================================================
 
 /// Get API version information
+#[utoipa::path(
+    get,
+    path = "/version",
+    tag = "system",
+    responses(
+        (status = 200, description = "Version information", body = ApiResponse<VersionInfo>),
+    ),
+)]
 #[instrument]
 pub async fn version_info() -> ApiResult<Json<crate::responses::ApiResponse<VersionInfo>>> {
     let info = VersionInfo {
@@ -529,7 +864,7 @@ pub async fn version_info() -> ApiResult<Json<crate::responses::ApiResponse<Vers
 }
 
 /// Version information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct VersionInfo {
     /// Semantic version
     pub version: String,
@@ -544,6 +879,12 @@ pub struct VersionInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::request;
+    use axum::body::Body;
+    use axum::http::{header::ETAG, Method};
+    use llm_registry_service::test_support::register_model;
+    use tower::ServiceExt;
 
     #[test]
     fn test_version_info_creation() {
@@ -556,4 +897,68 @@ mod tests {
         assert_eq!(info.version, "0.1.0");
         assert_eq!(info.api_version, "v1");
     }
+
+    #[tokio::test]
+    async fn test_etag_and_if_match() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let asset = register_model(&state.services, "model", "1.0.0").await;
+        let app = build_router(state);
+        let uri = format!("/v1/assets/{}", asset.id);
+        let patch =
+            || Body::from(r#"{"asset_id":"01ARZ3NDEKTSV4RRFFQ69G5FAV","add_tags":["bot"]}"#);
+
+        let response = app
+            .clone()
+            .oneshot(request(Method::GET, &uri, None, Body::empty()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], "\"1\"");
+
+        let response = app
+            .clone()
+            .oneshot(request(Method::GET, &uri, Some("\"2\""), Body::empty()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let response = app
+            .clone()
+            .oneshot(request(Method::PATCH, &uri, Some("\"1\""), patch()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], "\"2\"");
+
+        // A second writer that read revision 1 loses the race
+        let response = app
+            .clone()
+            .oneshot(request(Method::PATCH, &uri, Some("\"1\""), patch()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::DELETE,
+                &uri,
+                Some("\"1\", W/\"2\""),
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let response = app
+            .oneshot(request(
+                Method::DELETE,
+                &uri,
+                Some("\"1\", \"2\""),
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
@@ -0,0 +1,187 @@
+//! Asset history handlers
+//!
+//! `GET /v1/assets/{id}/history` lists an asset's revisions with the fields
+//! each one changed, rebuilt from the event log. The same projection serves
+//! the `as_of` parameter of `GET /v1/assets/{id}`, parsed here.
+
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_core::AssetId;
+use llm_registry_service::{AsOf, AssetRevision};
+use tracing::{debug, instrument};
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Parse an `as_of` parameter as an RFC 3339 timestamp or else an event ID
+pub(crate) fn parse_as_of(value: &str) -> ApiResult<AsOf> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ApiError::bad_request("as_of must not be empty"));
+    }
+
+    Ok(match chrono::DateTime::parse_from_rfc3339(value) {
+        Ok(timestamp) => AsOf::Timestamp(timestamp.with_timezone(&chrono::Utc)),
+        Err(_) => AsOf::Event(value.to_string()),
+    })
+}
+
+/// List an asset's revisions with the fields each one changed, oldest first
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/history",
+    tag = "assets",
+    params(("id" = String, Path, description = "Asset ID")),
+    responses(
+        (status = 200, description = "Revisions, oldest first", body = ExecutionEnvelope<Vec<AssetRevision>>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector))]
+pub async fn get_asset_history(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<AssetRevision>>>> {
+    debug!("Getting history for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("EventService");
+
+    let result = state.services.events().asset_history(&asset_id).await;
+
+    match result {
+        Ok(history) if !history.is_empty() => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "history".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "revisions": history.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(history, exec))
+        }
+        Ok(_) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(format!("No history for asset: {}", id)),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::not_found(format!("No history for asset: {}", id)).with_execution(exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::{read_data, request};
+    use axum::body::Body;
+    use axum::http::{header::ETAG, Method, StatusCode};
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_as_of_and_history() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let asset = register_model(&state.services, "model", "1.0.0").await;
+        let registered_at = chrono::Utc::now();
+        let app = build_router(state);
+        let uri = format!("/v1/assets/{}", asset.id);
+
+        let patch = Body::from(r#"{"asset_id":"01ARZ3NDEKTSV4RRFFQ69G5FAV","license":"MIT"}"#);
+        let response = app
+            .clone()
+            .oneshot(request(Method::PATCH, &uri, None, patch))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                &format!("{}/history", uri),
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let history = read_data(response).await;
+        assert_eq!(history[0]["event_type"], "asset_registered");
+        assert_eq!(history[1]["revision"], 2);
+        assert_eq!(history[1]["changes"][0]["field"], "license");
+        assert_eq!(history[1]["changes"][0]["new"], "MIT");
+
+        // The registration event ID selects the asset before the update
+        let registered_event = history[0]["event_id"].as_str().unwrap().to_string();
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                &format!("{}?as_of={}", uri, registered_event),
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], "\"1\"");
+        assert!(read_data(response).await["metadata"]["license"].is_null());
+
+        let before = (registered_at - chrono::Duration::hours(1)).to_rfc3339();
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                &format!("{}?as_of={}", uri, before.replace('+', "%2B")),
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let unknown = format!("/v1/assets/{}/history", AssetId::new());
+        let response = app
+            .oneshot(request(Method::GET, &unknown, None, Body::empty()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
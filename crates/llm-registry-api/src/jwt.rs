@@ -11,6 +11,7 @@ use jsonwebtoken::{
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// JWT configuration
@@ -57,7 +58,7 @@ impl Default for JwtConfig {
     fn default() -> Self {
         Self {
             secret: "change-me-in-production".to_string(),
-            expiration_seconds: 3600,          // 1 hour
+            expiration_seconds: 3600,              // 1 hour
             refresh_expiration_seconds: 86400 * 7, // 7 days
             issuer: "llm-registry".to_string(),
             audience: "llm-registry-api".to_string(),
@@ -262,7 +263,9 @@ impl Claims {
         }
 
         if self.sub.is_empty() {
-            return Err(TokenError::InvalidClaims("Subject cannot be empty".to_string()));
+            return Err(TokenError::InvalidClaims(
+                "Subject cannot be empty".to_string(),
+            ));
         }
 
         Ok(())
@@ -310,7 +313,7 @@ pub enum TokenError {
 }
 
 /// JWT token pair (access + refresh)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TokenPair {
     /// Access token
     pub access_token: String,
@@ -427,9 +430,7 @@ impl JwtManager {
 
         // Verify it's a refresh token
         if !claims.has_role("refresh") {
-            return Err(TokenError::InvalidClaims(
-                "Not a refresh token".to_string(),
-            ));
+            return Err(TokenError::InvalidClaims("Not a refresh token".to_string()));
         }
 
         // Generate new token pair
@@ -496,7 +497,10 @@ mod tests {
             secret: String::new(),
             ..create_test_config()
         };
-        assert!(matches!(config.validate(), Err(JwtConfigError::EmptySecret)));
+        assert!(matches!(
+            config.validate(),
+            Err(JwtConfigError::EmptySecret)
+        ));
     }
 
     #[test]
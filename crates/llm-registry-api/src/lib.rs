@@ -37,35 +37,54 @@
 //! # }
 //! ```
 
+pub mod audit;
 pub mod auth;
 pub mod auth_handlers;
+pub mod conditional;
+pub mod content;
 pub mod error;
+pub mod event_log;
+pub mod events_stream;
 pub mod execution_middleware;
 pub mod graphql;
 pub mod grpc;
 pub mod handlers;
+pub mod history;
 pub mod jwt;
 pub mod metrics_middleware;
 pub mod middleware;
+pub mod openapi;
 pub mod rate_limit;
 pub mod rbac;
 pub mod responses;
 pub mod routes;
+pub mod signature;
+pub mod validation;
+pub mod versioning;
+pub mod webhooks;
+
+#[cfg(test)]
+mod test_support;
 
 // Re-export main types for convenience
-pub use auth::{AuthState, AuthUser, optional_auth, require_auth, require_role};
+pub use auth::{
+    optional_auth, require_auth, require_permission, require_permission_from_query, require_role,
+    AuthState, AuthUser,
+};
 pub use auth_handlers::{AuthHandlerState, LoginRequest, LoginResponse, RefreshTokenRequest};
 pub use error::{ApiError, ApiResult, ErrorResponse};
+pub use execution_middleware::require_execution_context;
 pub use graphql::{
-    build_schema, graphql_handler, graphql_playground, AppSchema, Mutation as GraphQLMutation,
-    Query as GraphQLQuery,
+    build_schema, build_schema_with_auth, build_schema_with_limits, graphql_handler,
+    graphql_playground, graphql_ws_handler, AppSchema, GraphQLWsState, Mutation as GraphQLMutation,
+    Query as GraphQLQuery, SchemaLimits, Subscription as GraphQLSubscription,
 };
 pub use grpc::{build_grpc_server, serve_grpc, RegistryServiceImpl, RegistryServiceServer};
 pub use handlers::{AppState, VersionInfo};
 pub use jwt::{Claims, JwtConfig, JwtManager, TokenPair};
 pub use middleware::{CorsConfig, MiddlewareConfig, UuidRequestIdGenerator};
+pub use openapi::{openapi_routes, ApiDoc};
 pub use rate_limit::{rate_limit, RateLimitConfig, RateLimiterState};
-pub use execution_middleware::require_execution_context;
 pub use rbac::{Permission, RbacPolicy, Role};
 pub use responses::{
     created, created_with_execution, deleted, deleted_with_execution, no_content, ok,
@@ -80,7 +99,8 @@ use llm_registry_service::ServiceRegistry;
 /// Build a complete API server with middleware
 ///
 /// This is a convenience function that builds a router with all middleware
-/// configured using default settings.
+/// configured using default settings. The router is built with
+/// [`build_router`], so no authentication or permission checks apply.
 ///
 /// # Arguments
 ///
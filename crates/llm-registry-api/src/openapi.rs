@@ -0,0 +1,416 @@
+//! OpenAPI specification
+//!
+//! The OpenAPI 3 document is generated from the `#[utoipa::path]`
+//! annotations on the REST handlers and the `ToSchema` derives on their
+//! request and response types, so it cannot drift from the code. It is
+//! served as JSON on `/openapi.json`, with an interactive reference on
+//! `/docs`.
+//!
+//! Two conventions are applied to the whole document rather than repeated
+//! on every handler:
+//!
+//! - `/v1` operations behind the execution middleware require the
+//!   `X-Execution-Id` and `X-Parent-Span-Id` headers.
+//! - Requests may carry a JWT as a bearer token. Most operations also accept
+//!   anonymous requests; those that do not declare their own requirement.
+
+use axum::{routing::get, Json, Router};
+use utoipa::openapi::{
+    path::{Parameter, ParameterBuilder, ParameterIn},
+    security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme},
+    Object, OpenApi as OpenApiDocument, Required, Type,
+};
+use utoipa::{Modify, OpenApi};
+use utoipa_scalar::{Scalar, Servable};
+
+use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+use crate::{
+    audit, auth_handlers, content, event_log, events_stream, handlers, history, signature,
+    validation, versioning, webhooks,
+};
+
+/// Path of the JSON specification
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+/// Path of the interactive API reference
+pub const DOCS_PATH: &str = "/docs";
+
+/// Name of the bearer token security scheme
+pub const BEARER_AUTH: &str = "bearer_auth";
+
+/// Paths that are not behind the execution-context middleware
+const WITHOUT_EXECUTION_CONTEXT: &[&str] = &["/v1/auth/", "/v1/events/stream"];
+
+/// OpenAPI document for the REST API
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "LLM Registry API",
+        description = "Registry for LLM models, prompts, datasets and other assets."
+    ),
+    paths(
+        handlers::register_asset,
+        handlers::list_assets,
+        handlers::get_asset,
+        handlers::update_asset,
+        handlers::delete_asset,
+        history::get_asset_history,
+        content::upload_asset_content,
+        content::download_asset_content,
+        handlers::suggest_assets,
+        handlers::asset_facets,
+        versioning::list_asset_versions,
+        versioning::resolve_asset_version,
+        versioning::get_latest_version,
+        versioning::deprecate_asset,
+        handlers::get_dependencies,
+        handlers::get_dependents,
+        signature::get_asset_signature,
+        signature::verify_asset_signature,
+        validation::validate_registration,
+        validation::validate_asset,
+        validation::verify_asset_integrity,
+        validation::compute_checksum,
+        audit::verify_audit_chain,
+        audit::list_audit_checkpoints,
+        audit::create_audit_checkpoint,
+        webhooks::create_webhook,
+        webhooks::list_webhooks,
+        webhooks::get_webhook,
+        webhooks::update_webhook,
+        webhooks::delete_webhook,
+        webhooks::list_webhook_deliveries,
+        webhooks::replay_webhook_delivery,
+        handlers::health_check,
+        handlers::metrics,
+        handlers::version_info,
+        event_log::list_events,
+        event_log::list_asset_events,
+        events_stream::stream_events,
+        auth_handlers::login,
+        auth_handlers::refresh_token,
+        auth_handlers::me,
+        auth_handlers::logout,
+        auth_handlers::generate_api_key,
+    ),
+    components(schemas(
+        crate::responses::PaginatedResponse<llm_registry_core::Asset>,
+        crate::responses::ResponseMeta,
+    )),
+    modifiers(&SecurityAddon, &ExecutionContextHeaders),
+    tags(
+        (name = "assets", description = "Asset registration and lifecycle"),
+        (name = "content", description = "Asset content upload and download"),
+        (name = "search", description = "Search suggestions and facets"),
+        (name = "versions", description = "Version listing, resolution and deprecation"),
+        (name = "dependencies", description = "Dependency graphs"),
+        (name = "signatures", description = "Asset signatures"),
+        (name = "validation", description = "Pre-flight validation and integrity checks"),
+        (name = "audit", description = "Tamper-evident audit log"),
+        (name = "webhooks", description = "Outbound webhooks and deliveries"),
+        (name = "events", description = "Registry event log and feed"),
+        (name = "auth", description = "Authentication"),
+        (name = "system", description = "Health, metrics and version"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Registers the bearer scheme and accepts anonymous or bearer requests
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_AUTH,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+
+        openapi.security = Some(vec![
+            SecurityRequirement::default(),
+            SecurityRequirement::new(BEARER_AUTH, Vec::<String>::new()),
+        ]);
+    }
+}
+
+/// Adds the execution-context headers to `/v1` paths that require them
+struct ExecutionContextHeaders;
+
+impl Modify for ExecutionContextHeaders {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            if !requires_execution_context(path) {
+                continue;
+            }
+            item.parameters.get_or_insert_with(Vec::new).extend([
+                header(
+                    HEADER_EXECUTION_ID,
+                    "Execution-wide identifier assigned by the calling Core",
+                ),
+                header(
+                    HEADER_PARENT_SPAN_ID,
+                    "Span ID (ULID) of the calling Core's span",
+                ),
+            ]);
+        }
+    }
+}
+
+/// Whether requests to a path must carry an execution context
+pub fn requires_execution_context(path: &str) -> bool {
+    path.starts_with("/v1/")
+        && !WITHOUT_EXECUTION_CONTEXT
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+}
+
+fn header(name: &str, description: &str) -> Parameter {
+    ParameterBuilder::new()
+        .name(name)
+        .parameter_in(ParameterIn::Header)
+        .required(Required::True)
+        .description(Some(description))
+        .schema(Some(Object::with_type(Type::String)))
+        .build()
+}
+
+/// Routes serving the specification and the API reference
+pub fn openapi_routes() -> Router {
+    let spec = ApiDoc::openapi();
+
+    Router::new()
+        .route(
+            OPENAPI_PATH,
+            get({
+                let spec = spec.clone();
+                move || async move { Json(spec) }
+            }),
+        )
+        .merge(Scalar::with_url(DOCS_PATH, spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jwt::{JwtConfig, JwtManager};
+    use crate::{build_router, build_router_with_auth, AppState, AuthHandlerState, AuthState};
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Method, Request, StatusCode},
+    };
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+    use utoipa::openapi::path::HttpMethod;
+
+    /// Substitute sample values for path parameters
+    fn concrete(path: &str) -> String {
+        path.replace("{id}", "01ARZ3NDEKTSV4RRFFQ69G5FAV")
+            .replace("{name}", "llama")
+            .replace("{delivery_id}", "01ARZ3NDEKTSV4RRFFQ69G5FAW")
+    }
+
+    fn operations(spec: &OpenApiDocument) -> Vec<(Method, String)> {
+        let mut operations = Vec::new();
+        for (path, item) in &spec.paths.paths {
+            for (method, operation) in [
+                (Method::GET, &item.get),
+                (Method::POST, &item.post),
+                (Method::PATCH, &item.patch),
+                (Method::PUT, &item.put),
+                (Method::DELETE, &item.delete),
+            ] {
+                if operation.is_some() {
+                    operations.push((method, path.clone()));
+                }
+            }
+        }
+        operations
+    }
+
+    #[tokio::test]
+    async fn test_documented_operations_are_routed() {
+        let spec = ApiDoc::openapi();
+        let jwt_manager = || JwtManager::new(JwtConfig::new("test-secret")).unwrap();
+        let router = build_router_with_auth(
+            AppState::new(ServiceRegistry::in_memory()),
+            AuthHandlerState::new(jwt_manager()),
+            AuthState::new(jwt_manager()),
+        );
+
+        let operations = operations(&spec);
+        assert!(operations.len() > 25);
+
+        for (method, path) in operations {
+            let request = Request::builder()
+                .method(method.clone())
+                .uri(concrete(&path))
+                .header(HEADER_EXECUTION_ID, "exec-1")
+                .header(HEADER_PARENT_SPAN_ID, "01ARZ3NDEKTSV4RRFFQ69G5FAV")
+                .header("content-type", "application/json")
+                .body(Body::from("{}"))
+                .unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            assert_ne!(
+                status,
+                StatusCode::METHOD_NOT_ALLOWED,
+                "{} {}",
+                method,
+                path
+            );
+
+            // Unmatched routes fall through to axum's empty 404. Other
+            // bodies are left unread: the event stream never ends.
+            if status == StatusCode::NOT_FOUND {
+                let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                assert!(!body.is_empty(), "{} {} is not routed", method, path);
+            }
+        }
+    }
+
+    /// Operations registered by the `/v1` route builders
+    ///
+    /// axum cannot list a router's routes, so they are read from the
+    /// `.route(...)` calls in the source of the builders nested under `/v1`.
+    fn routed_operations() -> Vec<(Method, String)> {
+        let source = include_str!("routes.rs");
+        let mut operations = Vec::new();
+        for builder in source
+            .split("\nfn build_")
+            .skip(1)
+            .filter(|builder| builder.contains("-> Router<AppState>"))
+        {
+            let body = &builder[..builder.find("\n}\n").unwrap_or(builder.len())];
+            for call in body.split(".route(").skip(1) {
+                let call = &call[..closing_paren(call)];
+                let mut parts = call.splitn(3, '"');
+                let path = parts.nth(1).expect("route path literal");
+                let handlers = parts.next().unwrap_or_default();
+                for (name, method) in [
+                    ("get(", Method::GET),
+                    ("post(", Method::POST),
+                    ("patch(", Method::PATCH),
+                    ("put(", Method::PUT),
+                    ("delete(", Method::DELETE),
+                ] {
+                    let routed = handlers.match_indices(name).any(|(at, _)| {
+                        !handlers[..at].ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                    });
+                    if routed {
+                        operations.push((method, format!("/v1{}", path)));
+                    }
+                }
+            }
+        }
+        operations
+    }
+
+    /// Index of the parenthesis closing an already opened call
+    fn closing_paren(call: &str) -> usize {
+        let mut depth = 1;
+        for (at, c) in call.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                return at;
+            }
+        }
+        call.len()
+    }
+
+    #[test]
+    fn test_routed_operations_are_documented() {
+        let spec = ApiDoc::openapi();
+        let operations = routed_operations();
+        assert!(operations.len() > 25);
+
+        for (method, path) in operations {
+            let http_method = match method {
+                Method::GET => HttpMethod::Get,
+                Method::POST => HttpMethod::Post,
+                Method::PATCH => HttpMethod::Patch,
+                Method::PUT => HttpMethod::Put,
+                _ => HttpMethod::Delete,
+            };
+            assert!(
+                spec.paths.get_path_operation(&path, http_method).is_some(),
+                "{} {} is not documented",
+                method,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_spec_documents_conventions() {
+        let spec = ApiDoc::openapi();
+        let json = serde_json::to_value(&spec).unwrap();
+
+        let schemas = &json["components"]["schemas"];
+        for name in [
+            "RegisterAssetRequest",
+            "ErrorResponse",
+            "ExecutionEnvelope_Asset",
+            "PaginatedResponse_Asset",
+        ] {
+            assert!(schemas.get(name).is_some(), "missing schema {}", name);
+        }
+        assert_eq!(
+            json["components"]["securitySchemes"][BEARER_AUTH]["scheme"],
+            "bearer"
+        );
+
+        for (path, item) in &spec.paths.paths {
+            let headers: Vec<_> = item
+                .parameters
+                .iter()
+                .flatten()
+                .filter(|p| p.parameter_in == ParameterIn::Header)
+                .map(|p| p.name.as_str())
+                .collect();
+            let expected: &[&str] = if requires_execution_context(path) {
+                &[HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID]
+            } else {
+                &[]
+            };
+            assert_eq!(headers, expected, "{}", path);
+        }
+
+        let me = spec
+            .paths
+            .get_path_operation("/v1/auth/me", HttpMethod::Get);
+        assert!(me.unwrap().security.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_serves_spec_and_docs() {
+        let router = build_router(AppState::new(ServiceRegistry::in_memory()));
+
+        let response = router
+            .clone()
+            .oneshot(Request::get(OPENAPI_PATH).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(json["openapi"].as_str().unwrap().starts_with("3."));
+        assert!(json["paths"]["/v1/assets"]["post"].is_object());
+
+        let response = router
+            .oneshot(Request::get(DOCS_PATH).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("<html"));
+    }
+}
@@ -26,6 +26,21 @@ impl Permission {
         }
     }
 
+    /// Permission to query the registry event log
+    pub fn read_events() -> Self {
+        Self::new("event", "read")
+    }
+
+    /// Permission to sign checkpoints of the audit chain
+    pub fn manage_audit() -> Self {
+        Self::new("audit", "admin")
+    }
+
+    /// Permission to manage webhook subscriptions and their deliveries
+    pub fn manage_webhooks() -> Self {
+        Self::new("webhook", "admin")
+    }
+
     /// Check if this permission matches another (supports wildcards)
     pub fn matches(&self, other: &Permission) -> bool {
         let resource_match = self.resource == "*" || self.resource == other.resource;
@@ -168,6 +183,16 @@ impl RbacPolicy {
         ]);
         self.add_role(viewer);
 
+        // Auditor role
+        let mut auditor = Role::new("auditor");
+        auditor.description = Some("Read-only access to assets and the event log".to_string());
+        auditor.add_permissions(vec![
+            Permission::new("asset", "read"),
+            Permission::new("dependency", "read"),
+            Permission::read_events(),
+        ]);
+        self.add_role(auditor);
+
         // User role
         let mut user = Role::new("user");
         user.description = Some("Regular user with basic permissions".to_string());
@@ -260,25 +285,13 @@ impl RbacPolicy {
     }
 
     /// Check if a set of roles has ANY of the specified permissions
-    pub fn has_any_permission(
-        &mut self,
-        roles: &[String],
-        permissions: &[Permission],
-    ) -> bool {
-        permissions
-            .iter()
-            .any(|p| self.has_permission(roles, p))
+    pub fn has_any_permission(&mut self, roles: &[String], permissions: &[Permission]) -> bool {
+        permissions.iter().any(|p| self.has_permission(roles, p))
     }
 
     /// Check if a set of roles has ALL of the specified permissions
-    pub fn has_all_permissions(
-        &mut self,
-        roles: &[String],
-        permissions: &[Permission],
-    ) -> bool {
-        permissions
-            .iter()
-            .all(|p| self.has_permission(roles, p))
+    pub fn has_all_permissions(&mut self, roles: &[String], permissions: &[Permission]) -> bool {
+        permissions.iter().all(|p| self.has_permission(roles, p))
     }
 }
 
@@ -346,6 +359,7 @@ mod tests {
         assert!(policy.get_role("admin").is_some());
         assert!(policy.get_role("developer").is_some());
         assert!(policy.get_role("viewer").is_some());
+        assert!(policy.get_role("auditor").is_some());
     }
 
     #[test]
@@ -380,14 +394,8 @@ mod tests {
         let moderator_roles = vec!["moderator".to_string()];
 
         // Should have permissions from both moderator and viewer
-        assert!(policy.has_permission(
-            &moderator_roles,
-            &Permission::new("asset", "read")
-        ));
-        assert!(policy.has_permission(
-            &moderator_roles,
-            &Permission::new("asset", "delete")
-        ));
+        assert!(policy.has_permission(&moderator_roles, &Permission::new("asset", "read")));
+        assert!(policy.has_permission(&moderator_roles, &Permission::new("asset", "delete")));
     }
 
     #[test]
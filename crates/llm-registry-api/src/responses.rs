@@ -10,9 +10,11 @@ use axum::{
 };
 use llm_registry_core::execution::ExecutionResult;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
 
 /// Standard success response wrapper
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ApiResponse<T> {
     /// Response data
     pub data: T,
@@ -23,7 +25,7 @@ pub struct ApiResponse<T> {
 }
 
 /// Response metadata
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ResponseMeta {
     /// Request ID for tracking
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -91,7 +93,7 @@ where
 }
 
 /// Paginated response wrapper
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     /// List of items
     pub items: Vec<T>,
@@ -101,10 +103,11 @@ pub struct PaginatedResponse<T> {
 }
 
 /// Pagination metadata
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PaginationMeta {
-    /// Total number of items (without pagination)
-    pub total: i64,
+    /// Total number of items (without pagination), if counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
 
     /// Current offset
     pub offset: i64,
@@ -114,20 +117,39 @@ pub struct PaginationMeta {
 
     /// Whether there are more results
     pub has_more: bool,
+
+    /// Opaque cursor for fetching the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PaginatedResponse<T> {
     /// Create a new paginated response
     pub fn new(items: Vec<T>, total: i64, offset: i64, limit: i64) -> Self {
-        let has_more = offset + items.len() as i64 > total.min(offset + limit);
+        let has_more = offset + (items.len() as i64) < total;
 
         Self {
             items,
             pagination: PaginationMeta {
-                total,
+                total: Some(total),
                 offset,
                 limit,
                 has_more,
+                next_cursor: None,
+            },
+        }
+    }
+
+    /// Create a cursor-paginated response, without a total count
+    pub fn with_cursor(items: Vec<T>, limit: i64, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            pagination: PaginationMeta {
+                total: None,
+                offset: 0,
+                limit,
+                has_more: next_cursor.is_some(),
+                next_cursor,
             },
         }
     }
@@ -143,7 +165,7 @@ where
 }
 
 /// Empty response for operations with no return data
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct EmptyResponse {
     /// Success message
     pub message: String,
@@ -172,7 +194,7 @@ impl IntoResponse for EmptyResponse {
 }
 
 /// Health check response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthResponse {
     /// Service status
     pub status: HealthStatus,
@@ -187,7 +209,7 @@ pub struct HealthResponse {
 }
 
 /// Health status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum HealthStatus {
     /// Service is healthy
@@ -199,7 +221,7 @@ pub enum HealthStatus {
 }
 
 /// Component health status
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ComponentHealth {
     /// Component status
     pub status: HealthStatus,
@@ -340,7 +362,7 @@ pub fn deleted() -> (StatusCode, Json<EmptyResponse>) {
 ///
 /// Every `/v1/*` response includes the full span hierarchy (repo + agent spans)
 /// so that the calling Core can reconstruct the execution graph.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ExecutionEnvelope<T> {
     /// Original response data.
     pub data: T,
@@ -371,12 +393,15 @@ where
 }
 
 /// Paginated response with execution spans.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PaginatedExecutionEnvelope<T> {
     /// List of items.
     pub items: Vec<T>,
     /// Pagination metadata.
     pub pagination: PaginationMeta,
+    /// Highlighted text matches for search results, keyed by item ID.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub snippets: HashMap<String, String>,
     /// Execution trace.
     pub execution: ExecutionResult,
 }
@@ -400,7 +425,10 @@ pub fn created_with_execution<T: Serialize>(
     data: T,
     execution: ExecutionResult,
 ) -> (StatusCode, Json<ExecutionEnvelope<T>>) {
-    (StatusCode::CREATED, Json(ExecutionEnvelope::new(data, execution)))
+    (
+        StatusCode::CREATED,
+        Json(ExecutionEnvelope::new(data, execution)),
+    )
 }
 
 /// Helper: deleted response with execution spans.
@@ -433,9 +461,20 @@ mod tests {
         let response = PaginatedResponse::new(items, 10, 0, 5);
 
         assert_eq!(response.items.len(), 3);
-        assert_eq!(response.pagination.total, 10);
+        assert_eq!(response.pagination.total, Some(10));
         assert_eq!(response.pagination.offset, 0);
         assert_eq!(response.pagination.limit, 5);
+        assert!(response.pagination.has_more);
+    }
+
+    #[test]
+    fn test_cursor_paginated_response() {
+        let response = PaginatedResponse::with_cursor(vec![1, 2], 2, Some("abc".to_string()));
+        assert!(response.pagination.has_more);
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["pagination"]["next_cursor"], "abc");
+        assert!(json["pagination"].get("total").is_none());
     }
 
     #[test]
@@ -3,23 +3,49 @@
 //! This module defines all API routes and builds the router.
 
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, patch, post},
     Router,
 };
 
 use crate::{
-    auth::{optional_auth, require_auth, AuthState},
+    audit::{create_audit_checkpoint, list_audit_checkpoints, verify_audit_chain},
+    auth::{
+        optional_auth, require_auth, require_permission, require_permission_from_query, AuthState,
+    },
     auth_handlers::{generate_api_key, login, logout, me, refresh_token, AuthHandlerState},
+    content::{download_asset_content, upload_asset_content},
+    event_log::{list_asset_events, list_events},
+    events_stream::stream_events,
     execution_middleware::require_execution_context,
-    graphql::{build_schema, graphql_handler, graphql_playground},
+    graphql::{
+        build_schema_with_auth, graphql_handler, graphql_playground, graphql_ws_handler,
+        GraphQLWsState,
+    },
     handlers::{
-        delete_asset, get_asset, get_dependencies, get_dependents, health_check, list_assets,
-        metrics, register_asset, update_asset, version_info, AppState,
+        asset_facets, delete_asset, get_asset, get_dependencies, get_dependents, health_check,
+        list_assets, metrics, register_asset, suggest_assets, update_asset, version_info, AppState,
+    },
+    history::get_asset_history,
+    openapi::openapi_routes,
+    rbac::Permission,
+    signature::{get_asset_signature, verify_asset_signature},
+    validation::{compute_checksum, validate_asset, validate_registration, verify_asset_integrity},
+    versioning::{deprecate_asset, get_latest_version, list_asset_versions, resolve_asset_version},
+    webhooks::{
+        create_webhook, delete_webhook, get_webhook, list_webhook_deliveries, list_webhooks,
+        replay_webhook_delivery, update_webhook,
     },
 };
 
 /// Build the API router with all routes
+///
+/// No authentication or permission layers are applied, so the event log,
+/// audit and webhook routes are open to every client. This is the router
+/// [`build_api_server`](crate::build_api_server) serves; use
+/// [`build_router_with_auth`] to require the permissions described on each
+/// route builder.
 pub fn build_router(state: AppState) -> Router {
     Router::new()
         // Health and info endpoints
@@ -27,8 +53,19 @@ pub fn build_router(state: AppState) -> Router {
         .route("/metrics", get(metrics))
         .route("/version", get(version_info))
         // API v1 routes
-        .nest("/v1", build_v1_routes())
+        .nest(
+            "/v1",
+            build_v1_routes()
+                .merge(build_content_routes())
+                .merge(build_event_log_routes())
+                .merge(build_event_stream_routes())
+                .merge(build_audit_routes())
+                .merge(build_audit_checkpoint_routes())
+                .merge(build_webhook_routes()),
+        )
         .with_state(state)
+        // OpenAPI specification and API reference
+        .merge(openapi_routes())
 }
 
 /// Build the API router with authentication enabled
@@ -64,12 +101,15 @@ pub fn build_router_with_auth(
         ))
         .with_state(auth_handler_state);
 
-    // Build v1 routes (with optional authentication on some endpoints)
-    let v1_routes = build_v1_routes().with_state(state);
+    // Build v1 routes (with authentication and permissions on some endpoints)
+    let v1_routes = build_v1_routes()
+        .merge(permissioned_routes(&auth_state))
+        .with_state(state);
 
     // Combine all routes
     Router::new()
         .merge(public_routes)
+        .merge(openapi_routes())
         .nest("/v1/auth", auth_routes)
         .nest("/v1/auth", protected_auth_routes)
         .nest("/v1", v1_routes)
@@ -78,14 +118,15 @@ pub fn build_router_with_auth(
 /// Build the API router with GraphQL support
 ///
 /// This function builds a complete router with REST API, GraphQL API,
-/// authentication, and GraphQL Playground.
+/// GraphQL subscriptions over WebSocket, authentication, and GraphQL
+/// Playground.
 pub fn build_router_with_graphql(
     state: AppState,
     auth_handler_state: AuthHandlerState,
     auth_state: AuthState,
 ) -> Router {
     // Build GraphQL schema
-    let schema = build_schema(state.services.clone());
+    let schema = build_schema_with_auth(state.services.clone(), auth_state.clone());
 
     // Build public routes
     let public_routes = Router::new()
@@ -102,7 +143,15 @@ pub fn build_router_with_graphql(
             auth_state.clone(),
             optional_auth,
         ))
-        .with_state(schema);
+        .with_state(schema.clone());
+
+    // Build GraphQL subscription route, authenticated by the init payload
+    let graphql_ws_route = Router::new()
+        .route("/graphql/ws", get(graphql_ws_handler))
+        .with_state(GraphQLWsState {
+            schema,
+            auth_state: auth_state.clone(),
+        });
 
     // Build auth routes (public)
     let auth_routes = Router::new()
@@ -122,17 +171,59 @@ pub fn build_router_with_graphql(
         .with_state(auth_handler_state);
 
     // Build v1 routes
-    let v1_routes = build_v1_routes().with_state(state);
+    let v1_routes = build_v1_routes()
+        .merge(permissioned_routes(&auth_state))
+        .with_state(state);
 
     // Combine all routes
     Router::new()
         .merge(public_routes)
+        .merge(openapi_routes())
         .merge(graphql_route)
+        .merge(graphql_ws_route)
         .nest("/v1/auth", auth_routes)
         .nest("/v1/auth", protected_auth_routes)
         .nest("/v1", v1_routes)
 }
 
+/// Build the v1 routes that authentication applies to
+///
+/// These routes are kept outside [`build_v1_routes`] so that they can be
+/// layered with authentication or a permission check of their own.
+fn permissioned_routes(auth_state: &AuthState) -> Router<AppState> {
+    Router::new()
+        .merge(build_content_routes().layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            optional_auth,
+        )))
+        .merge(
+            build_event_log_routes().layer(middleware::from_fn_with_state(
+                (auth_state.clone(), Permission::read_events()),
+                require_permission,
+            )),
+        )
+        .merge(
+            build_event_stream_routes().layer(middleware::from_fn_with_state(
+                (auth_state.clone(), Permission::read_events()),
+                require_permission_from_query,
+            )),
+        )
+        .merge(build_audit_routes().layer(middleware::from_fn_with_state(
+            (auth_state.clone(), Permission::read_events()),
+            require_permission,
+        )))
+        .merge(
+            build_audit_checkpoint_routes().layer(middleware::from_fn_with_state(
+                (auth_state.clone(), Permission::manage_audit()),
+                require_permission,
+            )),
+        )
+        .merge(build_webhook_routes().layer(middleware::from_fn_with_state(
+            (auth_state.clone(), Permission::manage_webhooks()),
+            require_permission,
+        )))
+}
+
 /// Build v1 API routes
 ///
 /// All v1 routes require an execution context (X-Execution-Id and
@@ -145,14 +236,111 @@ fn build_v1_routes() -> Router<AppState> {
         .route("/assets/{id}", get(get_asset))
         .route("/assets/{id}", patch(update_asset))
         .route("/assets/{id}", delete(delete_asset))
+        .route("/assets/{id}/history", get(get_asset_history))
+        // Search
+        .route("/assets/suggest", get(suggest_assets))
+        .route("/assets/facets", get(asset_facets))
+        // Versioning
+        .route("/assets/by-name/{name}/versions", get(list_asset_versions))
+        .route("/assets/by-name/{name}/resolve", get(resolve_asset_version))
+        .route("/assets/by-name/{name}/latest", get(get_latest_version))
+        .route("/assets/{id}/deprecate", post(deprecate_asset))
         // Dependencies
         .route("/assets/{id}/dependencies", get(get_dependencies))
         .route("/assets/{id}/dependents", get(get_dependents))
+        // Signatures
+        .route("/assets/{id}/signature", get(get_asset_signature))
+        .route(
+            "/assets/{id}/signature/verify",
+            post(verify_asset_signature),
+        )
+        // Validation and integrity
+        .route("/validate", post(validate_registration))
+        .route("/assets/{id}/validate", post(validate_asset))
+        .route(
+            "/assets/{id}/integrity/verify",
+            post(verify_asset_integrity),
+        )
+        .route("/integrity/checksum", post(compute_checksum))
         // Execution context middleware — rejects requests without valid
         // X-Execution-Id and X-Parent-Span-Id headers.
         .layer(middleware::from_fn(require_execution_context))
 }
 
+/// Build the v1 asset content routes
+///
+/// With authentication enabled, downloads are attributed to the user. Uploads stream into the blob
+/// store, so the request body limit does not apply to them.
+fn build_content_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/assets/{id}/content",
+            post(upload_asset_content)
+                .layer(DefaultBodyLimit::disable())
+                .get(download_asset_content),
+        )
+        .layer(middleware::from_fn(require_execution_context))
+}
+
+/// Build the v1 event log routes
+///
+/// With authentication enabled they require the `event:read` permission.
+fn build_event_log_routes() -> Router<AppState> {
+    Router::new()
+        .route("/events", get(list_events))
+        .route("/assets/{id}/events", get(list_asset_events))
+        .layer(middleware::from_fn(require_execution_context))
+}
+
+/// Build the v1 audit log routes
+///
+/// With authentication enabled they require the `event:read` permission.
+fn build_audit_routes() -> Router<AppState> {
+    Router::new()
+        .route("/audit/verify", get(verify_audit_chain))
+        .route("/audit/checkpoints", get(list_audit_checkpoints))
+        .layer(middleware::from_fn(require_execution_context))
+}
+
+/// Build the v1 audit checkpoint routes
+///
+/// Signing a checkpoint is an administrative action, so with
+/// authentication enabled it requires the `audit:admin` permission.
+fn build_audit_checkpoint_routes() -> Router<AppState> {
+    Router::new()
+        .route("/audit/checkpoints", post(create_audit_checkpoint))
+        .layer(middleware::from_fn(require_execution_context))
+}
+
+/// Build the v1 webhook routes
+///
+/// Webhooks make the server send requests to arbitrary URLs, so with
+/// authentication enabled they require the `webhook:admin` permission.
+fn build_webhook_routes() -> Router<AppState> {
+    Router::new()
+        .route("/webhooks", post(create_webhook))
+        .route("/webhooks", get(list_webhooks))
+        .route("/webhooks/{id}", get(get_webhook))
+        .route("/webhooks/{id}", patch(update_webhook))
+        .route("/webhooks/{id}", delete(delete_webhook))
+        .route("/webhooks/{id}/deliveries", get(list_webhook_deliveries))
+        .route(
+            "/webhooks/{id}/deliveries/{delivery_id}/replay",
+            post(replay_webhook_delivery),
+        )
+        .layer(middleware::from_fn(require_execution_context))
+}
+
+/// Build the v1 event stream routes
+///
+/// Browser `EventSource` clients cannot send the execution context headers,
+/// so the stream does not require them. With authentication enabled it
+/// requires the `event:read` permission, and for the same reason accepts the
+/// token in the `access_token` query parameter.
+fn build_event_stream_routes() -> Router<AppState> {
+    Router::new().route("/events/stream", get(stream_events))
+}
+
 /// Route configuration
 #[derive(Debug, Clone)]
 pub struct RouteConfig {
@@ -204,9 +392,7 @@ mod tests {
 
     #[test]
     fn test_route_config_builder() {
-        let config = RouteConfig::new()
-            .with_base_path("/api")
-            .with_version("v2");
+        let config = RouteConfig::new().with_base_path("/api").with_version("v2");
 
         assert_eq!(config.base_path, "/api");
         assert_eq!(config.version, "v2");
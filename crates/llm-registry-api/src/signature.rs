@@ -0,0 +1,155 @@
+//! Asset signature handlers
+//!
+//! `GET /v1/assets/{id}/signature` returns the signature attached to an
+//! asset, and `POST /v1/assets/{id}/signature/verify` checks it against the
+//! trusted key store.
+
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_core::{AssetId, AssetSignature};
+use llm_registry_service::SignatureVerificationResult;
+use tracing::{debug, instrument};
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Get the signature attached to an asset
+#[utoipa::path(
+    get,
+    path = "/v1/assets/{id}/signature",
+    tag = "signatures",
+    params(("id" = String, Path, description = "Asset ID")),
+    responses(
+        (status = 200, description = "Asset signature", body = ExecutionEnvelope<AssetSignature>),
+        (status = 404, description = "Asset not found or not signed", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector))]
+pub async fn get_asset_signature(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<AssetSignature>>> {
+    debug!("Getting signature for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("SignatureService");
+
+    let result = state.services.signing().get_signature(&asset_id).await;
+
+    match result {
+        Ok(Some(signature)) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "signature".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&signature).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(signature, exec))
+        }
+        Ok(None) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(format!("Asset is not signed: {}", id)),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::not_found(format!("Asset is not signed: {}", id)).with_execution(exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Verify an asset's signature against the trusted key store
+#[utoipa::path(
+    post,
+    path = "/v1/assets/{id}/signature/verify",
+    tag = "signatures",
+    params(("id" = String, Path, description = "Asset ID")),
+    responses(
+        (status = 200, description = "Verification result", body = ExecutionEnvelope<SignatureVerificationResult>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, collector))]
+pub async fn verify_asset_signature(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<SignatureVerificationResult>>> {
+    debug!("Verifying signature for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("SignatureService");
+
+    let result = state
+        .services
+        .signing()
+        .verify_asset_signature(&asset_id)
+        .await;
+
+    match result {
+        Ok(verification) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "signature_verification".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&verification).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(verification, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
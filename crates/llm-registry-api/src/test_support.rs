@@ -0,0 +1,68 @@
+//! Helpers shared by the handler tests
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{
+        header::{AUTHORIZATION, CONTENT_TYPE, IF_MATCH},
+        Method, Request,
+    },
+    response::Response,
+    Router,
+};
+use llm_registry_core::execution::SpanId;
+use llm_registry_service::ServiceRegistry;
+
+use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+use crate::jwt::{Claims, JwtConfig, JwtManager};
+use crate::{build_router_with_auth, AppState, AuthHandlerState, AuthState};
+
+/// A JSON request carrying an execution context and an optional `If-Match`
+pub(crate) fn request(
+    method: Method,
+    uri: &str,
+    if_match: Option<&str>,
+    body: Body,
+) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(HEADER_EXECUTION_ID, "test-execution")
+        .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+        .header(CONTENT_TYPE, "application/json");
+    if let Some(if_match) = if_match {
+        builder = builder.header(IF_MATCH, if_match);
+    }
+    builder.body(body).unwrap()
+}
+
+/// The `data` field of a response envelope
+pub(crate) async fn read_data(response: Response) -> serde_json::Value {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice::<serde_json::Value>(&body).unwrap()["data"].take()
+}
+
+/// Router with authentication enabled, and a request signer for a role
+pub(crate) fn auth_router() -> (Router, impl Fn(Request<Body>, &str) -> Request<Body>) {
+    let jwt_manager = || {
+        JwtManager::new(
+            JwtConfig::new("test-secret-key")
+                .with_issuer("test")
+                .with_audience("test"),
+        )
+        .unwrap()
+    };
+    let app = build_router_with_auth(
+        AppState::new(ServiceRegistry::in_memory()),
+        AuthHandlerState::new(jwt_manager()),
+        AuthState::new(jwt_manager()),
+    );
+    let with_role = move |mut request: Request<Body>, role: &str| {
+        let claims = Claims::new("user123", "test", "test", 3600).with_role(role);
+        let token = jwt_manager().generate_token_with_claims(claims).unwrap();
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        request
+    };
+    (app, with_role)
+}
@@ -0,0 +1,396 @@
+//! Validation and integrity handlers
+//!
+//! `POST /v1/validate` dry-runs a registration, `POST /v1/assets/{id}/validate`
+//! applies policies to a registered asset, and the integrity routes compute
+//! checksums and verify assets against them. Each check is recorded as a
+//! registry event.
+
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_core::AssetId;
+use llm_registry_service::{
+    ComputeChecksumRequest, ComputeChecksumResponse, IntegrityVerificationResult,
+    RegisterAssetRequest, ValidatePoliciesRequest, ValidationResult, VerifyChecksumRequest,
+    VerifyIntegrityRequest,
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Validate a registration request without registering anything
+///
+/// Runs the checks `POST /v1/assets` would run and reports the ones that
+/// fail, so publishing pipelines can check an asset before registering it.
+/// Policy checks still emit `PolicyValidated` events.
+#[utoipa::path(
+    post,
+    path = "/v1/validate",
+    tag = "validation",
+    request_body = RegisterAssetRequest,
+    responses(
+        (status = 200, description = "Validation result; `valid` is false if registration would fail", body = ExecutionEnvelope<ValidationResult>),
+    )
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn validate_registration(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Json(request): Json<RegisterAssetRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<ValidationResult>>> {
+    debug!(
+        "Validating registration: {}@{}",
+        request.name, request.version
+    );
+
+    let span_id = collector.begin_agent_span("RegistrationService");
+
+    let result = state
+        .services
+        .registration()
+        .validate_registration(request)
+        .await;
+
+    match result {
+        Ok(validation) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "validation_result".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&validation).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(validation, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Validate a registered asset against policies
+///
+/// All policies are applied when none are given. Each applied policy emits
+/// a `PolicyValidated` event.
+#[utoipa::path(
+    post,
+    path = "/v1/assets/{id}/validate",
+    tag = "validation",
+    params(("id" = String, Path, description = "Asset ID")),
+    request_body = ValidatePoliciesRequest,
+    responses(
+        (status = 200, description = "Validation result", body = ExecutionEnvelope<ValidationResult>),
+        (status = 400, description = "Unknown policy", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn validate_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(request): Json<ValidatePoliciesRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<ValidationResult>>> {
+    debug!("Validating asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("ValidationService");
+
+    let result = state
+        .services
+        .validation()
+        .validate_registered_asset(&asset_id, request.policies, request.deep)
+        .await;
+
+    match result {
+        Ok(validation) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "validation_result".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&validation).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(validation, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Verify a registered asset against a checksum computed over its content
+///
+/// A mismatch is reported in the result rather than as an error. Every
+/// verification emits a `ChecksumVerified` event, and a mismatch also emits
+/// `ChecksumFailed`.
+#[utoipa::path(
+    post,
+    path = "/v1/assets/{id}/integrity/verify",
+    tag = "validation",
+    params(("id" = String, Path, description = "Asset ID")),
+    request_body = VerifyChecksumRequest,
+    responses(
+        (status = 200, description = "Verification result", body = ExecutionEnvelope<IntegrityVerificationResult>),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn verify_asset_integrity(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(request): Json<VerifyChecksumRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<IntegrityVerificationResult>>> {
+    debug!("Verifying integrity of asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("IntegrityService");
+
+    let result = state
+        .services
+        .integrity()
+        .verify_integrity(VerifyIntegrityRequest {
+            asset_id,
+            computed_checksum: Some(request.checksum),
+        })
+        .await;
+
+    match result {
+        Ok(verification) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "integrity_verification".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&verification).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(verification, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Compute the checksum of base64-encoded content
+#[utoipa::path(
+    post,
+    path = "/v1/integrity/checksum",
+    tag = "validation",
+    request_body = ComputeChecksumRequest,
+    responses(
+        (status = 200, description = "Computed checksum", body = ExecutionEnvelope<ComputeChecksumResponse>),
+        (status = 400, description = "Content is not valid base64", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn compute_checksum(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Json(request): Json<ComputeChecksumRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<ComputeChecksumResponse>>> {
+    debug!("Computing {} checksum", request.algorithm);
+
+    let span_id = collector.begin_agent_span("IntegrityService");
+
+    let result = state.services.integrity().compute_checksum(request).await;
+
+    match result {
+        Ok(response) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "checksum".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&response).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(response, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::{read_data, request};
+    use axum::body::Body;
+    use axum::http::{Method, StatusCode};
+    use llm_registry_service::test_support::{register_model, register_request};
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_validation_and_integrity_endpoints() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        let asset = register_model(&state.services, "model", "1.0.0").await;
+        let events = state.services.events().clone();
+        let app = build_router(state);
+        let post = |uri: String, body: String| request(Method::POST, &uri, None, Body::from(body));
+
+        // Dry runs report problems without registering anything
+        let body = serde_json::to_string(&register_request("model", "2.0.0")).unwrap();
+        let response = app
+            .clone()
+            .oneshot(post("/v1/validate".to_string(), body))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await["valid"], true);
+
+        let body = serde_json::to_string(&register_request("model", "1.0.0")).unwrap();
+        let response = app
+            .clone()
+            .oneshot(post("/v1/validate".to_string(), body))
+            .await
+            .unwrap();
+        let result = read_data(response).await;
+        assert_eq!(result["valid"], false);
+        assert_eq!(result["errors"][0]["code"], "ALREADY_EXISTS");
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/v1/assets/{}/validate", asset.id),
+                r#"{"policies":["license","size"]}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await["valid"], true);
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/v1/assets/{}/validate", asset.id),
+                r#"{"policies":["unknown"]}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // Compute a checksum, then verify the asset against it
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/v1/integrity/checksum".to_string(),
+                r#"{"data":"aGVsbG8=","algorithm":"SHA256"}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let checksum = read_data(response).await["checksum"].clone();
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/v1/assets/{}/integrity/verify", asset.id),
+                serde_json::json!({ "checksum": checksum }).to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let verification = read_data(response).await;
+        assert_eq!(verification["verified"], false);
+        assert!(verification["error"].as_str().unwrap().contains("mismatch"));
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/v1/assets/{}/integrity/verify", asset.id),
+                serde_json::json!({ "checksum": asset.checksum }).to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(read_data(response).await["verified"], true);
+
+        let query = llm_registry_service::EventQuery::new().asset_id(asset.id);
+        let types: Vec<_> = events
+            .query_events(query)
+            .await
+            .unwrap()
+            .events
+            .into_iter()
+            .map(|e| e.event_type.event_name().to_string())
+            .collect();
+        // Registration applied all three policies before the two above
+        assert_eq!(types.iter().filter(|t| *t == "policy_validated").count(), 5);
+        assert_eq!(
+            types.iter().filter(|t| *t == "checksum_verified").count(),
+            2
+        );
+        assert!(types.iter().any(|t| t == "checksum_failed"));
+    }
+}
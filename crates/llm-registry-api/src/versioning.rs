@@ -0,0 +1,413 @@
+//! Asset versioning handlers
+//!
+//! Versions of an asset share its name. These handlers list them, resolve
+//! a SemVer requirement to the highest matching version, return the latest
+//! active version and deprecate a version in favour of another.
+
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_core::{Asset, AssetId};
+use llm_registry_service::{DeprecateVersionRequest, ListVersionsRequest, ListVersionsResponse};
+use semver::VersionReq;
+use serde::Deserialize;
+use tracing::{debug, info, instrument};
+use utoipa::IntoParams;
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{ok_with_execution, ExecutionEnvelope},
+};
+
+/// Query parameters for listing the versions of an asset
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListVersionsParams {
+    /// Include deprecated versions
+    #[serde(default)]
+    pub include_deprecated: bool,
+}
+
+/// Query parameters for resolving a version requirement
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ResolveVersionParams {
+    /// SemVer requirement, e.g. `^1.2` or `>=1.0, <2.0`
+    pub req: String,
+
+    /// Consider deprecated versions when no active one matches
+    #[serde(default)]
+    pub include_deprecated: bool,
+}
+
+/// List the versions of an asset, highest first
+#[utoipa::path(
+    get,
+    path = "/v1/assets/by-name/{name}/versions",
+    tag = "versions",
+    params(("name" = String, Path, description = "Asset name"), ListVersionsParams),
+    responses(
+        (status = 200, description = "Versions of the asset", body = ExecutionEnvelope<ListVersionsResponse>),
+    )
+)]
+#[instrument(skip(state, collector))]
+pub async fn list_asset_versions(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(name): Path<String>,
+    Query(params): Query<ListVersionsParams>,
+) -> ApiResult<Json<ExecutionEnvelope<ListVersionsResponse>>> {
+    debug!("Listing versions of asset: {}", name);
+
+    let span_id = collector.begin_agent_span("VersioningService");
+
+    let result = state
+        .services
+        .versioning()
+        .list_versions(ListVersionsRequest {
+            name,
+            include_deprecated: params.include_deprecated,
+        })
+        .await;
+
+    match result {
+        Ok(response) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "versions".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": response.versions.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(response, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Resolve a version requirement to the highest matching version
+///
+/// Active versions are preferred; deprecated ones are only considered with
+/// `include_deprecated`. Returns 404 when nothing matches.
+#[utoipa::path(
+    get,
+    path = "/v1/assets/by-name/{name}/resolve",
+    tag = "versions",
+    params(("name" = String, Path, description = "Asset name"), ResolveVersionParams),
+    responses(
+        (status = 200, description = "Highest matching version", body = ExecutionEnvelope<Asset>),
+        (status = 400, description = "Invalid version requirement", body = ErrorResponse),
+        (status = 404, description = "No version matches", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector))]
+pub async fn resolve_asset_version(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(name): Path<String>,
+    Query(params): Query<ResolveVersionParams>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    debug!("Resolving {} for asset: {}", params.req, name);
+
+    let req = VersionReq::parse(&params.req).map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid version requirement: {}", e));
+        let exec = collector.finalize_failed("Invalid version requirement");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("VersioningService");
+
+    let result = state
+        .services
+        .versioning()
+        .resolve_version(&name, &req, params.include_deprecated)
+        .await;
+
+    let error = match result {
+        Ok(Some(asset)) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "resolved".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_id": asset.id.to_string(),
+                        "version": asset.metadata.version.to_string(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            return Ok(ok_with_execution(asset, exec));
+        }
+        Ok(None) => ApiError::not_found(format!("No version of {} matches {}", name, req)),
+        Err(e) => ApiError::from(e),
+    };
+
+    let _ = collector.attach_artifact(
+        span_id,
+        SpanArtifact {
+            name: "error".to_string(),
+            content_type: Some("text/plain".to_string()),
+            data: serde_json::Value::String(error.to_string()),
+        },
+    );
+    collector.end_agent_span(span_id, SpanStatus::Failed);
+    let exec = collector.finalize();
+    Err(error.with_execution(exec))
+}
+
+/// Get the latest active version of an asset
+#[utoipa::path(
+    get,
+    path = "/v1/assets/by-name/{name}/latest",
+    tag = "versions",
+    params(("name" = String, Path, description = "Asset name")),
+    responses(
+        (status = 200, description = "Latest active version", body = ExecutionEnvelope<Asset>),
+        (status = 404, description = "No active version", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector))]
+pub async fn get_latest_version(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    debug!("Getting latest version of asset: {}", name);
+
+    let span_id = collector.begin_agent_span("VersioningService");
+
+    let result = state.services.versioning().get_latest_version(&name).await;
+
+    let error = match result {
+        Ok(Some(asset)) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "latest".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_id": asset.id.to_string(),
+                        "version": asset.metadata.version.to_string(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            return Ok(ok_with_execution(asset, exec));
+        }
+        Ok(None) => ApiError::not_found(format!("No active version of {}", name)),
+        Err(e) => ApiError::from(e),
+    };
+
+    let _ = collector.attach_artifact(
+        span_id,
+        SpanArtifact {
+            name: "error".to_string(),
+            content_type: Some("text/plain".to_string()),
+            data: serde_json::Value::String(error.to_string()),
+        },
+    );
+    collector.end_agent_span(span_id, SpanStatus::Failed);
+    let exec = collector.finalize();
+    Err(error.with_execution(exec))
+}
+
+/// Deprecate an asset version
+///
+/// The reason and alternative are recorded as the `deprecation_reason` and
+/// `alternative_version` annotations. The alternative must be another
+/// registered version of the same asset.
+#[utoipa::path(
+    post,
+    path = "/v1/assets/{id}/deprecate",
+    tag = "versions",
+    params(("id" = String, Path, description = "Asset ID")),
+    request_body = DeprecateVersionRequest,
+    responses(
+        (status = 200, description = "Asset deprecated", body = ExecutionEnvelope<Asset>),
+        (status = 400, description = "Already deprecated, or invalid alternative", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn deprecate_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(request): Json<DeprecateVersionRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    info!("Deprecating asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span("VersioningService");
+
+    let result = state
+        .services
+        .versioning()
+        .deprecate_version(&asset_id, request.reason, request.alternative)
+        .await;
+
+    match result {
+        Ok(asset) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "deprecated".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_id": asset.id.to_string(),
+                        "revision": asset.revision,
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(asset, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::{read_data, request};
+    use axum::body::Body;
+    use axum::http::{Method, StatusCode};
+    use llm_registry_service::test_support::register_model;
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_versioning_endpoints() {
+        let state = AppState::new(ServiceRegistry::in_memory());
+        register_model(&state.services, "model", "1.0.0").await;
+        let v1_2 = register_model(&state.services, "model", "1.2.0").await;
+        register_model(&state.services, "model", "2.0.0").await;
+        let app = build_router(state);
+        let get = |uri: &str| request(Method::GET, uri, None, Body::empty());
+
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/versions"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let versions = read_data(response).await;
+        assert_eq!(versions["versions"].as_array().unwrap().len(), 3);
+        assert_eq!(versions["versions"][0]["version"], "2.0.0");
+        assert_eq!(versions["latest"], "2.0.0");
+
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/resolve?req=%5E1.0"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await["id"], v1_2.id.to_string());
+
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/resolve?req=not-a-req"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let deprecate = Body::from(r#"{"reason":"Broken tokenizer","alternative":"2.0.0"}"#);
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                &format!("/v1/assets/{}/deprecate", v1_2.id),
+                None,
+                deprecate,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let deprecated = read_data(response).await;
+        assert_eq!(deprecated["status"], "deprecated");
+        assert_eq!(
+            deprecated["metadata"]["annotations"]["alternative_version"],
+            "2.0.0"
+        );
+
+        // Deprecated versions drop out of listing and resolution
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/versions"))
+            .await
+            .unwrap();
+        assert_eq!(
+            read_data(response).await["versions"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/resolve?req=%5E1.0"))
+            .await
+            .unwrap();
+        assert_eq!(read_data(response).await["metadata"]["version"], "1.0.0");
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/resolve?req=%5E3"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(get("/v1/assets/by-name/model/latest"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await["metadata"]["version"], "2.0.0");
+
+        let response = app
+            .oneshot(get("/v1/assets/by-name/unknown/latest"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
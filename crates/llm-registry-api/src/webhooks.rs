@@ -0,0 +1,601 @@
+//! Webhook management handlers
+//!
+//! Webhooks deliver registry events to external HTTP endpoints, signed
+//! with the subscription's secret. These handlers create, update and delete
+//! subscriptions and expose their delivery log, from which a delivery can be
+//! replayed. All of them require the `webhook:admin` permission when
+//! authentication is enabled.
+
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
+use llm_registry_service::{CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookDelivery};
+use serde::Deserialize;
+use tracing::{debug, info, instrument};
+use utoipa::IntoParams;
+
+use crate::{
+    error::{ApiError, ApiResult, ErrorResponse},
+    handlers::AppState,
+    responses::{
+        created_with_execution, deleted_with_execution, ok_with_execution, EmptyResponse,
+        ExecutionEnvelope,
+    },
+};
+
+/// Create a webhook subscription
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    post,
+    path = "/v1/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created", body = ExecutionEnvelope<Webhook>),
+        (status = 400, description = "Invalid webhook", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Json(request): Json<CreateWebhookRequest>,
+) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<Webhook>>)> {
+    info!("Creating webhook for {}", request.url);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state.services.webhooks().create_webhook(request).await;
+
+    match result {
+        Ok(webhook) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "webhook".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&webhook).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(created_with_execution(webhook, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// List webhook subscriptions
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/webhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "Webhooks", body = ExecutionEnvelope<Vec<Webhook>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<Webhook>>>> {
+    debug!("Listing webhooks");
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state.services.webhooks().list_webhooks().await;
+
+    match result {
+        Ok(webhooks) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "webhooks".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": webhooks.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(webhooks, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Get a webhook subscription
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Webhook", body = ExecutionEnvelope<Webhook>),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn get_webhook(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<Webhook>>> {
+    debug!("Getting webhook: {}", id);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state.services.webhooks().get_webhook(&id).await;
+
+    match result {
+        Ok(webhook) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "webhook".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&webhook).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(webhook, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Update a webhook subscription
+///
+/// Setting `enabled` to true re-enables a webhook that was disabled for
+/// failing and resets its failure count.
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    patch,
+    path = "/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = ExecutionEnvelope<Webhook>),
+        (status = 400, description = "Invalid webhook", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector, request))]
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateWebhookRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<Webhook>>> {
+    info!("Updating webhook: {}", id);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state.services.webhooks().update_webhook(&id, request).await;
+
+    match result {
+        Ok(webhook) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "webhook".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&webhook).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(webhook, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Delete a webhook subscription and its delivery log
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    delete,
+    path = "/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Webhook deleted", body = ExecutionEnvelope<EmptyResponse>),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<crate::responses::EmptyResponse>>,
+)> {
+    info!("Deleting webhook: {}", id);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state.services.webhooks().delete_webhook(&id).await;
+
+    match result {
+        Ok(()) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "deleted_webhook_id".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(id),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(deleted_with_execution(exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Query parameters for the webhook delivery log
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WebhookDeliveriesParams {
+    /// Maximum number of deliveries to return (default 100, at most 1000)
+    pub limit: Option<i64>,
+}
+
+/// List a webhook's deliveries, newest first
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    get,
+    path = "/v1/webhooks/{id}/deliveries",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Webhook ID"), WebhookDeliveriesParams),
+    responses(
+        (status = 200, description = "Deliveries, newest first", body = ExecutionEnvelope<Vec<WebhookDelivery>>),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Query(params): Query<WebhookDeliveriesParams>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<WebhookDelivery>>>> {
+    debug!("Listing deliveries for webhook: {}", id);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state
+        .services
+        .webhooks()
+        .list_deliveries(&id, params.limit.unwrap_or(100))
+        .await;
+
+    match result {
+        Ok(deliveries) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "deliveries".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": deliveries.len() }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(deliveries, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Send a webhook delivery again
+///
+/// The replay is queued as a new delivery referencing the original, so the
+/// log of the original attempts is kept.
+///
+/// Requires the `webhook:admin` permission.
+#[utoipa::path(
+    post,
+    path = "/v1/webhooks/{id}/deliveries/{delivery_id}/replay",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Webhook ID"), ("delivery_id" = String, Path, description = "Delivery to replay")),
+    responses(
+        (status = 201, description = "Replay queued", body = ExecutionEnvelope<WebhookDelivery>),
+        (status = 404, description = "Webhook or delivery not found", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 403, description = "Missing `webhook:admin` permission", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(state, collector))]
+pub async fn replay_webhook_delivery(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path((id, delivery_id)): Path<(String, String)>,
+) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<WebhookDelivery>>)> {
+    info!("Replaying delivery {} of webhook {}", delivery_id, id);
+
+    let span_id = collector.begin_agent_span("WebhookService");
+
+    let result = state
+        .services
+        .webhooks()
+        .replay_delivery(&id, &delivery_id)
+        .await;
+
+    match result {
+        Ok(delivery) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "delivery".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::to_value(&delivery).unwrap_or_default(),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(created_with_execution(delivery, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::build_router;
+    use crate::test_support::{auth_router, read_data, request};
+    use axum::body::Body;
+    use axum::http::Method;
+    use llm_registry_service::ServiceRegistry;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_webhook_routes_require_permission() {
+        let (app, with_role) = auth_router();
+        let create = || {
+            let body = serde_json::json!({
+                "url": "https://example.com/hooks/registry",
+                "secret": "0123456789abcdef",
+            });
+            request(
+                Method::POST,
+                "/v1/webhooks",
+                None,
+                Body::from(body.to_string()),
+            )
+        };
+
+        let response = app.clone().oneshot(create()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = app
+            .clone()
+            .oneshot(with_role(create(), "developer"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let list = request(Method::GET, "/v1/webhooks", None, Body::empty());
+        let response = app.clone().oneshot(list).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .clone()
+            .oneshot(with_role(create(), "admin"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let list = request(Method::GET, "/v1/webhooks", None, Body::empty());
+        let response = app.oneshot(with_role(list, "admin")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await.as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_management() {
+        let app = build_router(AppState::new(ServiceRegistry::in_memory()));
+
+        let create = serde_json::json!({
+            "url": "https://example.com/hooks/registry",
+            "secret": "0123456789abcdef",
+            "event_types": ["asset_registered"],
+            "tags": ["prod"],
+        });
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                "/v1/webhooks",
+                None,
+                Body::from(create.to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let webhook = read_data(response).await;
+        let id = webhook["id"].as_str().unwrap().to_string();
+        assert_eq!(webhook["enabled"], true);
+        assert!(webhook.get("secret").is_none());
+
+        let invalid = serde_json::json!({ "url": "ftp://example.com", "secret": "short" });
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                "/v1/webhooks",
+                None,
+                Body::from(invalid.to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let uri = format!("/v1/webhooks/{}", id);
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::PATCH,
+                &uri,
+                None,
+                Body::from(serde_json::json!({ "enabled": false }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await["enabled"], false);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                &format!("{}/deliveries", uri),
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_data(response).await, serde_json::json!([]));
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                Method::POST,
+                &format!("{}/deliveries/missing/replay", uri),
+                None,
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(request(Method::DELETE, &uri, None, Body::empty()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app
+            .oneshot(request(Method::GET, &uri, None, Body::empty()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
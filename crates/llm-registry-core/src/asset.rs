@@ -13,11 +13,13 @@ use crate::checksum::Checksum;
 use crate::dependency::AssetReference;
 use crate::error::{RegistryError, Result};
 use crate::provenance::Provenance;
+use crate::signature::{signing_payload, AssetSignature};
 use crate::storage::StorageLocation;
 use crate::types::{Annotations, AssetId, AssetStatus, Tags};
 
 /// Types of assets that can be stored in the registry
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AssetType {
     /// Language model (GPT, BERT, etc.)
@@ -90,11 +92,13 @@ impl Default for AssetType {
 ///
 /// Contains descriptive and technical information about the asset.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct AssetMetadata {
     /// Human-readable name of the asset
     pub name: String,
 
     /// Semantic version of the asset
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "1.0.0"))]
     pub version: Version,
 
     /// Optional description of the asset
@@ -168,9 +172,10 @@ impl AssetMetadata {
             }
             // Basic MIME type validation
             if !ct.contains('/') {
-                return Err(RegistryError::ValidationError(
-                    format!("Invalid content type format: {}", ct),
-                ));
+                return Err(RegistryError::ValidationError(format!(
+                    "Invalid content type format: {}",
+                    ct
+                )));
             }
         }
 
@@ -273,6 +278,7 @@ impl AssetMetadataBuilder {
 
 /// Main asset structure representing a versioned artifact in the registry
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Asset {
     /// Unique identifier for this asset
     pub id: AssetId,
@@ -292,6 +298,10 @@ pub struct Asset {
     /// Checksum for integrity verification
     pub checksum: Checksum,
 
+    /// Detached signature over the asset's signing payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<AssetSignature>,
+
     /// Provenance information
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provenance: Option<Provenance>,
@@ -309,6 +319,20 @@ pub struct Asset {
     /// Optional timestamp when the asset was deprecated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated_at: Option<DateTime<Utc>>,
+
+    /// Revision number, incremented by the repository on every update
+    ///
+    /// Updates are rejected when the stored revision no longer matches the
+    /// revision the change was based on.
+    #[serde(default = "initial_revision")]
+    pub revision: u64,
+}
+
+/// Revision of a newly registered asset
+pub const INITIAL_REVISION: u64 = 1;
+
+fn initial_revision() -> u64 {
+    INITIAL_REVISION
 }
 
 impl Asset {
@@ -334,11 +358,13 @@ impl Asset {
             status: AssetStatus::default(),
             storage,
             checksum,
+            signature: None,
             provenance: None,
             dependencies: Vec::new(),
             created_at: now,
             updated_at: now,
             deprecated_at: None,
+            revision: INITIAL_REVISION,
         })
     }
 
@@ -361,6 +387,10 @@ impl Asset {
             prov.validate()?;
         }
 
+        if let Some(ref sig) = self.signature {
+            sig.validate()?;
+        }
+
         for dep in &self.dependencies {
             dep.validate()?;
         }
@@ -368,6 +398,16 @@ impl Asset {
         Ok(())
     }
 
+    /// Get the canonical payload covered by the asset signature
+    pub fn signing_payload(&self) -> Vec<u8> {
+        signing_payload(&self.asset_type, &self.metadata, &self.checksum)
+    }
+
+    /// Check if the asset carries a signature
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
     /// Update the asset status
     pub fn set_status(&mut self, status: AssetStatus) {
         self.status = status;
@@ -435,6 +475,7 @@ pub struct AssetBuilder {
     status: AssetStatus,
     storage: StorageLocation,
     checksum: Checksum,
+    signature: Option<AssetSignature>,
     provenance: Option<Provenance>,
     dependencies: Vec<AssetReference>,
     created_at: DateTime<Utc>,
@@ -455,6 +496,7 @@ impl AssetBuilder {
             status: AssetStatus::default(),
             storage,
             checksum,
+            signature: None,
             provenance: None,
             dependencies: Vec::new(),
             created_at: Utc::now(),
@@ -473,6 +515,12 @@ impl AssetBuilder {
         self
     }
 
+    /// Set the signature
+    pub fn signature(mut self, signature: AssetSignature) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
     /// Set the provenance
     pub fn provenance(mut self, provenance: Provenance) -> Self {
         self.provenance = Some(provenance);
@@ -506,6 +554,10 @@ impl AssetBuilder {
             prov.validate()?;
         }
 
+        if let Some(ref sig) = self.signature {
+            sig.validate()?;
+        }
+
         for dep in &self.dependencies {
             dep.validate()?;
         }
@@ -523,11 +575,13 @@ impl AssetBuilder {
             status: self.status,
             storage: self.storage,
             checksum: self.checksum,
+            signature: self.signature,
             provenance: self.provenance,
             dependencies: self.dependencies,
             created_at: self.created_at,
             updated_at: self.created_at,
             deprecated_at,
+            revision: INITIAL_REVISION,
         })
     }
 
@@ -546,11 +600,13 @@ impl AssetBuilder {
             status: self.status,
             storage: self.storage,
             checksum: self.checksum,
+            signature: self.signature,
             provenance: self.provenance,
             dependencies: self.dependencies,
             created_at: self.created_at,
             updated_at: self.created_at,
             deprecated_at,
+            revision: INITIAL_REVISION,
         }
     }
 }
@@ -628,9 +684,15 @@ mod tests {
         assert_eq!(metadata.license.as_deref(), Some("MIT"));
         assert_eq!(metadata.tags.len(), 2);
         assert!(metadata.has_tag("nlp"));
-        assert_eq!(metadata.get_annotation("framework"), Some(&"pytorch".to_string()));
+        assert_eq!(
+            metadata.get_annotation("framework"),
+            Some(&"pytorch".to_string())
+        );
         assert_eq!(metadata.size_bytes, Some(1024));
-        assert_eq!(metadata.content_type.as_deref(), Some("application/octet-stream"));
+        assert_eq!(
+            metadata.content_type.as_deref(),
+            Some("application/octet-stream")
+        );
     }
 
     #[test]
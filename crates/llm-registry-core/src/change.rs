@@ -0,0 +1,166 @@
+//! Field-level changes between asset revisions
+//!
+//! Update events record the fields they changed, with the old and new values,
+//! so that an asset can be rebuilt from its registration snapshot by applying
+//! the changes in order, and so that its history can be shown field by field.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::asset::Asset;
+use crate::error::{RegistryError, Result};
+
+/// Mutable asset fields tracked in change sets, in reporting order
+///
+/// Identity fields (ID, type, name and version) never change after
+/// registration and are not tracked.
+pub const TRACKED_FIELDS: &[&str] = &[
+    "status",
+    "description",
+    "license",
+    "tags",
+    "annotations",
+    "size_bytes",
+    "content_type",
+    "storage",
+    "checksum",
+    "signature",
+    "provenance",
+    "dependencies",
+    "deprecated_at",
+];
+
+/// A change to a single asset field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FieldChange {
+    /// Name of the changed field, one of [`TRACKED_FIELDS`]
+    pub field: String,
+
+    /// Value before the change (`null` if unset)
+    #[serde(default)]
+    pub old: Value,
+
+    /// Value after the change (`null` if unset)
+    #[serde(default)]
+    pub new: Value,
+}
+
+impl Asset {
+    /// Compute the changes turning this asset into `after`
+    pub fn diff(&self, after: &Asset) -> Vec<FieldChange> {
+        TRACKED_FIELDS
+            .iter()
+            .filter_map(|field| {
+                let old = self.field_value(field);
+                let new = after.field_value(field);
+                (old != new).then(|| FieldChange {
+                    field: field.to_string(),
+                    old,
+                    new,
+                })
+            })
+            .collect()
+    }
+
+    /// Apply a recorded change, setting the field to its new value
+    pub fn apply_change(&mut self, change: &FieldChange) -> Result<()> {
+        let new = change.new.clone();
+        match change.field.as_str() {
+            "status" => self.status = serde_json::from_value(new)?,
+            "description" => self.metadata.description = serde_json::from_value(new)?,
+            "license" => self.metadata.license = serde_json::from_value(new)?,
+            "tags" => self.metadata.tags = serde_json::from_value(new)?,
+            "annotations" => self.metadata.annotations = serde_json::from_value(new)?,
+            "size_bytes" => self.metadata.size_bytes = serde_json::from_value(new)?,
+            "content_type" => self.metadata.content_type = serde_json::from_value(new)?,
+            "storage" => self.storage = serde_json::from_value(new)?,
+            "checksum" => self.checksum = serde_json::from_value(new)?,
+            "signature" => self.signature = serde_json::from_value(new)?,
+            "provenance" => self.provenance = serde_json::from_value(new)?,
+            "dependencies" => self.dependencies = serde_json::from_value(new)?,
+            "deprecated_at" => self.deprecated_at = serde_json::from_value(new)?,
+            field => {
+                return Err(RegistryError::ValidationError(format!(
+                    "Unknown asset field in change: {}",
+                    field
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Current value of a tracked field
+    fn field_value(&self, field: &str) -> Value {
+        let value = match field {
+            "status" => serde_json::to_value(self.status),
+            "description" => serde_json::to_value(&self.metadata.description),
+            "license" => serde_json::to_value(&self.metadata.license),
+            "tags" => serde_json::to_value(&self.metadata.tags),
+            "annotations" => serde_json::to_value(&self.metadata.annotations),
+            "size_bytes" => serde_json::to_value(self.metadata.size_bytes),
+            "content_type" => serde_json::to_value(&self.metadata.content_type),
+            "storage" => serde_json::to_value(&self.storage),
+            "checksum" => serde_json::to_value(&self.checksum),
+            "signature" => serde_json::to_value(&self.signature),
+            "provenance" => serde_json::to_value(&self.provenance),
+            "dependencies" => serde_json::to_value(&self.dependencies),
+            "deprecated_at" => serde_json::to_value(self.deprecated_at),
+            _ => Ok(Value::Null),
+        };
+        value.unwrap_or(Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_asset;
+    use crate::types::AssetStatus;
+
+    #[test]
+    fn test_diff_reports_changed_fields() {
+        let before = test_asset("gpt-2", "1.0.0");
+        let mut after = before.clone();
+        after.metadata.description = Some("Updated".to_string());
+        after.metadata.add_annotation("owner", "ml-team");
+        after.set_status(AssetStatus::Deprecated);
+
+        let changes = before.diff(&after);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["status", "description", "annotations", "deprecated_at"]
+        );
+        assert_eq!(changes[1].old, Value::Null);
+        assert_eq!(changes[1].new, Value::String("Updated".to_string()));
+
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn test_apply_changes_round_trip() {
+        let before = test_asset("gpt-2", "1.0.0");
+        let mut after = before.clone();
+        after.metadata.tags = vec!["llm".to_string()];
+        after.metadata.license = Some("MIT".to_string());
+        after.metadata.size_bytes = Some(1024);
+
+        let mut replayed = before.clone();
+        for change in before.diff(&after) {
+            replayed.apply_change(&change).unwrap();
+        }
+        assert_eq!(replayed, after);
+    }
+
+    #[test]
+    fn test_apply_unknown_field_fails() {
+        let mut asset = test_asset("gpt-2", "1.0.0");
+        let change = FieldChange {
+            field: "name".to_string(),
+            old: Value::Null,
+            new: Value::String("other".to_string()),
+        };
+        assert!(asset.apply_change(&change).is_err());
+    }
+}
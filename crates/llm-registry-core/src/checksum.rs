@@ -11,6 +11,7 @@ use crate::error::{RegistryError, Result};
 
 /// Supported hashing algorithms for checksum verification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "UPPERCASE")]
 pub enum HashAlgorithm {
     /// SHA-256 (most widely supported)
@@ -36,6 +37,26 @@ impl HashAlgorithm {
         self.hash_length() * 2
     }
 
+    /// Hash data, returning the lowercase hexadecimal digest
+    pub fn digest(&self, data: &[u8]) -> String {
+        let mut hasher = self.hasher();
+        hasher.update(data);
+        hasher.finalize().value
+    }
+
+    /// Start an incremental hash, for data that arrives in chunks
+    pub fn hasher(&self) -> ChecksumHasher {
+        let state = match self {
+            HashAlgorithm::SHA256 => HasherState::Sha256(Default::default()),
+            HashAlgorithm::SHA3_256 => HasherState::Sha3_256(Box::default()),
+            HashAlgorithm::BLAKE3 => HasherState::Blake3(Box::default()),
+        };
+        ChecksumHasher {
+            algorithm: *self,
+            state,
+        }
+    }
+
     /// Validate that a hash string has the correct length for this algorithm
     pub fn validate_hash_format(&self, hash: &str) -> Result<()> {
         let expected_len = self.hex_length();
@@ -96,6 +117,7 @@ impl FromStr for HashAlgorithm {
 /// Stores a hash value along with the algorithm used to compute it.
 /// This allows for verification of asset contents and detection of tampering.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Checksum {
     /// The hashing algorithm used
     pub algorithm: HashAlgorithm,
@@ -153,6 +175,62 @@ impl Checksum {
     }
 }
 
+/// Incremental hasher producing a [`Checksum`]
+///
+/// Created with [`HashAlgorithm::hasher`]. Feeding the data in any split
+/// gives the same checksum as hashing it at once.
+#[derive(Clone)]
+pub struct ChecksumHasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Sha256(sha2::Sha256),
+    Sha3_256(Box<sha3::Sha3_256>),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl ChecksumHasher {
+    /// Algorithm the hasher computes
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Feed the next chunk of data
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => sha2::Digest::update(hasher, data),
+            HasherState::Sha3_256(hasher) => sha3::Digest::update(hasher.as_mut(), data),
+            HasherState::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    /// Finish hashing and return the checksum of all data fed
+    pub fn finalize(self) -> Checksum {
+        let value = match self.state {
+            HasherState::Sha256(hasher) => format!("{:x}", sha2::Digest::finalize(hasher)),
+            HasherState::Sha3_256(hasher) => format!("{:x}", sha3::Digest::finalize(*hasher)),
+            HasherState::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        };
+        Checksum {
+            algorithm: self.algorithm,
+            value,
+        }
+    }
+}
+
+impl fmt::Debug for ChecksumHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChecksumHasher")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
 impl fmt::Display for Checksum {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:{}", self.algorithm, self.value)
@@ -163,6 +241,41 @@ impl fmt::Display for Checksum {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_digest() {
+        assert_eq!(
+            HashAlgorithm::SHA256.digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        for algorithm in [
+            HashAlgorithm::SHA256,
+            HashAlgorithm::SHA3_256,
+            HashAlgorithm::BLAKE3,
+        ] {
+            assert!(algorithm
+                .validate_hash_format(&algorithm.digest(b"abc"))
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hasher_matches_digest() {
+        let data = b"the quick brown fox jumps over the lazy dog";
+        for algorithm in [
+            HashAlgorithm::SHA256,
+            HashAlgorithm::SHA3_256,
+            HashAlgorithm::BLAKE3,
+        ] {
+            let mut hasher = algorithm.hasher();
+            for chunk in data.chunks(7) {
+                hasher.update(chunk);
+            }
+            let checksum = hasher.finalize();
+            assert_eq!(checksum.algorithm(), algorithm);
+            assert_eq!(checksum.value(), algorithm.digest(data));
+        }
+    }
+
     #[test]
     fn test_hash_algorithm_lengths() {
         assert_eq!(HashAlgorithm::SHA256.hash_length(), 32);
@@ -174,13 +287,19 @@ mod tests {
     #[test]
     fn test_hash_algorithm_validation() {
         let valid_sha256 = "a".repeat(64);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&valid_sha256).is_ok());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&valid_sha256)
+            .is_ok());
 
         let invalid_length = "a".repeat(63);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&invalid_length).is_err());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&invalid_length)
+            .is_err());
 
         let invalid_chars = "g".repeat(64);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&invalid_chars).is_err());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&invalid_chars)
+            .is_err());
     }
 
     #[test]
@@ -224,9 +343,6 @@ mod tests {
     fn test_checksum_display() {
         let hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
         let checksum = Checksum::new(HashAlgorithm::SHA256, hash.to_string()).unwrap();
-        assert_eq!(
-            checksum.to_string(),
-            format!("SHA256:{}", hash)
-        );
+        assert_eq!(checksum.to_string(), format!("SHA256:{}", hash));
     }
 }
@@ -8,10 +8,13 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 
+use crate::asset::Asset;
+use crate::change::FieldChange;
 use crate::types::{AssetId, AssetStatus};
 
 /// Types of events that can occur in the registry
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EventType {
     /// A new asset was registered
@@ -24,6 +27,9 @@ pub enum EventType {
         asset_version: String,
         /// Type of the asset
         asset_type: String,
+        /// The asset as registered, the base that later changes apply to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        snapshot: Option<Box<Asset>>,
     },
 
     /// An existing asset was updated
@@ -34,6 +40,12 @@ pub enum EventType {
         asset_name: String,
         /// Fields that were updated
         updated_fields: Vec<String>,
+        /// Old and new values of the changed fields
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        changes: Vec<FieldChange>,
+        /// Revision of the asset after the update
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        revision: Option<u64>,
     },
 
     /// An asset was deleted/removed
@@ -185,7 +197,12 @@ impl fmt::Display for EventType {
 ///
 /// Represents an event that occurred in the registry, with timestamp and context.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RegistryEvent {
+    /// Unique event identifier (ULID format)
+    #[serde(default = "generate_event_id")]
+    pub event_id: String,
+
     /// Event type and details
     #[serde(flatten)]
     pub event_type: EventType,
@@ -210,10 +227,16 @@ pub struct RegistryEvent {
     pub context: HashMap<String, String>,
 }
 
+/// Generate a new, time-ordered event identifier
+fn generate_event_id() -> String {
+    ulid::Ulid::new().to_string()
+}
+
 impl RegistryEvent {
     /// Create a new event with the current timestamp
     pub fn new(event_type: EventType) -> Self {
         Self {
+            event_id: generate_event_id(),
             event_type,
             timestamp: Utc::now(),
             correlation_id: None,
@@ -288,6 +311,12 @@ impl RegistryEventBuilder {
         }
     }
 
+    /// Set the event ID
+    pub fn event_id(mut self, id: impl Into<String>) -> Self {
+        self.event.event_id = id.into();
+        self
+    }
+
     /// Set the timestamp
     pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
         self.event.timestamp = timestamp;
@@ -343,6 +372,7 @@ mod tests {
             asset_name: "gpt-2".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         assert_eq!(event_type.event_name(), "asset_registered");
@@ -408,6 +438,7 @@ mod tests {
             asset_name: "gpt-2".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         let event = RegistryEvent::new(event_type);
@@ -425,6 +456,7 @@ mod tests {
             asset_name: "gpt-2".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         let event = RegistryEvent::builder(event_type)
@@ -437,7 +469,10 @@ mod tests {
         assert_eq!(event.correlation_id.as_deref(), Some("corr-123"));
         assert_eq!(event.actor.as_deref(), Some("user@example.com"));
         assert_eq!(event.source.as_deref(), Some("api-server"));
-        assert_eq!(event.get_context("request_id"), Some(&"req-456".to_string()));
+        assert_eq!(
+            event.get_context("request_id"),
+            Some(&"req-456".to_string())
+        );
     }
 
     #[test]
@@ -454,8 +489,14 @@ mod tests {
         event.add_context("download_size", "1024");
         event.add_context("download_duration_ms", "150");
 
-        assert_eq!(event.get_context("download_size"), Some(&"1024".to_string()));
-        assert_eq!(event.get_context("download_duration_ms"), Some(&"150".to_string()));
+        assert_eq!(
+            event.get_context("download_size"),
+            Some(&"1024".to_string())
+        );
+        assert_eq!(
+            event.get_context("download_duration_ms"),
+            Some(&"150".to_string())
+        );
     }
 
     #[test]
@@ -467,6 +508,7 @@ mod tests {
             asset_name: "test".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         });
         assert!(!normal_event.is_critical());
 
@@ -486,6 +528,7 @@ mod tests {
             asset_name: "gpt-2".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         let event = RegistryEvent::builder(event_type)
@@ -505,6 +548,7 @@ mod tests {
             asset_name: "gpt-2".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         let event = RegistryEvent::new(event_type);
@@ -515,6 +559,20 @@ mod tests {
         let deserialized: RegistryEvent = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.event_name(), "asset_registered");
         assert_eq!(deserialized.asset_id(), Some(asset_id));
+        assert_eq!(deserialized.event_id, event.event_id);
+    }
+
+    #[test]
+    fn test_event_ids_are_unique() {
+        let event_type = EventType::Custom {
+            name: "test".to_string(),
+            data: HashMap::new(),
+        };
+
+        let first = RegistryEvent::new(event_type.clone());
+        let second = RegistryEvent::new(event_type);
+        assert_ne!(first.event_id, second.event_id);
+        assert!(ulid::Ulid::from_string(&first.event_id).is_ok());
     }
 
     #[test]
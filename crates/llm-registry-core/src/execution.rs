@@ -23,7 +23,9 @@ use ulid::Ulid;
 
 /// Unique identifier for a span within an execution graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(transparent)]
+#[cfg_attr(feature = "openapi", schema(value_type = String))]
 pub struct SpanId(Ulid);
 
 impl SpanId {
@@ -54,6 +56,7 @@ impl std::fmt::Display for SpanId {
 
 /// Execution-wide identifier assigned by the orchestrating Core.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(transparent)]
 pub struct ExecutionId(String);
 
@@ -95,6 +98,7 @@ pub struct ExecutionContext {
 
 /// Discriminator for span hierarchy level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SpanType {
     /// Repository-level span (top of the FEU hierarchy).
@@ -105,6 +109,7 @@ pub enum SpanType {
 
 /// Terminal status of a span.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SpanStatus {
     Ok,
@@ -115,6 +120,7 @@ pub enum SpanStatus {
 ///
 /// Artifacts MUST only be attached to agent-level spans, never to repo spans.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SpanArtifact {
     /// Artifact name (e.g., "registered_asset", "validation_report").
     pub name: String,
@@ -127,6 +133,7 @@ pub struct SpanArtifact {
 
 /// A single execution span (repo-level or agent-level).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ExecutionSpan {
     pub span_id: SpanId,
     pub parent_span_id: SpanId,
@@ -145,6 +152,7 @@ pub struct ExecutionSpan {
 
 /// The finalized execution result included in every response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ExecutionResult {
     pub execution_id: ExecutionId,
     pub spans: Vec<ExecutionSpan>,
@@ -204,9 +212,10 @@ impl SpanCollector {
     pub fn begin_agent_span(&self, agent_name: &str) -> SpanId {
         let mut inner = self.inner.lock().unwrap();
         let span_id = SpanId::new();
+        let parent_span_id = inner.repo_span_id;
         inner.spans.push(ExecutionSpan {
             span_id,
-            parent_span_id: inner.repo_span_id,
+            parent_span_id,
             span_type: SpanType::Agent,
             name: agent_name.to_string(),
             started_at: Utc::now(),
@@ -258,10 +267,7 @@ impl SpanCollector {
     /// `Failed`.
     pub fn finalize(&self) -> ExecutionResult {
         let mut inner = self.inner.lock().unwrap();
-        let any_failed = inner
-            .spans
-            .iter()
-            .any(|s| s.status == SpanStatus::Failed);
+        let any_failed = inner.spans.iter().any(|s| s.status == SpanStatus::Failed);
         // Close repo span
         if let Some(repo) = inner.spans.first_mut() {
             repo.ended_at = Some(Utc::now());
@@ -5,18 +5,23 @@
 //! the LLM Registry system.
 
 pub mod asset;
+pub mod change;
 pub mod checksum;
 pub mod dependency;
 pub mod error;
 pub mod event;
 pub mod execution;
 pub mod provenance;
+pub mod signature;
 pub mod storage;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod types;
 
 // Re-exports for convenience
-pub use asset::{Asset, AssetMetadata, AssetType};
-pub use checksum::{Checksum, HashAlgorithm};
+pub use asset::{Asset, AssetMetadata, AssetType, INITIAL_REVISION};
+pub use change::{FieldChange, TRACKED_FIELDS};
+pub use checksum::{Checksum, ChecksumHasher, HashAlgorithm};
 pub use dependency::{AssetReference, DependencyGraph};
 pub use error::{RegistryError, Result};
 pub use event::{EventType, RegistryEvent};
@@ -25,5 +30,6 @@ pub use execution::{
     SpanId, SpanStatus, SpanType,
 };
 pub use provenance::Provenance;
+pub use signature::{AssetSignature, SignatureAlgorithm};
 pub use storage::{StorageBackend, StorageLocation};
-pub use types::{AssetId, AssetStatus, Tags, Annotations};
+pub use types::{Annotations, AssetId, AssetStatus, Tags};
@@ -0,0 +1,221 @@
+//! Asset signatures
+//!
+//! This module provides types for representing detached cryptographic signatures
+//! over an asset's identity. A signature covers a canonical payload built from the
+//! asset's immutable metadata and its checksum, so that a verifier holding the
+//! signer's public key can confirm who published a given artifact.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::asset::{AssetMetadata, AssetType};
+use crate::checksum::Checksum;
+use crate::error::{RegistryError, Result};
+
+/// Version prefix of the canonical signing payload
+pub const SIGNING_PAYLOAD_VERSION: &str = "llm-registry-signature-v1";
+
+/// Supported signature algorithms
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureAlgorithm {
+    /// Ed25519 (RFC 8032)
+    #[default]
+    Ed25519,
+}
+
+impl SignatureAlgorithm {
+    /// Get the expected length of a raw signature in bytes
+    pub fn signature_length(&self) -> usize {
+        match self {
+            SignatureAlgorithm::Ed25519 => 64,
+        }
+    }
+
+    /// Get the expected length of a raw public key in bytes
+    pub fn public_key_length(&self) -> usize {
+        match self {
+            SignatureAlgorithm::Ed25519 => 32,
+        }
+    }
+}
+
+impl fmt::Display for SignatureAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureAlgorithm::Ed25519 => write!(f, "ed25519"),
+        }
+    }
+}
+
+impl FromStr for SignatureAlgorithm {
+    type Err = RegistryError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ed25519" => Ok(SignatureAlgorithm::Ed25519),
+            _ => Err(RegistryError::ValidationError(format!(
+                "Invalid signature algorithm: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Detached signature over an asset's canonical signing payload
+///
+/// The signature value is the base64-encoded (standard alphabet, padded) raw
+/// signature. `key_id` identifies the trusted public key used for verification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AssetSignature {
+    /// The signature algorithm used
+    pub algorithm: SignatureAlgorithm,
+    /// Identifier of the signing key
+    pub key_id: String,
+    /// Base64-encoded signature value
+    pub value: String,
+}
+
+impl AssetSignature {
+    /// Create a new signature with validation
+    pub fn new(
+        algorithm: SignatureAlgorithm,
+        key_id: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self> {
+        let signature = Self {
+            algorithm,
+            key_id: key_id.into(),
+            value: value.into(),
+        };
+        signature.validate()?;
+        Ok(signature)
+    }
+
+    /// Create a new Ed25519 signature
+    pub fn ed25519(key_id: impl Into<String>, value: impl Into<String>) -> Result<Self> {
+        Self::new(SignatureAlgorithm::Ed25519, key_id, value)
+    }
+
+    /// Validate the signature fields
+    pub fn validate(&self) -> Result<()> {
+        if self.key_id.trim().is_empty() {
+            return Err(RegistryError::ValidationError(
+                "Signature key ID cannot be empty".to_string(),
+            ));
+        }
+
+        if self.key_id.len() > 255 {
+            return Err(RegistryError::ValidationError(
+                "Signature key ID cannot exceed 255 characters".to_string(),
+            ));
+        }
+
+        if self.value.trim().is_empty() {
+            return Err(RegistryError::ValidationError(
+                "Signature value cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for AssetSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.key_id)
+    }
+}
+
+/// Build the canonical payload that is signed for an asset
+///
+/// The payload is a newline-separated list of `field:value` lines covering the
+/// asset type, name, version, size, content type and checksum. Descriptive
+/// fields that can change after registration (description, license, tags and
+/// annotations) are deliberately excluded so metadata updates do not
+/// invalidate an existing signature.
+pub fn signing_payload(
+    asset_type: &AssetType,
+    metadata: &AssetMetadata,
+    checksum: &Checksum,
+) -> Vec<u8> {
+    let size = metadata
+        .size_bytes
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+    let content_type = metadata.content_type.as_deref().unwrap_or_default();
+
+    format!(
+        "{}\nasset_type:{}\nname:{}\nversion:{}\nsize_bytes:{}\ncontent_type:{}\nchecksum:{}:{}\n",
+        SIGNING_PAYLOAD_VERSION,
+        asset_type,
+        metadata.name,
+        metadata.version,
+        size,
+        content_type,
+        checksum.algorithm,
+        checksum.value.to_lowercase(),
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::HashAlgorithm;
+    use semver::Version;
+
+    fn test_checksum() -> Checksum {
+        Checksum::new(
+            HashAlgorithm::SHA256,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_signature_algorithm_from_str() {
+        assert_eq!(
+            SignatureAlgorithm::from_str("Ed25519").unwrap(),
+            SignatureAlgorithm::Ed25519
+        );
+        assert!(SignatureAlgorithm::from_str("rsa").is_err());
+    }
+
+    #[test]
+    fn test_asset_signature_validation() {
+        assert!(AssetSignature::ed25519("key-1", "c2ln").is_ok());
+        assert!(AssetSignature::ed25519("", "c2ln").is_err());
+        assert!(AssetSignature::ed25519("key-1", "").is_err());
+    }
+
+    #[test]
+    fn test_signing_payload_ignores_mutable_fields() {
+        let mut metadata = AssetMetadata::new("gpt-model", Version::new(1, 0, 0));
+        let before = signing_payload(&AssetType::Model, &metadata, &test_checksum());
+
+        metadata.description = Some("updated".to_string());
+        metadata.add_tag("llm");
+        let after = signing_payload(&AssetType::Model, &metadata, &test_checksum());
+
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_signing_payload_covers_checksum() {
+        let metadata = AssetMetadata::new("gpt-model", Version::new(1, 0, 0));
+        let other = Checksum::new(
+            HashAlgorithm::SHA256,
+            "a3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string(),
+        )
+        .unwrap();
+
+        assert_ne!(
+            signing_payload(&AssetType::Model, &metadata, &test_checksum()),
+            signing_payload(&AssetType::Model, &metadata, &other)
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Test fixtures
+//!
+//! Shared by the tests of this crate and, through the `test-support`
+//! feature, by the tests of crates built on it.
+
+use semver::Version;
+
+use crate::asset::{Asset, AssetMetadata, AssetType};
+use crate::checksum::{Checksum, HashAlgorithm};
+use crate::storage::{StorageBackend, StorageLocation};
+use crate::types::AssetId;
+
+/// A valid model asset stored on the file system
+///
+/// Tests needing more set fields on the returned asset.
+pub fn test_asset(name: &str, version: &str) -> Asset {
+    let storage = StorageLocation::new(
+        StorageBackend::FileSystem {
+            base_path: "/var/lib/registry".to_string(),
+        },
+        format!("{}/{}", name, version),
+        None,
+    )
+    .unwrap();
+    let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+
+    Asset::new(
+        AssetId::new(),
+        AssetType::Model,
+        AssetMetadata::new(name, Version::parse(version).unwrap()),
+        storage,
+        checksum,
+    )
+    .unwrap()
+}
@@ -8,7 +8,9 @@ use ulid::Ulid;
 
 /// Asset identifier using ULID (Universally Unique Lexicographically Sortable Identifier)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(transparent)]
+#[cfg_attr(feature = "openapi", schema(value_type = String, example = "01ARZ3NDEKTSV4RRFFQ69G5FAV"))]
 pub struct AssetId(Ulid);
 
 impl AssetId {
@@ -54,6 +56,7 @@ impl fmt::Display for AssetId {
 
 /// Asset status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AssetStatus {
     /// Asset is active and usable
@@ -0,0 +1,35 @@
+-- Transactional outbox for registry events
+-- Migration: 20250120000001_event_outbox
+--
+-- Every event written to registry_events is also queued here in the same
+-- transaction. The outbox relay publishes queued events to NATS and deletes
+-- their rows once the broker has acknowledged them.
+--
+-- A relay claims rows by pushing available_at past the time it needs to
+-- publish them, so that other relays skip the rows while it works. Failed
+-- rows become available again after a backoff, and rows that keep failing
+-- are dead-lettered instead of being retried forever.
+
+CREATE TABLE event_outbox (
+    -- Relay order
+    sequence BIGSERIAL PRIMARY KEY,
+
+    -- Queued event
+    event_id VARCHAR(26) NOT NULL UNIQUE REFERENCES registry_events(event_id) ON DELETE CASCADE,
+
+    -- Queue timing
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Delivery attempts that failed so far
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at TIMESTAMPTZ,
+
+    -- Claiming, retry backoff and dead-lettering
+    available_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    dead_lettered_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_event_outbox_available
+    ON event_outbox (available_at, sequence)
+    WHERE dead_lettered_at IS NULL;
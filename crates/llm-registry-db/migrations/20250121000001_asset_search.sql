@@ -0,0 +1,38 @@
+-- Full-text search over assets
+-- Migration: 20250121000001_asset_search
+--
+-- Adds a weighted tsvector to assets covering the name, tags, description and
+-- annotation values, indexed with GIN. The repository computes the vector on
+-- every insert and update from the same values it writes, since the tags live
+-- in asset_tags and are only known to the writer at that point.
+
+-- Build the search document for an asset
+--
+-- Weights: name (A), tags (B), description (C), annotation values (D).
+CREATE OR REPLACE FUNCTION asset_search_vector(
+    name TEXT,
+    description TEXT,
+    tags TEXT[],
+    annotations JSONB
+)
+RETURNS tsvector AS $$
+    SELECT
+        setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+        setweight(to_tsvector('english', coalesce(array_to_string(tags, ' '), '')), 'B') ||
+        setweight(to_tsvector('english', coalesce(description, '')), 'C') ||
+        setweight(jsonb_to_tsvector('english', coalesce(annotations, '{}'::jsonb), '["string"]'), 'D')
+$$ LANGUAGE sql IMMUTABLE;
+
+ALTER TABLE assets ADD COLUMN search_vector tsvector;
+
+-- Backfill existing assets
+UPDATE assets a SET search_vector = asset_search_vector(
+    a.name,
+    a.description,
+    ARRAY(SELECT t.tag FROM asset_tags t WHERE t.asset_id = a.id ORDER BY t.tag),
+    a.metadata
+);
+
+CREATE INDEX idx_assets_search_vector ON assets USING GIN(search_vector);
+
+COMMENT ON COLUMN assets.search_vector IS 'Weighted full-text search document (name, tags, description, annotations)';
@@ -0,0 +1,10 @@
+-- Asset revisions for optimistic concurrency control
+-- Migration: 20250123000001_asset_revision
+--
+-- Every update increments the revision and only applies while the stored
+-- revision matches the one the change was based on, so concurrent writers
+-- cannot silently overwrite each other.
+
+ALTER TABLE assets ADD COLUMN revision BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE assets ADD CONSTRAINT chk_assets_revision_positive CHECK (revision > 0);
@@ -0,0 +1,62 @@
+-- Outbound webhooks
+-- Migration: 20250125000001_webhooks
+--
+-- Webhook subscriptions and their delivery log. Deliveries are queued by the
+-- outbox relay and sent by the webhook dispatcher, which claims due rows by
+-- pushing their next_attempt_at forward so replicas never send the same
+-- delivery concurrently.
+
+CREATE TABLE webhooks (
+    id TEXT PRIMARY KEY,
+    url TEXT NOT NULL,
+    description TEXT,
+
+    -- HMAC-SHA256 signing secret
+    secret TEXT NOT NULL,
+
+    -- Filters as JSON arrays of strings; an empty array matches everything
+    event_types JSONB NOT NULL DEFAULT '[]',
+    asset_names JSONB NOT NULL DEFAULT '[]',
+    tags JSONB NOT NULL DEFAULT '[]',
+
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0 CHECK (consecutive_failures >= 0),
+    disabled_reason TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE webhook_deliveries (
+    id TEXT PRIMARY KEY,
+    webhook_id TEXT NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+
+    -- Request body sent to the endpoint
+    payload JSONB NOT NULL,
+
+    status TEXT NOT NULL CHECK (status IN ('pending', 'succeeded', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0 CHECK (attempts >= 0),
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_status_code INTEGER,
+    last_error TEXT,
+
+    -- Delivery this one replays, if any
+    replay_of TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL,
+    delivered_at TIMESTAMPTZ
+);
+
+-- An event is queued at most once per webhook, however often it is relayed
+CREATE UNIQUE INDEX idx_webhook_deliveries_event
+    ON webhook_deliveries (webhook_id, event_id)
+    WHERE replay_of IS NULL;
+
+CREATE INDEX idx_webhook_deliveries_due
+    ON webhook_deliveries (next_attempt_at)
+    WHERE status = 'pending';
+
+CREATE INDEX idx_webhook_deliveries_log
+    ON webhook_deliveries (webhook_id, created_at DESC);
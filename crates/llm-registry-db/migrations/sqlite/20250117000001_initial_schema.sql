@@ -0,0 +1,151 @@
+-- Initial schema for LLM Registry SQLite database
+-- Migration: 20250117000001_initial_schema
+--
+-- Mirrors the PostgreSQL schema in ../20250117000001_initial_schema.sql.
+-- Timestamps are stored as RFC 3339 TEXT and JSON columns as TEXT.
+
+-- Assets table: Core registry for all assets (models, pipelines, datasets, etc.)
+CREATE TABLE assets (
+    -- Primary identifier (ULID format)
+    id TEXT PRIMARY KEY NOT NULL,
+
+    -- Asset identification
+    name TEXT NOT NULL,
+    version TEXT NOT NULL,
+    asset_type TEXT NOT NULL,
+
+    -- Status tracking
+    status TEXT NOT NULL DEFAULT 'active',
+
+    -- Storage information
+    storage_backend TEXT NOT NULL,
+    storage_uri TEXT NOT NULL,
+    storage_path TEXT,
+    size_bytes INTEGER,
+
+    -- Integrity verification
+    checksum_algorithm TEXT NOT NULL,
+    checksum_value TEXT NOT NULL,
+
+    -- Digital signature (optional)
+    signature_algorithm TEXT,
+    signature_value TEXT,
+    signature_key_id TEXT,
+
+    -- Metadata
+    description TEXT,
+    license TEXT,
+    content_type TEXT,
+
+    -- Provenance tracking
+    author TEXT,
+    source_repo TEXT,
+    commit_hash TEXT,
+    build_id TEXT,
+
+    -- Timestamps
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    deprecated_at TEXT,
+
+    -- Flexible metadata storage (annotations)
+    metadata TEXT NOT NULL DEFAULT '{}',
+
+    -- Constraints
+    UNIQUE(name, version),
+    CHECK (name != ''),
+    CHECK (version != ''),
+    CHECK (size_bytes IS NULL OR size_bytes >= 0)
+);
+
+-- Indexes for efficient queries
+CREATE INDEX idx_assets_name ON assets(name);
+CREATE INDEX idx_assets_type ON assets(asset_type);
+CREATE INDEX idx_assets_status ON assets(status);
+CREATE INDEX idx_assets_deprecated ON assets(deprecated_at);
+CREATE INDEX idx_assets_created_at ON assets(created_at DESC);
+CREATE INDEX idx_assets_updated_at ON assets(updated_at DESC);
+CREATE INDEX idx_assets_author ON assets(author);
+CREATE INDEX idx_assets_storage_backend ON assets(storage_backend);
+
+-- Asset tags table: Many-to-many relationship for tags
+CREATE TABLE asset_tags (
+    asset_id TEXT NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY(asset_id, tag),
+    CHECK (tag != '')
+);
+
+-- Index for tag-based queries
+CREATE INDEX idx_asset_tags_tag ON asset_tags(tag);
+
+-- Asset dependencies table: Tracks dependencies between assets
+CREATE TABLE asset_dependencies (
+    asset_id TEXT NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+    dependency_id TEXT NOT NULL REFERENCES assets(id) ON DELETE CASCADE,
+    dependency_type TEXT NOT NULL DEFAULT 'runtime',
+    version_constraint TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY(asset_id, dependency_id),
+    -- Prevent self-referencing dependencies
+    CHECK (asset_id != dependency_id)
+);
+
+-- Indexes for dependency graph traversal
+CREATE INDEX idx_asset_dependencies_dependency ON asset_dependencies(dependency_id);
+CREATE INDEX idx_asset_dependencies_type ON asset_dependencies(dependency_type);
+
+-- Registry events table: Event sourcing and audit log
+CREATE TABLE registry_events (
+    -- Event identifier (ULID format)
+    event_id TEXT PRIMARY KEY NOT NULL,
+
+    -- Event classification
+    event_type TEXT NOT NULL,
+
+    -- Related asset (nullable for system-level events)
+    asset_id TEXT,
+
+    -- Event timing
+    timestamp TEXT NOT NULL,
+
+    -- Actor information
+    actor TEXT NOT NULL,
+
+    -- Event payload (JSON)
+    payload TEXT NOT NULL DEFAULT '{}',
+
+    -- Additional metadata (JSON)
+    metadata TEXT NOT NULL DEFAULT '{}',
+
+    -- Constraints
+    CHECK (event_type != ''),
+    CHECK (actor != '')
+);
+
+-- Indexes for event queries
+CREATE INDEX idx_registry_events_asset ON registry_events(asset_id);
+CREATE INDEX idx_registry_events_timestamp ON registry_events(timestamp DESC);
+CREATE INDEX idx_registry_events_type ON registry_events(event_type);
+CREATE INDEX idx_registry_events_actor ON registry_events(actor);
+
+-- Create a view for active (non-deprecated) assets
+CREATE VIEW active_assets AS
+SELECT * FROM assets
+WHERE deprecated_at IS NULL;
+
+-- Create a view for asset statistics
+CREATE VIEW asset_statistics AS
+SELECT
+    asset_type,
+    COUNT(*) as total_count,
+    COUNT(CASE WHEN deprecated_at IS NULL THEN 1 END) as active_count,
+    COUNT(CASE WHEN deprecated_at IS NOT NULL THEN 1 END) as deprecated_count,
+    SUM(size_bytes) as total_size_bytes,
+    MIN(created_at) as first_created,
+    MAX(created_at) as last_created
+FROM assets
+GROUP BY asset_type;
@@ -0,0 +1,29 @@
+-- Transactional outbox for registry events (SQLite)
+-- Migration: 20250120000001_event_outbox
+--
+-- Mirrors the PostgreSQL schema in ../20250120000001_event_outbox.sql.
+-- available_at is stored in Unix milliseconds so that it compares correctly.
+
+CREATE TABLE event_outbox (
+    -- Relay order
+    sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- Queued event
+    event_id TEXT NOT NULL UNIQUE REFERENCES registry_events(event_id) ON DELETE CASCADE,
+
+    -- Queue timing
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    -- Delivery attempts that failed so far
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at TEXT,
+
+    -- Claiming, retry backoff and dead-lettering
+    available_at INTEGER NOT NULL DEFAULT 0,
+    dead_lettered_at TEXT
+);
+
+CREATE INDEX idx_event_outbox_available
+    ON event_outbox (available_at, sequence)
+    WHERE dead_lettered_at IS NULL;
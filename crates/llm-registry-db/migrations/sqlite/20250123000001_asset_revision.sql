@@ -0,0 +1,6 @@
+-- Asset revisions for optimistic concurrency control (SQLite)
+-- Migration: 20250123000001_asset_revision
+--
+-- Mirrors the PostgreSQL schema in ../20250123000001_asset_revision.sql.
+
+ALTER TABLE assets ADD COLUMN revision INTEGER NOT NULL DEFAULT 1 CHECK (revision > 0);
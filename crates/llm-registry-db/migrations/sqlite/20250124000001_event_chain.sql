@@ -0,0 +1,21 @@
+-- Tamper-evident event hash chain (SQLite)
+-- Migration: 20250124000001_event_chain
+--
+-- Mirrors the PostgreSQL schema in ../20250124000001_event_chain.sql.
+
+ALTER TABLE registry_events ADD COLUMN chain_sequence INTEGER CHECK (chain_sequence > 0);
+ALTER TABLE registry_events ADD COLUMN chain_algorithm TEXT;
+ALTER TABLE registry_events ADD COLUMN chain_prev_hash TEXT;
+ALTER TABLE registry_events ADD COLUMN chain_hash TEXT;
+
+CREATE UNIQUE INDEX idx_registry_events_chain_sequence
+    ON registry_events (chain_sequence)
+    WHERE chain_sequence IS NOT NULL;
+
+CREATE TABLE event_chain_checkpoints (
+    sequence INTEGER PRIMARY KEY NOT NULL CHECK (sequence > 0),
+    hash TEXT NOT NULL,
+    key_id TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
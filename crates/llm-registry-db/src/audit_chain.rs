@@ -0,0 +1,568 @@
+//! Tamper-evident audit chain
+//!
+//! Every event appended to an event store is linked to its predecessor. The
+//! link hash covers the event's persisted fields together with the previous
+//! link hash, so editing or deleting any stored event breaks every later link.
+//! Signed checkpoints of the chain head additionally expose truncation of the
+//! chain and wholesale rewrites by anyone without the checkpoint signing key.
+//!
+//! Events appended before the chain was introduced are not linked.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use llm_registry_core::{HashAlgorithm, RegistryEvent};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::{DbError, DbResult};
+use crate::event_store::EventStore;
+
+/// Algorithm used to hash newly appended events
+pub const CHAIN_HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::SHA256;
+
+/// Number of events read per page when verifying the chain
+const VERIFY_PAGE_SIZE: i64 = 500;
+
+/// Actor recorded for events appended without one
+const DEFAULT_ACTOR: &str = "system";
+
+/// Position of an event in the audit chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChainLink {
+    /// Position in the chain, starting at 1
+    pub sequence: u64,
+
+    /// Algorithm used to compute the hash
+    pub algorithm: HashAlgorithm,
+
+    /// Hash of the previous link, all zeros for the first event
+    pub prev_hash: String,
+
+    /// Hash of this link
+    pub hash: String,
+}
+
+impl ChainLink {
+    /// Previous hash of the first link in a chain
+    pub fn genesis_hash(algorithm: HashAlgorithm) -> String {
+        "0".repeat(algorithm.hex_length())
+    }
+
+    /// Link an event after the current chain head
+    pub fn next(head: Option<&ChainLink>, event: &RegistryEvent) -> DbResult<Self> {
+        let algorithm = CHAIN_HASH_ALGORITHM;
+        let (sequence, prev_hash) = match head {
+            Some(head) => (head.sequence + 1, head.hash.clone()),
+            None => (1, Self::genesis_hash(algorithm)),
+        };
+        let hash = link_hash(algorithm, sequence, &prev_hash, event)?;
+
+        Ok(Self {
+            sequence,
+            algorithm,
+            prev_hash,
+            hash,
+        })
+    }
+
+    /// Recompute the hash of this link from the stored event
+    pub fn compute_hash(&self, event: &RegistryEvent) -> DbResult<String> {
+        link_hash(self.algorithm, self.sequence, &self.prev_hash, event)
+    }
+
+    /// Build a link from its stored columns
+    pub(crate) fn from_columns(
+        sequence: Option<i64>,
+        algorithm: Option<String>,
+        prev_hash: Option<String>,
+        hash: Option<String>,
+    ) -> DbResult<Option<Self>> {
+        let (Some(sequence), Some(algorithm), Some(prev_hash), Some(hash)) =
+            (sequence, algorithm, prev_hash, hash)
+        else {
+            return Ok(None);
+        };
+
+        let algorithm = algorithm.parse().map_err(|_| {
+            DbError::InvalidData(format!("Unknown chain hash algorithm: {}", algorithm))
+        })?;
+        Ok(Some(Self {
+            sequence: sequence as u64,
+            algorithm,
+            prev_hash,
+            hash,
+        }))
+    }
+}
+
+/// A stored event with its position in the audit chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainedEvent {
+    /// Chain link stored with the event
+    pub link: ChainLink,
+
+    /// The event as stored
+    pub event: RegistryEvent,
+}
+
+/// Signed record of the chain head at a point in time
+///
+/// The signature is an Ed25519 signature over [`ChainCheckpoint::message`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChainCheckpoint {
+    /// Sequence of the chain head
+    pub sequence: u64,
+
+    /// Hash of the chain head
+    pub hash: String,
+
+    /// Signing key identifier
+    pub key_id: String,
+
+    /// Base64-encoded signature
+    pub signature: String,
+
+    /// When the checkpoint was taken
+    pub created_at: DateTime<Utc>,
+}
+
+impl ChainCheckpoint {
+    /// Bytes signed for a checkpoint
+    pub fn message(sequence: u64, hash: &str, created_at: DateTime<Utc>) -> Vec<u8> {
+        format!(
+            "llm-registry-checkpoint:{}:{}:{}",
+            sequence,
+            hash,
+            canonical_timestamp(created_at)
+        )
+        .into_bytes()
+    }
+
+    /// Bytes signed for this checkpoint
+    pub fn signed_message(&self) -> Vec<u8> {
+        Self::message(self.sequence, &self.hash, self.created_at)
+    }
+}
+
+/// Kind of break found in the audit chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum BreakKind {
+    /// The event no longer matches the hash stored with it
+    HashMismatch,
+    /// The link does not reference the hash of the previous event
+    PredecessorMismatch,
+    /// An event is missing from the chain
+    MissingEvent,
+    /// A checkpoint does not match the chain at its sequence
+    CheckpointMismatch,
+    /// A checkpoint lies beyond the chain head, so events were removed
+    Truncated,
+    /// A checkpoint signature does not verify
+    InvalidSignature,
+}
+
+/// First problem found when verifying the audit chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BrokenLink {
+    /// Chain sequence at which the chain breaks
+    pub sequence: u64,
+
+    /// Stored event at that sequence, if there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+
+    /// What is wrong
+    pub kind: BreakKind,
+
+    /// Human-readable description
+    pub detail: String,
+}
+
+/// Result of verifying the audit chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChainVerification {
+    /// Whether the chain and all checkpoints verified
+    pub valid: bool,
+
+    /// Number of events verified before the first break
+    pub events_verified: u64,
+
+    /// Number of checkpoints matching the chain
+    pub checkpoints_verified: u64,
+
+    /// Latest link of the chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<ChainLink>,
+
+    /// First break in chain order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_broken_link: Option<BrokenLink>,
+}
+
+impl ChainVerification {
+    /// Record a break, keeping the earliest one in chain order
+    pub fn report_break(&mut self, broken: BrokenLink) {
+        self.valid = false;
+        if self
+            .first_broken_link
+            .as_ref()
+            .map_or(true, |first| broken.sequence < first.sequence)
+        {
+            self.first_broken_link = Some(broken);
+        }
+    }
+}
+
+/// Verify the audit chain of an event store
+///
+/// Recomputes every link in chain order and compares each checkpoint with
+/// the link at its sequence, stopping at the first broken link.
+/// `checkpoints` must be sorted by sequence. Their signatures are not
+/// checked here, so only pass checkpoints whose signatures were verified.
+pub async fn verify_chain(
+    store: &dyn EventStore,
+    checkpoints: &[ChainCheckpoint],
+) -> DbResult<ChainVerification> {
+    let mut report = ChainVerification {
+        valid: true,
+        events_verified: 0,
+        checkpoints_verified: 0,
+        head: store.chain_head().await?,
+        first_broken_link: None,
+    };
+    let mut pending = checkpoints.iter().peekable();
+    let mut previous: Option<ChainLink> = None;
+
+    'scan: loop {
+        let after = previous.as_ref().map_or(0, |link| link.sequence);
+        let page = store.chain_events(after, VERIFY_PAGE_SIZE).await?;
+        if page.is_empty() {
+            break;
+        }
+
+        for ChainedEvent { link, event } in page {
+            if let Some(broken) = check_link(previous.as_ref(), &link, &event)? {
+                report.report_break(broken);
+                break 'scan;
+            }
+
+            while let Some(checkpoint) = pending.next_if(|c| c.sequence <= link.sequence) {
+                if checkpoint.sequence != link.sequence || checkpoint.hash != link.hash {
+                    report.report_break(BrokenLink {
+                        sequence: checkpoint.sequence,
+                        event_id: (checkpoint.sequence == link.sequence)
+                            .then(|| event.event_id.clone()),
+                        kind: BreakKind::CheckpointMismatch,
+                        detail: format!(
+                            "Checkpoint at sequence {} does not match the chain",
+                            checkpoint.sequence
+                        ),
+                    });
+                    break 'scan;
+                }
+                report.checkpoints_verified += 1;
+            }
+
+            report.events_verified += 1;
+            previous = Some(link);
+        }
+    }
+
+    if report.valid {
+        if let Some(checkpoint) = pending.next() {
+            report.report_break(BrokenLink {
+                sequence: checkpoint.sequence,
+                event_id: None,
+                kind: BreakKind::Truncated,
+                detail: format!(
+                    "Checkpoint at sequence {} is beyond the last chained event at sequence {}",
+                    checkpoint.sequence,
+                    previous.as_ref().map_or(0, |link| link.sequence)
+                ),
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+/// Check a link against its predecessor and the stored event
+fn check_link(
+    previous: Option<&ChainLink>,
+    link: &ChainLink,
+    event: &RegistryEvent,
+) -> DbResult<Option<BrokenLink>> {
+    let expected_sequence = previous.map_or(1, |previous| previous.sequence + 1);
+    if link.sequence != expected_sequence {
+        return Ok(Some(BrokenLink {
+            sequence: expected_sequence,
+            event_id: None,
+            kind: BreakKind::MissingEvent,
+            detail: format!(
+                "Event at sequence {} is missing; the next stored event is {} at sequence {}",
+                expected_sequence, event.event_id, link.sequence
+            ),
+        }));
+    }
+
+    let expected_prev_hash = previous.map_or_else(
+        || ChainLink::genesis_hash(link.algorithm),
+        |previous| previous.hash.clone(),
+    );
+    let broken = |kind, detail| {
+        Some(BrokenLink {
+            sequence: link.sequence,
+            event_id: Some(event.event_id.clone()),
+            kind,
+            detail,
+        })
+    };
+
+    if link.prev_hash != expected_prev_hash {
+        return Ok(broken(
+            BreakKind::PredecessorMismatch,
+            format!(
+                "Event {} does not reference the hash of the event before it",
+                event.event_id
+            ),
+        ));
+    }
+    if link.compute_hash(event)? != link.hash {
+        return Ok(broken(
+            BreakKind::HashMismatch,
+            format!(
+                "Event {} was modified after it was recorded",
+                event.event_id
+            ),
+        ));
+    }
+
+    Ok(None)
+}
+
+/// Hash of an event linked at `sequence` after `prev_hash`
+///
+/// Only fields that every event store persists are covered, in a canonical
+/// JSON encoding with sorted keys and microsecond timestamps, so the hash
+/// can be recomputed from the stored row.
+fn link_hash(
+    algorithm: HashAlgorithm,
+    sequence: u64,
+    prev_hash: &str,
+    event: &RegistryEvent,
+) -> DbResult<String> {
+    let content = json!({
+        "sequence": sequence,
+        "prev_hash": prev_hash,
+        "event_id": event.event_id,
+        "event_type": event.event_name(),
+        "asset_id": event.asset_id().map(|id| id.to_string()),
+        "timestamp": canonical_timestamp(event.timestamp),
+        "actor": event.actor.as_deref().unwrap_or(DEFAULT_ACTOR),
+        "payload": serde_json::to_value(&event.event_type)?,
+        "context": serde_json::to_value(&event.context)?,
+    });
+
+    let mut canonical = String::new();
+    write_canonical(&content, &mut canonical);
+    Ok(algorithm.digest(canonical.as_bytes()))
+}
+
+/// Timestamp at the microsecond precision kept by the SQL backends
+fn canonical_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// Write JSON with object keys sorted at every level
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Timelike};
+    use llm_registry_core::{AssetId, EventType};
+
+    fn test_event() -> RegistryEvent {
+        let mut event = RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        });
+        event.add_context("b", "2");
+        event.add_context("a", "1");
+        event
+    }
+
+    #[test]
+    fn test_links_chain_hashes() {
+        let first_event = test_event();
+        let first = ChainLink::next(None, &first_event).unwrap();
+        assert_eq!(first.sequence, 1);
+        assert_eq!(
+            first.prev_hash,
+            ChainLink::genesis_hash(HashAlgorithm::SHA256)
+        );
+        assert_eq!(first.compute_hash(&first_event).unwrap(), first.hash);
+
+        let second = ChainLink::next(Some(&first), &test_event()).unwrap();
+        assert_eq!(second.sequence, 2);
+        assert_eq!(second.prev_hash, first.hash);
+    }
+
+    #[test]
+    fn test_hash_covers_stored_fields_only() {
+        let event = test_event();
+        let link = ChainLink::next(None, &event).unwrap();
+
+        // Normalizations applied by the stores leave the hash unchanged
+        let mut stored = event.clone();
+        stored.actor = Some(DEFAULT_ACTOR.to_string());
+        stored.correlation_id = Some("request-1".to_string());
+        stored.timestamp = event
+            .timestamp
+            .with_nanosecond(event.timestamp.nanosecond() / 1_000 * 1_000)
+            .unwrap();
+        assert_eq!(link.compute_hash(&stored).unwrap(), link.hash);
+
+        let mut tampered = event.clone();
+        tampered.timestamp = event.timestamp - Duration::seconds(1);
+        assert_ne!(link.compute_hash(&tampered).unwrap(), link.hash);
+
+        let mut tampered = event;
+        tampered.add_context("a", "changed");
+        assert_ne!(link.compute_hash(&tampered).unwrap(), link.hash);
+    }
+
+    #[test]
+    fn test_checkpoint_message() {
+        let created_at = Utc::now();
+        let message = ChainCheckpoint::message(7, "abc", created_at);
+        assert!(String::from_utf8(message)
+            .unwrap()
+            .starts_with("llm-registry-checkpoint:7:abc:"));
+    }
+
+    fn checkpoint_at(link: &ChainLink) -> ChainCheckpoint {
+        ChainCheckpoint {
+            sequence: link.sequence,
+            hash: link.hash.clone(),
+            key_id: "registry".to_string(),
+            signature: String::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_intact_chain_and_checkpoints() {
+        let store = crate::memory::InMemoryEventStore::new();
+        store
+            .append_batch(vec![test_event(), test_event(), test_event()])
+            .await
+            .unwrap();
+
+        let head = store.chain_head().await.unwrap().unwrap();
+        assert_eq!(head.sequence, 3);
+
+        let report = verify_chain(&store, &[checkpoint_at(&head)]).await.unwrap();
+        assert!(report.valid);
+        assert_eq!(report.events_verified, 3);
+        assert_eq!(report.checkpoints_verified, 1);
+        assert_eq!(report.head, Some(head.clone()));
+
+        // A checkpoint recorded for a longer chain means events were removed
+        let mut beyond = checkpoint_at(&head);
+        beyond.sequence = 5;
+        let report = verify_chain(&store, &[beyond]).await.unwrap();
+        let broken = report.first_broken_link.unwrap();
+        assert_eq!((broken.sequence, broken.kind), (5, BreakKind::Truncated));
+
+        let mut forged = checkpoint_at(&head);
+        forged.hash = "f".repeat(64);
+        let report = verify_chain(&store, &[forged]).await.unwrap();
+        assert_eq!(
+            report.first_broken_link.unwrap().kind,
+            BreakKind::CheckpointMismatch
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_verify_detects_tampered_rows() {
+        use crate::event_store::SqliteEventStore;
+        use crate::pool::{create_sqlite_pool, PoolConfig};
+
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        let store = SqliteEventStore::new(pool.clone());
+        let events = store
+            .append_batch(vec![test_event(), test_event(), test_event(), test_event()])
+            .await
+            .unwrap();
+        assert!(verify_chain(&store, &[]).await.unwrap().valid);
+
+        // Rewriting a stored field breaks that event's link
+        sqlx::query("UPDATE registry_events SET actor = 'mallory' WHERE event_id = ?")
+            .bind(&events[1].event_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = verify_chain(&store, &[]).await.unwrap();
+        assert!(!report.valid);
+        assert_eq!(report.events_verified, 1);
+        let broken = report.first_broken_link.unwrap();
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.kind, BreakKind::HashMismatch);
+        assert_eq!(
+            broken.event_id.as_deref(),
+            Some(events[1].event_id.as_str())
+        );
+
+        // Deleting an event leaves a gap before the tampered one
+        sqlx::query("DELETE FROM registry_events WHERE event_id = ?")
+            .bind(&events[0].event_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let broken = verify_chain(&store, &[])
+            .await
+            .unwrap()
+            .first_broken_link
+            .unwrap();
+        assert_eq!((broken.sequence, broken.kind), (1, BreakKind::MissingEvent));
+    }
+}
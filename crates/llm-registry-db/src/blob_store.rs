@@ -0,0 +1,322 @@
+//! Blob storage for asset content
+//!
+//! The registry stores the bytes of an asset, not just a pointer to them. A
+//! [`BlobStore`] holds blobs under keys chosen by the registry and reports
+//! the [`StorageBackend`] they live in, so that assets can record where
+//! their content is. Two stores are provided:
+//!
+//! - [`FileSystemBlobStore`] keeps blobs in a local directory
+//! - [`S3BlobStore`](crate::s3::S3BlobStore) keeps them in Amazon S3 or an
+//!   S3-compatible service such as MinIO
+//!
+//! Writes are all-or-nothing: a blob becomes visible only once its whole
+//! body has been written, and a body that fails part way (for example
+//! because the caller found its checksum wrong) leaves any previous blob
+//! under the key in place.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt};
+use llm_registry_core::StorageBackend;
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use ulid::Ulid;
+
+use crate::error::{DbError, DbResult};
+
+/// Stream of blob bytes, read or written chunk by chunk
+pub type ByteStream = BoxStream<'static, io::Result<Bytes>>;
+
+/// Size of the chunks blobs are read in
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Longest key accepted, matching the S3 limit
+const MAX_KEY_LENGTH: usize = 1024;
+
+/// Inclusive range of bytes within a blob
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    /// Offset of the first byte
+    pub start: u64,
+    /// Offset of the last byte
+    pub end: u64,
+}
+
+impl ByteRange {
+    /// Create a range from the first to the last byte, inclusive
+    pub fn new(start: u64, end: u64) -> Self {
+        debug_assert!(start <= end, "byte range ends before it starts");
+        Self { start, end }
+    }
+
+    /// Number of bytes in the range
+    pub fn length(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+/// Storage for blobs of asset content
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Backend blobs are stored in, recorded in asset storage locations
+    fn backend(&self) -> StorageBackend;
+
+    /// Write a blob, replacing any blob under the key
+    ///
+    /// Returns the number of bytes written. If the body yields an error,
+    /// nothing is stored and a storage error is returned.
+    async fn put(&self, key: &str, body: ByteStream) -> DbResult<u64>;
+
+    /// Size of a blob in bytes, or `None` if there is no blob under the key
+    async fn size(&self, key: &str) -> DbResult<Option<u64>>;
+
+    /// Read a blob, or a range of it
+    ///
+    /// The range must lie within the blob. Fails with a not-found error if
+    /// there is no blob under the key.
+    async fn get(&self, key: &str, range: Option<ByteRange>) -> DbResult<ByteStream>;
+
+    /// Delete a blob; deleting a missing blob is not an error
+    async fn delete(&self, key: &str) -> DbResult<()>;
+}
+
+/// Check that a key is a relative `/`-separated path without `.` or `..`
+/// segments, so that it cannot escape the store
+pub(crate) fn validate_key(key: &str) -> DbResult<()> {
+    let valid = !key.is_empty()
+        && key.len() <= MAX_KEY_LENGTH
+        && !key.contains(['\\', '\0'])
+        && key
+            .split('/')
+            .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+    if valid {
+        Ok(())
+    } else {
+        Err(DbError::InvalidData(format!("Invalid blob key: {:?}", key)))
+    }
+}
+
+/// Error for a body that failed while a blob was being written
+pub(crate) fn body_error(key: &str, err: io::Error) -> DbError {
+    DbError::Storage(format!("Failed to read body of blob {}: {}", key, err))
+}
+
+/// Stream a reader in chunks, ending after the first error
+pub(crate) fn read_stream<R>(reader: R) -> ByteStream
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut buffer = vec![0; READ_CHUNK_SIZE];
+        match reader.read(&mut buffer).await {
+            Ok(0) => None,
+            Ok(read) => {
+                buffer.truncate(read);
+                Some((Ok(Bytes::from(buffer)), Some(reader)))
+            }
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+    .boxed()
+}
+
+/// Blob store keeping blobs as files under a base directory
+///
+/// Keys map to paths relative to the base directory. Blobs are written to a
+/// temporary file next to their final path and renamed into place once
+/// complete, so readers never see a partial blob.
+#[derive(Debug, Clone)]
+pub struct FileSystemBlobStore {
+    base_path: PathBuf,
+}
+
+impl FileSystemBlobStore {
+    /// Create a store under a base directory, created on first write
+    pub fn new(base_path: impl Into<PathBuf>) -> Self {
+        Self {
+            base_path: base_path.into(),
+        }
+    }
+
+    /// Directory blobs are stored under
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    fn path(&self, key: &str) -> DbResult<PathBuf> {
+        validate_key(key)?;
+        Ok(self.base_path.join(key))
+    }
+
+    async fn write(key: &str, path: &Path, mut body: ByteStream) -> DbResult<u64> {
+        let mut file = fs::File::create(path).await.map_err(|e| io_error(key, e))?;
+        let mut size = 0;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| body_error(key, e))?;
+            file.write_all(&chunk).await.map_err(|e| io_error(key, e))?;
+            size += chunk.len() as u64;
+        }
+        file.sync_all().await.map_err(|e| io_error(key, e))?;
+        Ok(size)
+    }
+}
+
+fn io_error(key: &str, err: io::Error) -> DbError {
+    DbError::Storage(format!("Blob {}: {}", key, err))
+}
+
+#[async_trait]
+impl BlobStore for FileSystemBlobStore {
+    fn backend(&self) -> StorageBackend {
+        StorageBackend::FileSystem {
+            base_path: self.base_path.to_string_lossy().into_owned(),
+        }
+    }
+
+    async fn put(&self, key: &str, body: ByteStream) -> DbResult<u64> {
+        let path = self.path(key)?;
+        let directory = path.parent().unwrap_or(&self.base_path);
+        fs::create_dir_all(directory)
+            .await
+            .map_err(|e| io_error(key, e))?;
+
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let partial = directory.join(format!(".{}.{}.partial", file_name, Ulid::new()));
+        let written = match Self::write(key, &partial, body).await {
+            Ok(size) => fs::rename(&partial, &path)
+                .await
+                .map(|_| size)
+                .map_err(|e| io_error(key, e)),
+            Err(e) => Err(e),
+        };
+        if written.is_err() {
+            let _ = fs::remove_file(&partial).await;
+        }
+        written
+    }
+
+    async fn size(&self, key: &str) -> DbResult<Option<u64>> {
+        match fs::metadata(self.path(key)?).await {
+            Ok(metadata) => Ok(Some(metadata.len())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error(key, e)),
+        }
+    }
+
+    async fn get(&self, key: &str, range: Option<ByteRange>) -> DbResult<ByteStream> {
+        let mut file = match fs::File::open(self.path(key)?).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(DbError::NotFound(format!("blob {}", key)))
+            }
+            Err(e) => return Err(io_error(key, e)),
+        };
+
+        let size = file.metadata().await.map_err(|e| io_error(key, e))?.len();
+        let length = match range {
+            Some(range) if range.end >= size => {
+                return Err(DbError::InvalidQuery(format!(
+                    "Range {}-{} is outside blob {} of {} bytes",
+                    range.start, range.end, key, size
+                )))
+            }
+            Some(range) => {
+                file.seek(SeekFrom::Start(range.start))
+                    .await
+                    .map_err(|e| io_error(key, e))?;
+                range.length()
+            }
+            None => size,
+        };
+        Ok(read_stream(file.take(length)))
+    }
+
+    async fn delete(&self, key: &str) -> DbResult<()> {
+        match fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(io_error(key, e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(chunks: Vec<io::Result<&'static [u8]>>) -> ByteStream {
+        stream::iter(chunks.into_iter().map(|c| c.map(Bytes::from_static))).boxed()
+    }
+
+    async fn read(stream: ByteStream) -> Vec<u8> {
+        stream.map(|chunk| chunk.unwrap().to_vec()).concat().await
+    }
+
+    #[tokio::test]
+    async fn test_file_system_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSystemBlobStore::new(dir.path());
+        let key = "assets/01ARZ3NDEKTSV4RRFFQ69G5FAV";
+
+        assert_eq!(store.size(key).await.unwrap(), None);
+        assert!(matches!(store.get(key, None).await, Err(e) if e.is_not_found()));
+
+        let size = store
+            .put(key, body(vec![Ok(b"hello "), Ok(b"world")]))
+            .await
+            .unwrap();
+        assert_eq!(size, 11);
+        assert_eq!(store.size(key).await.unwrap(), Some(11));
+        assert_eq!(
+            read(store.get(key, None).await.unwrap()).await,
+            b"hello world"
+        );
+        let range = Some(ByteRange::new(6, 10));
+        assert_eq!(read(store.get(key, range).await.unwrap()).await, b"world");
+        assert!(store.get(key, Some(ByteRange::new(6, 11))).await.is_err());
+
+        store.delete(key).await.unwrap();
+        store.delete(key).await.unwrap();
+        assert_eq!(store.size(key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_failed_body_keeps_previous_blob() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSystemBlobStore::new(dir.path());
+        store.put("model", body(vec![Ok(b"v1")])).await.unwrap();
+
+        let failed = body(vec![Ok(b"v2 partial"), Err(io::Error::other("mismatch"))]);
+        let err = store.put("model", failed).await.unwrap_err();
+        assert!(matches!(err, DbError::Storage(_)));
+
+        assert_eq!(read(store.get("model", None).await.unwrap()).await, b"v1");
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1, "partial file left behind");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_keys_escaping_the_store() {
+        let store = FileSystemBlobStore::new("/tmp/blobs");
+        for key in [
+            "",
+            "../etc/passwd",
+            "a/../../b",
+            "/abs",
+            "a//b",
+            "a\\b",
+            "./a",
+        ] {
+            let err = store.size(key).await.unwrap_err();
+            assert!(matches!(err, DbError::InvalidData(_)), "{:?}", key);
+        }
+        assert_eq!(
+            store.backend(),
+            StorageBackend::FileSystem {
+                base_path: "/tmp/blobs".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! In-process event broadcasting
+//!
+//! This module provides [`BroadcastEventStore`], an [`EventStore`] decorator that
+//! publishes every successfully appended event to an in-process broadcast channel.
+//! Subscribers (streaming APIs, webhooks, cache invalidation) receive events in
+//! append order without polling the underlying store. [`BroadcastUnitOfWork`]
+//! does the same for events committed through a [`UnitOfWork`].
+
+use async_trait::async_trait;
+use llm_registry_core::{Asset, AssetId, RegistryEvent};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::trace;
+
+use crate::audit_chain::{ChainCheckpoint, ChainLink, ChainedEvent};
+use crate::error::DbResult;
+use crate::event_store::{EventQuery, EventQueryResults, EventStore};
+use crate::unit_of_work::{UnitOfWork, WriteTransaction};
+
+/// Default number of events buffered per subscriber before it starts lagging
+pub const DEFAULT_BROADCAST_CAPACITY: usize = 1024;
+
+/// Event store decorator that broadcasts appended events to live subscribers
+///
+/// Events are only broadcast after the wrapped store has persisted them, so a
+/// subscriber never observes an event that cannot later be found through
+/// [`EventStore::query`]. Each subscriber has a bounded buffer; a subscriber that
+/// falls more than `capacity` events behind receives
+/// [`broadcast::error::RecvError::Lagged`] and is expected to catch up from the
+/// store.
+#[derive(Clone)]
+pub struct BroadcastEventStore {
+    inner: Arc<dyn EventStore>,
+    sender: broadcast::Sender<RegistryEvent>,
+}
+
+impl BroadcastEventStore {
+    /// Wrap an event store using the default channel capacity
+    pub fn new(inner: Arc<dyn EventStore>) -> Self {
+        Self::with_capacity(inner, DEFAULT_BROADCAST_CAPACITY)
+    }
+
+    /// Wrap an event store with a custom per-subscriber buffer capacity
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(inner: Arc<dyn EventStore>, capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { inner, sender }
+    }
+
+    /// Subscribe to events appended from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<RegistryEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Number of active subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Get the wrapped event store
+    pub fn inner(&self) -> &Arc<dyn EventStore> {
+        &self.inner
+    }
+
+    fn publish(&self, event: &RegistryEvent) {
+        publish(&self.sender, event);
+    }
+}
+
+fn publish(sender: &broadcast::Sender<RegistryEvent>, event: &RegistryEvent) {
+    // Sending only fails when nobody is subscribed, which is not an error.
+    if sender.send(event.clone()).is_err() {
+        trace!(event_id = %event.event_id, "No subscribers for event");
+    }
+}
+
+#[async_trait]
+impl EventStore for BroadcastEventStore {
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        let event = self.inner.append(event).await?;
+        self.publish(&event);
+        Ok(event)
+    }
+
+    async fn append_batch(&self, events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        let events = self.inner.append_batch(events).await?;
+        for event in &events {
+            self.publish(event);
+        }
+        Ok(events)
+    }
+
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        self.inner.query(query).await
+    }
+
+    async fn get_event(&self, event_id: &str) -> DbResult<Option<RegistryEvent>> {
+        self.inner.get_event(event_id).await
+    }
+
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
+        self.inner.get_asset_events(asset_id, limit).await
+    }
+
+    async fn get_latest_event(&self, asset_id: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        self.inner.get_latest_event(asset_id).await
+    }
+
+    async fn count_events(&self) -> DbResult<i64> {
+        self.inner.count_events().await
+    }
+
+    async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
+        self.inner.count_by_type(event_type).await
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        self.inner.health_check().await
+    }
+
+    async fn chain_events(&self, after_sequence: u64, limit: i64) -> DbResult<Vec<ChainedEvent>> {
+        self.inner.chain_events(after_sequence, limit).await
+    }
+
+    async fn chain_head(&self) -> DbResult<Option<ChainLink>> {
+        self.inner.chain_head().await
+    }
+
+    async fn chain_link(&self, event_id: &str) -> DbResult<Option<ChainLink>> {
+        self.inner.chain_link(event_id).await
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &ChainCheckpoint) -> DbResult<()> {
+        self.inner.save_checkpoint(checkpoint).await
+    }
+
+    async fn checkpoints(&self) -> DbResult<Vec<ChainCheckpoint>> {
+        self.inner.checkpoints().await
+    }
+}
+
+/// Unit of work decorator that broadcasts committed events
+///
+/// Events are broadcast through the channel of a [`BroadcastEventStore`] once
+/// the wrapped transaction has committed, so subscribers of that store observe
+/// events from both paths in commit order.
+#[derive(Clone)]
+pub struct BroadcastUnitOfWork {
+    inner: Arc<dyn UnitOfWork>,
+    sender: broadcast::Sender<RegistryEvent>,
+}
+
+impl BroadcastUnitOfWork {
+    /// Wrap a unit of work, broadcasting through the given event store's channel
+    pub fn new(inner: Arc<dyn UnitOfWork>, event_store: &BroadcastEventStore) -> Self {
+        Self {
+            inner,
+            sender: event_store.sender.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for BroadcastUnitOfWork {
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>> {
+        Ok(Box::new(BroadcastTransaction {
+            inner: self.inner.begin().await?,
+            sender: self.sender.clone(),
+        }))
+    }
+}
+
+/// A write transaction whose events are broadcast on commit
+struct BroadcastTransaction {
+    inner: Box<dyn WriteTransaction>,
+    sender: broadcast::Sender<RegistryEvent>,
+}
+
+#[async_trait]
+impl WriteTransaction for BroadcastTransaction {
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        self.inner.create_asset(asset).await
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        self.inner.update_asset(asset).await
+    }
+
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()> {
+        self.inner.delete_asset(id).await
+    }
+
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        self.inner.append_event(event).await
+    }
+
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>> {
+        let events = self.inner.commit().await?;
+        for event in &events {
+            publish(&self.sender, event);
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
+    use llm_registry_core::EventType;
+    use tokio::sync::broadcast::error::RecvError;
+
+    fn deleted_event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_appended_events_are_broadcast() {
+        let store = BroadcastEventStore::new(Arc::new(InMemoryEventStore::new()));
+        let mut receiver = store.subscribe();
+
+        let first = store.append(deleted_event()).await.unwrap();
+        let batch = store
+            .append_batch(vec![deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap().event_id, first.event_id);
+        assert_eq!(receiver.recv().await.unwrap().event_id, batch[0].event_id);
+        assert_eq!(receiver.recv().await.unwrap().event_id, batch[1].event_id);
+        assert_eq!(store.count_events().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_append_without_subscribers() {
+        let store = BroadcastEventStore::new(Arc::new(InMemoryEventStore::new()));
+        assert_eq!(store.subscriber_count(), 0);
+
+        let event = store.append(deleted_event()).await.unwrap();
+        assert!(store.get_event(&event.event_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_lags() {
+        let store = BroadcastEventStore::with_capacity(Arc::new(InMemoryEventStore::new()), 2);
+        let mut receiver = store.subscribe();
+
+        for _ in 0..3 {
+            store.append(deleted_event()).await.unwrap();
+        }
+
+        assert!(matches!(receiver.recv().await, Err(RecvError::Lagged(1))));
+        assert!(receiver.recv().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_committed_unit_of_work_events_are_broadcast() {
+        let events = Arc::new(InMemoryEventStore::new());
+        let store = BroadcastEventStore::new(events.clone());
+        let uow = BroadcastUnitOfWork::new(
+            Arc::new(InMemoryUnitOfWork::new(
+                Arc::new(InMemoryAssetRepository::new()),
+                events,
+            )),
+            &store,
+        );
+        let mut receiver = store.subscribe();
+
+        // Rolled back transactions are never broadcast
+        let mut tx = uow.begin().await.unwrap();
+        tx.append_event(&deleted_event()).await.unwrap();
+        drop(tx);
+
+        let event = deleted_event();
+        let mut tx = uow.begin().await.unwrap();
+        tx.append_event(&event).await.unwrap();
+        tx.commit().await.unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap().event_id, event.event_id);
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(store.count_events().await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,829 @@
+//! Read-through caching for asset repositories
+//!
+//! [`CachedAssetRepository`] serves asset lookups from a two-tier
+//! [`AssetCache`]: a bounded in-process `moka` tier in front of an optional
+//! shared Redis tier. Search results are only cached in-process, since a
+//! single write can change any of them.
+//!
+//! Every write through the repository, or through a [`CachedUnitOfWork`],
+//! evicts the written assets from both tiers and drops all cached searches
+//! before returning. When NATS invalidation is configured, the eviction is
+//! also broadcast so other replicas drop their in-process copies; the Redis
+//! tier is shared and only needs to be evicted once.
+//!
+//! Every eviction bumps the cache generation. A read only stores what it
+//! fetched when no eviction ran since it started and no newer revision is
+//! cached, so a read that races a write cannot repopulate a tier with the
+//! value it read before the write committed. A write on another replica is
+//! only seen once its broadcast arrives; until then, tier TTLs bound how long
+//! a stale Redis entry can survive.
+
+use async_nats::Client;
+use async_trait::async_trait;
+use futures::StreamExt;
+use llm_registry_core::{Asset, AssetId, AssetType, HashAlgorithm, RegistryEvent};
+use moka::future::Cache;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::cache::RedisCache;
+use crate::error::{DbError, DbResult};
+use crate::repository::{AssetRepository, DependencyLink, SearchQuery, SearchResults};
+use crate::unit_of_work::{UnitOfWork, WriteTransaction};
+
+/// Default NATS subject cache invalidations are broadcast on
+pub const DEFAULT_INVALIDATION_SUBJECT: &str = "registry.cache.invalidate";
+
+/// Sink for cache hit/miss metrics
+///
+/// `operation` names the tier and access (`local_get`, `redis_get`,
+/// `search`, `invalidate`); `result` is `hit`, `miss`, `ok` or `error`.
+pub trait CacheMetrics: Send + Sync {
+    /// Record a single cache operation
+    fn record(&self, operation: &str, result: &str);
+}
+
+/// In-process cache tier configuration
+#[derive(Debug, Clone)]
+pub struct LocalCacheConfig {
+    /// Maximum number of assets held in process
+    pub max_capacity: u64,
+
+    /// Time-to-live for cached assets
+    pub asset_ttl: Duration,
+
+    /// Maximum number of search result pages held in process
+    pub max_searches: u64,
+
+    /// Time-to-live for cached search results
+    pub search_ttl: Duration,
+}
+
+impl Default for LocalCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_capacity: 10_000,
+            asset_ttl: Duration::from_secs(60),
+            max_searches: 1_000,
+            search_ttl: Duration::from_secs(30),
+        }
+    }
+}
+
+impl LocalCacheConfig {
+    /// Set the maximum number of cached assets
+    pub fn with_max_capacity(mut self, capacity: u64) -> Self {
+        self.max_capacity = capacity;
+        self
+    }
+
+    /// Set the asset TTL
+    pub fn with_asset_ttl(mut self, ttl: Duration) -> Self {
+        self.asset_ttl = ttl;
+        self
+    }
+
+    /// Set the maximum number of cached search result pages
+    pub fn with_max_searches(mut self, capacity: u64) -> Self {
+        self.max_searches = capacity;
+        self
+    }
+
+    /// Set the search result TTL
+    pub fn with_search_ttl(mut self, ttl: Duration) -> Self {
+        self.search_ttl = ttl;
+        self
+    }
+}
+
+/// Invalidation broadcast to other replicas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct InvalidationMessage {
+    /// Cache instance that performed the write
+    origin: String,
+
+    /// Assets to evict; cached searches are always dropped
+    asset_ids: Vec<AssetId>,
+}
+
+/// Two-tier asset cache shared by a repository and its unit of work
+#[derive(Clone)]
+pub struct AssetCache {
+    assets: Cache<AssetId, Asset>,
+    searches: Cache<String, SearchResults>,
+    redis: Option<RedisCache>,
+    nats: Option<(Client, String)>,
+    metrics: Option<Arc<dyn CacheMetrics>>,
+    origin: Arc<str>,
+    generation: Arc<AtomicU64>,
+}
+
+impl AssetCache {
+    /// Create an in-process cache with no shared tier
+    pub fn new(config: LocalCacheConfig) -> Self {
+        Self {
+            assets: Cache::builder()
+                .max_capacity(config.max_capacity)
+                .time_to_live(config.asset_ttl)
+                .build(),
+            searches: Cache::builder()
+                .max_capacity(config.max_searches)
+                .time_to_live(config.search_ttl)
+                .build(),
+            redis: None,
+            nats: None,
+            metrics: None,
+            origin: ulid::Ulid::new().to_string().into(),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Back the in-process tier with a shared Redis tier
+    pub fn with_redis(mut self, redis: RedisCache) -> Self {
+        self.redis = Some(redis);
+        self
+    }
+
+    /// Broadcast invalidations to other replicas over NATS
+    ///
+    /// Other replicas must run [`AssetCache::spawn_invalidation_listener`]
+    /// on the same subject.
+    pub fn with_nats_invalidation(mut self, client: Client, subject: impl Into<String>) -> Self {
+        self.nats = Some((client, subject.into()));
+        self
+    }
+
+    /// Report hits and misses to a metrics sink
+    pub fn with_metrics(mut self, metrics: Arc<dyn CacheMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Look up an asset, trying the in-process tier before Redis
+    ///
+    /// Redis errors are logged and treated as misses.
+    pub async fn get_asset(&self, id: &AssetId) -> Option<Asset> {
+        let generation = self.generation();
+        if let Some(asset) = self.assets.get(id).await {
+            self.record("local_get", "hit");
+            return Some(asset);
+        }
+        self.record("local_get", "miss");
+
+        let redis = self.redis.as_ref()?;
+        match redis.get_asset(id).await {
+            Ok(Some(asset)) => {
+                self.record("redis_get", "hit");
+                self.assets.insert(asset.id, asset.clone()).await;
+                if self.generation() != generation {
+                    self.assets.invalidate(id).await;
+                }
+                Some(asset)
+            }
+            Ok(None) => {
+                self.record("redis_get", "miss");
+                None
+            }
+            Err(e) => {
+                self.record("redis_get", "error");
+                warn!(asset_id = %id, error = %e, "Redis cache read failed");
+                None
+            }
+        }
+    }
+
+    /// Store an asset in both tiers
+    pub async fn put_asset(&self, asset: &Asset) {
+        self.assets.insert(asset.id, asset.clone()).await;
+        if let Some(redis) = &self.redis {
+            if let Err(e) = redis.set_asset(asset).await {
+                self.record("redis_set", "error");
+                warn!(asset_id = %asset.id, error = %e, "Redis cache write failed");
+            }
+        }
+    }
+
+    /// Store an asset read from the repository at `generation`
+    ///
+    /// Nothing is stored if an eviction ran since `generation` was taken or
+    /// a newer revision is already cached. An eviction that runs while the
+    /// asset is being stored is detected afterwards and the entry removed.
+    pub async fn fill_asset(&self, asset: &Asset, generation: u64) {
+        if self.generation() != generation {
+            return;
+        }
+        if let Some(cached) = self.assets.get(&asset.id).await {
+            if cached.revision > asset.revision {
+                return;
+            }
+        }
+
+        self.put_asset(asset).await;
+        if self.generation() != generation {
+            self.assets.invalidate(&asset.id).await;
+            if let Some(redis) = &self.redis {
+                if let Err(e) = redis.delete_asset(&asset.id).await {
+                    warn!(asset_id = %asset.id, error = %e, "Redis cache invalidation failed");
+                }
+            }
+        }
+    }
+
+    /// Current generation, bumped by every eviction
+    ///
+    /// Take it before reading from the repository and pass it to
+    /// [`AssetCache::fill_asset`].
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Evict assets from both tiers and drop all cached searches
+    ///
+    /// The eviction is broadcast to other replicas when NATS invalidation is
+    /// configured. Failures are logged rather than returned, since the write
+    /// that triggered the invalidation has already been committed.
+    pub async fn invalidate(&self, ids: &[AssetId]) {
+        self.invalidate_local(ids).await;
+
+        let mut result = "ok";
+        if let Some(redis) = &self.redis {
+            for id in ids {
+                if let Err(e) = redis.delete_asset(id).await {
+                    result = "error";
+                    warn!(asset_id = %id, error = %e, "Redis cache invalidation failed");
+                }
+            }
+        }
+
+        if let Some((client, subject)) = &self.nats {
+            let message = InvalidationMessage {
+                origin: self.origin.to_string(),
+                asset_ids: ids.to_vec(),
+            };
+            let published = match serde_json::to_vec(&message) {
+                Ok(payload) => client
+                    .publish(subject.clone(), payload.into())
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(e) = published {
+                result = "error";
+                warn!(error = %e, "Failed to broadcast cache invalidation");
+            }
+        }
+
+        self.record("invalidate", result);
+    }
+
+    /// Evict assets and searches from the in-process tier only
+    async fn invalidate_local(&self, ids: &[AssetId]) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        for id in ids {
+            self.assets.invalidate(id).await;
+        }
+        self.searches.invalidate_all();
+        debug!(count = ids.len(), "Invalidated cached assets and searches");
+    }
+
+    /// Apply an invalidation received from another replica
+    async fn apply_invalidation(&self, payload: &[u8]) {
+        match serde_json::from_slice::<InvalidationMessage>(payload) {
+            Ok(message) if *message.origin == *self.origin => {}
+            Ok(message) => self.invalidate_local(&message.asset_ids).await,
+            Err(e) => warn!(error = %e, "Ignoring malformed cache invalidation"),
+        }
+    }
+
+    /// Evict in-process entries when other replicas broadcast invalidations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if NATS invalidation is not configured or the
+    /// subscription fails.
+    pub async fn spawn_invalidation_listener(&self) -> DbResult<JoinHandle<()>> {
+        let (client, subject) = self.nats.as_ref().ok_or_else(|| {
+            DbError::Configuration("NATS cache invalidation is not configured".to_string())
+        })?;
+        let mut subscriber = client.subscribe(subject.clone()).await.map_err(|e| {
+            DbError::Connection(format!("Failed to subscribe to {}: {}", subject, e))
+        })?;
+
+        info!("Listening for cache invalidations on {}", subject);
+        let cache = self.clone();
+        Ok(tokio::spawn(async move {
+            while let Some(message) = subscriber.next().await {
+                cache.apply_invalidation(&message.payload).await;
+            }
+        }))
+    }
+
+    async fn cached_search(&self, key: &str) -> Option<SearchResults> {
+        let results = self.searches.get(key).await;
+        self.record("search", if results.is_some() { "hit" } else { "miss" });
+        results
+    }
+
+    fn record(&self, operation: &str, result: &str) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record(operation, result);
+        }
+    }
+}
+
+/// Key identifying a search query in the in-process tier
+///
+/// Hashes the query's JSON form, which lists every field in declaration
+/// order, so the key does not depend on how the query prints.
+fn search_key(query: &SearchQuery) -> String {
+    let canonical = serde_json::to_vec(query).expect("search queries serialize to JSON");
+    HashAlgorithm::SHA256.digest(&canonical)
+}
+
+/// Asset repository decorator serving reads from an [`AssetCache`]
+///
+/// `find_by_id`, `find_by_ids` and `search` are read through the cache; all
+/// other reads go straight to the wrapped repository. Writes to the wrapped
+/// repository that bypass this decorator are not seen by the cache, so a
+/// unit of work over the same storage must be wrapped in
+/// [`CachedUnitOfWork`].
+pub struct CachedAssetRepository<R: AssetRepository + ?Sized> {
+    inner: Arc<R>,
+    cache: AssetCache,
+}
+
+impl<R: AssetRepository + ?Sized> CachedAssetRepository<R> {
+    /// Wrap a repository with a cache
+    pub fn new(inner: Arc<R>, cache: AssetCache) -> Self {
+        Self { inner, cache }
+    }
+
+    /// Get the cache
+    pub fn cache(&self) -> &AssetCache {
+        &self.cache
+    }
+
+    /// Get the wrapped repository
+    pub fn inner(&self) -> &Arc<R> {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<R: AssetRepository + ?Sized> AssetRepository for CachedAssetRepository<R> {
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        let asset = self.inner.create(asset).await?;
+        self.cache.invalidate(&[asset.id]).await;
+        Ok(asset)
+    }
+
+    async fn create_batch(&self, assets: Vec<Asset>) -> DbResult<Vec<Asset>> {
+        let assets = self.inner.create_batch(assets).await?;
+        let ids: Vec<AssetId> = assets.iter().map(|asset| asset.id).collect();
+        self.cache.invalidate(&ids).await;
+        Ok(assets)
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
+        let generation = self.cache.generation();
+        if let Some(asset) = self.cache.get_asset(id).await {
+            return Ok(Some(asset));
+        }
+
+        let asset = self.inner.find_by_id(id).await?;
+        if let Some(asset) = &asset {
+            self.cache.fill_asset(asset, generation).await;
+        }
+        Ok(asset)
+    }
+
+    async fn find_by_name_and_version(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> DbResult<Option<Asset>> {
+        self.inner.find_by_name_and_version(name, version).await
+    }
+
+    async fn find_by_ids(&self, ids: &[AssetId]) -> DbResult<Vec<Asset>> {
+        let generation = self.cache.generation();
+        let mut found = HashMap::with_capacity(ids.len());
+        let mut missing = Vec::new();
+        for id in ids {
+            match self.cache.get_asset(id).await {
+                Some(asset) => {
+                    found.insert(*id, asset);
+                }
+                None => missing.push(*id),
+            }
+        }
+
+        if !missing.is_empty() {
+            for asset in self.inner.find_by_ids(&missing).await? {
+                self.cache.fill_asset(&asset, generation).await;
+                found.insert(asset.id, asset);
+            }
+        }
+        Ok(ids.iter().filter_map(|id| found.get(id).cloned()).collect())
+    }
+
+    async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
+        let key = search_key(query);
+        if let Some(results) = self.cache.cached_search(&key).await {
+            return Ok(results);
+        }
+
+        let results = self.inner.search(query).await?;
+        self.cache.searches.insert(key, results.clone()).await;
+        Ok(results)
+    }
+
+    async fn update(&self, asset: Asset) -> DbResult<Asset> {
+        let id = asset.id;
+        let result = self.inner.update(asset).await;
+        // The caller may have read a stale cached revision, so evict on conflict
+        if matches!(result, Ok(_) | Err(DbError::Conflict(_))) {
+            self.cache.invalidate(&[id]).await;
+        }
+        result
+    }
+
+    async fn delete(&self, id: &AssetId) -> DbResult<()> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate(&[*id]).await;
+        Ok(())
+    }
+
+    async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>> {
+        self.inner.list_versions(name).await
+    }
+
+    async fn list_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        self.inner.list_dependencies(id).await
+    }
+
+    async fn list_reverse_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        self.inner.list_reverse_dependencies(id).await
+    }
+
+    async fn find_by_names(&self, names: &[String]) -> DbResult<Vec<Asset>> {
+        self.inner.find_by_names(names).await
+    }
+
+    async fn list_dependency_links(&self, ids: &[AssetId]) -> DbResult<Vec<DependencyLink>> {
+        self.inner.list_dependency_links(ids).await
+    }
+
+    async fn list_reverse_dependency_links(
+        &self,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        self.inner.list_reverse_dependency_links(ids).await
+    }
+
+    async fn add_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        self.inner.add_tag(id, tag).await?;
+        self.cache.invalidate(&[*id]).await;
+        Ok(())
+    }
+
+    async fn remove_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        self.inner.remove_tag(id, tag).await?;
+        self.cache.invalidate(&[*id]).await;
+        Ok(())
+    }
+
+    async fn get_tags(&self, id: &AssetId) -> DbResult<Vec<String>> {
+        self.inner.get_tags(id).await
+    }
+
+    async fn list_all_tags(&self) -> DbResult<Vec<String>> {
+        self.inner.list_all_tags().await
+    }
+
+    async fn add_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+        version_constraint: Option<&str>,
+    ) -> DbResult<()> {
+        self.inner
+            .add_dependency(asset_id, dependency_id, version_constraint)
+            .await?;
+        self.cache.invalidate(&[*asset_id]).await;
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
+        self.inner
+            .remove_dependency(asset_id, dependency_id)
+            .await?;
+        self.cache.invalidate(&[*asset_id]).await;
+        Ok(())
+    }
+
+    async fn count_assets(&self) -> DbResult<i64> {
+        self.inner.count_assets().await
+    }
+
+    async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
+        self.inner.count_by_type(asset_type).await
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        self.inner.health_check().await
+    }
+}
+
+/// Unit of work decorator that invalidates an [`AssetCache`] on commit
+pub struct CachedUnitOfWork {
+    inner: Arc<dyn UnitOfWork>,
+    cache: AssetCache,
+}
+
+impl CachedUnitOfWork {
+    /// Wrap a unit of work, invalidating the given cache on commit
+    pub fn new(inner: Arc<dyn UnitOfWork>, cache: AssetCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for CachedUnitOfWork {
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>> {
+        Ok(Box::new(CachedTransaction {
+            inner: self.inner.begin().await?,
+            cache: self.cache.clone(),
+            written: Vec::new(),
+        }))
+    }
+}
+
+/// A write transaction whose written assets are evicted on commit
+struct CachedTransaction {
+    inner: Box<dyn WriteTransaction>,
+    cache: AssetCache,
+    written: Vec<AssetId>,
+}
+
+#[async_trait]
+impl WriteTransaction for CachedTransaction {
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        self.inner.create_asset(asset).await?;
+        self.written.push(asset.id);
+        Ok(())
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        let result = self.inner.update_asset(asset).await;
+        match &result {
+            Ok(()) => self.written.push(asset.id),
+            // The caller may have read a stale cached revision
+            Err(DbError::Conflict(_)) => self.cache.invalidate(&[asset.id]).await,
+            Err(_) => {}
+        }
+        result
+    }
+
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()> {
+        self.inner.delete_asset(id).await?;
+        self.written.push(*id);
+        Ok(())
+    }
+
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        self.inner.append_event(event).await
+    }
+
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>> {
+        let result = self.inner.commit().await;
+        // Backends may defer a stale revision conflict until commit
+        if matches!(result, Ok(_) | Err(DbError::Conflict(_))) {
+            self.cache.invalidate(&self.written).await;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
+    use crate::repository::SortOrder;
+    use crate::test_support::test_asset;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingMetrics(Mutex<HashMap<String, usize>>);
+
+    impl RecordingMetrics {
+        fn count(&self, operation: &str, result: &str) -> usize {
+            let counts = self.0.lock().unwrap();
+            counts
+                .get(&format!("{}:{}", operation, result))
+                .copied()
+                .unwrap_or(0)
+        }
+    }
+
+    impl CacheMetrics for RecordingMetrics {
+        fn record(&self, operation: &str, result: &str) {
+            *self
+                .0
+                .lock()
+                .unwrap()
+                .entry(format!("{}:{}", operation, result))
+                .or_default() += 1;
+        }
+    }
+
+    fn setup() -> (
+        Arc<InMemoryAssetRepository>,
+        CachedAssetRepository<InMemoryAssetRepository>,
+        Arc<RecordingMetrics>,
+    ) {
+        let metrics = Arc::new(RecordingMetrics::default());
+        let inner = Arc::new(InMemoryAssetRepository::new());
+        let cache = AssetCache::new(LocalCacheConfig::default()).with_metrics(metrics.clone());
+        let repository = CachedAssetRepository::new(inner.clone(), cache);
+        (inner, repository, metrics)
+    }
+
+    #[tokio::test]
+    async fn test_reads_through_and_invalidates_on_write() {
+        let (inner, repository, metrics) = setup();
+        let created = repository
+            .create(test_asset("cached", "1.0.0"))
+            .await
+            .unwrap();
+
+        repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert_eq!(metrics.count("local_get", "miss"), 1);
+        repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert_eq!(metrics.count("local_get", "hit"), 1);
+
+        // A write behind the decorator's back is not seen until evicted
+        let mut changed = created.clone();
+        changed.metadata.description = Some("changed".to_string());
+        let changed = inner.update(changed).await.unwrap();
+        let cached = repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert!(cached.metadata.description.is_none());
+
+        let mut updated = changed.clone();
+        updated.metadata.license = Some("MIT".to_string());
+        repository.update(updated).await.unwrap();
+        let fresh = repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert_eq!(fresh.metadata.license.as_deref(), Some("MIT"));
+        assert_eq!(fresh.revision, changed.revision + 1);
+
+        repository.delete(&created.id).await.unwrap();
+        assert!(repository.find_by_id(&created.id).await.unwrap().is_none());
+        assert!(metrics.count("invalidate", "ok") >= 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_cache_dropped_on_write() {
+        let (_, repository, metrics) = setup();
+        repository
+            .create(test_asset("first", "1.0.0"))
+            .await
+            .unwrap();
+
+        let query = SearchQuery::new();
+        assert_eq!(repository.search(&query).await.unwrap().assets.len(), 1);
+        assert_eq!(repository.search(&query).await.unwrap().assets.len(), 1);
+        assert_eq!(metrics.count("search", "hit"), 1);
+
+        repository
+            .create(test_asset("second", "1.0.0"))
+            .await
+            .unwrap();
+        assert_eq!(repository.search(&query).await.unwrap().assets.len(), 2);
+        assert_eq!(metrics.count("search", "miss"), 2);
+
+        // Different queries are cached separately
+        let filtered = SearchQuery::new().text("first");
+        assert_eq!(repository.search(&filtered).await.unwrap().assets.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commit_invalidates() {
+        let (inner, repository, _) = setup();
+        let created = repository.create(test_asset("uow", "1.0.0")).await.unwrap();
+        repository.find_by_id(&created.id).await.unwrap().unwrap();
+
+        let unit_of_work = CachedUnitOfWork::new(
+            Arc::new(InMemoryUnitOfWork::new(
+                inner,
+                Arc::new(InMemoryEventStore::new()),
+            )),
+            repository.cache().clone(),
+        );
+        let mut updated = created.clone();
+        updated.metadata.license = Some("Apache-2.0".to_string());
+        let mut tx = unit_of_work.begin().await.unwrap();
+        tx.update_asset(&updated).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let fresh = repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert_eq!(fresh.metadata.license.as_deref(), Some("Apache-2.0"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_invalidation_skips_own_messages() {
+        let (_, repository, _) = setup();
+        let created = repository
+            .create(test_asset("remote", "1.0.0"))
+            .await
+            .unwrap();
+        let cache = repository.cache();
+        repository.find_by_id(&created.id).await.unwrap().unwrap();
+
+        let own = InvalidationMessage {
+            origin: cache.origin.to_string(),
+            asset_ids: vec![created.id],
+        };
+        cache
+            .apply_invalidation(&serde_json::to_vec(&own).unwrap())
+            .await;
+        assert!(cache.assets.get(&created.id).await.is_some());
+
+        let remote = InvalidationMessage {
+            origin: "other-replica".to_string(),
+            asset_ids: vec![created.id],
+        };
+        cache
+            .apply_invalidation(&serde_json::to_vec(&remote).unwrap())
+            .await;
+        assert!(cache.assets.get(&created.id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_ids_keeps_input_order() {
+        let (_, repository, _) = setup();
+        let mut ids = Vec::new();
+        for name in ["a", "b", "c"] {
+            ids.push(
+                repository
+                    .create(test_asset(name, "1.0.0"))
+                    .await
+                    .unwrap()
+                    .id,
+            );
+        }
+        // Cache the middle asset so the result mixes hits and misses
+        repository.find_by_id(&ids[1]).await.unwrap().unwrap();
+
+        let requested = [ids[2], ids[1], ids[0]];
+        let found = repository.find_by_ids(&requested).await.unwrap();
+        let found: Vec<AssetId> = found.iter().map(|asset| asset.id).collect();
+        assert_eq!(found, requested);
+    }
+
+    #[tokio::test]
+    async fn test_fill_skips_stale_reads() {
+        let (_, repository, _) = setup();
+        let created = repository
+            .create(test_asset("racy", "1.0.0"))
+            .await
+            .unwrap();
+        let cache = repository.cache();
+
+        // A read that started before an eviction does not store its result
+        let generation = cache.generation();
+        cache.invalidate(&[created.id]).await;
+        cache.fill_asset(&created, generation).await;
+        assert!(cache.assets.get(&created.id).await.is_none());
+
+        // Nor does one that read an older revision than the cached one
+        let mut newer = created.clone();
+        newer.revision += 1;
+        cache.put_asset(&newer).await;
+        cache.fill_asset(&created, cache.generation()).await;
+        let cached = cache.assets.get(&created.id).await.unwrap();
+        assert_eq!(cached.revision, newer.revision);
+    }
+
+    #[test]
+    fn test_search_key_covers_every_field() {
+        let query = SearchQuery::new().text("llama");
+        assert_eq!(search_key(&query), search_key(&query.clone()));
+
+        let mut deprecated = query.clone();
+        deprecated.exclude_deprecated = !query.exclude_deprecated;
+        let mut sorted = query.clone();
+        sorted.sort_order = SortOrder::Ascending;
+        let mut paged = query.clone();
+        paged.offset = 50;
+        for other in [deprecated, sorted, paged] {
+            assert_ne!(search_key(&query), search_key(&other));
+        }
+    }
+}
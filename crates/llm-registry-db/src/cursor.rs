@@ -0,0 +1,286 @@
+//! Opaque cursors for keyset pagination
+//!
+//! A cursor records the sort key and ID of the last item on a page. The next
+//! page resumes strictly after that position, so each page is an index seek
+//! rather than a scan over skipped rows, and rows inserted concurrently never
+//! shift items between pages.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use llm_registry_core::{Asset, RegistryEvent};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sqlx::{Database, Encode, QueryBuilder, Type};
+use std::cmp::Ordering;
+
+use crate::error::{DbError, DbResult};
+use crate::repository::{SortField, SortOrder};
+
+/// Field event queries are ordered by
+const EVENT_ORDER_FIELD: &str = "timestamp";
+
+/// Position of the last item on a page
+///
+/// Clients treat cursors as opaque tokens: [`Cursor::encode`] them into
+/// responses and [`Cursor::decode`] them from requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    /// Ordering the cursor was issued for, e.g. `created_at:desc`
+    #[serde(rename = "o")]
+    pub ordering: String,
+
+    /// Sort key of the last item
+    #[serde(rename = "k")]
+    pub key: CursorKey,
+
+    /// ID of the last item, breaking ties between equal sort keys
+    #[serde(rename = "i")]
+    pub id: String,
+}
+
+/// Sort key value stored in a cursor
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CursorKey {
+    /// Timestamp sort key
+    Timestamp(DateTime<Utc>),
+    /// Text sort key
+    Text(String),
+    /// Integer sort key
+    Integer(i64),
+}
+
+impl Cursor {
+    /// Create a cursor positioned at an asset
+    ///
+    /// [`SortField::Relevance`] positions by creation time, matching the
+    /// order used when a query has no search text.
+    pub fn for_asset(asset: &Asset, field: SortField, order: SortOrder) -> Self {
+        Self {
+            ordering: asset_ordering(field, order),
+            key: asset_key(asset, field),
+            id: asset.id.to_string(),
+        }
+    }
+
+    /// Create a cursor positioned at an event, for newest-first queries
+    pub fn for_event(event: &RegistryEvent) -> Self {
+        Self {
+            ordering: ordering_name(EVENT_ORDER_FIELD, SortOrder::Descending),
+            key: CursorKey::Timestamp(event.timestamp),
+            id: event.event_id.clone(),
+        }
+    }
+
+    /// The same position in the opposite sort order
+    ///
+    /// Paging backward from a cursor is paging forward from its reverse.
+    pub fn reversed(&self) -> Self {
+        let ordering = match self.ordering.rsplit_once(':') {
+            Some((field, "asc")) => format!("{}:desc", field),
+            Some((field, "desc")) => format!("{}:asc", field),
+            _ => self.ordering.clone(),
+        };
+        Self {
+            ordering,
+            ..self.clone()
+        }
+    }
+
+    /// Encode the cursor as an opaque, URL-safe token
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serialization is infallible");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decode a token produced by [`Cursor::encode`]
+    pub fn decode(token: &str) -> DbResult<Self> {
+        URL_SAFE_NO_PAD
+            .decode(token)
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .ok_or_else(|| DbError::InvalidQuery("Malformed pagination cursor".to_string()))
+    }
+
+    /// Ensure the cursor was issued for an asset ordering
+    pub fn check_asset_ordering(&self, field: SortField, order: SortOrder) -> DbResult<()> {
+        let key_matches = match field {
+            SortField::Name | SortField::Version => matches!(self.key, CursorKey::Text(_)),
+            SortField::SizeBytes => matches!(self.key, CursorKey::Integer(_)),
+            _ => matches!(self.key, CursorKey::Timestamp(_)),
+        };
+        if !key_matches || self.ordering != asset_ordering(field, order) {
+            return Err(DbError::InvalidQuery(
+                "Pagination cursor does not match the requested sort".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensure the cursor was issued for an event query in the given order
+    pub(crate) fn check_event_ordering(&self, order: SortOrder) -> DbResult<()> {
+        if self.ordering != ordering_name(EVENT_ORDER_FIELD, order)
+            || !matches!(self.key, CursorKey::Timestamp(_))
+        {
+            return Err(DbError::InvalidQuery(
+                "Pagination cursor was not issued for an event query".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Compare an asset's position with the cursor in ascending sort order
+    pub(crate) fn compare_asset(&self, asset: &Asset, field: SortField) -> Ordering {
+        let ordering = match (field, &self.key) {
+            // Versions sort semantically in memory
+            (SortField::Version, CursorKey::Text(version)) => match Version::parse(version) {
+                Ok(version) => asset.metadata.version.cmp(&version),
+                Err(_) => asset.metadata.version.to_string().cmp(version),
+            },
+            (field, key) => asset_key(asset, field)
+                .partial_cmp(key)
+                .unwrap_or(Ordering::Equal),
+        };
+
+        ordering.then_with(|| asset.id.to_string().cmp(&self.id))
+    }
+
+    /// Compare an event's position with the cursor in ascending time order
+    pub(crate) fn compare_event(&self, event: &RegistryEvent) -> Ordering {
+        CursorKey::Timestamp(event.timestamp)
+            .partial_cmp(&self.key)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| event.event_id.cmp(&self.id))
+    }
+}
+
+/// Name of an asset ordering, e.g. `created_at:desc`
+fn asset_ordering(field: SortField, order: SortOrder) -> String {
+    let field = match field {
+        SortField::CreatedAt | SortField::Relevance => "created_at",
+        SortField::UpdatedAt => "updated_at",
+        SortField::Name => "name",
+        SortField::Version => "version",
+        SortField::SizeBytes => "size_bytes",
+    };
+    ordering_name(field, order)
+}
+
+/// Name of an ordering by a field, e.g. `timestamp:desc`
+fn ordering_name(field: &str, order: SortOrder) -> String {
+    let order = match order {
+        SortOrder::Ascending => "asc",
+        SortOrder::Descending => "desc",
+    };
+    format!("{}:{}", field, order)
+}
+
+/// Sort key of an asset
+///
+/// Assets without a size sort as `-1`, before every sized asset.
+fn asset_key(asset: &Asset, field: SortField) -> CursorKey {
+    match field {
+        SortField::CreatedAt | SortField::Relevance => CursorKey::Timestamp(asset.created_at),
+        SortField::UpdatedAt => CursorKey::Timestamp(asset.updated_at),
+        SortField::Name => CursorKey::Text(asset.metadata.name.clone()),
+        SortField::Version => CursorKey::Text(asset.metadata.version.to_string()),
+        SortField::SizeBytes => CursorKey::Integer(
+            asset
+                .metadata
+                .size_bytes
+                .map_or(-1, |size| i64::try_from(size).unwrap_or(i64::MAX)),
+        ),
+    }
+}
+
+/// Append a condition selecting rows after a cursor
+///
+/// `key_column` must be the expression the query orders by, and the query
+/// must break ties on `id_column` in the same direction.
+pub(crate) fn push_keyset_condition<'args, DB>(
+    builder: &mut QueryBuilder<'args, DB>,
+    key_column: &str,
+    id_column: &str,
+    cursor: &Cursor,
+    order: SortOrder,
+) where
+    DB: Database,
+    DateTime<Utc>: Encode<'args, DB> + Type<DB>,
+    String: Encode<'args, DB> + Type<DB>,
+    i64: Encode<'args, DB> + Type<DB>,
+{
+    let operator = match order {
+        SortOrder::Ascending => ">",
+        SortOrder::Descending => "<",
+    };
+
+    builder.push(format!(
+        " AND ({}, {}) {} (",
+        key_column, id_column, operator
+    ));
+    match &cursor.key {
+        CursorKey::Timestamp(timestamp) => builder.push_bind(*timestamp),
+        CursorKey::Text(text) => builder.push_bind(text.clone()),
+        CursorKey::Integer(value) => builder.push_bind(*value),
+    };
+    builder.push(", ").push_bind(cursor.id.clone()).push(")");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor {
+            ordering: "name:asc".to_string(),
+            key: CursorKey::Text("gpt-2".to_string()),
+            id: "01ARZ3NDEKTSV4RRFFQ69G5FAV".to_string(),
+        };
+
+        let token = cursor.encode();
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(Cursor::decode(&token).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_malformed_cursor_rejected() {
+        assert!(matches!(
+            Cursor::decode("not a cursor"),
+            Err(DbError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode(b"{}")),
+            Err(DbError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_cursor_ordering_checked() {
+        let cursor = Cursor {
+            ordering: asset_ordering(SortField::Name, SortOrder::Ascending),
+            key: CursorKey::Text("gpt-2".to_string()),
+            id: "01ARZ3NDEKTSV4RRFFQ69G5FAV".to_string(),
+        };
+
+        assert!(cursor
+            .check_asset_ordering(SortField::Name, SortOrder::Ascending)
+            .is_ok());
+        assert!(cursor
+            .check_asset_ordering(SortField::Name, SortOrder::Descending)
+            .is_err());
+        assert!(cursor
+            .check_asset_ordering(SortField::CreatedAt, SortOrder::Ascending)
+            .is_err());
+        assert!(cursor.check_event_ordering(SortOrder::Ascending).is_err());
+
+        let reversed = cursor.reversed();
+        assert!(reversed
+            .check_asset_ordering(SortField::Name, SortOrder::Descending)
+            .is_ok());
+        assert_eq!(reversed.reversed(), cursor);
+    }
+}
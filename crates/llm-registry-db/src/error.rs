@@ -39,6 +39,10 @@ pub enum DbError {
     #[error("Asset already exists: {0}")]
     AlreadyExists(String),
 
+    /// Asset was modified since the revision the update was based on
+    #[error("Revision conflict: {0}")]
+    Conflict(String),
+
     /// Constraint violation
     #[error("Constraint violation: {0}")]
     ConstraintViolation(String),
@@ -75,6 +79,10 @@ pub enum DbError {
     #[error("Cache error: {0}")]
     Cache(String),
 
+    /// Blob storage error
+    #[error("Storage error: {0}")]
+    Storage(String),
+
     /// Internal database error
     #[error("Internal database error: {0}")]
     Internal(String),
@@ -89,11 +97,24 @@ pub enum DbError {
 }
 
 impl DbError {
+    /// Create a conflict error for an update based on a stale revision
+    pub fn revision_conflict(id: impl std::fmt::Display, expected: u64, current: u64) -> Self {
+        DbError::Conflict(format!(
+            "Asset {} is at revision {}, expected {}",
+            id, current, expected
+        ))
+    }
+
     /// Check if this error is a not-found error
     pub fn is_not_found(&self) -> bool {
         matches!(self, DbError::NotFound(_))
     }
 
+    /// Check if this error is a revision conflict
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, DbError::Conflict(_))
+    }
+
     /// Check if this error is a constraint violation
     pub fn is_constraint_violation(&self) -> bool {
         matches!(
@@ -106,7 +127,10 @@ impl DbError {
 
     /// Check if this error is a duplicate/already exists error
     pub fn is_already_exists(&self) -> bool {
-        matches!(self, DbError::AlreadyExists(_) | DbError::UniqueViolation(_))
+        matches!(
+            self,
+            DbError::AlreadyExists(_) | DbError::UniqueViolation(_)
+        )
     }
 
     /// Check if this is a transient error that could be retried
@@ -146,7 +170,21 @@ impl From<sqlx::Error> for DbError {
                         // Various integrity constraint violations
                         DbError::ConstraintViolation(message.to_string())
                     }
-                    _ => DbError::Query(message.to_string()),
+                    // Other backends (e.g. SQLite) report extended result codes,
+                    // so fall back to sqlx's backend-agnostic classification
+                    _ => match db_err.kind() {
+                        sqlx::error::ErrorKind::UniqueViolation => {
+                            DbError::UniqueViolation(message.to_string())
+                        }
+                        sqlx::error::ErrorKind::ForeignKeyViolation => {
+                            DbError::ForeignKeyViolation(message.to_string())
+                        }
+                        sqlx::error::ErrorKind::NotNullViolation
+                        | sqlx::error::ErrorKind::CheckViolation => {
+                            DbError::ConstraintViolation(message.to_string())
+                        }
+                        _ => DbError::Query(message.to_string()),
+                    },
                 }
             }
 
@@ -168,13 +206,9 @@ impl From<sqlx::Error> for DbError {
                 DbError::InvalidData(format!("Column not found: {}", col))
             }
 
-            sqlx::Error::Decode(msg) => {
-                DbError::Serialization(format!("Decode error: {}", msg))
-            }
+            sqlx::Error::Decode(msg) => DbError::Serialization(format!("Decode error: {}", msg)),
 
-            sqlx::Error::Migrate(migrate_err) => {
-                DbError::Migration(format!("{}", migrate_err))
-            }
+            sqlx::Error::Migrate(migrate_err) => DbError::Migration(format!("{}", migrate_err)),
 
             _ => DbError::Internal(format!("{}", err)),
         }
@@ -233,6 +267,16 @@ mod tests {
         assert_eq!(err.to_string(), "Asset not found: asset-123");
 
         let err = DbError::UniqueViolation("duplicate key".to_string());
-        assert_eq!(err.to_string(), "Unique constraint violation: duplicate key");
+        assert_eq!(
+            err.to_string(),
+            "Unique constraint violation: duplicate key"
+        );
+
+        let err = DbError::revision_conflict("asset-123", 2, 3);
+        assert!(err.is_conflict());
+        assert_eq!(
+            err.to_string(),
+            "Revision conflict: Asset asset-123 is at revision 3, expected 2"
+        );
     }
 }
@@ -11,7 +11,13 @@ use sqlx::postgres::PgRow;
 use sqlx::{PgPool, Row};
 use tracing::{debug, instrument};
 
+use crate::audit_chain::{ChainCheckpoint, ChainLink, ChainedEvent};
+use crate::cursor::{push_keyset_condition, Cursor};
 use crate::error::{DbError, DbResult};
+use crate::repository::SortOrder;
+
+/// Advisory lock key serializing appends to the PostgreSQL audit chain
+const CHAIN_LOCK_KEY: i64 = 0x6c6c_6d5f_6368_6169;
 
 /// Query parameters for searching events
 #[derive(Debug, Clone, Default)]
@@ -35,7 +41,18 @@ pub struct EventQuery {
     pub limit: i64,
 
     /// Number of events to skip
+    ///
+    /// Ignored when resuming from a cursor.
     pub offset: i64,
+
+    /// Resume after the last event of a previous page
+    pub cursor: Option<Cursor>,
+
+    /// Count all matching events, which costs a second query
+    pub include_total: bool,
+
+    /// Order by timestamp; newest first by default
+    pub sort_order: SortOrder,
 }
 
 impl EventQuery {
@@ -44,6 +61,7 @@ impl EventQuery {
         Self {
             limit: 100,
             offset: 0,
+            include_total: true,
             ..Default::default()
         }
     }
@@ -89,6 +107,90 @@ impl EventQuery {
         self.offset = offset;
         self
     }
+
+    /// Resume after a cursor from a previous page
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Count all matching events
+    pub fn include_total(mut self, include: bool) -> Self {
+        self.include_total = include;
+        self
+    }
+
+    /// Set the timestamp sort order
+    pub fn sort_order(mut self, order: SortOrder) -> Self {
+        self.sort_order = order;
+        self
+    }
+
+    /// Check whether an event matches all filters
+    ///
+    /// Pagination fields (limit, offset, cursor) are not considered.
+    pub fn matches(&self, event: &RegistryEvent) -> bool {
+        if let Some(ref asset_id) = self.asset_id {
+            if event.asset_id().as_ref() != Some(asset_id) {
+                return false;
+            }
+        }
+
+        if !self.event_types.is_empty() && !self.event_types.iter().any(|t| t == event.event_name())
+        {
+            return false;
+        }
+
+        if let Some(ref actor) = self.actor {
+            if event.actor.as_ref() != Some(actor) {
+                return false;
+            }
+        }
+
+        if let Some(after) = self.after {
+            if event.timestamp <= after {
+                return false;
+            }
+        }
+
+        if let Some(before) = self.before {
+            if event.timestamp >= before {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// The cursor to resume after, checked to be an event cursor
+    pub(crate) fn resume_after(&self) -> DbResult<Option<&Cursor>> {
+        if let Some(ref cursor) = self.cursor {
+            cursor.check_event_ordering(self.sort_order)?;
+        }
+        Ok(self.cursor.as_ref())
+    }
+
+    /// `ORDER BY` clause for the SQL event stores
+    pub(crate) fn order_by(&self) -> &'static str {
+        match self.sort_order {
+            SortOrder::Ascending => " ORDER BY timestamp ASC, event_id ASC",
+            SortOrder::Descending => " ORDER BY timestamp DESC, event_id DESC",
+        }
+    }
+
+    /// Number of events to skip
+    pub(crate) fn effective_offset(&self) -> i64 {
+        if self.cursor.is_some() {
+            0
+        } else {
+            self.offset.max(0)
+        }
+    }
+
+    /// Number of rows to fetch: one past the limit, to detect a following page
+    pub(crate) fn fetch_limit(&self) -> i64 {
+        self.limit.max(0).saturating_add(1)
+    }
 }
 
 /// Results from an event query
@@ -98,19 +200,55 @@ pub struct EventQueryResults {
     pub events: Vec<RegistryEvent>,
 
     /// Total number of matching events (without pagination)
-    pub total: i64,
+    ///
+    /// `None` unless the query asked for a count.
+    pub total: Option<i64>,
 
     /// Current offset
     pub offset: i64,
 
     /// Current limit
     pub limit: i64,
+
+    /// Cursor for the next page, if there is one
+    pub next_cursor: Option<Cursor>,
 }
 
 impl EventQueryResults {
+    /// Build a page from events fetched with [`EventQuery::fetch_limit`]
+    pub(crate) fn page(
+        query: &EventQuery,
+        mut events: Vec<RegistryEvent>,
+        total: Option<i64>,
+    ) -> Self {
+        let limit = query.limit.max(0) as usize;
+        let overflow = events.len() > limit;
+        events.truncate(limit);
+
+        let next_cursor = if overflow {
+            events.last().map(|event| match query.sort_order {
+                SortOrder::Ascending => Cursor::for_event(event).reversed(),
+                SortOrder::Descending => Cursor::for_event(event),
+            })
+        } else {
+            None
+        };
+
+        Self {
+            events,
+            total,
+            offset: query.effective_offset(),
+            limit: query.limit,
+            next_cursor,
+        }
+    }
+
     /// Check if there are more events available
     pub fn has_more(&self) -> bool {
-        (self.offset + self.events.len() as i64) < self.total
+        self.next_cursor.is_some()
+            || self
+                .total
+                .is_some_and(|total| self.offset + (self.events.len() as i64) < total)
     }
 
     /// Get the number of events in this page
@@ -149,6 +287,15 @@ pub trait EventStore: Send + Sync {
     /// * Query results with matching events
     async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults>;
 
+    /// Get a single event by its ID
+    ///
+    /// # Arguments
+    /// * `event_id` - The event ID
+    ///
+    /// # Returns
+    /// * The event if found, None otherwise
+    async fn get_event(&self, event_id: &str) -> DbResult<Option<RegistryEvent>>;
+
     /// Get events for a specific asset
     ///
     /// # Arguments
@@ -157,7 +304,11 @@ pub trait EventStore: Send + Sync {
     ///
     /// # Returns
     /// * Vector of events for the asset, ordered by timestamp descending
-    async fn get_asset_events(&self, asset_id: &AssetId, limit: i64) -> DbResult<Vec<RegistryEvent>>;
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>>;
 
     /// Get the latest event for an asset
     ///
@@ -176,6 +327,28 @@ pub trait EventStore: Send + Sync {
 
     /// Health check for event store
     async fn health_check(&self) -> DbResult<()>;
+
+    /// Read chained events in chain order
+    ///
+    /// # Arguments
+    /// * `after_sequence` - Return events after this chain sequence
+    /// * `limit` - Maximum number of events to return
+    async fn chain_events(&self, after_sequence: u64, limit: i64) -> DbResult<Vec<ChainedEvent>>;
+
+    /// Get the latest link of the audit chain, if any event is chained
+    async fn chain_head(&self) -> DbResult<Option<ChainLink>>;
+
+    /// Get the audit chain link of an event
+    ///
+    /// Returns `None` if the event does not exist or was appended before the
+    /// chain was introduced.
+    async fn chain_link(&self, event_id: &str) -> DbResult<Option<ChainLink>>;
+
+    /// Store a signed checkpoint of the audit chain
+    async fn save_checkpoint(&self, checkpoint: &ChainCheckpoint) -> DbResult<()>;
+
+    /// List audit chain checkpoints in sequence order
+    async fn checkpoints(&self) -> DbResult<Vec<ChainCheckpoint>>;
 }
 
 /// PostgreSQL implementation of EventStore
@@ -194,35 +367,83 @@ impl PostgresEventStore {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
-}
-
-#[async_trait]
-impl EventStore for PostgresEventStore {
-    #[instrument(skip(self, event))]
-    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
-        debug!("Appending event to store");
 
-        let event_type_str = event.event_type.event_name();
-        let asset_id = event.event_type.asset_id();
+    /// Insert an event and queue it in the outbox on the given connection
+    ///
+    /// The event is linked to the audit chain head. Appends are serialized
+    /// by a transaction-scoped advisory lock so the chain never forks.
+    pub(crate) async fn insert_event(
+        conn: &mut sqlx::PgConnection,
+        event: &RegistryEvent,
+    ) -> DbResult<()> {
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(CHAIN_LOCK_KEY)
+            .execute(&mut *conn)
+            .await?;
+        let head = Self::fetch_chain_head(&mut *conn).await?;
+        let link = ChainLink::next(head.as_ref(), event)?;
 
         sqlx::query(
             r#"
             INSERT INTO registry_events (
                 event_id, event_type, asset_id, timestamp,
-                actor, payload, metadata
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                actor, payload, metadata,
+                chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             "#,
         )
-        .bind(ulid::Ulid::new().to_string())
-        .bind(event_type_str)
-        .bind(asset_id.map(|id| id.to_string()))
-        .bind(&event.timestamp)
-        .bind(&event.actor.as_deref().unwrap_or("system"))
+        .bind(&event.event_id)
+        .bind(event.event_type.event_name())
+        .bind(event.event_type.asset_id().map(|id| id.to_string()))
+        .bind(event.timestamp)
+        .bind(event.actor.as_deref().unwrap_or("system"))
         .bind(serde_json::to_value(&event.event_type)?)
         .bind(serde_json::to_value(&event.context)?)
-        .execute(&self.pool)
+        .bind(link.sequence as i64)
+        .bind(link.algorithm.to_string())
+        .bind(&link.prev_hash)
+        .bind(&link.hash)
+        .execute(&mut *conn)
         .await?;
 
+        sqlx::query("INSERT INTO event_outbox (event_id) VALUES ($1)")
+            .bind(&event.event_id)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read the audit chain head on the given connection
+    async fn fetch_chain_head(conn: &mut sqlx::PgConnection) -> DbResult<Option<ChainLink>> {
+        let row = sqlx::query(
+            r#"
+            SELECT chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE chain_sequence IS NOT NULL
+            ORDER BY chain_sequence DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        row.map(|row| row_to_link(&row))
+            .transpose()
+            .map(Option::flatten)
+    }
+}
+
+#[async_trait]
+impl EventStore for PostgresEventStore {
+    #[instrument(skip(self, event))]
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        debug!("Appending event to store");
+
+        let mut tx = self.pool.begin().await?;
+        Self::insert_event(&mut tx, &event).await?;
+        tx.commit().await?;
+
         debug!("Event appended successfully");
         Ok(event)
     }
@@ -238,26 +459,7 @@ impl EventStore for PostgresEventStore {
         let mut tx = self.pool.begin().await?;
 
         for event in &events {
-            let event_type_str = event.event_type.event_name();
-            let asset_id = event.event_type.asset_id();
-
-            sqlx::query(
-                r#"
-                INSERT INTO registry_events (
-                    event_id, event_type, asset_id, timestamp,
-                    actor, payload, metadata
-                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
-                "#,
-            )
-            .bind(ulid::Ulid::new().to_string())
-            .bind(event_type_str)
-            .bind(asset_id.map(|id| id.to_string()))
-            .bind(&event.timestamp)
-            .bind(&event.actor.as_deref().unwrap_or("system"))
-            .bind(serde_json::to_value(&event.event_type)?)
-            .bind(serde_json::to_value(&event.context)?)
-            .execute(&mut *tx)
-            .await?;
+            Self::insert_event(&mut tx, event).await?;
         }
 
         tx.commit().await?;
@@ -270,7 +472,7 @@ impl EventStore for PostgresEventStore {
     async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
         debug!("Querying events");
 
-        let mut sql = String::from(
+        let mut builder = sqlx::QueryBuilder::new(
             r#"
             SELECT
                 event_id, event_type, asset_id, timestamp,
@@ -279,82 +481,532 @@ impl EventStore for PostgresEventStore {
             WHERE 1=1
             "#,
         );
+        push_pg_event_filters(&mut builder, query);
+        if let Some(cursor) = query.resume_after()? {
+            push_keyset_condition(
+                &mut builder,
+                "timestamp",
+                "event_id",
+                cursor,
+                query.sort_order,
+            );
+        }
+        builder.push(query.order_by());
+        builder.push(" LIMIT ").push_bind(query.fetch_limit());
+        builder.push(" OFFSET ").push_bind(query.effective_offset());
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let events = rows
+            .into_iter()
+            .map(row_to_event)
+            .collect::<DbResult<Vec<_>>>()?;
+
+        let total = if query.include_total {
+            let mut count_builder =
+                sqlx::QueryBuilder::new("SELECT COUNT(*) AS count FROM registry_events WHERE 1=1");
+            push_pg_event_filters(&mut count_builder, query);
+            Some(
+                count_builder
+                    .build()
+                    .fetch_one(&self.pool)
+                    .await?
+                    .get("count"),
+            )
+        } else {
+            None
+        };
 
-        let mut conditions = Vec::new();
+        Ok(EventQueryResults::page(query, events, total))
+    }
 
-        if query.asset_id.is_some() {
-            conditions.push("asset_id = $ASSET_ID");
-        }
+    #[instrument(skip(self))]
+    async fn get_event(&self, event_id: &str) -> DbResult<Option<RegistryEvent>> {
+        debug!("Getting event by ID");
 
-        if !query.event_types.is_empty() {
-            conditions.push("event_type = ANY($EVENT_TYPES)");
-        }
+        let row = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata
+            FROM registry_events
+            WHERE event_id = $1
+            "#,
+        )
+        .bind(event_id)
+        .fetch_optional(&self.pool)
+        .await?;
 
-        if query.actor.is_some() {
-            conditions.push("actor = $ACTOR");
-        }
+        row.map(row_to_event).transpose()
+    }
 
-        if query.after.is_some() {
-            conditions.push("timestamp > $AFTER");
-        }
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
+        debug!("Getting events for asset");
 
-        if query.before.is_some() {
-            conditions.push("timestamp < $BEFORE");
-        }
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata
+            FROM registry_events
+            WHERE asset_id = $1
+            ORDER BY timestamp DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(&asset_id.to_string())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
 
-        if !conditions.is_empty() {
-            sql.push_str(" AND ");
-            sql.push_str(&conditions.join(" AND "));
-        }
+        let events: Result<Vec<RegistryEvent>, DbError> =
+            rows.into_iter().map(row_to_event).collect();
 
-        sql.push_str(" ORDER BY timestamp DESC");
-        sql.push_str(&format!(" LIMIT {} OFFSET {}", query.limit, query.offset));
+        events
+    }
 
-        // Create string bindings before building query to ensure proper lifetimes
-        let asset_id_str = query.asset_id.as_ref().map(|id| id.to_string());
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn get_latest_event(&self, asset_id: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        debug!("Getting latest event for asset");
 
-        // Build query dynamically
-        let mut db_query = sqlx::query(&sql);
+        let row = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata
+            FROM registry_events
+            WHERE asset_id = $1
+            ORDER BY timestamp DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(&asset_id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
 
-        if let Some(ref id_str) = asset_id_str {
-            db_query = db_query.bind(id_str);
+        match row {
+            Some(row) => Ok(Some(row_to_event(row)?)),
+            None => Ok(None),
         }
+    }
 
-        if !query.event_types.is_empty() {
-            db_query = db_query.bind(&query.event_types);
-        }
+    async fn count_events(&self) -> DbResult<i64> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM registry_events")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("count"))
+    }
+
+    async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
+        let row =
+            sqlx::query("SELECT COUNT(*) as count FROM registry_events WHERE event_type = $1")
+                .bind(event_type)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(row.get("count"))
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[instrument(skip(self))]
+    async fn chain_events(&self, after_sequence: u64, limit: i64) -> DbResult<Vec<ChainedEvent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata,
+                chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE chain_sequence > $1
+            ORDER BY chain_sequence
+            LIMIT $2
+            "#,
+        )
+        .bind(after_sequence as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let link = row_to_link(&row)?.ok_or_else(|| {
+                    DbError::InvalidData("Chained event has incomplete chain columns".to_string())
+                })?;
+                Ok(ChainedEvent {
+                    link,
+                    event: row_to_event(row)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn chain_head(&self) -> DbResult<Option<ChainLink>> {
+        let mut conn = self.pool.acquire().await?;
+        Self::fetch_chain_head(&mut conn).await
+    }
+
+    async fn chain_link(&self, event_id: &str) -> DbResult<Option<ChainLink>> {
+        let row = sqlx::query(
+            r#"
+            SELECT chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE event_id = $1
+            "#,
+        )
+        .bind(event_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| row_to_link(&row))
+            .transpose()
+            .map(Option::flatten)
+    }
+
+    #[instrument(skip(self, checkpoint), fields(sequence = checkpoint.sequence))]
+    async fn save_checkpoint(&self, checkpoint: &ChainCheckpoint) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO event_chain_checkpoints (sequence, hash, key_id, signature, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(checkpoint.sequence as i64)
+        .bind(&checkpoint.hash)
+        .bind(&checkpoint.key_id)
+        .bind(&checkpoint.signature)
+        .bind(checkpoint.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn checkpoints(&self) -> DbResult<Vec<ChainCheckpoint>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT sequence, hash, key_id, signature, created_at
+            FROM event_chain_checkpoints
+            ORDER BY sequence
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ChainCheckpoint {
+                sequence: row.get::<i64, _>("sequence") as u64,
+                hash: row.get("hash"),
+                key_id: row.get("key_id"),
+                signature: row.get("signature"),
+                created_at: row.get("created_at"),
+            })
+            .collect())
+    }
+}
+
+/// Append the WHERE conditions for an event query
+///
+/// Shared by the query and count statements so the reported total always
+/// matches the filters applied to the page.
+fn push_pg_event_filters(builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, query: &EventQuery) {
+    if let Some(ref asset_id) = query.asset_id {
+        builder
+            .push(" AND asset_id = ")
+            .push_bind(asset_id.to_string());
+    }
+
+    if !query.event_types.is_empty() {
+        builder
+            .push(" AND event_type = ANY(")
+            .push_bind(query.event_types.clone())
+            .push(")");
+    }
+
+    if let Some(ref actor) = query.actor {
+        builder.push(" AND actor = ").push_bind(actor.clone());
+    }
+
+    if let Some(after) = query.after {
+        builder.push(" AND timestamp > ").push_bind(after);
+    }
+
+    if let Some(before) = query.before {
+        builder.push(" AND timestamp < ").push_bind(before);
+    }
+}
+
+/// Read the audit chain link stored with an event row
+fn row_to_link(row: &PgRow) -> DbResult<Option<ChainLink>> {
+    ChainLink::from_columns(
+        row.get("chain_sequence"),
+        row.get("chain_algorithm"),
+        row.get("chain_prev_hash"),
+        row.get("chain_hash"),
+    )
+}
+
+/// Convert database row to RegistryEvent
+pub(crate) fn row_to_event(row: PgRow) -> DbResult<RegistryEvent> {
+    let event_id: String = row.get("event_id");
+    let payload: JsonValue = row.get("payload");
+    let event_type: EventType = serde_json::from_value(payload)
+        .map_err(|e| DbError::Serialization(format!("Failed to parse event type: {}", e)))?;
+
+    let timestamp: DateTime<Utc> = row.get("timestamp");
+    let actor: Option<String> = row.get("actor");
+
+    let metadata_json: JsonValue = row.get("metadata");
+    let context =
+        serde_json::from_value(metadata_json).unwrap_or_else(|_| std::collections::HashMap::new());
+
+    Ok(RegistryEvent {
+        event_id,
+        event_type,
+        timestamp,
+        correlation_id: None,
+        actor,
+        source: None,
+        context,
+    })
+}
+
+/// SQLite implementation of EventStore
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone)]
+pub struct SqliteEventStore {
+    pool: sqlx::SqlitePool,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteEventStore {
+    /// Create a new SQLite event store
+    pub fn new(pool: sqlx::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get a reference to the connection pool
+    pub fn pool(&self) -> &sqlx::SqlitePool {
+        &self.pool
+    }
+
+    /// Insert an event and queue it in the outbox on the given connection
+    ///
+    /// The event is linked to the audit chain head. SQLite serializes
+    /// writers, and the unique chain sequence rejects a forked append.
+    pub(crate) async fn insert_event(
+        conn: &mut sqlx::SqliteConnection,
+        event: &RegistryEvent,
+    ) -> DbResult<()> {
+        let head = Self::fetch_chain_head(&mut *conn).await?;
+        let link = ChainLink::next(head.as_ref(), event)?;
 
-        if let Some(ref actor) = query.actor {
-            db_query = db_query.bind(actor);
+        sqlx::query(
+            r#"
+            INSERT INTO registry_events (
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata,
+                chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&event.event_id)
+        .bind(event.event_type.event_name())
+        .bind(event.event_type.asset_id().map(|id| id.to_string()))
+        .bind(event.timestamp)
+        .bind(event.actor.as_deref().unwrap_or("system"))
+        .bind(serde_json::to_string(&event.event_type)?)
+        .bind(serde_json::to_string(&event.context)?)
+        .bind(link.sequence as i64)
+        .bind(link.algorithm.to_string())
+        .bind(&link.prev_hash)
+        .bind(&link.hash)
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query("INSERT INTO event_outbox (event_id) VALUES (?)")
+            .bind(&event.event_id)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read the audit chain head on the given connection
+    async fn fetch_chain_head(conn: &mut sqlx::SqliteConnection) -> DbResult<Option<ChainLink>> {
+        let row = sqlx::query(
+            r#"
+            SELECT chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE chain_sequence IS NOT NULL
+            ORDER BY chain_sequence DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        row.map(|row| sqlite_row_to_link(&row))
+            .transpose()
+            .map(Option::flatten)
+    }
+}
+
+/// Append the WHERE conditions for an event query
+#[cfg(feature = "sqlite")]
+fn push_event_filters(builder: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>, query: &EventQuery) {
+    if let Some(ref asset_id) = query.asset_id {
+        builder
+            .push(" AND asset_id = ")
+            .push_bind(asset_id.to_string());
+    }
+
+    if !query.event_types.is_empty() {
+        builder.push(" AND event_type IN (");
+        let mut separated = builder.separated(", ");
+        for event_type in &query.event_types {
+            separated.push_bind(event_type.clone());
         }
+        separated.push_unseparated(")");
+    }
+
+    if let Some(ref actor) = query.actor {
+        builder.push(" AND actor = ").push_bind(actor.clone());
+    }
 
-        if let Some(after) = query.after {
-            db_query = db_query.bind(after);
+    if let Some(after) = query.after {
+        builder.push(" AND timestamp > ").push_bind(after);
+    }
+
+    if let Some(before) = query.before {
+        builder.push(" AND timestamp < ").push_bind(before);
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl EventStore for SqliteEventStore {
+    #[instrument(skip(self, event))]
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        debug!("Appending event to store");
+
+        let mut tx = self.pool.begin().await?;
+        Self::insert_event(&mut tx, &event).await?;
+        tx.commit().await?;
+
+        debug!("Event appended successfully");
+        Ok(event)
+    }
+
+    #[instrument(skip(self, events), fields(count = events.len()))]
+    async fn append_batch(&self, events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        debug!("Appending batch of events");
+
+        if events.is_empty() {
+            return Ok(Vec::new());
         }
 
-        if let Some(before) = query.before {
-            db_query = db_query.bind(before);
+        let mut tx = self.pool.begin().await?;
+
+        for event in &events {
+            Self::insert_event(&mut tx, event).await?;
         }
 
-        let rows = db_query.fetch_all(&self.pool).await?;
+        tx.commit().await?;
 
-        let events: Result<Vec<RegistryEvent>, DbError> =
-            rows.into_iter().map(row_to_event).collect();
+        debug!("Event batch appended successfully");
+        Ok(events)
+    }
+
+    #[instrument(skip(self, query))]
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        debug!("Querying events");
+
+        let mut builder = sqlx::QueryBuilder::new(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata
+            FROM registry_events
+            WHERE 1=1
+            "#,
+        );
+        push_event_filters(&mut builder, query);
+        if let Some(cursor) = query.resume_after()? {
+            push_keyset_condition(
+                &mut builder,
+                "timestamp",
+                "event_id",
+                cursor,
+                query.sort_order,
+            );
+        }
+        builder.push(query.order_by());
+        builder.push(" LIMIT ").push_bind(query.fetch_limit());
+        builder.push(" OFFSET ").push_bind(query.effective_offset());
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let events = rows
+            .into_iter()
+            .map(sqlite_row_to_event)
+            .collect::<DbResult<Vec<_>>>()?;
+
+        let total = if query.include_total {
+            let mut count_builder =
+                sqlx::QueryBuilder::new("SELECT COUNT(*) AS count FROM registry_events WHERE 1=1");
+            push_event_filters(&mut count_builder, query);
+            Some(
+                count_builder
+                    .build()
+                    .fetch_one(&self.pool)
+                    .await?
+                    .get("count"),
+            )
+        } else {
+            None
+        };
 
-        let events = events?;
+        Ok(EventQueryResults::page(query, events, total))
+    }
 
-        let total = self.count_query_results(query).await?;
+    #[instrument(skip(self))]
+    async fn get_event(&self, event_id: &str) -> DbResult<Option<RegistryEvent>> {
+        debug!("Getting event by ID");
 
-        Ok(EventQueryResults {
-            events,
-            total,
-            offset: query.offset,
-            limit: query.limit,
-        })
+        let row = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata
+            FROM registry_events
+            WHERE event_id = ?
+            "#,
+        )
+        .bind(event_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(sqlite_row_to_event).transpose()
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn get_asset_events(&self, asset_id: &AssetId, limit: i64) -> DbResult<Vec<RegistryEvent>> {
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
         debug!("Getting events for asset");
 
         let rows = sqlx::query(
@@ -363,20 +1015,17 @@ impl EventStore for PostgresEventStore {
                 event_id, event_type, asset_id, timestamp,
                 actor, payload, metadata
             FROM registry_events
-            WHERE asset_id = $1
+            WHERE asset_id = ?
             ORDER BY timestamp DESC
-            LIMIT $2
+            LIMIT ?
             "#,
         )
-        .bind(&asset_id.to_string())
+        .bind(asset_id.to_string())
         .bind(limit)
         .fetch_all(&self.pool)
         .await?;
 
-        let events: Result<Vec<RegistryEvent>, DbError> =
-            rows.into_iter().map(row_to_event).collect();
-
-        events
+        rows.into_iter().map(sqlite_row_to_event).collect()
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
@@ -389,19 +1038,16 @@ impl EventStore for PostgresEventStore {
                 event_id, event_type, asset_id, timestamp,
                 actor, payload, metadata
             FROM registry_events
-            WHERE asset_id = $1
+            WHERE asset_id = ?
             ORDER BY timestamp DESC
             LIMIT 1
             "#,
         )
-        .bind(&asset_id.to_string())
+        .bind(asset_id.to_string())
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => Ok(Some(row_to_event(row)?)),
-            None => Ok(None),
-        }
+        row.map(sqlite_row_to_event).transpose()
     }
 
     async fn count_events(&self) -> DbResult<i64> {
@@ -413,7 +1059,7 @@ impl EventStore for PostgresEventStore {
     }
 
     async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM registry_events WHERE event_type = $1")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM registry_events WHERE event_type = ?")
             .bind(event_type)
             .fetch_one(&self.pool)
             .await?;
@@ -428,54 +1074,132 @@ impl EventStore for PostgresEventStore {
             .map(|_| ())
             .map_err(Into::into)
     }
-}
 
-impl PostgresEventStore {
-    /// Count query results without pagination
-    async fn count_query_results(&self, query: &EventQuery) -> DbResult<i64> {
-        let mut sql = String::from("SELECT COUNT(*) as count FROM registry_events WHERE 1=1");
+    #[instrument(skip(self))]
+    async fn chain_events(&self, after_sequence: u64, limit: i64) -> DbResult<Vec<ChainedEvent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                event_id, event_type, asset_id, timestamp,
+                actor, payload, metadata,
+                chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE chain_sequence > ?
+            ORDER BY chain_sequence
+            LIMIT ?
+            "#,
+        )
+        .bind(after_sequence as i64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
 
-        if query.asset_id.is_some() {
-            sql.push_str(" AND asset_id = $1");
-        }
+        rows.into_iter()
+            .map(|row| {
+                let link = sqlite_row_to_link(&row)?.ok_or_else(|| {
+                    DbError::InvalidData("Chained event has incomplete chain columns".to_string())
+                })?;
+                Ok(ChainedEvent {
+                    link,
+                    event: sqlite_row_to_event(row)?,
+                })
+            })
+            .collect()
+    }
 
-        if query.actor.is_some() {
-            sql.push_str(" AND actor = $2");
-        }
+    async fn chain_head(&self) -> DbResult<Option<ChainLink>> {
+        let mut conn = self.pool.acquire().await?;
+        Self::fetch_chain_head(&mut conn).await
+    }
 
-        // Create string bindings before building query to ensure proper lifetimes
-        let asset_id_str = query.asset_id.as_ref().map(|id| id.to_string());
+    async fn chain_link(&self, event_id: &str) -> DbResult<Option<ChainLink>> {
+        let row = sqlx::query(
+            r#"
+            SELECT chain_sequence, chain_algorithm, chain_prev_hash, chain_hash
+            FROM registry_events
+            WHERE event_id = ?
+            "#,
+        )
+        .bind(event_id)
+        .fetch_optional(&self.pool)
+        .await?;
 
-        let mut db_query = sqlx::query(&sql);
+        row.map(|row| sqlite_row_to_link(&row))
+            .transpose()
+            .map(Option::flatten)
+    }
 
-        if let Some(ref id_str) = asset_id_str {
-            db_query = db_query.bind(id_str);
-        }
+    #[instrument(skip(self, checkpoint), fields(sequence = checkpoint.sequence))]
+    async fn save_checkpoint(&self, checkpoint: &ChainCheckpoint) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO event_chain_checkpoints (sequence, hash, key_id, signature, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(checkpoint.sequence as i64)
+        .bind(&checkpoint.hash)
+        .bind(&checkpoint.key_id)
+        .bind(&checkpoint.signature)
+        .bind(checkpoint.created_at)
+        .execute(&self.pool)
+        .await?;
 
-        if let Some(ref actor) = query.actor {
-            db_query = db_query.bind(actor);
-        }
+        Ok(())
+    }
 
-        let row = db_query.fetch_one(&self.pool).await?;
+    async fn checkpoints(&self) -> DbResult<Vec<ChainCheckpoint>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT sequence, hash, key_id, signature, created_at
+            FROM event_chain_checkpoints
+            ORDER BY sequence
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
 
-        Ok(row.get("count"))
+        Ok(rows
+            .into_iter()
+            .map(|row| ChainCheckpoint {
+                sequence: row.get::<i64, _>("sequence") as u64,
+                hash: row.get("hash"),
+                key_id: row.get("key_id"),
+                signature: row.get("signature"),
+                created_at: row.get("created_at"),
+            })
+            .collect())
     }
 }
 
-/// Convert database row to RegistryEvent
-fn row_to_event(row: PgRow) -> DbResult<RegistryEvent> {
-    let payload: JsonValue = row.get("payload");
-    let event_type: EventType = serde_json::from_value(payload)
+/// Read the audit chain link stored with an SQLite event row
+#[cfg(feature = "sqlite")]
+fn sqlite_row_to_link(row: &sqlx::sqlite::SqliteRow) -> DbResult<Option<ChainLink>> {
+    ChainLink::from_columns(
+        row.get("chain_sequence"),
+        row.get("chain_algorithm"),
+        row.get("chain_prev_hash"),
+        row.get("chain_hash"),
+    )
+}
+
+/// Convert SQLite row to RegistryEvent
+#[cfg(feature = "sqlite")]
+pub(crate) fn sqlite_row_to_event(row: sqlx::sqlite::SqliteRow) -> DbResult<RegistryEvent> {
+    let event_id: String = row.get("event_id");
+    let payload: String = row.get("payload");
+    let event_type: EventType = serde_json::from_str(&payload)
         .map_err(|e| DbError::Serialization(format!("Failed to parse event type: {}", e)))?;
 
     let timestamp: DateTime<Utc> = row.get("timestamp");
     let actor: Option<String> = row.get("actor");
 
-    let metadata_json: JsonValue = row.get("metadata");
-    let context = serde_json::from_value(metadata_json)
-        .unwrap_or_else(|_| std::collections::HashMap::new());
+    let metadata: String = row.get("metadata");
+    let context =
+        serde_json::from_str(&metadata).unwrap_or_else(|_| std::collections::HashMap::new());
 
     Ok(RegistryEvent {
+        event_id,
         event_type,
         timestamp,
         correlation_id: None,
@@ -508,13 +1232,136 @@ mod tests {
     fn test_event_query_results_has_more() {
         let results = EventQueryResults {
             events: vec![],
-            total: 100,
+            total: Some(100),
             offset: 0,
             limit: 50,
+            next_cursor: None,
         };
 
         assert_eq!(results.count(), 0);
         // With offset 0 and 0 events, offset + count (0) < total (100), so has_more = true
         assert!(results.has_more());
     }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_sqlite_event_store_append_and_query() {
+        use crate::pool::{create_sqlite_pool, PoolConfig};
+
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        let store = SqliteEventStore::new(pool);
+
+        let asset_id = AssetId::new();
+        let registered = RegistryEvent::builder(EventType::AssetRegistered {
+            asset_id,
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+            asset_type: "model".to_string(),
+            snapshot: None,
+        })
+        .actor("alice")
+        .build();
+        let deleted = RegistryEvent::new(EventType::AssetDeleted {
+            asset_id,
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        });
+
+        let registered_id = registered.event_id.clone();
+        store.append(registered).await.unwrap();
+        store.append_batch(vec![deleted]).await.unwrap();
+
+        assert_eq!(store.count_events().await.unwrap(), 2);
+        assert_eq!(store.count_by_type("asset_deleted").await.unwrap(), 1);
+
+        let results = store
+            .query(&EventQuery::new().asset_id(asset_id).actor("alice"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+        assert_eq!(results.events[0].event_name(), "asset_registered");
+
+        let results = store
+            .query(
+                &EventQuery::new()
+                    .event_type("asset_deleted")
+                    .event_type("asset_registered"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(2));
+
+        assert_eq!(
+            store.get_asset_events(&asset_id, 10).await.unwrap().len(),
+            2
+        );
+        assert!(store.get_latest_event(&asset_id).await.unwrap().is_some());
+
+        let fetched = store.get_event(&registered_id).await.unwrap().unwrap();
+        assert_eq!(fetched.event_id, registered_id);
+        assert_eq!(fetched.actor.as_deref(), Some("alice"));
+        assert!(store.get_event("missing").await.unwrap().is_none());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_sqlite_event_store_cursor_pagination() {
+        use crate::pool::{create_sqlite_pool, PoolConfig};
+
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        let store = SqliteEventStore::new(pool);
+
+        // Several events share a timestamp, so pages must break ties by ID
+        let base = Utc::now();
+        let events: Vec<RegistryEvent> = (0..5)
+            .map(|i| {
+                let mut event = RegistryEvent::new(EventType::AssetDeleted {
+                    asset_id: AssetId::new(),
+                    asset_name: format!("model-{}", i),
+                    asset_version: "1.0.0".to_string(),
+                });
+                event.timestamp = base + chrono::Duration::seconds(i / 2);
+                event
+            })
+            .collect();
+        store.append_batch(events.clone()).await.unwrap();
+
+        let first = store
+            .query(&EventQuery::new().limit(2).include_total(false))
+            .await
+            .unwrap();
+        assert_eq!(first.total, None);
+        assert_eq!(first.count(), 2);
+        assert!(first.has_more());
+
+        // Events appended after the first page do not shift later pages
+        let mut newer = RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "newer".to_string(),
+            asset_version: "1.0.0".to_string(),
+        });
+        newer.timestamp = base + chrono::Duration::seconds(10);
+        store.append(newer).await.unwrap();
+
+        let mut seen: Vec<String> = first.events.iter().map(|e| e.event_id.clone()).collect();
+        let mut cursor = first.next_cursor;
+        while let Some(next) = cursor {
+            let page = store
+                .query(&EventQuery::new().limit(2).cursor(next))
+                .await
+                .unwrap();
+            assert_eq!(page.total, Some(6));
+            seen.extend(page.events.iter().map(|e| e.event_id.clone()));
+            cursor = page.next_cursor;
+        }
+
+        let mut expected = events;
+        expected.sort_by(|a, b| (b.timestamp, &b.event_id).cmp(&(a.timestamp, &a.event_id)));
+        let expected: Vec<String> = expected.into_iter().map(|e| e.event_id).collect();
+        assert_eq!(seen, expected);
+    }
 }
@@ -4,7 +4,14 @@
 //! - Connection pool management with deadpool
 //! - Repository trait abstractions for assets
 //! - PostgreSQL implementation with SQLx
+//! - SQLite implementation for local development and CI (`sqlite` feature)
+//! - In-memory implementation for embedding and tests
 //! - Event store for audit trails and event sourcing
+//! - Unit of work for writing assets and events in one transaction
+//! - Transactional outbox relay publishing committed events to NATS
+//! - In-process broadcasting of appended events
+//! - Webhook subscriptions and their delivery log
+//! - Blob storage for asset content on the filesystem or S3-compatible stores
 //! - Database migrations
 //! - Comprehensive error handling
 //!
@@ -42,30 +49,85 @@
 pub use llm_registry_core;
 
 // Public modules
+pub mod audit_chain;
+pub mod blob_store;
+pub mod broadcast;
 pub mod cache;
+pub mod cached_repository;
+pub mod cursor;
 pub mod error;
 pub mod event_store;
+pub mod memory;
 pub mod nats_publisher;
+pub mod nats_subscriber;
+pub mod outbox;
 pub mod pool;
 pub mod postgres;
+pub mod projection;
 pub mod repository;
+pub mod s3;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+pub mod unit_of_work;
+pub mod webhook;
 
 // Re-exports for convenience
+pub use audit_chain::{
+    verify_chain, BreakKind, BrokenLink, ChainCheckpoint, ChainLink, ChainVerification,
+    ChainedEvent, CHAIN_HASH_ALGORITHM,
+};
+pub use blob_store::{BlobStore, ByteRange, ByteStream, FileSystemBlobStore};
+pub use broadcast::{BroadcastEventStore, BroadcastUnitOfWork, DEFAULT_BROADCAST_CAPACITY};
 pub use cache::{CacheConfig, CacheStats, RedisCache};
+pub use cached_repository::{
+    AssetCache, CacheMetrics, CachedAssetRepository, CachedUnitOfWork, LocalCacheConfig,
+    DEFAULT_INVALIDATION_SUBJECT,
+};
+pub use cursor::{Cursor, CursorKey};
 pub use error::{DbError, DbResult};
+#[cfg(feature = "sqlite")]
+pub use event_store::SqliteEventStore;
 pub use event_store::{EventQuery, EventQueryResults, EventStore, PostgresEventStore};
+pub use memory::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
 pub use nats_publisher::{
-    EventMessage, NatsEventPublisher, NatsPublisherConfig, NatsSubscriberConfig,
+    connect as connect_nats, DurableConsumer, EventMessage, NatsEventPublisher,
+    NatsPublisherConfig, NatsSubscriberConfig,
+};
+pub use nats_subscriber::{EventHandler, EventSubscriber, HandlerError};
+pub use outbox::{
+    EventPublisher, FanoutPublisher, OutboxEntry, OutboxRelay, OutboxRelayConfig, OutboxStore,
 };
 pub use pool::{
-    close_pool, create_pool, get_pool_stats, run_migrations, verify_pool_health, PoolConfig,
-    PoolStats,
+    close_pool, create_pool, get_pool_stats, run_migrations, verify_pool_health, DatabaseBackend,
+    PoolConfig, PoolStats,
 };
+#[cfg(feature = "sqlite")]
+pub use pool::{create_sqlite_pool, run_sqlite_migrations};
 pub use postgres::PostgresAssetRepository;
-pub use repository::{AssetRepository, SearchQuery, SearchResults, SortField, SortOrder};
+pub use projection::{AsOf, AssetProjection, AssetRevision, ProjectionEngine};
+pub use repository::{
+    AssetRepository, DependencyLink, SearchQuery, SearchResults, SortField, SortOrder, SNIPPET_END,
+    SNIPPET_START,
+};
+pub use s3::{S3BlobStore, S3Config, DEFAULT_PART_SIZE};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteAssetRepository;
+#[cfg(feature = "sqlite")]
+pub use unit_of_work::SqliteUnitOfWork;
+pub use unit_of_work::{PostgresUnitOfWork, UnitOfWork, WriteTransaction};
+#[cfg(feature = "sqlite")]
+pub use webhook::SqliteWebhookStore;
+pub use webhook::{
+    DeliveryStatus, InMemoryWebhookStore, PostgresWebhookStore, Webhook, WebhookDelivery,
+    WebhookStore,
+};
 
 // Re-export sqlx types that users may need
 pub use sqlx::postgres::PgPool;
+#[cfg(feature = "sqlite")]
+pub use sqlx::sqlite::SqlitePool;
 
 /// Database layer version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
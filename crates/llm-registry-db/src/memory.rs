@@ -0,0 +1,1452 @@
+//! In-memory implementations of AssetRepository, EventStore and UnitOfWork
+//!
+//! These implementations keep all state in process memory behind a lock and
+//! honour the same semantics as the SQL backends (uniqueness, cascading
+//! deletes, cycle detection, search filters, sorting and pagination). They are
+//! intended for embedding the registry in-process and for fast, realistic tests.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use llm_registry_core::{Asset, AssetId, AssetReference, AssetType, RegistryEvent};
+use semver::Version;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, instrument};
+
+use crate::audit_chain::{ChainCheckpoint, ChainLink, ChainedEvent};
+use crate::error::{DbError, DbResult};
+use crate::event_store::{EventQuery, EventQueryResults, EventStore};
+use crate::repository::{
+    highlight_snippet, search_terms, text_relevance, AssetRepository, DependencyLink, SearchQuery,
+    SearchResults, SortField, SortOrder,
+};
+use crate::unit_of_work::{UnitOfWork, WriteTransaction};
+
+/// A stored dependency edge
+#[derive(Debug, Clone)]
+struct DependencyEdge {
+    dependency_id: AssetId,
+    version_constraint: Option<String>,
+}
+
+/// Repository state guarded by a single lock
+#[derive(Debug, Default, Clone)]
+struct RepositoryState {
+    /// Assets by ID (tags and dependencies are kept in the maps below)
+    assets: HashMap<AssetId, Asset>,
+    /// Tags per asset, kept sorted
+    tags: HashMap<AssetId, BTreeSet<String>>,
+    /// Outgoing dependency edges per asset, in insertion order
+    dependencies: HashMap<AssetId, Vec<DependencyEdge>>,
+}
+
+impl RepositoryState {
+    /// Materialize an asset with its tags and dependencies
+    fn load(&self, id: &AssetId) -> Option<Asset> {
+        let mut asset = self.assets.get(id)?.clone();
+
+        asset.metadata.tags = self
+            .tags
+            .get(id)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default();
+
+        asset.dependencies = self
+            .dependencies
+            .get(id)
+            .map(|edges| {
+                edges
+                    .iter()
+                    .map(|edge| AssetReference::by_id(edge.dependency_id))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(asset)
+    }
+
+    /// Insert a new asset, enforcing uniqueness and dependency existence
+    fn insert(&mut self, asset: &Asset) -> DbResult<()> {
+        if self.assets.contains_key(&asset.id) {
+            return Err(DbError::AlreadyExists(format!(
+                "Asset {} already exists",
+                asset.id
+            )));
+        }
+
+        if self
+            .find_name_version(&asset.metadata.name, &asset.metadata.version)
+            .is_some()
+        {
+            return Err(DbError::AlreadyExists(format!(
+                "Asset {}@{} already exists",
+                asset.metadata.name, asset.metadata.version
+            )));
+        }
+
+        let mut edges: Vec<DependencyEdge> = Vec::new();
+        for dep in &asset.dependencies {
+            let dep_id = dep.as_id().ok_or_else(|| {
+                DbError::InvalidData(
+                    "Dependency must be resolved to ID before persisting".to_string(),
+                )
+            })?;
+
+            if !self.assets.contains_key(dep_id) {
+                return Err(DbError::ForeignKeyViolation(format!(
+                    "Dependency {} does not exist",
+                    dep_id
+                )));
+            }
+
+            if !edges.iter().any(|e| &e.dependency_id == dep_id) {
+                edges.push(DependencyEdge {
+                    dependency_id: *dep_id,
+                    version_constraint: dep.as_name_version().map(|(_, v)| v.to_string()),
+                });
+            }
+        }
+
+        let tags: BTreeSet<String> = asset.metadata.tags.iter().cloned().collect();
+
+        let mut stored = asset.clone();
+        stored.metadata.tags.clear();
+        stored.dependencies.clear();
+
+        self.assets.insert(asset.id, stored);
+        if !tags.is_empty() {
+            self.tags.insert(asset.id, tags);
+        }
+        if !edges.is_empty() {
+            self.dependencies.insert(asset.id, edges);
+        }
+
+        Ok(())
+    }
+
+    /// Update an existing asset and replace its tags
+    ///
+    /// Dependencies are managed separately and left unchanged. The stored
+    /// revision must equal `asset.revision` and is incremented.
+    fn update(&mut self, asset: &Asset) -> DbResult<()> {
+        let current = match self.assets.get(&asset.id) {
+            Some(existing) => existing.revision,
+            None => return Err(DbError::NotFound(format!("Asset {} not found", asset.id))),
+        };
+        if current != asset.revision {
+            return Err(DbError::revision_conflict(
+                asset.id,
+                asset.revision,
+                current,
+            ));
+        }
+
+        if let Some(existing) =
+            self.find_name_version(&asset.metadata.name, &asset.metadata.version)
+        {
+            if existing != asset.id {
+                return Err(DbError::UniqueViolation(format!(
+                    "Asset {}@{} already exists",
+                    asset.metadata.name, asset.metadata.version
+                )));
+            }
+        }
+
+        let tags: BTreeSet<String> = asset.metadata.tags.iter().cloned().collect();
+
+        let mut stored = asset.clone();
+        stored.metadata.tags.clear();
+        stored.dependencies.clear();
+        stored.updated_at = Utc::now();
+        stored.revision += 1;
+
+        self.assets.insert(asset.id, stored);
+        if tags.is_empty() {
+            self.tags.remove(&asset.id);
+        } else {
+            self.tags.insert(asset.id, tags);
+        }
+
+        Ok(())
+    }
+
+    /// Bump an asset's revision after its tags changed
+    fn touch_tags(&mut self, id: &AssetId) {
+        if let Some(asset) = self.assets.get_mut(id) {
+            asset.updated_at = Utc::now();
+            asset.revision += 1;
+        }
+    }
+
+    /// Delete an asset, cascading to tags and dependency edges in both directions
+    fn delete(&mut self, id: &AssetId) -> DbResult<()> {
+        if self.assets.remove(id).is_none() {
+            return Err(DbError::NotFound(format!("Asset {} not found", id)));
+        }
+
+        self.tags.remove(id);
+        self.dependencies.remove(id);
+        for edges in self.dependencies.values_mut() {
+            edges.retain(|edge| &edge.dependency_id != id);
+        }
+        self.dependencies.retain(|_, edges| !edges.is_empty());
+
+        Ok(())
+    }
+
+    /// Remove an asset and its tags and outgoing dependency edges
+    fn remove(&mut self, id: &AssetId) {
+        self.assets.remove(id);
+        self.tags.remove(id);
+        self.dependencies.remove(id);
+    }
+
+    /// Find the ID of an asset with the given name and version
+    fn find_name_version(&self, name: &str, version: &Version) -> Option<AssetId> {
+        self.assets
+            .values()
+            .find(|a| a.metadata.name == name && &a.metadata.version == version)
+            .map(|a| a.id)
+    }
+
+    /// Check whether `to` can already reach `from` through dependency edges
+    fn would_create_cycle(&self, from: &AssetId, to: &AssetId) -> bool {
+        if from == to {
+            return true;
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![*to];
+
+        while let Some(current) = stack.pop() {
+            if &current == from {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(edges) = self.dependencies.get(&current) {
+                stack.extend(edges.iter().map(|edge| edge.dependency_id));
+            }
+        }
+
+        false
+    }
+
+    /// Check whether an asset matches the structured search filters
+    ///
+    /// Text is matched separately, against the loaded asset, so that tags
+    /// count towards relevance.
+    fn matches(&self, asset: &Asset, query: &SearchQuery) -> bool {
+        if !query.asset_types.is_empty() && !query.asset_types.contains(&asset.asset_type) {
+            return false;
+        }
+
+        if let Some(ref author) = query.author {
+            let asset_author = asset.provenance.as_ref().and_then(|p| p.author.as_ref());
+            if asset_author != Some(author) {
+                return false;
+            }
+        }
+
+        if let Some(ref backend) = query.storage_backend {
+            if &asset.storage.backend.to_string() != backend {
+                return false;
+            }
+        }
+
+        if let Some(ref license) = query.license {
+            if asset.metadata.license.as_ref() != Some(license) {
+                return false;
+            }
+        }
+
+        if query.min_size_bytes.is_some() || query.max_size_bytes.is_some() {
+            let Some(size) = asset.metadata.size_bytes else {
+                return false;
+            };
+            if query.min_size_bytes.is_some_and(|min| size < min)
+                || query.max_size_bytes.is_some_and(|max| size > max)
+            {
+                return false;
+            }
+        }
+
+        if query
+            .created_after
+            .is_some_and(|after| asset.created_at < after)
+            || query
+                .created_before
+                .is_some_and(|before| asset.created_at >= before)
+        {
+            return false;
+        }
+
+        if query.exclude_deprecated && asset.deprecated_at.is_some() {
+            return false;
+        }
+
+        if !query.tags.is_empty() {
+            let Some(tags) = self.tags.get(&asset.id) else {
+                return false;
+            };
+            if !query.tags.iter().all(|t| tags.contains(t)) {
+                return false;
+            }
+        }
+
+        if let Some(tags) = self.tags.get(&asset.id) {
+            if query.exclude_tags.iter().any(|t| tags.contains(t)) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Compare two assets by a sort field, falling back to ID for a stable order
+fn compare_assets(a: &Asset, b: &Asset, field: SortField) -> Ordering {
+    let ordering = match field {
+        SortField::CreatedAt => a.created_at.cmp(&b.created_at),
+        SortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+        SortField::Name => a.metadata.name.cmp(&b.metadata.name),
+        SortField::Version => a.metadata.version.cmp(&b.metadata.version),
+        SortField::SizeBytes => a.metadata.size_bytes.cmp(&b.metadata.size_bytes),
+        SortField::Relevance => a.created_at.cmp(&b.created_at),
+    };
+
+    ordering.then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+}
+
+/// Apply offset and limit to a result set
+fn paginate<T>(items: Vec<T>, offset: i64, limit: i64) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect()
+}
+
+/// In-memory implementation of AssetRepository
+#[derive(Debug, Default)]
+pub struct InMemoryAssetRepository {
+    state: RwLock<RepositoryState>,
+}
+
+impl InMemoryAssetRepository {
+    /// Create a new, empty in-memory asset repository
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AssetRepository for InMemoryAssetRepository {
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id, asset_name = %asset.metadata.name))]
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        debug!("Creating asset in memory");
+
+        self.state.write().await.insert(&asset)?;
+        Ok(asset)
+    }
+
+    async fn create_batch(&self, assets: Vec<Asset>) -> DbResult<Vec<Asset>> {
+        debug!(count = assets.len(), "Creating asset batch in memory");
+
+        let mut state = self.state.write().await;
+        for (index, asset) in assets.iter().enumerate() {
+            if let Err(e) = state.insert(asset) {
+                for inserted in &assets[..index] {
+                    state.remove(&inserted.id);
+                }
+                return Err(e);
+            }
+        }
+
+        Ok(assets)
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
+        Ok(self.state.read().await.load(id))
+    }
+
+    async fn find_by_name_and_version(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> DbResult<Option<Asset>> {
+        let state = self.state.read().await;
+        Ok(state
+            .find_name_version(name, version)
+            .and_then(|id| state.load(&id)))
+    }
+
+    async fn find_by_ids(&self, ids: &[AssetId]) -> DbResult<Vec<Asset>> {
+        let state = self.state.read().await;
+        Ok(ids.iter().filter_map(|id| state.load(id)).collect())
+    }
+
+    #[instrument(skip(self, query))]
+    async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
+        debug!("Searching assets in memory");
+
+        let state = self.state.read().await;
+
+        let terms = query.text.as_deref().map(search_terms).unwrap_or_default();
+        let mut matches: Vec<(Asset, f64)> = state
+            .assets
+            .values()
+            .filter(|asset| state.matches(asset, query))
+            .filter_map(|asset| state.load(&asset.id))
+            .filter_map(|asset| {
+                if terms.is_empty() {
+                    return Some((asset, 0.0));
+                }
+                text_relevance(&asset, &terms).map(|score| (asset, score))
+            })
+            .collect();
+
+        matches.sort_by(|(a, a_score), (b, b_score)| {
+            let ordering = match query.sort_by {
+                SortField::Relevance if !terms.is_empty() => a_score
+                    .total_cmp(b_score)
+                    .then_with(|| compare_assets(a, b, SortField::CreatedAt)),
+                field => compare_assets(a, b, field),
+            };
+            match query.sort_order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+
+        let total = query.counts_total().then_some(matches.len() as i64);
+        if let (Some(cursor), Some(field)) = (query.resume_after()?, query.keyset_field()) {
+            matches.retain(|(asset, _)| {
+                let position = cursor.compare_asset(asset, field);
+                match query.sort_order {
+                    SortOrder::Ascending => position == Ordering::Greater,
+                    SortOrder::Descending => position == Ordering::Less,
+                }
+            });
+        }
+
+        let assets = paginate(matches, query.effective_offset(), query.fetch_limit())
+            .into_iter()
+            .map(|(asset, _)| asset)
+            .collect();
+        let mut results = SearchResults::page(query, assets, total);
+        results.snippets = results
+            .assets
+            .iter()
+            .filter_map(|asset| Some((asset.id, highlight_snippet(asset, &terms)?)))
+            .collect();
+
+        Ok(results)
+    }
+
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
+    async fn update(&self, asset: Asset) -> DbResult<Asset> {
+        debug!("Updating asset in memory");
+
+        self.state.write().await.update(&asset)?;
+        Ok(Asset {
+            revision: asset.revision + 1,
+            ..asset
+        })
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn delete(&self, id: &AssetId) -> DbResult<()> {
+        debug!("Deleting asset from memory");
+
+        self.state.write().await.delete(id)
+    }
+
+    async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>> {
+        let state = self.state.read().await;
+
+        let mut versions: Vec<&Asset> = state
+            .assets
+            .values()
+            .filter(|a| a.metadata.name == name)
+            .collect();
+        versions.sort_by(|a, b| b.metadata.version.cmp(&a.metadata.version));
+
+        Ok(versions
+            .into_iter()
+            .filter_map(|a| state.load(&a.id))
+            .collect())
+    }
+
+    async fn list_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        let state = self.state.read().await;
+
+        Ok(state
+            .dependencies
+            .get(id)
+            .map(|edges| {
+                edges
+                    .iter()
+                    .filter_map(|edge| state.load(&edge.dependency_id))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn list_reverse_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        let state = self.state.read().await;
+
+        let mut dependents: Vec<&AssetId> = state
+            .dependencies
+            .iter()
+            .filter(|(_, edges)| edges.iter().any(|edge| &edge.dependency_id == id))
+            .map(|(asset_id, _)| asset_id)
+            .collect();
+        dependents.sort_by_key(|asset_id| asset_id.to_string());
+
+        Ok(dependents
+            .into_iter()
+            .filter_map(|asset_id| state.load(asset_id))
+            .collect())
+    }
+
+    async fn find_by_names(&self, names: &[String]) -> DbResult<Vec<Asset>> {
+        let state = self.state.read().await;
+        let names: HashSet<&str> = names.iter().map(String::as_str).collect();
+
+        Ok(state
+            .assets
+            .values()
+            .filter(|a| names.contains(a.metadata.name.as_str()))
+            .filter_map(|a| state.load(&a.id))
+            .collect())
+    }
+
+    async fn list_dependency_links(&self, ids: &[AssetId]) -> DbResult<Vec<DependencyLink>> {
+        let state = self.state.read().await;
+
+        Ok(ids
+            .iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .flat_map(|asset_id| {
+                state
+                    .dependencies
+                    .get(asset_id)
+                    .into_iter()
+                    .flatten()
+                    .map(|edge| DependencyLink {
+                        asset_id: *asset_id,
+                        dependency_id: edge.dependency_id,
+                    })
+            })
+            .collect())
+    }
+
+    async fn list_reverse_dependency_links(
+        &self,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        let state = self.state.read().await;
+        let ids: HashSet<&AssetId> = ids.iter().collect();
+
+        Ok(state
+            .dependencies
+            .iter()
+            .flat_map(|(asset_id, edges)| {
+                edges.iter().map(|edge| DependencyLink {
+                    asset_id: *asset_id,
+                    dependency_id: edge.dependency_id,
+                })
+            })
+            .filter(|link| ids.contains(&link.dependency_id))
+            .collect())
+    }
+
+    async fn add_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        let mut state = self.state.write().await;
+
+        if !state.assets.contains_key(id) {
+            return Err(DbError::ForeignKeyViolation(format!(
+                "Asset {} does not exist",
+                id
+            )));
+        }
+
+        if state.tags.entry(*id).or_default().insert(tag.to_string()) {
+            state.touch_tags(id);
+        }
+        Ok(())
+    }
+
+    async fn remove_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        let mut state = self.state.write().await;
+
+        if let Some(tags) = state.tags.get_mut(id) {
+            let removed = tags.remove(tag);
+            if tags.is_empty() {
+                state.tags.remove(id);
+            }
+            if removed {
+                state.touch_tags(id);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_tags(&self, id: &AssetId) -> DbResult<Vec<String>> {
+        let state = self.state.read().await;
+
+        Ok(state
+            .tags
+            .get(id)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn list_all_tags(&self) -> DbResult<Vec<String>> {
+        let state = self.state.read().await;
+
+        let all: BTreeSet<&String> = state.tags.values().flatten().collect();
+        Ok(all.into_iter().cloned().collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn add_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+        version_constraint: Option<&str>,
+    ) -> DbResult<()> {
+        debug!("Adding dependency relationship in memory");
+
+        let mut state = self.state.write().await;
+
+        for id in [asset_id, dependency_id] {
+            if !state.assets.contains_key(id) {
+                return Err(DbError::ForeignKeyViolation(format!(
+                    "Asset {} does not exist",
+                    id
+                )));
+            }
+        }
+
+        if state.would_create_cycle(asset_id, dependency_id) {
+            return Err(DbError::CircularDependency(format!(
+                "Adding dependency from {} to {} would create a cycle",
+                asset_id, dependency_id
+            )));
+        }
+
+        let edges = state.dependencies.entry(*asset_id).or_default();
+        let constraint = version_constraint.map(str::to_string);
+
+        match edges
+            .iter_mut()
+            .find(|edge| &edge.dependency_id == dependency_id)
+        {
+            Some(edge) => edge.version_constraint = constraint,
+            None => edges.push(DependencyEdge {
+                dependency_id: *dependency_id,
+                version_constraint: constraint,
+            }),
+        }
+
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
+        let mut state = self.state.write().await;
+
+        if let Some(edges) = state.dependencies.get_mut(asset_id) {
+            edges.retain(|edge| &edge.dependency_id != dependency_id);
+            if edges.is_empty() {
+                state.dependencies.remove(asset_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn count_assets(&self) -> DbResult<i64> {
+        Ok(self.state.read().await.assets.len() as i64)
+    }
+
+    async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
+        let state = self.state.read().await;
+        Ok(state
+            .assets
+            .values()
+            .filter(|a| &a.asset_type == asset_type)
+            .count() as i64)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        Ok(())
+    }
+}
+
+/// In-memory implementation of EventStore
+///
+/// Events are kept in append order. As with the SQL backends, events without
+/// an actor are stored with the actor `"system"`, and every event is linked
+/// to the audit chain. The chain links are stored alongside the events at
+/// the same index, and are always locked after the events.
+#[derive(Debug, Default)]
+pub struct InMemoryEventStore {
+    events: RwLock<Vec<RegistryEvent>>,
+    links: RwLock<Vec<ChainLink>>,
+    checkpoints: RwLock<Vec<ChainCheckpoint>>,
+}
+
+impl InMemoryEventStore {
+    /// Create a new, empty in-memory event store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalize an event before storing it
+    fn to_stored(event: &RegistryEvent) -> RegistryEvent {
+        let mut stored = event.clone();
+        if stored.actor.is_none() {
+            stored.actor = Some("system".to_string());
+        }
+        stored
+    }
+
+    /// Store events, linking each to the audit chain
+    ///
+    /// Nothing is stored if any event cannot be linked.
+    fn store_all(
+        events: &mut Vec<RegistryEvent>,
+        links: &mut Vec<ChainLink>,
+        appended: &[RegistryEvent],
+    ) -> DbResult<()> {
+        let mut new_links: Vec<ChainLink> = Vec::with_capacity(appended.len());
+        let stored: Vec<RegistryEvent> = appended.iter().map(Self::to_stored).collect();
+        for event in &stored {
+            let head = new_links.last().or(links.last());
+            new_links.push(ChainLink::next(head, event)?);
+        }
+
+        events.extend(stored);
+        links.extend(new_links);
+        Ok(())
+    }
+
+    /// Events matching a predicate, newest first
+    ///
+    /// Events with equal timestamps are ordered by descending event ID, as
+    /// in the SQL event stores.
+    fn newest_first<'a>(
+        events: &'a [RegistryEvent],
+        predicate: impl Fn(&RegistryEvent) -> bool + 'a,
+    ) -> Vec<&'a RegistryEvent> {
+        let mut matching: Vec<&RegistryEvent> = events.iter().filter(|e| predicate(e)).collect();
+        matching.sort_by(|a, b| (b.timestamp, &b.event_id).cmp(&(a.timestamp, &a.event_id)));
+        matching
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        let mut events = self.events.write().await;
+        let mut links = self.links.write().await;
+        Self::store_all(&mut events, &mut links, std::slice::from_ref(&event))?;
+        Ok(event)
+    }
+
+    async fn append_batch(&self, events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        let mut stored = self.events.write().await;
+        let mut links = self.links.write().await;
+        Self::store_all(&mut stored, &mut links, &events)?;
+        Ok(events)
+    }
+
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        let events = self.events.read().await;
+
+        let mut matching = Self::newest_first(&events, |e| query.matches(e));
+        if query.sort_order == SortOrder::Ascending {
+            matching.reverse();
+        }
+        let total = query.include_total.then_some(matching.len() as i64);
+        if let Some(cursor) = query.resume_after()? {
+            let after = match query.sort_order {
+                SortOrder::Ascending => Ordering::Greater,
+                SortOrder::Descending => Ordering::Less,
+            };
+            matching.retain(|e| cursor.compare_event(e) == after);
+        }
+
+        let page = paginate(matching, query.effective_offset(), query.fetch_limit())
+            .into_iter()
+            .cloned()
+            .collect();
+        Ok(EventQueryResults::page(query, page, total))
+    }
+
+    async fn get_event(&self, event_id: &str) -> DbResult<Option<RegistryEvent>> {
+        let events = self.events.read().await;
+        Ok(events.iter().find(|e| e.event_id == event_id).cloned())
+    }
+
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
+        let events = self.events.read().await;
+
+        let matching = Self::newest_first(&events, |e| e.asset_id().as_ref() == Some(asset_id));
+        Ok(paginate(matching, 0, limit).into_iter().cloned().collect())
+    }
+
+    async fn get_latest_event(&self, asset_id: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        Ok(self.get_asset_events(asset_id, 1).await?.into_iter().next())
+    }
+
+    async fn count_events(&self) -> DbResult<i64> {
+        Ok(self.events.read().await.len() as i64)
+    }
+
+    async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
+        let events = self.events.read().await;
+        Ok(events
+            .iter()
+            .filter(|e| e.event_name() == event_type)
+            .count() as i64)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn chain_events(&self, after_sequence: u64, limit: i64) -> DbResult<Vec<ChainedEvent>> {
+        let events = self.events.read().await;
+        let links = self.links.read().await;
+
+        Ok(links
+            .iter()
+            .zip(events.iter())
+            .filter(|(link, _)| link.sequence > after_sequence)
+            .take(usize::try_from(limit).unwrap_or(0))
+            .map(|(link, event)| ChainedEvent {
+                link: link.clone(),
+                event: event.clone(),
+            })
+            .collect())
+    }
+
+    async fn chain_head(&self) -> DbResult<Option<ChainLink>> {
+        Ok(self.links.read().await.last().cloned())
+    }
+
+    async fn chain_link(&self, event_id: &str) -> DbResult<Option<ChainLink>> {
+        let events = self.events.read().await;
+        let links = self.links.read().await;
+
+        Ok(events
+            .iter()
+            .position(|e| e.event_id == event_id)
+            .and_then(|index| links.get(index).cloned()))
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &ChainCheckpoint) -> DbResult<()> {
+        let mut checkpoints = self.checkpoints.write().await;
+        if checkpoints
+            .iter()
+            .any(|c| c.sequence == checkpoint.sequence)
+        {
+            return Err(DbError::UniqueViolation(format!(
+                "Checkpoint at sequence {} already exists",
+                checkpoint.sequence
+            )));
+        }
+        checkpoints.push(checkpoint.clone());
+        checkpoints.sort_by_key(|c| c.sequence);
+        Ok(())
+    }
+
+    async fn checkpoints(&self) -> DbResult<Vec<ChainCheckpoint>> {
+        Ok(self.checkpoints.read().await.clone())
+    }
+}
+
+/// A write buffered by an in-memory transaction
+#[derive(Debug)]
+enum PendingWrite {
+    Create(Asset),
+    Update(Asset),
+    Delete(AssetId),
+}
+
+/// In-memory implementation of UnitOfWork
+///
+/// Writes are buffered and applied on commit to a copy of the repository
+/// state while both the repository and the event store are locked, so other
+/// readers see either all of a transaction's changes or none of them.
+#[derive(Debug, Clone)]
+pub struct InMemoryUnitOfWork {
+    repository: Arc<InMemoryAssetRepository>,
+    event_store: Arc<InMemoryEventStore>,
+}
+
+impl InMemoryUnitOfWork {
+    /// Create a unit of work over an in-memory repository and event store
+    pub fn new(
+        repository: Arc<InMemoryAssetRepository>,
+        event_store: Arc<InMemoryEventStore>,
+    ) -> Self {
+        Self {
+            repository,
+            event_store,
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>> {
+        Ok(Box::new(InMemoryWriteTransaction {
+            unit_of_work: self.clone(),
+            writes: Vec::new(),
+            events: Vec::new(),
+        }))
+    }
+}
+
+/// An open in-memory write transaction
+struct InMemoryWriteTransaction {
+    unit_of_work: InMemoryUnitOfWork,
+    writes: Vec<PendingWrite>,
+    events: Vec<RegistryEvent>,
+}
+
+#[async_trait]
+impl WriteTransaction for InMemoryWriteTransaction {
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        self.writes.push(PendingWrite::Create(asset.clone()));
+        Ok(())
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        self.writes.push(PendingWrite::Update(asset.clone()));
+        Ok(())
+    }
+
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()> {
+        self.writes.push(PendingWrite::Delete(*id));
+        Ok(())
+    }
+
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        self.events.push(event.clone());
+        Ok(())
+    }
+
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>> {
+        let mut state = self.unit_of_work.repository.state.write().await;
+        let mut events = self.unit_of_work.event_store.events.write().await;
+        let mut links = self.unit_of_work.event_store.links.write().await;
+
+        let mut staged = state.clone();
+        for write in &self.writes {
+            match write {
+                PendingWrite::Create(asset) => staged.insert(asset)?,
+                PendingWrite::Update(asset) => staged.update(asset)?,
+                PendingWrite::Delete(id) => staged.delete(id)?,
+            }
+        }
+
+        InMemoryEventStore::store_all(&mut events, &mut links, &self.events)?;
+        *state = staged;
+
+        debug!(
+            writes = self.writes.len(),
+            events = self.events.len(),
+            "Unit of work committed"
+        );
+        Ok(self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cursor::Cursor;
+    use crate::test_support::test_asset;
+    use chrono::Duration;
+    use llm_registry_core::{EventType, Provenance};
+
+    #[tokio::test]
+    async fn test_create_find_and_duplicate() {
+        let repo = InMemoryAssetRepository::new();
+        let mut asset = test_asset("model", "1.0.0");
+        asset.metadata.tags = vec!["b".to_string(), "a".to_string()];
+
+        repo.create(asset.clone()).await.unwrap();
+
+        let found = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert_eq!(found.metadata.tags, vec!["a", "b"]);
+        assert!(repo
+            .find_by_name_and_version("model", &Version::new(1, 0, 0))
+            .await
+            .unwrap()
+            .is_some());
+
+        let err = repo.create(test_asset("model", "1.0.0")).await.unwrap_err();
+        assert!(err.is_already_exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_is_atomic() {
+        let repo = InMemoryAssetRepository::new();
+        let base = test_asset("base", "1.0.0");
+        let mut derived = test_asset("derived", "1.0.0");
+        derived.dependencies = vec![AssetReference::by_id(base.id)];
+
+        repo.create_batch(vec![base.clone(), derived.clone()])
+            .await
+            .unwrap();
+        assert_eq!(repo.list_dependencies(&derived.id).await.unwrap().len(), 1);
+
+        // The duplicate in second position rolls back the first asset too
+        let fresh = test_asset("fresh", "1.0.0");
+        let err = repo
+            .create_batch(vec![fresh.clone(), test_asset("base", "1.0.0")])
+            .await
+            .unwrap_err();
+        assert!(err.is_already_exists());
+        assert!(repo.find_by_id(&fresh.id).await.unwrap().is_none());
+        assert_eq!(repo.count_assets().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commits_atomically() {
+        let repo = Arc::new(InMemoryAssetRepository::new());
+        let events = Arc::new(InMemoryEventStore::new());
+        let uow = InMemoryUnitOfWork::new(repo.clone(), events.clone());
+
+        let asset = test_asset("model", "1.0.0");
+        let deleted = RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: asset.id,
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        });
+
+        let mut tx = uow.begin().await.unwrap();
+        tx.create_asset(&asset).await.unwrap();
+        tx.append_event(&deleted).await.unwrap();
+        assert!(repo.find_by_id(&asset.id).await.unwrap().is_none());
+        assert_eq!(tx.commit().await.unwrap().len(), 1);
+        assert!(repo.find_by_id(&asset.id).await.unwrap().is_some());
+        assert_eq!(events.count_events().await.unwrap(), 1);
+
+        // A failing write discards the whole transaction, events included
+        let mut updated = asset.clone();
+        updated.metadata.description = Some("changed".to_string());
+
+        let mut tx = uow.begin().await.unwrap();
+        tx.update_asset(&updated).await.unwrap();
+        tx.create_asset(&test_asset("model", "1.0.0"))
+            .await
+            .unwrap();
+        tx.append_event(&deleted).await.unwrap();
+        assert!(tx.commit().await.unwrap_err().is_already_exists());
+
+        let stored = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert!(stored.metadata.description.is_none());
+        assert_eq!(events.count_events().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_stale_revision() {
+        let repo = InMemoryAssetRepository::new();
+        let asset = test_asset("model", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        let mut first = asset.clone();
+        first.metadata.tags = vec!["first".to_string()];
+        let mut second = asset.clone();
+        second.metadata.tags = vec!["second".to_string()];
+
+        assert_eq!(repo.update(first).await.unwrap().revision, 2);
+        assert!(repo.update(second).await.unwrap_err().is_conflict());
+
+        let stored = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert_eq!(stored.metadata.tags, vec!["first"]);
+        assert_eq!(stored.revision, 2);
+
+        let missing = test_asset("missing", "1.0.0");
+        assert!(repo.update(missing).await.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_sorting_and_pagination() {
+        let repo = InMemoryAssetRepository::new();
+
+        for (i, name) in ["alpha", "beta", "gamma", "delta"].iter().enumerate() {
+            let mut asset = test_asset(name, "1.0.0");
+            asset.created_at = Utc::now() + Duration::seconds(i as i64);
+            asset.metadata.tags = vec!["nlp".to_string()];
+            if *name == "gamma" {
+                asset.asset_type = AssetType::Dataset;
+                asset.provenance = Some(Provenance::builder().author("alice").build().unwrap());
+            }
+            if *name == "delta" {
+                asset.deprecated_at = Some(Utc::now());
+            }
+            repo.create(asset).await.unwrap();
+        }
+
+        // Default query excludes deprecated assets, newest first
+        let results = repo.search(&SearchQuery::new()).await.unwrap();
+        let names: Vec<_> = results
+            .assets
+            .iter()
+            .map(|a| a.metadata.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["gamma", "beta", "alpha"]);
+
+        let results = repo
+            .search(
+                &SearchQuery::new()
+                    .sort_by(SortField::Name)
+                    .sort_order(SortOrder::Ascending)
+                    .limit(2)
+                    .offset(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(3));
+        let names: Vec<_> = results
+            .assets
+            .iter()
+            .map(|a| a.metadata.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["beta", "gamma"]);
+
+        let results = repo
+            .search(
+                &SearchQuery::new()
+                    .asset_type(AssetType::Dataset)
+                    .author("alice"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+
+        let results = repo
+            .search(&SearchQuery::new().text("ALP").tag("nlp"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+
+        let results = repo
+            .search(&SearchQuery::new().tag("nlp").tag("missing"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(0));
+
+        let results = repo
+            .search(&SearchQuery::new().exclude_deprecated(false))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_search_cursor_pagination() {
+        let repo = InMemoryAssetRepository::new();
+        for version in ["1.10.0", "1.0.0", "1.2.0"] {
+            repo.create(test_asset("model", version)).await.unwrap();
+        }
+
+        let query = SearchQuery::new()
+            .sort_by(SortField::Version)
+            .sort_order(SortOrder::Ascending)
+            .limit(1)
+            .include_total(false);
+        let mut versions = Vec::new();
+        let mut page = repo.search(&query).await.unwrap();
+        loop {
+            assert_eq!(page.total, None);
+            versions.extend(page.assets.iter().map(|a| a.metadata.version.to_string()));
+            let Some(cursor) = page.next_cursor.take() else {
+                break;
+            };
+            page = repo.search(&query.clone().cursor(cursor)).await.unwrap();
+        }
+        assert_eq!(versions, vec!["1.0.0", "1.2.0", "1.10.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_relevance_and_snippets() {
+        let repo = InMemoryAssetRepository::new();
+
+        let mut by_description = test_asset("whisper", "1.0.0");
+        by_description.metadata.description = Some("Speech to text transcription".to_string());
+        by_description.created_at = Utc::now() + Duration::seconds(1);
+        let mut by_name = test_asset("speech-t5", "1.0.0");
+        by_name.metadata.tags = vec!["audio".to_string()];
+        let unrelated = test_asset("resnet", "1.0.0");
+
+        for asset in [&by_description, &by_name, &unrelated] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        let results = repo
+            .search(
+                &SearchQuery::new()
+                    .text("speech")
+                    .sort_by(SortField::Relevance),
+            )
+            .await
+            .unwrap();
+        let ids: Vec<_> = results.assets.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![by_name.id, by_description.id]);
+        assert_eq!(
+            results.snippets[&by_description.id],
+            "<mark>Speech</mark> to text transcription"
+        );
+
+        // Every term must match, across fields
+        let results = repo
+            .search(&SearchQuery::new().text("speech audio"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+        assert_eq!(results.assets[0].id, by_name.id);
+    }
+
+    #[tokio::test]
+    async fn test_search_structured_filters() {
+        let repo = InMemoryAssetRepository::new();
+
+        let mut old = test_asset("old", "1.0.0");
+        old.metadata.size_bytes = Some(1_000);
+        old.metadata.license = Some("mit".to_string());
+        old.metadata.tags = vec!["stable".to_string()];
+        old.created_at = Utc::now() - Duration::days(30);
+        let mut new = test_asset("new", "1.0.0");
+        new.metadata.size_bytes = Some(3_000_000_000);
+        new.metadata.tags = vec!["stable".to_string(), "experimental".to_string()];
+        for asset in [&old, &new] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        let only = |results: SearchResults| {
+            assert_eq!(results.total, Some(1));
+            results.assets[0].id
+        };
+
+        let query = SearchQuery::new().license("mit");
+        assert_eq!(only(repo.search(&query).await.unwrap()), old.id);
+        let query = SearchQuery::new().size_range(Some(2_000_000_000), None);
+        assert_eq!(only(repo.search(&query).await.unwrap()), new.id);
+        let query = SearchQuery::new().created_between(None, Some(Utc::now() - Duration::days(1)));
+        assert_eq!(only(repo.search(&query).await.unwrap()), old.id);
+        let query = SearchQuery::new().exclude_tag("experimental");
+        assert_eq!(only(repo.search(&query).await.unwrap()), old.id);
+    }
+
+    #[tokio::test]
+    async fn test_dependencies_cycles_and_cascade() {
+        let repo = InMemoryAssetRepository::new();
+        let a = test_asset("a", "1.0.0");
+        let b = test_asset("b", "1.0.0");
+        let c = test_asset("c", "1.0.0");
+        for asset in [&a, &b, &c] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        repo.add_dependency(&a.id, &b.id, None).await.unwrap();
+        repo.add_dependency(&b.id, &c.id, Some("^1.0"))
+            .await
+            .unwrap();
+
+        assert_eq!(repo.list_dependencies(&a.id).await.unwrap()[0].id, b.id);
+        assert_eq!(
+            repo.list_reverse_dependencies(&c.id).await.unwrap()[0].id,
+            b.id
+        );
+        assert_eq!(
+            repo.find_by_id(&a.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .dependencies
+                .len(),
+            1
+        );
+
+        let mut links = repo
+            .list_dependency_links(&[a.id, b.id, a.id])
+            .await
+            .unwrap();
+        links.sort_by_key(|link| link.asset_id == b.id);
+        assert_eq!(
+            links,
+            vec![
+                DependencyLink {
+                    asset_id: a.id,
+                    dependency_id: b.id
+                },
+                DependencyLink {
+                    asset_id: b.id,
+                    dependency_id: c.id
+                },
+            ]
+        );
+        let reverse = repo
+            .list_reverse_dependency_links(&[b.id, c.id])
+            .await
+            .unwrap();
+        assert_eq!(reverse.len(), 2);
+
+        repo.create(test_asset("a", "2.0.0")).await.unwrap();
+        let names = vec!["a".to_string(), "c".to_string()];
+        assert_eq!(repo.find_by_names(&names).await.unwrap().len(), 3);
+
+        let err = repo.add_dependency(&c.id, &a.id, None).await.unwrap_err();
+        assert!(matches!(err, DbError::CircularDependency(_)));
+
+        repo.delete(&b.id).await.unwrap();
+        assert!(repo.list_dependencies(&a.id).await.unwrap().is_empty());
+        assert!(repo
+            .list_reverse_dependencies(&c.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tags_and_counts() {
+        let repo = InMemoryAssetRepository::new();
+        let asset = test_asset("model", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        repo.add_tag(&asset.id, "prod").await.unwrap();
+        repo.add_tag(&asset.id, "prod").await.unwrap();
+        assert_eq!(repo.get_tags(&asset.id).await.unwrap(), vec!["prod"]);
+        assert!(repo.add_tag(&AssetId::new(), "x").await.is_err());
+
+        repo.remove_tag(&asset.id, "prod").await.unwrap();
+        assert!(repo.list_all_tags().await.unwrap().is_empty());
+        assert_eq!(
+            repo.find_by_id(&asset.id).await.unwrap().unwrap().revision,
+            3
+        );
+
+        assert_eq!(repo.count_assets().await.unwrap(), 1);
+        assert_eq!(repo.count_by_type(&AssetType::Model).await.unwrap(), 1);
+        assert_eq!(repo.count_by_type(&AssetType::Dataset).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_event_store_query() {
+        let store = InMemoryEventStore::new();
+        let asset_id = AssetId::new();
+        let now = Utc::now();
+
+        for i in 0..5 {
+            let mut event = RegistryEvent::new(EventType::AssetUpdated {
+                asset_id,
+                asset_name: "model".to_string(),
+                updated_fields: vec![format!("field{}", i)],
+                changes: vec![],
+                revision: None,
+            });
+            event.timestamp = now + Duration::seconds(i);
+            store.append(event).await.unwrap();
+        }
+        store
+            .append(
+                RegistryEvent::builder(EventType::AssetDeleted {
+                    asset_id: AssetId::new(),
+                    asset_name: "other".to_string(),
+                    asset_version: "1.0.0".to_string(),
+                })
+                .actor("alice")
+                .build(),
+            )
+            .await
+            .unwrap();
+
+        let results = store
+            .query(&EventQuery::new().asset_id(asset_id).limit(2).offset(1))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(5));
+        assert_eq!(results.events.len(), 2);
+        assert!(results.events[0].timestamp > results.events[1].timestamp);
+
+        let results = store
+            .query(&EventQuery::new().actor("system"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(5));
+
+        let results = store
+            .query(&EventQuery::new().event_type("asset_deleted"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+
+        let results = store
+            .query(&EventQuery::new().after(now + Duration::seconds(2)))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(2));
+
+        let first = store
+            .query(
+                &EventQuery::new()
+                    .asset_id(asset_id)
+                    .limit(3)
+                    .include_total(false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(first.total, None);
+        let rest = store
+            .query(
+                &EventQuery::new()
+                    .asset_id(asset_id)
+                    .cursor(first.next_cursor.unwrap()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(rest.events.len(), 2);
+        assert!(rest.next_cursor.is_none());
+        assert!(first.events[2].timestamp > rest.events[0].timestamp);
+
+        // Paging backward from the first page's cursor returns the events before it
+        let backward = store
+            .query(
+                &EventQuery::new()
+                    .asset_id(asset_id)
+                    .sort_order(SortOrder::Ascending)
+                    .cursor(Cursor::for_event(&first.events[1]).reversed()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(backward.events.len(), 1);
+        assert_eq!(backward.events[0].event_id, first.events[0].event_id);
+        let err = store
+            .query(&EventQuery::new().cursor(Cursor::for_event(&first.events[1]).reversed()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::InvalidQuery(_)));
+
+        let latest = store.get_latest_event(&asset_id).await.unwrap().unwrap();
+        assert_eq!(latest.timestamp, now + Duration::seconds(4));
+        assert_eq!(store.count_by_type("asset_updated").await.unwrap(), 5);
+    }
+}
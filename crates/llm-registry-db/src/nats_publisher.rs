@@ -3,10 +3,15 @@
 //! This module provides NATS-based event publishing for the LLM Registry.
 //! Events stored in PostgreSQL are also published to NATS for real-time
 //! notifications and event-driven integrations.
+//!
+//! Every message carries the event ID in the `Nats-Msg-Id` header, so a
+//! JetStream stream deduplicates events that are published more than once.
 
-use async_nats::{Client, ConnectOptions};
+use async_nats::header::{HeaderMap, NATS_MESSAGE_ID};
+use async_nats::{jetstream, Client, ConnectOptions};
 use llm_registry_core::{EventType, RegistryEvent};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
@@ -30,7 +35,10 @@ pub struct NatsPublisherConfig {
     /// Reconnect delay
     pub reconnect_delay: Duration,
 
-    /// Enable JetStream
+    /// Publish through JetStream and wait for the stream to acknowledge
+    ///
+    /// Requires a stream bound to `registry.events.>`. When disabled, messages
+    /// are published with core NATS and only flushed to the server.
     pub enable_jetstream: bool,
 }
 
@@ -79,42 +87,25 @@ impl NatsPublisherConfig {
 #[derive(Clone)]
 pub struct NatsEventPublisher {
     client: Client,
-    #[allow(dead_code)]
-    config: NatsPublisherConfig,
+    jetstream: Option<jetstream::Context>,
 }
 
 impl NatsEventPublisher {
     /// Create a new NATS event publisher
     pub async fn new(config: NatsPublisherConfig) -> DbResult<Self> {
-        info!(
-            "Connecting to NATS server at {}",
-            config.server_url
-        );
-
-        let connect_options = ConnectOptions::new()
-            .name(&config.client_name)
-            .connection_timeout(config.connect_timeout)
-            .reconnect_delay_callback(move |attempts| {
-                if attempts > 5 {
-                    warn!("NATS reconnection attempt #{}", attempts);
-                }
-                config.reconnect_delay
-            });
+        let client = connect(&config).await?;
 
-        let client = connect_options
-            .connect(&config.server_url)
-            .await
-            .map_err(|e| DbError::Configuration(format!("Failed to connect to NATS: {}", e)))?;
-
-        info!("Successfully connected to NATS");
+        let jetstream = config
+            .enable_jetstream
+            .then(|| jetstream::new(client.clone()));
 
-        Ok(Self {
-            client,
-            config,
-        })
+        Ok(Self { client, jetstream })
     }
 
     /// Publish an event to NATS
+    ///
+    /// With JetStream enabled this returns once the stream has stored the
+    /// message; otherwise once the message has been flushed to the server.
     pub async fn publish(&self, event: &RegistryEvent) -> DbResult<()> {
         let subject = self.build_subject(event);
 
@@ -128,14 +119,34 @@ impl NatsEventPublisher {
         let payload = serde_json::to_vec(&EventMessage::from(event))
             .map_err(|e| DbError::Serialization(format!("Failed to serialize event: {}", e)))?;
 
+        let mut headers = HeaderMap::new();
+        headers.insert(NATS_MESSAGE_ID, event.event_id.as_str());
+
         // Publish to NATS
-        self.client
-            .publish(subject.clone(), payload.into())
-            .await
-            .map_err(|e| {
-                error!("Failed to publish event to NATS: {}", e);
-                DbError::Connection(format!("NATS publish failed: {}", e))
-            })?;
+        let published = match self.jetstream {
+            Some(ref jetstream) => {
+                match jetstream
+                    .publish_with_headers(subject.clone(), headers, payload.into())
+                    .await
+                {
+                    Ok(ack) => ack.await.map(|_| ()).map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+            None => match self
+                .client
+                .publish_with_headers(subject.clone(), headers, payload.into())
+                .await
+            {
+                Ok(()) => self.client.flush().await.map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+        };
+
+        published.map_err(|e| {
+            error!("Failed to publish event to NATS: {}", e);
+            DbError::Connection(format!("NATS publish failed: {}", e))
+        })?;
 
         debug!("Event published successfully to {}", subject);
         Ok(())
@@ -195,9 +206,40 @@ impl NatsEventPublisher {
     }
 }
 
+/// Connect to the NATS server described by `config`
+///
+/// Shared by the event publisher and other NATS users such as cache
+/// invalidation. The `enable_jetstream` setting is ignored.
+pub async fn connect(config: &NatsPublisherConfig) -> DbResult<Client> {
+    info!("Connecting to NATS server at {}", config.server_url);
+
+    let reconnect_delay = config.reconnect_delay;
+    let connect_options = ConnectOptions::new()
+        .name(&config.client_name)
+        .connection_timeout(config.connect_timeout)
+        .reconnect_delay_callback(move |attempts| {
+            if attempts > 5 {
+                warn!("NATS reconnection attempt #{}", attempts);
+            }
+            reconnect_delay
+        });
+
+    let client = connect_options
+        .connect(&config.server_url)
+        .await
+        .map_err(|e| DbError::Configuration(format!("Failed to connect to NATS: {}", e)))?;
+
+    info!("Successfully connected to NATS");
+    Ok(client)
+}
+
 /// Event message wrapper for NATS
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventMessage {
+    /// Event ID, stable across redeliveries
+    #[serde(default)]
+    pub event_id: String,
+
     /// Event type/name
     pub event_type: String,
 
@@ -222,11 +264,34 @@ pub struct EventMessage {
 
     /// Event data (the EventType as JSON)
     pub data: serde_json::Value,
+
+    /// Additional context data
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub context: HashMap<String, String>,
+}
+
+impl EventMessage {
+    /// Decode the registry event carried by this message
+    pub fn into_event(self) -> DbResult<RegistryEvent> {
+        let event_type: EventType = serde_json::from_value(self.data)
+            .map_err(|e| DbError::Serialization(format!("Invalid event data: {}", e)))?;
+
+        Ok(RegistryEvent {
+            event_id: self.event_id,
+            event_type,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            actor: self.actor,
+            source: self.source,
+            context: self.context,
+        })
+    }
 }
 
 impl From<&RegistryEvent> for EventMessage {
     fn from(event: &RegistryEvent) -> Self {
         Self {
+            event_id: event.event_id.clone(),
             event_type: event.event_name().to_string(),
             asset_id: event.asset_id().map(|id| id.to_string()),
             timestamp: event.timestamp,
@@ -234,6 +299,7 @@ impl From<&RegistryEvent> for EventMessage {
             actor: event.actor.clone(),
             source: event.source.clone(),
             data: serde_json::to_value(&event.event_type).unwrap_or(serde_json::json!({})),
+            context: event.context.clone(),
         }
     }
 }
@@ -256,16 +322,50 @@ fn event_type_to_subject(event_type: &EventType) -> &'static str {
 }
 
 /// NATS event subscriber configuration
+///
+/// Subscribes with core NATS unless a durable JetStream consumer is set with
+/// [`NatsSubscriberConfig::durable`]. Redelivery settings only apply to
+/// durable consumers.
 #[derive(Debug, Clone)]
 pub struct NatsSubscriberConfig {
     /// Subject pattern to subscribe to
     pub subject: String,
 
     /// Queue group name (for load balancing)
+    ///
+    /// Only used with core NATS; replicas of a durable consumer share its
+    /// messages instead.
     pub queue_group: Option<String>,
 
     /// Maximum pending messages
+    ///
+    /// With JetStream, the maximum number of unacknowledged messages.
     pub max_pending: usize,
+
+    /// Durable JetStream consumer to pull messages from
+    pub durable: Option<DurableConsumer>,
+
+    /// Deliveries of a message before it is dead-lettered
+    pub max_deliver: i64,
+
+    /// Time the server waits for an acknowledgement before redelivering
+    pub ack_wait: Duration,
+
+    /// Delay before redelivering a message whose handler asked for a retry
+    pub retry_delay: Duration,
+
+    /// Subject messages are republished to when they cannot be processed
+    pub dead_letter_subject: Option<String>,
+}
+
+/// Durable JetStream consumer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurableConsumer {
+    /// Stream bound to the subscribed subjects
+    pub stream: String,
+
+    /// Consumer name, shared by all replicas of a subscriber
+    pub name: String,
 }
 
 impl NatsSubscriberConfig {
@@ -275,9 +375,49 @@ impl NatsSubscriberConfig {
             subject: subject.into(),
             queue_group: None,
             max_pending: 1000,
+            durable: None,
+            max_deliver: 5,
+            ack_wait: Duration::from_secs(30),
+            retry_delay: Duration::from_secs(5),
+            dead_letter_subject: None,
         }
     }
 
+    /// Pull messages from a durable JetStream consumer
+    ///
+    /// The consumer is created on the stream if it does not exist.
+    pub fn durable(mut self, stream: impl Into<String>, name: impl Into<String>) -> Self {
+        self.durable = Some(DurableConsumer {
+            stream: stream.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Set the deliveries of a message before it is dead-lettered
+    pub fn with_max_deliver(mut self, max_deliver: i64) -> Self {
+        self.max_deliver = max_deliver;
+        self
+    }
+
+    /// Set the acknowledgement timeout
+    pub fn with_ack_wait(mut self, ack_wait: Duration) -> Self {
+        self.ack_wait = ack_wait;
+        self
+    }
+
+    /// Set the delay before retrying a message
+    pub fn with_retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Republish unprocessable messages to a dead-letter subject
+    pub fn with_dead_letter_subject(mut self, subject: impl Into<String>) -> Self {
+        self.dead_letter_subject = Some(subject.into());
+        self
+    }
+
     /// Set queue group
     pub fn with_queue_group(mut self, group: impl Into<String>) -> Self {
         self.queue_group = Some(group.into());
@@ -314,6 +454,7 @@ mod tests {
             asset_name: "test-asset".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         });
 
         let message = EventMessage::from(&event);
@@ -321,6 +462,23 @@ mod tests {
 
         assert!(json.contains("event_type"));
         assert!(json.contains("asset_registered"));
+        assert!(json.contains(&event.event_id));
+    }
+
+    #[test]
+    fn test_event_message_round_trip() {
+        let event = RegistryEvent::builder(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "test-asset".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+        .actor("alice")
+        .context("request_id", "r-1")
+        .build();
+
+        let json = serde_json::to_vec(&EventMessage::from(&event)).unwrap();
+        let message: EventMessage = serde_json::from_slice(&json).unwrap();
+        assert_eq!(message.into_event().unwrap(), event);
     }
 
     #[test]
@@ -330,6 +488,7 @@ mod tests {
             asset_name: "test".to_string(),
             asset_version: "1.0.0".to_string(),
             asset_type: "model".to_string(),
+            snapshot: None,
         };
 
         assert_eq!(event_type_to_subject(&event_type), "asset.registered");
@@ -344,5 +503,17 @@ mod tests {
         assert_eq!(config.subject, "registry.events.>");
         assert_eq!(config.queue_group, Some("workers".to_string()));
         assert_eq!(config.max_pending, 5000);
+        assert!(config.durable.is_none());
+
+        let config = NatsSubscriberConfig::new("registry.events.>")
+            .durable("REGISTRY_EVENTS", "indexer")
+            .with_max_deliver(3)
+            .with_dead_letter_subject("registry.dead_letter.indexer");
+        assert_eq!(config.durable.unwrap().name, "indexer");
+        assert_eq!(config.max_deliver, 3);
+        assert_eq!(
+            config.dead_letter_subject.as_deref(),
+            Some("registry.dead_letter.indexer")
+        );
     }
 }
@@ -0,0 +1,407 @@
+//! NATS event subscriber
+//!
+//! This module consumes registry events published by
+//! [`NatsEventPublisher`](crate::NatsEventPublisher). An [`EventSubscriber`]
+//! decodes each [`EventMessage`] and dispatches the event to the handlers
+//! registered for its event type.
+//!
+//! With core NATS, messages are delivered at most once and failed messages
+//! are dead-lettered without a retry. With a durable JetStream consumer,
+//! every message is acknowledged explicitly: handlers that ask for a retry
+//! are redelivered after a delay until the delivery limit is reached, and
+//! messages that cannot be processed are republished to the dead-letter
+//! subject before being terminated.
+
+use async_nats::header::HeaderMap;
+use async_nats::jetstream::{self, consumer::pull, consumer::AckPolicy, AckKind};
+use async_nats::{Client, Subject};
+use async_trait::async_trait;
+use futures::StreamExt;
+use llm_registry_core::RegistryEvent;
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::error::{DbError, DbResult};
+use crate::nats_publisher::{EventMessage, NatsSubscriberConfig};
+
+/// Header carrying the reason a message was dead-lettered
+pub const DEAD_LETTER_REASON_HEADER: &str = "Registry-Dead-Letter-Reason";
+
+/// Header carrying the subject a dead-lettered message was received on
+pub const ORIGINAL_SUBJECT_HEADER: &str = "Registry-Original-Subject";
+
+/// Header carrying the number of deliveries of a dead-lettered message
+pub const DELIVERY_COUNT_HEADER: &str = "Registry-Delivery-Count";
+
+/// Error returned by an event handler
+#[derive(Debug, Error)]
+pub enum HandlerError {
+    /// Transient failure; the event should be redelivered
+    #[error("Retryable handler error: {0}")]
+    Retry(String),
+
+    /// Permanent failure; the event should be dead-lettered
+    #[error("Handler rejected event: {0}")]
+    Reject(String),
+}
+
+/// Handler for registry events received from NATS
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// Handle a single event
+    ///
+    /// Events may be delivered more than once, so handlers should be
+    /// idempotent, e.g. by deduplicating on `event_id`.
+    async fn handle(&self, event: &RegistryEvent) -> Result<(), HandlerError>;
+}
+
+/// What to do with a received message
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Disposition {
+    /// Processed; acknowledge it
+    Ack,
+    /// Redeliver it after a delay
+    Retry,
+    /// Republish it to the dead-letter subject and stop redelivery
+    DeadLetter(String),
+}
+
+/// Decide what to do with a message from the handlers' result
+///
+/// `delivered` is the delivery count of the message, starting at 1.
+fn disposition(result: Result<(), HandlerError>, delivered: i64, max_deliver: i64) -> Disposition {
+    match result {
+        Ok(()) => Disposition::Ack,
+        Err(HandlerError::Retry(reason)) if max_deliver > 0 && delivered >= max_deliver => {
+            Disposition::DeadLetter(format!(
+                "Gave up after {} deliveries: {}",
+                delivered, reason
+            ))
+        }
+        Err(HandlerError::Retry(_)) => Disposition::Retry,
+        Err(HandlerError::Reject(reason)) => Disposition::DeadLetter(reason),
+    }
+}
+
+/// Subscriber dispatching registry events to typed handlers
+///
+/// Handlers are registered per event type name, as returned by
+/// [`RegistryEvent::event_name`]. Every handler registered for an event's
+/// type runs in registration order, followed by the catch-all handlers;
+/// dispatch stops at the first handler that fails.
+pub struct EventSubscriber {
+    client: Client,
+    config: NatsSubscriberConfig,
+    handlers: HashMap<String, Vec<Arc<dyn EventHandler>>>,
+    catch_all: Vec<Arc<dyn EventHandler>>,
+}
+
+impl EventSubscriber {
+    /// Create a subscriber with no handlers
+    pub fn new(client: Client, config: NatsSubscriberConfig) -> Self {
+        Self {
+            client,
+            config,
+            handlers: HashMap::new(),
+            catch_all: Vec::new(),
+        }
+    }
+
+    /// Handle events of one type, e.g. `"asset_registered"`
+    pub fn on(mut self, event_type: impl Into<String>, handler: Arc<dyn EventHandler>) -> Self {
+        self.handlers
+            .entry(event_type.into())
+            .or_default()
+            .push(handler);
+        self
+    }
+
+    /// Handle events of every type
+    pub fn on_any(mut self, handler: Arc<dyn EventHandler>) -> Self {
+        self.catch_all.push(handler);
+        self
+    }
+
+    /// Dispatch an event to its handlers
+    ///
+    /// Events without a handler are treated as handled.
+    pub async fn dispatch(&self, event: &RegistryEvent) -> Result<(), HandlerError> {
+        let typed = self
+            .handlers
+            .get(event.event_name())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for handler in typed.iter().chain(&self.catch_all) {
+            handler.handle(event).await?;
+        }
+        Ok(())
+    }
+
+    /// Decode a message payload and dispatch its event
+    ///
+    /// Undecodable messages are rejected, since redelivery cannot fix them.
+    async fn process(&self, payload: &[u8]) -> Result<(), HandlerError> {
+        let event = serde_json::from_slice::<EventMessage>(payload)
+            .map_err(|e| DbError::Serialization(e.to_string()))
+            .and_then(EventMessage::into_event)
+            .map_err(|e| HandlerError::Reject(format!("Undecodable event message: {}", e)))?;
+
+        debug!(
+            event_id = %event.event_id,
+            event_type = event.event_name(),
+            "Dispatching event"
+        );
+        self.dispatch(&event).await
+    }
+
+    /// Subscribe and spawn a task dispatching received events
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created, e.g. when the
+    /// durable consumer's stream does not exist.
+    pub async fn start(self) -> DbResult<JoinHandle<()>> {
+        match self.config.durable.clone() {
+            Some(durable) => {
+                let stream = jetstream::new(self.client.clone())
+                    .get_stream(&durable.stream)
+                    .await
+                    .map_err(|e| {
+                        DbError::Configuration(format!(
+                            "JetStream stream {} is not available: {}",
+                            durable.stream, e
+                        ))
+                    })?;
+                let consumer = stream
+                    .get_or_create_consumer(
+                        &durable.name,
+                        pull::Config {
+                            durable_name: Some(durable.name.clone()),
+                            filter_subject: self.config.subject.clone(),
+                            ack_policy: AckPolicy::Explicit,
+                            ack_wait: self.config.ack_wait,
+                            max_deliver: self.config.max_deliver,
+                            max_ack_pending: self.config.max_pending as i64,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .map_err(|e| {
+                        DbError::Configuration(format!(
+                            "Failed to create consumer {}: {}",
+                            durable.name, e
+                        ))
+                    })?;
+                let mut messages = consumer.messages().await.map_err(|e| {
+                    DbError::Connection(format!("Failed to pull from {}: {}", durable.name, e))
+                })?;
+
+                info!(
+                    stream = %durable.stream,
+                    consumer = %durable.name,
+                    "Consuming {}",
+                    self.config.subject
+                );
+                Ok(tokio::spawn(async move {
+                    while let Some(message) = messages.next().await {
+                        match message {
+                            Ok(message) => self.handle_jetstream(message).await,
+                            Err(e) => warn!("Failed to receive message: {}", e),
+                        }
+                    }
+                    warn!("JetStream consumer {} closed", durable.name);
+                }))
+            }
+            None => {
+                let subject = self.config.subject.clone();
+                let mut subscriber = match &self.config.queue_group {
+                    Some(group) => {
+                        self.client
+                            .queue_subscribe(subject.clone(), group.clone())
+                            .await
+                    }
+                    None => self.client.subscribe(subject.clone()).await,
+                }
+                .map_err(|e| {
+                    DbError::Connection(format!("Failed to subscribe to {}: {}", subject, e))
+                })?;
+
+                info!("Subscribed to {}", subject);
+                Ok(tokio::spawn(async move {
+                    while let Some(message) = subscriber.next().await {
+                        self.handle_core(message).await;
+                    }
+                    warn!("Subscription to {} closed", subject);
+                }))
+            }
+        }
+    }
+
+    /// Handle a core NATS message, which cannot be redelivered
+    async fn handle_core(&self, message: async_nats::Message) {
+        // Without redelivery, a retryable failure is as final as a rejection
+        if let Err(e) = self.process(&message.payload).await {
+            self.dead_letter(&message.subject, &message.payload, &e.to_string(), 1)
+                .await;
+        }
+    }
+
+    /// Handle a JetStream message, acknowledging it explicitly
+    async fn handle_jetstream(&self, message: jetstream::Message) {
+        let delivered = message.info().map(|info| info.delivered).unwrap_or(1);
+        let result = self.process(&message.payload).await;
+
+        let acked = match disposition(result, delivered, self.config.max_deliver) {
+            Disposition::Ack => message.ack().await,
+            Disposition::Retry => {
+                debug!(delivered, "Retrying message on {}", message.subject);
+                message
+                    .ack_with(AckKind::Nak(Some(self.config.retry_delay)))
+                    .await
+            }
+            Disposition::DeadLetter(reason) => {
+                self.dead_letter(&message.subject, &message.payload, &reason, delivered)
+                    .await;
+                message.ack_with(AckKind::Term).await
+            }
+        };
+        if let Err(e) = acked {
+            warn!(
+                "Failed to acknowledge message on {}: {}",
+                message.subject, e
+            );
+        }
+    }
+
+    /// Republish a message to the dead-letter subject, if one is configured
+    async fn dead_letter(&self, subject: &Subject, payload: &[u8], reason: &str, delivered: i64) {
+        let Some(dead_letter_subject) = &self.config.dead_letter_subject else {
+            error!("Dropping message on {}: {}", subject, reason);
+            return;
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(DEAD_LETTER_REASON_HEADER, reason);
+        headers.insert(ORIGINAL_SUBJECT_HEADER, subject.as_str());
+        headers.insert(DELIVERY_COUNT_HEADER, delivered.to_string().as_str());
+
+        warn!(
+            "Dead-lettering message on {} to {}: {}",
+            subject, dead_letter_subject, reason
+        );
+        if let Err(e) = self
+            .client
+            .publish_with_headers(
+                dead_letter_subject.clone(),
+                headers,
+                payload.to_vec().into(),
+            )
+            .await
+        {
+            error!("Failed to dead-letter message on {}: {}", subject, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_core::{AssetId, EventType};
+    use std::sync::Mutex;
+
+    /// Records handled event IDs and fails with a preset error
+    #[derive(Default)]
+    struct Recorder {
+        seen: Mutex<Vec<String>>,
+        fail: Option<fn() -> HandlerError>,
+    }
+
+    #[async_trait]
+    impl EventHandler for Recorder {
+        async fn handle(&self, event: &RegistryEvent) -> Result<(), HandlerError> {
+            self.seen.lock().unwrap().push(event.event_id.clone());
+            self.fail.map_or(Ok(()), |fail| Err(fail()))
+        }
+    }
+
+    fn deleted_event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    async fn subscriber() -> EventSubscriber {
+        // Keeps connecting in the background, so no server is needed
+        let client = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect("nats://127.0.0.1:1")
+            .await
+            .unwrap();
+        EventSubscriber::new(client, NatsSubscriberConfig::new("registry.events.>"))
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_event_type() {
+        let deleted = Arc::new(Recorder::default());
+        let registered = Arc::new(Recorder::default());
+        let any = Arc::new(Recorder::default());
+        let subscriber = subscriber()
+            .await
+            .on("asset_deleted", deleted.clone())
+            .on("asset_registered", registered.clone())
+            .on_any(any.clone());
+
+        let event = deleted_event();
+        let payload = serde_json::to_vec(&EventMessage::from(&event)).unwrap();
+        subscriber.process(&payload).await.unwrap();
+
+        assert_eq!(*deleted.seen.lock().unwrap(), vec![event.event_id.clone()]);
+        assert!(registered.seen.lock().unwrap().is_empty());
+        assert_eq!(*any.seen.lock().unwrap(), vec![event.event_id]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stops_at_first_failure() {
+        let failing = Arc::new(Recorder {
+            fail: Some(|| HandlerError::Retry("index unavailable".to_string())),
+            ..Default::default()
+        });
+        let any = Arc::new(Recorder::default());
+        let subscriber = subscriber()
+            .await
+            .on("asset_deleted", failing)
+            .on_any(any.clone());
+
+        let result = subscriber.dispatch(&deleted_event()).await;
+        assert!(matches!(result, Err(HandlerError::Retry(_))));
+        assert!(any.seen.lock().unwrap().is_empty());
+
+        assert!(matches!(
+            subscriber.process(b"not json").await,
+            Err(HandlerError::Reject(_))
+        ));
+    }
+
+    #[test]
+    fn test_disposition() {
+        let retry = || Err(HandlerError::Retry("busy".to_string()));
+        assert_eq!(disposition(Ok(()), 1, 5), Disposition::Ack);
+        assert_eq!(disposition(retry(), 4, 5), Disposition::Retry);
+        assert!(matches!(
+            disposition(retry(), 5, 5),
+            Disposition::DeadLetter(reason) if reason.contains("5 deliveries")
+        ));
+        // A non-positive limit redelivers forever
+        assert_eq!(disposition(retry(), 50, -1), Disposition::Retry);
+        assert_eq!(
+            disposition(Err(HandlerError::Reject("bad".to_string())), 1, 5),
+            Disposition::DeadLetter("bad".to_string())
+        );
+    }
+}
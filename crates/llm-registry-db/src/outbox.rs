@@ -0,0 +1,861 @@
+//! Transactional outbox relay
+//!
+//! Every event persisted by the SQL event stores is queued in the
+//! `event_outbox` table in the same transaction as the event itself. The
+//! [`OutboxRelay`] claims batches of queued events and publishes each event
+//! through an [`EventPublisher`], removing it from the outbox only after the
+//! publisher has confirmed delivery. A crash between publishing and removal
+//! causes the event to be published again, so delivery is at-least-once and
+//! consumers should deduplicate on `event_id`. [`FanoutPublisher`] relays
+//! the same events to several destinations, such as NATS and webhooks.
+//!
+//! Claiming an event hides it from other relays for a lease period, and the
+//! PostgreSQL store claims with `FOR UPDATE SKIP LOCKED`, so one relay per
+//! replica can drain the same outbox. Events are claimed roughly in the order
+//! they were queued, but relays publish concurrently and a failed event is
+//! retried after a backoff while later events go ahead, so consumers must not
+//! rely on publication order. An event that fails
+//! [`OutboxRelayConfig::max_attempts`] times is dead-lettered: it stays in
+//! the outbox but is no longer retried until it is requeued.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::future::join_all;
+use llm_registry_core::RegistryEvent;
+use moka::future::Cache;
+use sqlx::Row;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, instrument, warn};
+
+use crate::error::{DbError, DbResult};
+use crate::event_store::{row_to_event, PostgresEventStore};
+use crate::nats_publisher::NatsEventPublisher;
+
+/// An event waiting in the outbox
+#[derive(Debug, Clone)]
+pub struct OutboxEntry {
+    /// The queued event
+    pub event: RegistryEvent,
+
+    /// Number of failed delivery attempts so far
+    pub attempts: i32,
+
+    /// Error of the last failed delivery attempt
+    pub last_error: Option<String>,
+}
+
+/// Storage for events waiting to be published
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// Claim up to `limit` events that are due for delivery, oldest first
+    ///
+    /// Claimed events are not returned again, to this or any other relay,
+    /// until `lease` has elapsed, unless a failure is recorded for them.
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> DbResult<Vec<OutboxEntry>>;
+
+    /// Remove published events from the outbox
+    async fn mark_published(&self, event_ids: &[String]) -> DbResult<()>;
+
+    /// Record a failed delivery attempt, making the event due again after
+    /// `retry_after`
+    async fn record_failure(
+        &self,
+        event_id: &str,
+        error: &str,
+        retry_after: Duration,
+    ) -> DbResult<()>;
+
+    /// Record a final failed delivery attempt and stop retrying the event
+    async fn dead_letter(&self, event_id: &str, error: &str) -> DbResult<()>;
+
+    /// Get dead-lettered events, oldest first
+    async fn fetch_dead_lettered(&self, limit: i64) -> DbResult<Vec<OutboxEntry>>;
+
+    /// Queue dead-lettered events for delivery again, resetting their attempts
+    ///
+    /// Returns the number of events requeued.
+    async fn requeue_dead_lettered(&self, event_ids: &[String]) -> DbResult<u64>;
+
+    /// Count events waiting to be published, excluding dead-lettered events
+    async fn count_pending(&self) -> DbResult<i64>;
+}
+
+/// Destination for events relayed from the outbox
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Publish an event, returning only once delivery has been confirmed
+    async fn publish(&self, event: &RegistryEvent) -> DbResult<()>;
+}
+
+#[async_trait]
+impl EventPublisher for NatsEventPublisher {
+    async fn publish(&self, event: &RegistryEvent) -> DbResult<()> {
+        NatsEventPublisher::publish(self, event).await
+    }
+}
+
+/// Maximum number of partially published events a fanout remembers
+const MAX_PARTIALLY_PUBLISHED: u64 = 10_000;
+
+/// Publisher delivering each event to several publishers
+///
+/// Every publisher is given the event, even if another fails, and
+/// publishing fails if any of them did. The publishers a failed event
+/// reached are remembered, so when the relay retries it only the failed
+/// ones see it again: an outage of one destination does not hold up or
+/// repeat deliveries to the others. That memory is kept in process and
+/// bounded, so after a restart a publisher may still see an event twice and
+/// must tolerate duplicates. With no publishers every event is accepted, so
+/// a relay publishing through an empty fanout just prunes the outbox.
+pub struct FanoutPublisher {
+    publishers: Vec<Arc<dyn EventPublisher>>,
+    /// Indexes of the publishers that accepted each partially published event
+    reached: Cache<String, Vec<usize>>,
+}
+
+impl FanoutPublisher {
+    /// Create a publisher fanning out to `publishers`
+    pub fn new(publishers: Vec<Arc<dyn EventPublisher>>) -> Self {
+        Self {
+            publishers,
+            reached: Cache::new(MAX_PARTIALLY_PUBLISHED),
+        }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for FanoutPublisher {
+    async fn publish(&self, event: &RegistryEvent) -> DbResult<()> {
+        let mut reached = self.reached.get(&event.event_id).await.unwrap_or_default();
+        let pending = self
+            .publishers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !reached.contains(index))
+            .map(|(index, publisher)| async move { (index, publisher.publish(event).await) });
+
+        let mut failures = Vec::new();
+        for (index, result) in join_all(pending).await {
+            match result {
+                Ok(()) => reached.push(index),
+                Err(e) => failures.push(e),
+            }
+        }
+
+        if failures.is_empty() {
+            self.reached.invalidate(&event.event_id).await;
+            return Ok(());
+        }
+        self.reached.insert(event.event_id.clone(), reached).await;
+        if failures.len() == 1 {
+            return Err(failures.remove(0));
+        }
+        let errors: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        Err(DbError::Other(format!(
+            "{} destinations failed: {}",
+            failures.len(),
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Outbox relay configuration
+#[derive(Debug, Clone)]
+pub struct OutboxRelayConfig {
+    /// Maximum number of events claimed per pass
+    pub batch_size: i64,
+
+    /// Delay between passes when the outbox is drained
+    pub poll_interval: Duration,
+
+    /// How long claimed events are hidden from other relays
+    ///
+    /// Must exceed the time needed to publish a batch, or other relays will
+    /// publish the same events again.
+    pub claim_timeout: Duration,
+
+    /// Delay before the first retry of a failed event, doubled on each
+    /// further failure
+    pub retry_backoff: Duration,
+
+    /// Upper bound of the retry delay
+    pub max_retry_backoff: Duration,
+
+    /// Failed attempts after which an event is dead-lettered
+    pub max_attempts: i32,
+}
+
+impl Default for OutboxRelayConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            poll_interval: Duration::from_secs(1),
+            claim_timeout: Duration::from_secs(60),
+            retry_backoff: Duration::from_secs(1),
+            max_retry_backoff: Duration::from_secs(600),
+            max_attempts: 20,
+        }
+    }
+}
+
+impl OutboxRelayConfig {
+    /// Set the batch size
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the poll interval
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the claim timeout
+    pub fn with_claim_timeout(mut self, claim_timeout: Duration) -> Self {
+        self.claim_timeout = claim_timeout;
+        self
+    }
+
+    /// Set the initial and maximum retry delay
+    pub fn with_retry_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.retry_backoff = initial;
+        self.max_retry_backoff = max;
+        self
+    }
+
+    /// Set the number of failed attempts after which an event is dead-lettered
+    pub fn with_max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Delay before retrying an event that has failed `attempts` times
+    fn retry_delay(&self, attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 31) as u32;
+        self.retry_backoff
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_retry_backoff)
+    }
+}
+
+/// Background task publishing committed events from the outbox
+pub struct OutboxRelay {
+    store: Arc<dyn OutboxStore>,
+    publisher: Arc<dyn EventPublisher>,
+    config: OutboxRelayConfig,
+}
+
+impl OutboxRelay {
+    /// Create a relay with the default configuration
+    pub fn new(store: Arc<dyn OutboxStore>, publisher: Arc<dyn EventPublisher>) -> Self {
+        Self {
+            store,
+            publisher,
+            config: OutboxRelayConfig::default(),
+        }
+    }
+
+    /// Set the relay configuration
+    pub fn with_config(mut self, config: OutboxRelayConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Publish one batch of pending events
+    ///
+    /// A failed event is retried after a backoff, or dead-lettered once it
+    /// has failed `max_attempts` times, without holding up the rest of the
+    /// batch. Returns the number of events claimed, published or not.
+    #[instrument(skip(self))]
+    pub async fn relay_pending(&self) -> DbResult<usize> {
+        let claimed = self
+            .store
+            .claim_pending(self.config.batch_size, self.config.claim_timeout)
+            .await?;
+        let count = claimed.len();
+        let mut published = Vec::with_capacity(count);
+
+        for entry in claimed {
+            match self.publisher.publish(&entry.event).await {
+                Ok(()) => published.push(entry.event.event_id),
+                Err(e) => self.record_failure(&entry, &e.to_string()).await?,
+            }
+        }
+
+        if !published.is_empty() {
+            self.store.mark_published(&published).await?;
+            debug!(count = published.len(), "Relayed events from outbox");
+        }
+
+        Ok(count)
+    }
+
+    /// Schedule a retry of a failed event, or dead-letter it
+    async fn record_failure(&self, entry: &OutboxEntry, error: &str) -> DbResult<()> {
+        let event_id = &entry.event.event_id;
+        let attempts = entry.attempts + 1;
+
+        if attempts >= self.config.max_attempts {
+            error!(%event_id, attempts, "Dead-lettering event: {}", error);
+            return self.store.dead_letter(event_id, error).await;
+        }
+
+        let retry_after = self.config.retry_delay(attempts);
+        warn!(
+            %event_id,
+            attempts,
+            retry_after_ms = retry_after.as_millis() as u64,
+            "Failed to relay event: {}",
+            error
+        );
+        self.store
+            .record_failure(event_id, error, retry_after)
+            .await
+    }
+
+    /// Relay events until the task is aborted
+    pub async fn run(self) {
+        info!(
+            batch_size = self.config.batch_size,
+            poll_interval_ms = self.config.poll_interval.as_millis() as u64,
+            "Starting outbox relay"
+        );
+
+        loop {
+            match self.relay_pending().await {
+                // A full batch means more events are probably waiting
+                Ok(count) if count as i64 >= self.config.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => warn!("Outbox relay pass failed: {}", e),
+            }
+
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Spawn the relay onto the Tokio runtime
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+}
+
+#[async_trait]
+impl OutboxStore for PostgresEventStore {
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> DbResult<Vec<OutboxEntry>> {
+        let rows = sqlx::query(
+            r#"
+            WITH due AS (
+                SELECT sequence
+                FROM event_outbox
+                WHERE dead_lettered_at IS NULL AND available_at <= NOW()
+                ORDER BY sequence
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            ), claimed AS (
+                UPDATE event_outbox o
+                SET available_at = NOW() + make_interval(secs => $2)
+                FROM due
+                WHERE o.sequence = due.sequence
+                RETURNING o.sequence, o.event_id, o.attempts, o.last_error
+            )
+            SELECT
+                e.event_id, e.event_type, e.asset_id, e.timestamp,
+                e.actor, e.payload, e.metadata, c.attempts, c.last_error
+            FROM claimed c
+            JOIN registry_events e ON e.event_id = c.event_id
+            ORDER BY c.sequence
+            "#,
+        )
+        .bind(limit)
+        .bind(lease.as_secs_f64())
+        .fetch_all(self.pool())
+        .await?;
+
+        rows.into_iter().map(pg_row_to_entry).collect()
+    }
+
+    async fn mark_published(&self, event_ids: &[String]) -> DbResult<()> {
+        sqlx::query("DELETE FROM event_outbox WHERE event_id = ANY($1)")
+            .bind(event_ids)
+            .execute(self.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        event_id: &str,
+        error: &str,
+        retry_after: Duration,
+    ) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET attempts = attempts + 1, last_error = $2, last_attempt_at = NOW(),
+                available_at = NOW() + make_interval(secs => $3)
+            WHERE event_id = $1
+            "#,
+        )
+        .bind(event_id)
+        .bind(error)
+        .bind(retry_after.as_secs_f64())
+        .execute(self.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self, event_id: &str, error: &str) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET attempts = attempts + 1, last_error = $2, last_attempt_at = NOW(),
+                dead_lettered_at = NOW()
+            WHERE event_id = $1
+            "#,
+        )
+        .bind(event_id)
+        .bind(error)
+        .execute(self.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_dead_lettered(&self, limit: i64) -> DbResult<Vec<OutboxEntry>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                e.event_id, e.event_type, e.asset_id, e.timestamp,
+                e.actor, e.payload, e.metadata, o.attempts, o.last_error
+            FROM event_outbox o
+            JOIN registry_events e ON e.event_id = o.event_id
+            WHERE o.dead_lettered_at IS NOT NULL
+            ORDER BY o.sequence
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        rows.into_iter().map(pg_row_to_entry).collect()
+    }
+
+    async fn requeue_dead_lettered(&self, event_ids: &[String]) -> DbResult<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET attempts = 0, dead_lettered_at = NULL, available_at = NOW()
+            WHERE event_id = ANY($1) AND dead_lettered_at IS NOT NULL
+            "#,
+        )
+        .bind(event_ids)
+        .execute(self.pool())
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn count_pending(&self) -> DbResult<i64> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) as count FROM event_outbox WHERE dead_lettered_at IS NULL",
+        )
+        .fetch_one(self.pool())
+        .await?;
+
+        Ok(row.get("count"))
+    }
+}
+
+/// Convert a PostgreSQL event row with outbox columns to an outbox entry
+fn pg_row_to_entry(row: sqlx::postgres::PgRow) -> DbResult<OutboxEntry> {
+    let attempts: i32 = row.get("attempts");
+    let last_error: Option<String> = row.get("last_error");
+    Ok(OutboxEntry {
+        event: row_to_event(row)?,
+        attempts,
+        last_error,
+    })
+}
+
+/// Current time in Unix milliseconds, as stored in the SQLite outbox
+#[cfg(feature = "sqlite")]
+fn sqlite_now_millis() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+/// Time `delay` from now in Unix milliseconds
+#[cfg(feature = "sqlite")]
+fn sqlite_millis_after(delay: Duration) -> i64 {
+    sqlite_now_millis().saturating_add(delay.as_millis().min(i64::MAX as u128) as i64)
+}
+
+/// Convert a SQLite event row with outbox columns to an outbox entry
+#[cfg(feature = "sqlite")]
+fn sqlite_row_to_entry(row: sqlx::sqlite::SqliteRow) -> DbResult<OutboxEntry> {
+    let attempts: i32 = row.get("attempts");
+    let last_error: Option<String> = row.get("last_error");
+    Ok(OutboxEntry {
+        event: crate::event_store::sqlite_row_to_event(row)?,
+        attempts,
+        last_error,
+    })
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl OutboxStore for crate::event_store::SqliteEventStore {
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> DbResult<Vec<OutboxEntry>> {
+        // SQLite has a single writer, so the claiming update cannot race
+        let mut tx = self.pool().begin().await?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                e.event_id, e.event_type, e.asset_id, e.timestamp,
+                e.actor, e.payload, e.metadata, o.attempts, o.last_error
+            FROM event_outbox o
+            JOIN registry_events e ON e.event_id = o.event_id
+            WHERE o.dead_lettered_at IS NULL AND o.available_at <= ?
+            ORDER BY o.sequence
+            LIMIT ?
+            "#,
+        )
+        .bind(sqlite_now_millis())
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let entries = rows
+            .into_iter()
+            .map(sqlite_row_to_entry)
+            .collect::<DbResult<Vec<_>>>()?;
+
+        if !entries.is_empty() {
+            let mut builder = sqlx::QueryBuilder::new("UPDATE event_outbox SET available_at = ");
+            builder.push_bind(sqlite_millis_after(lease));
+            builder.push(" WHERE event_id IN (");
+            let mut separated = builder.separated(", ");
+            for entry in &entries {
+                separated.push_bind(entry.event.event_id.as_str());
+            }
+            separated.push_unseparated(")");
+            builder.build().execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+        Ok(entries)
+    }
+
+    async fn mark_published(&self, event_ids: &[String]) -> DbResult<()> {
+        if event_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut builder = sqlx::QueryBuilder::new("DELETE FROM event_outbox WHERE event_id IN (");
+        let mut separated = builder.separated(", ");
+        for event_id in event_ids {
+            separated.push_bind(event_id.as_str());
+        }
+        separated.push_unseparated(")");
+
+        builder.build().execute(self.pool()).await?;
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        event_id: &str,
+        error: &str,
+        retry_after: Duration,
+    ) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET attempts = attempts + 1, last_error = ?, last_attempt_at = ?,
+                available_at = ?
+            WHERE event_id = ?
+            "#,
+        )
+        .bind(error)
+        .bind(Utc::now())
+        .bind(sqlite_millis_after(retry_after))
+        .bind(event_id)
+        .execute(self.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self, event_id: &str, error: &str) -> DbResult<()> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET attempts = attempts + 1, last_error = ?, last_attempt_at = ?,
+                dead_lettered_at = ?
+            WHERE event_id = ?
+            "#,
+        )
+        .bind(error)
+        .bind(now)
+        .bind(now)
+        .bind(event_id)
+        .execute(self.pool())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_dead_lettered(&self, limit: i64) -> DbResult<Vec<OutboxEntry>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                e.event_id, e.event_type, e.asset_id, e.timestamp,
+                e.actor, e.payload, e.metadata, o.attempts, o.last_error
+            FROM event_outbox o
+            JOIN registry_events e ON e.event_id = o.event_id
+            WHERE o.dead_lettered_at IS NOT NULL
+            ORDER BY o.sequence
+            LIMIT ?
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        rows.into_iter().map(sqlite_row_to_entry).collect()
+    }
+
+    async fn requeue_dead_lettered(&self, event_ids: &[String]) -> DbResult<u64> {
+        if event_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut builder = sqlx::QueryBuilder::new(
+            "UPDATE event_outbox SET attempts = 0, dead_lettered_at = NULL, available_at = ",
+        );
+        builder.push_bind(sqlite_now_millis());
+        builder.push(" WHERE dead_lettered_at IS NOT NULL AND event_id IN (");
+        let mut separated = builder.separated(", ");
+        for event_id in event_ids {
+            separated.push_bind(event_id.as_str());
+        }
+        separated.push_unseparated(")");
+
+        let result = builder.build().execute(self.pool()).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn count_pending(&self) -> DbResult<i64> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) as count FROM event_outbox WHERE dead_lettered_at IS NULL",
+        )
+        .fetch_one(self.pool())
+        .await?;
+
+        Ok(row.get("count"))
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::event_store::{EventStore, SqliteEventStore};
+    use crate::pool::{create_sqlite_pool, PoolConfig};
+    use llm_registry_core::{AssetId, EventType};
+    use std::sync::Mutex;
+
+    /// Publisher that records events and fails on demand
+    #[derive(Default)]
+    struct RecordingPublisher {
+        published: Mutex<Vec<String>>,
+        fail_on: Mutex<Option<String>>,
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish(&self, event: &RegistryEvent) -> DbResult<()> {
+            if self.fail_on.lock().unwrap().as_deref() == Some(event.event_id.as_str()) {
+                return Err(DbError::Connection("broker unavailable".to_string()));
+            }
+            self.published.lock().unwrap().push(event.event_id.clone());
+            Ok(())
+        }
+    }
+
+    fn deleted_event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    async fn setup() -> Arc<SqliteEventStore> {
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        Arc::new(SqliteEventStore::new(pool))
+    }
+
+    fn relay(store: &Arc<SqliteEventStore>, publisher: &Arc<RecordingPublisher>) -> OutboxRelay {
+        OutboxRelay::new(store.clone(), publisher.clone()).with_config(
+            OutboxRelayConfig::default().with_retry_backoff(Duration::ZERO, Duration::ZERO),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_relay_publishes_in_queue_order() {
+        let store = setup().await;
+        let events = store
+            .append_batch(vec![deleted_event(), deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+        assert_eq!(store.count_pending().await.unwrap(), 3);
+
+        let publisher = Arc::new(RecordingPublisher::default());
+        let relay = relay(&store, &publisher);
+
+        assert_eq!(relay.relay_pending().await.unwrap(), 3);
+        assert_eq!(store.count_pending().await.unwrap(), 0);
+
+        let expected: Vec<String> = events.iter().map(|e| e.event_id.clone()).collect();
+        assert_eq!(*publisher.published.lock().unwrap(), expected);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fanout_retries_only_failed_destinations() {
+        let store = setup().await;
+        let events = store.append_batch(vec![deleted_event()]).await.unwrap();
+        let event_id = &events[0].event_id;
+
+        let broker = Arc::new(RecordingPublisher::default());
+        *broker.fail_on.lock().unwrap() = Some(event_id.clone());
+        let webhooks = Arc::new(RecordingPublisher::default());
+        let relay = OutboxRelay::new(
+            store.clone(),
+            Arc::new(FanoutPublisher::new(vec![broker.clone(), webhooks.clone()])),
+        )
+        .with_config(
+            OutboxRelayConfig::default().with_retry_backoff(Duration::ZERO, Duration::ZERO),
+        );
+
+        // A failing first destination does not stop the second
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert!(broker.published.lock().unwrap().is_empty());
+        assert_eq!(*webhooks.published.lock().unwrap(), vec![event_id.clone()]);
+        assert_eq!(store.count_pending().await.unwrap(), 1);
+
+        // The retry only goes to the destination that failed
+        *broker.fail_on.lock().unwrap() = None;
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert_eq!(*broker.published.lock().unwrap(), vec![event_id.clone()]);
+        assert_eq!(webhooks.published.lock().unwrap().len(), 1);
+        assert_eq!(store.count_pending().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_retries_failed_events() {
+        let store = setup().await;
+        let events = store
+            .append_batch(vec![deleted_event(), deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+
+        let publisher = Arc::new(RecordingPublisher::default());
+        *publisher.fail_on.lock().unwrap() = Some(events[1].event_id.clone());
+        let relay = relay(&store, &publisher);
+
+        // A failed event does not hold up the rest of the batch
+        assert_eq!(relay.relay_pending().await.unwrap(), 3);
+        assert_eq!(publisher.published.lock().unwrap().len(), 2);
+        assert_eq!(store.count_pending().await.unwrap(), 1);
+
+        *publisher.fail_on.lock().unwrap() = None;
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert_eq!(
+            publisher.published.lock().unwrap().last(),
+            Some(&events[1].event_id)
+        );
+        assert_eq!(store.count_pending().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_dead_letters_failing_events() {
+        let store = setup().await;
+        let events = store
+            .append_batch(vec![deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+
+        let publisher = Arc::new(RecordingPublisher::default());
+        *publisher.fail_on.lock().unwrap() = Some(events[0].event_id.clone());
+        let relay = relay(&store, &publisher).with_config(
+            OutboxRelayConfig::default()
+                .with_retry_backoff(Duration::ZERO, Duration::ZERO)
+                .with_max_attempts(2),
+        );
+
+        assert_eq!(relay.relay_pending().await.unwrap(), 2);
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        assert_eq!(store.count_pending().await.unwrap(), 0);
+
+        let dead = store.fetch_dead_lettered(10).await.unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].event.event_id, events[0].event_id);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("Database connection error: broker unavailable")
+        );
+
+        // Requeued events are delivered again
+        *publisher.fail_on.lock().unwrap() = None;
+        let ids = vec![events[0].event_id.clone()];
+        assert_eq!(store.requeue_dead_lettered(&ids).await.unwrap(), 1);
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert!(store.fetch_dead_lettered(10).await.unwrap().is_empty());
+        assert_eq!(publisher.published.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_claimed_events_are_hidden_until_lease_expires() {
+        let store = setup().await;
+        store
+            .append_batch(vec![deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+
+        let claimed = store
+            .claim_pending(10, Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(claimed.len(), 2);
+        assert!(store
+            .claim_pending(10, Duration::from_secs(60))
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(store.count_pending().await.unwrap(), 2);
+
+        // An expired lease makes the events due again
+        let store = setup().await;
+        store.append(deleted_event()).await.unwrap();
+        assert_eq!(
+            store.claim_pending(10, Duration::ZERO).await.unwrap().len(),
+            1
+        );
+        assert_eq!(
+            store.claim_pending(10, Duration::ZERO).await.unwrap().len(),
+            1
+        );
+    }
+}
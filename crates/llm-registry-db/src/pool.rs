@@ -1,10 +1,14 @@
 //! Database connection pool management
 //!
-//! This module provides connection pooling for PostgreSQL using SQLx's built-in
-//! pooling capabilities with additional configuration and health checking.
+//! This module provides connection pooling for PostgreSQL (and SQLite, with the
+//! `sqlite` feature) using SQLx's built-in pooling capabilities with additional
+//! configuration and health checking.
 
 use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+#[cfg(feature = "sqlite")]
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions};
 use sqlx::ConnectOptions;
+use std::fmt;
 use std::str::FromStr;
 use std::time::Duration;
 use tracing::{debug, info};
@@ -26,6 +30,40 @@ pub const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 600;
 /// Default maximum lifetime for a connection (30 minutes)
 pub const DEFAULT_MAX_LIFETIME_SECS: u64 = 1800;
 
+/// Database backend, selected by the scheme of the database URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// PostgreSQL (`postgres://` or `postgresql://`)
+    Postgres,
+    /// SQLite (`sqlite:`), e.g. `sqlite://registry.db` or `sqlite::memory:`
+    Sqlite,
+}
+
+impl DatabaseBackend {
+    /// Determine the backend from a database URL
+    pub fn from_url(url: &str) -> DbResult<Self> {
+        let scheme = url.split(':').next().unwrap_or_default().to_lowercase();
+
+        match scheme.as_str() {
+            "postgres" | "postgresql" => Ok(DatabaseBackend::Postgres),
+            "sqlite" => Ok(DatabaseBackend::Sqlite),
+            _ => Err(DbError::Configuration(format!(
+                "Unsupported database URL scheme: {}",
+                mask_password(url)
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DatabaseBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseBackend::Postgres => write!(f, "postgres"),
+            DatabaseBackend::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
 /// Configuration for database connection pool
 #[derive(Debug, Clone)]
 pub struct PoolConfig {
@@ -201,6 +239,89 @@ pub async fn run_migrations(pool: &PgPool) -> DbResult<()> {
     Ok(())
 }
 
+/// Create a SQLite connection pool from configuration
+///
+/// The database file is created if it does not exist. In-memory databases
+/// (`sqlite::memory:`) are private to a single connection, so the pool is
+/// pinned to one connection that is never recycled.
+#[cfg(feature = "sqlite")]
+pub async fn create_sqlite_pool(config: &PoolConfig) -> DbResult<SqlitePool> {
+    config.validate()?;
+
+    info!(
+        "Creating SQLite connection pool: max={}, database={}",
+        config.max_connections, config.database_url
+    );
+
+    let mut connect_opts = SqliteConnectOptions::from_str(&config.database_url)
+        .map_err(|e| DbError::Configuration(format!("Invalid database URL: {}", e)))?
+        .create_if_missing(true)
+        .foreign_keys(true);
+
+    if config.enable_logging {
+        connect_opts = connect_opts.log_statements(tracing::log::LevelFilter::Debug);
+    } else {
+        connect_opts = connect_opts.log_statements(tracing::log::LevelFilter::Off);
+    }
+
+    let in_memory = is_sqlite_in_memory(&config.database_url);
+
+    let pool_opts = if in_memory {
+        SqlitePoolOptions::new()
+            .min_connections(1)
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+    } else {
+        connect_opts = connect_opts.journal_mode(SqliteJournalMode::Wal);
+
+        SqlitePoolOptions::new()
+            .min_connections(config.min_connections)
+            .max_connections(config.max_connections)
+            .idle_timeout(config.idle_timeout)
+            .max_lifetime(config.max_lifetime)
+    };
+
+    let pool = pool_opts
+        .acquire_timeout(config.connect_timeout)
+        .connect_with(connect_opts)
+        .await
+        .map_err(|e| DbError::Connection(format!("Failed to create pool: {}", e)))?;
+
+    info!("SQLite connection pool created successfully");
+
+    if config.run_migrations {
+        run_sqlite_migrations(&pool).await?;
+    }
+
+    sqlx::query("SELECT 1")
+        .execute(&pool)
+        .await
+        .map_err(|e| DbError::Connection(format!("Health check failed: {}", e)))?;
+
+    Ok(pool)
+}
+
+/// Run SQLite database migrations
+#[cfg(feature = "sqlite")]
+pub async fn run_sqlite_migrations(pool: &SqlitePool) -> DbResult<()> {
+    info!("Running SQLite database migrations");
+
+    sqlx::migrate!("./migrations/sqlite")
+        .run(pool)
+        .await
+        .map_err(|e| DbError::Migration(format!("Migration failed: {}", e)))?;
+
+    info!("Database migrations completed successfully");
+    Ok(())
+}
+
+/// Check whether a SQLite URL refers to an in-memory database
+#[cfg(feature = "sqlite")]
+fn is_sqlite_in_memory(url: &str) -> bool {
+    url.contains(":memory:") || url.contains("mode=memory")
+}
+
 /// Verify that the connection pool is healthy
 pub async fn verify_pool_health(pool: &PgPool) -> DbResult<()> {
     debug!("Verifying database pool health");
@@ -302,6 +423,40 @@ mod tests {
         assert!(masked.contains("localhost"));
     }
 
+    #[test]
+    fn test_database_backend_from_url() {
+        assert_eq!(
+            DatabaseBackend::from_url("postgres://localhost/db").unwrap(),
+            DatabaseBackend::Postgres
+        );
+        assert_eq!(
+            DatabaseBackend::from_url("postgresql://localhost/db").unwrap(),
+            DatabaseBackend::Postgres
+        );
+        assert_eq!(
+            DatabaseBackend::from_url("sqlite://registry.db").unwrap(),
+            DatabaseBackend::Sqlite
+        );
+        assert_eq!(
+            DatabaseBackend::from_url("sqlite::memory:").unwrap(),
+            DatabaseBackend::Sqlite
+        );
+        assert!(DatabaseBackend::from_url("mysql://localhost/db").is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_create_sqlite_pool_in_memory() {
+        let config = PoolConfig::new("sqlite::memory:");
+        let pool = create_sqlite_pool(&config).await.unwrap();
+
+        let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM assets")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row.0, 0);
+    }
+
     #[test]
     fn test_pool_stats() {
         let stats = PoolStats {
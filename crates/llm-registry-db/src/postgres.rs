@@ -6,19 +6,34 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, AssetStatus, AssetType, Checksum, HashAlgorithm, Provenance,
-    StorageBackend, StorageLocation,
+    Asset, AssetId, AssetMetadata, AssetSignature, AssetStatus, AssetType, Checksum, HashAlgorithm,
+    Provenance, SignatureAlgorithm, StorageBackend, StorageLocation,
 };
 use semver::Version;
 use serde_json::Value as JsonValue;
 use sqlx::postgres::PgRow;
-use sqlx::{PgPool, Row};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
 use std::collections::HashMap;
 use std::str::FromStr;
 use tracing::{debug, instrument};
 
+use crate::cursor::push_keyset_condition;
 use crate::error::{DbError, DbResult};
-use crate::repository::{AssetRepository, SearchQuery, SearchResults, SortField, SortOrder};
+use crate::repository::{
+    AssetRepository, DependencyLink, SearchQuery, SearchResults, SortOrder, SNIPPET_END,
+    SNIPPET_START,
+};
+
+/// Asset columns selected by search queries
+const ASSET_COLUMNS: &str = r#"
+    a.id, a.name, a.version, a.asset_type, a.status,
+    a.storage_backend, a.storage_uri, a.storage_path, a.size_bytes,
+    a.checksum_algorithm, a.checksum_value,
+    a.signature_algorithm, a.signature_value, a.signature_key_id,
+    a.description, a.license, a.content_type,
+    a.author, a.source_repo, a.commit_hash, a.build_id,
+    a.created_at, a.updated_at, a.deprecated_at, a.metadata, a.revision
+"#;
 
 /// PostgreSQL implementation of AssetRepository
 #[derive(Debug, Clone)]
@@ -36,17 +51,9 @@ impl PostgresAssetRepository {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
-}
-
-#[async_trait]
-impl AssetRepository for PostgresAssetRepository {
-    #[instrument(skip(self, asset), fields(asset_id = %asset.id, asset_name = %asset.metadata.name))]
-    async fn create(&self, asset: Asset) -> DbResult<Asset> {
-        debug!("Creating asset in database");
-
-        // Start a transaction
-        let mut tx = self.pool.begin().await?;
 
+    /// Insert an asset with its tags and dependencies on the given connection
+    pub(crate) async fn insert_asset(conn: &mut sqlx::PgConnection, asset: &Asset) -> DbResult<()> {
         // Insert main asset record
         sqlx::query(
             r#"
@@ -57,7 +64,8 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, metadata, revision,
+                search_vector
             ) VALUES (
                 $1, $2, $3, $4, $5,
                 $6, $7, $8, $9,
@@ -65,7 +73,8 @@ impl AssetRepository for PostgresAssetRepository {
                 $12, $13, $14,
                 $15, $16, $17,
                 $18, $19, $20, $21,
-                $22, $23, $24, $25
+                $22, $23, $24, $25, $27,
+                asset_search_vector($2::text, $15::text, $26::text[], $25::jsonb)
             )
             "#,
         )
@@ -75,26 +84,53 @@ impl AssetRepository for PostgresAssetRepository {
         .bind(&asset.asset_type.to_string())
         .bind(&asset.status.to_string())
         .bind(&asset.storage.backend.to_string())
-        .bind(asset.storage.uri.as_ref().unwrap_or(&asset.storage.get_uri()))
-        .bind(if asset.storage.path.is_empty() { None } else { Some(&asset.storage.path) })
+        .bind(
+            asset
+                .storage
+                .uri
+                .as_ref()
+                .unwrap_or(&asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
         .bind(asset.metadata.size_bytes.map(|s| s as i64))
         .bind(&asset.checksum.algorithm.to_string())
         .bind(&asset.checksum.value)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
+        .bind(asset.signature.as_ref().map(|s| s.algorithm.to_string()))
+        .bind(asset.signature.as_ref().map(|s| s.value.as_str()))
+        .bind(asset.signature.as_ref().map(|s| s.key_id.as_str()))
         .bind(&asset.metadata.description)
         .bind(&asset.metadata.license)
         .bind(&asset.metadata.content_type)
         .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.source_repo.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.commit_hash.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.build_id.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
         .bind(&asset.created_at)
         .bind(&asset.updated_at)
         .bind(&asset.deprecated_at)
         .bind(serde_json::to_value(&asset.metadata.annotations)?)
-        .execute(&mut *tx)
+        .bind(&asset.metadata.tags)
+        .bind(asset.revision as i64)
+        .execute(&mut *conn)
         .await?;
 
         // Insert tags
@@ -108,14 +144,16 @@ impl AssetRepository for PostgresAssetRepository {
             )
             .bind(&asset.id.to_string())
             .bind(tag)
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await?;
         }
 
         // Insert dependencies
         for dep in &asset.dependencies {
             let dep_id = dep.as_id().ok_or_else(|| {
-                DbError::InvalidData("Dependency must be resolved to ID before persisting".to_string())
+                DbError::InvalidData(
+                    "Dependency must be resolved to ID before persisting".to_string(),
+                )
             })?;
 
             sqlx::query(
@@ -128,17 +166,213 @@ impl AssetRepository for PostgresAssetRepository {
             .bind(&asset.id.to_string())
             .bind(&dep_id.to_string())
             .bind(dep.as_name_version().map(|(_, v)| v))
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await?;
         }
 
-        // Commit transaction
+        Ok(())
+    }
+
+    /// Update an asset and replace its tags on the given connection
+    ///
+    /// The update only applies while the stored revision still equals
+    /// `asset.revision`, and increments it. A stale revision fails with
+    /// [`DbError::Conflict`].
+    pub(crate) async fn update_asset(conn: &mut sqlx::PgConnection, asset: &Asset) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE assets SET
+                name = $2,
+                version = $3,
+                asset_type = $4,
+                status = $5,
+                storage_backend = $6,
+                storage_uri = $7,
+                storage_path = $8,
+                size_bytes = $9,
+                checksum_algorithm = $10,
+                checksum_value = $11,
+                signature_algorithm = $12,
+                signature_value = $13,
+                signature_key_id = $14,
+                description = $15,
+                license = $16,
+                content_type = $17,
+                author = $18,
+                source_repo = $19,
+                commit_hash = $20,
+                build_id = $21,
+                deprecated_at = $22,
+                metadata = $23,
+                updated_at = $24,
+                search_vector = asset_search_vector($2::text, $15::text, $25::text[], $23::jsonb),
+                revision = revision + 1
+            WHERE id = $1 AND revision = $26
+            "#,
+        )
+        .bind(&asset.id.to_string())
+        .bind(&asset.metadata.name)
+        .bind(&asset.metadata.version.to_string())
+        .bind(&asset.asset_type.to_string())
+        .bind(&asset.status.to_string())
+        .bind(&asset.storage.backend.to_string())
+        .bind(
+            asset
+                .storage
+                .uri
+                .as_ref()
+                .unwrap_or(&asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
+        .bind(asset.metadata.size_bytes.map(|s| s as i64))
+        .bind(&asset.checksum.algorithm.to_string())
+        .bind(&asset.checksum.value)
+        .bind(asset.signature.as_ref().map(|s| s.algorithm.to_string()))
+        .bind(asset.signature.as_ref().map(|s| s.value.as_str()))
+        .bind(asset.signature.as_ref().map(|s| s.key_id.as_str()))
+        .bind(&asset.metadata.description)
+        .bind(&asset.metadata.license)
+        .bind(&asset.metadata.content_type)
+        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
+        .bind(&asset.deprecated_at)
+        .bind(serde_json::to_value(&asset.metadata.annotations)?)
+        .bind(Utc::now())
+        .bind(&asset.metadata.tags)
+        .bind(asset.revision as i64)
+        .execute(&mut *conn)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            let current: Option<i64> =
+                sqlx::query_scalar("SELECT revision FROM assets WHERE id = $1")
+                    .bind(asset.id.to_string())
+                    .fetch_optional(&mut *conn)
+                    .await?;
+
+            return Err(match current {
+                Some(current) => {
+                    DbError::revision_conflict(asset.id, asset.revision, current as u64)
+                }
+                None => DbError::NotFound(format!("Asset {} not found", asset.id)),
+            });
+        }
+
+        // Update tags - delete and re-insert for simplicity
+        sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1")
+            .bind(&asset.id.to_string())
+            .execute(&mut *conn)
+            .await?;
+
+        for tag in &asset.metadata.tags {
+            sqlx::query(
+                r#"
+                INSERT INTO asset_tags (asset_id, tag)
+                VALUES ($1, $2)
+                "#,
+            )
+            .bind(&asset.id.to_string())
+            .bind(tag)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Recompute an asset's search vector after its tags changed
+    ///
+    /// Like [`Self::update_asset`], this increments the revision so that
+    /// writers holding the previous tags fail with a conflict.
+    async fn touch_tags(conn: &mut sqlx::PgConnection, id: &AssetId) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE assets a SET
+                search_vector = asset_search_vector(
+                    a.name,
+                    a.description,
+                    ARRAY(SELECT t.tag FROM asset_tags t WHERE t.asset_id = a.id ORDER BY t.tag),
+                    a.metadata
+                ),
+                updated_at = $2,
+                revision = a.revision + 1
+            WHERE a.id = $1
+            "#,
+        )
+        .bind(&id.to_string())
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Delete an asset on the given connection
+    ///
+    /// Tags and dependency edges are removed by `ON DELETE CASCADE`.
+    pub(crate) async fn delete_asset(conn: &mut sqlx::PgConnection, id: &AssetId) -> DbResult<()> {
+        let result = sqlx::query("DELETE FROM assets WHERE id = $1")
+            .bind(&id.to_string())
+            .execute(&mut *conn)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!("Asset {} not found", id)));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AssetRepository for PostgresAssetRepository {
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id, asset_name = %asset.metadata.name))]
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        debug!("Creating asset in database");
+
+        let mut tx = self.pool.begin().await?;
+        Self::insert_asset(&mut tx, &asset).await?;
         tx.commit().await?;
 
         debug!("Asset created successfully");
         Ok(asset)
     }
 
+    #[instrument(skip(self, assets), fields(count = assets.len()))]
+    async fn create_batch(&self, assets: Vec<Asset>) -> DbResult<Vec<Asset>> {
+        debug!("Creating asset batch in database");
+
+        let mut tx = self.pool.begin().await?;
+        for asset in &assets {
+            Self::insert_asset(&mut tx, asset).await?;
+        }
+        tx.commit().await?;
+
+        debug!("Asset batch created successfully");
+        Ok(assets)
+    }
+
     #[instrument(skip(self), fields(asset_id = %id))]
     async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
         debug!("Finding asset by ID");
@@ -152,7 +386,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, metadata, revision
             FROM assets
             WHERE id = $1
             "#,
@@ -188,7 +422,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, metadata, revision
             FROM assets
             WHERE name = $1 AND version = $2
             "#,
@@ -227,7 +461,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, metadata, revision
             FROM assets
             WHERE id = ANY($1)
             "#,
@@ -250,143 +484,95 @@ impl AssetRepository for PostgresAssetRepository {
     async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
         debug!("Searching assets with filters");
 
-        // Build dynamic query
-        let mut sql = String::from(
-            r#"
-            SELECT
-                a.id, a.name, a.version, a.asset_type, a.status,
-                a.storage_backend, a.storage_uri, a.storage_path, a.size_bytes,
-                a.checksum_algorithm, a.checksum_value,
-                a.signature_algorithm, a.signature_value, a.signature_key_id,
-                a.description, a.license, a.content_type,
-                a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
-            FROM assets a
-            WHERE 1=1
-            "#,
-        );
-
-        let mut conditions = Vec::new();
-        let mut bind_values: Vec<String> = Vec::new();
-        let mut param_num = 1;
-
-        // Text search
-        if let Some(ref text) = query.text {
-            conditions.push(format!(
-                "(a.name ILIKE ${} OR a.description ILIKE ${})",
-                param_num, param_num + 1
-            ));
-            bind_values.push(format!("%{}%", text));
-            bind_values.push(format!("%{}%", text));
-            param_num += 2;
-        }
-
-        // Asset type filter
-        if !query.asset_types.is_empty() {
-            conditions.push(format!("a.asset_type = ANY(${})", param_num));
-            // This is a placeholder - we'll use a different approach for ANY
-            param_num += 1;
+        let text = search_text(query);
+
+        let mut builder = QueryBuilder::<Postgres>::new(format!("SELECT {}", ASSET_COLUMNS));
+        if text.is_some() {
+            // Highlight the description when it matches, otherwise the name
+            builder
+                .push(
+                    ", ts_rank_cd(a.search_vector, q) AS rank, \
+                     ts_headline('english', \
+                         CASE WHEN to_tsvector('english', coalesce(a.description, '')) @@ q \
+                         THEN a.description ELSE a.name END, \
+                         q, ",
+                )
+                .push_bind(headline_options())
+                .push(") AS snippet");
         }
-
-        // Author filter
-        if let Some(ref author) = query.author {
-            conditions.push(format!("a.author = ${}", param_num));
-            bind_values.push(author.clone());
-            param_num += 1;
-        }
-
-        // Storage backend filter
-        if let Some(ref backend) = query.storage_backend {
-            conditions.push(format!("a.storage_backend = ${}", param_num));
-            bind_values.push(backend.clone());
-            param_num += 1;
-        }
-
-        // Deprecated filter
-        if query.exclude_deprecated {
-            conditions.push("a.deprecated_at IS NULL".to_string());
-        }
-
-        // Tag filter - must have all specified tags
-        if !query.tags.is_empty() {
-            let tag_condition = format!(
-                "a.id IN (
-                    SELECT asset_id FROM asset_tags
-                    WHERE tag = ANY(${}::text[])
-                    GROUP BY asset_id
-                    HAVING COUNT(DISTINCT tag) = {}
-                )",
-                param_num,
-                query.tags.len()
-            );
-            conditions.push(tag_condition);
-            #[allow(unused_assignments)]
-            {
-                param_num += 1;
-            }
-        }
-
-        // Add conditions to query
-        if !conditions.is_empty() {
-            sql.push_str(" AND ");
-            sql.push_str(&conditions.join(" AND "));
-        }
-
-        // Add ORDER BY
-        let sort_field = match query.sort_by {
-            SortField::CreatedAt => "a.created_at",
-            SortField::UpdatedAt => "a.updated_at",
-            SortField::Name => "a.name",
-            SortField::Version => "a.version",
-            SortField::SizeBytes => "a.size_bytes",
-        };
+        push_search_source(&mut builder, text);
+        push_search_filters(&mut builder, query);
 
         let sort_order = match query.sort_order {
             SortOrder::Ascending => "ASC",
             SortOrder::Descending => "DESC",
         };
 
-        sql.push_str(&format!(" ORDER BY {} {}", sort_field, sort_order));
-
-        // Add LIMIT and OFFSET
-        sql.push_str(&format!(" LIMIT {} OFFSET {}", query.limit, query.offset));
-
-        // For simplicity, we'll use a simpler approach - rebuild with sqlx query builder
-        // In production, you'd want to use a query builder or macro for this
-        let mut final_query = sqlx::query(&sql);
-
-        // Bind parameters in order
-        for value in &bind_values {
-            final_query = final_query.bind(value);
-        }
-
-        let types: Vec<String> = query.asset_types.iter().map(|t| t.to_string()).collect();
-        if !query.asset_types.is_empty() {
-            final_query = final_query.bind(&types);
-        }
-
-        if !query.tags.is_empty() {
-            final_query = final_query.bind(&query.tags);
+        let resume_after = query.resume_after()?;
+        match query.keyset_field() {
+            Some(field) => {
+                if let Some(cursor) = resume_after {
+                    push_keyset_condition(
+                        &mut builder,
+                        field.column(),
+                        "a.id",
+                        cursor,
+                        query.sort_order,
+                    );
+                }
+                builder.push(format!(
+                    " ORDER BY {} {}, a.id {}",
+                    field.column(),
+                    sort_order,
+                    sort_order
+                ));
+            }
+            None => {
+                builder.push(format!(
+                    " ORDER BY rank {}, a.created_at {}",
+                    sort_order, sort_order
+                ));
+            }
         }
+        builder.push(" LIMIT ").push_bind(query.fetch_limit());
+        builder.push(" OFFSET ").push_bind(query.effective_offset());
 
-        let rows = final_query.fetch_all(&self.pool).await?;
+        let rows = builder.build().fetch_all(&self.pool).await?;
 
-        let mut assets = Vec::new();
+        let mut assets = Vec::with_capacity(rows.len());
+        let mut snippets = HashMap::new();
         for row in rows {
-            let asset = row_to_asset(row)?;
-            let asset = self.load_asset_relations(asset).await?;
+            let snippet: Option<String> = match text {
+                Some(_) => row.get("snippet"),
+                None => None,
+            };
+            let asset = self.load_asset_relations(row_to_asset(row)?).await?;
+            if let Some(snippet) = snippet {
+                snippets.insert(asset.id, snippet);
+            }
             assets.push(asset);
         }
 
-        // Get total count (without pagination)
-        let total = self.count_search_results(query).await?;
+        let total = if query.counts_total() {
+            let mut count_builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) AS count");
+            push_search_source(&mut count_builder, text);
+            push_search_filters(&mut count_builder, query);
+            Some(
+                count_builder
+                    .build()
+                    .fetch_one(&self.pool)
+                    .await?
+                    .get("count"),
+            )
+        } else {
+            None
+        };
+
+        let mut results = SearchResults::page(query, assets, total);
+        snippets.retain(|id, _| results.assets.iter().any(|asset| asset.id == *id));
+        results.snippets = snippets;
 
-        Ok(SearchResults {
-            assets,
-            total,
-            offset: query.offset,
-            limit: query.limit,
-        })
+        Ok(results)
     }
 
     #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
@@ -394,104 +580,22 @@ impl AssetRepository for PostgresAssetRepository {
         debug!("Updating asset");
 
         let mut tx = self.pool.begin().await?;
-
-        let result = sqlx::query(
-            r#"
-            UPDATE assets SET
-                name = $2,
-                version = $3,
-                asset_type = $4,
-                status = $5,
-                storage_backend = $6,
-                storage_uri = $7,
-                storage_path = $8,
-                size_bytes = $9,
-                checksum_algorithm = $10,
-                checksum_value = $11,
-                signature_algorithm = $12,
-                signature_value = $13,
-                signature_key_id = $14,
-                description = $15,
-                license = $16,
-                content_type = $17,
-                author = $18,
-                source_repo = $19,
-                commit_hash = $20,
-                build_id = $21,
-                deprecated_at = $22,
-                metadata = $23,
-                updated_at = $24
-            WHERE id = $1
-            "#,
-        )
-        .bind(&asset.id.to_string())
-        .bind(&asset.metadata.name)
-        .bind(&asset.metadata.version.to_string())
-        .bind(&asset.asset_type.to_string())
-        .bind(&asset.status.to_string())
-        .bind(&asset.storage.backend.to_string())
-        .bind(asset.storage.uri.as_ref().unwrap_or(&asset.storage.get_uri()))
-        .bind(if asset.storage.path.is_empty() { None } else { Some(&asset.storage.path) })
-        .bind(asset.metadata.size_bytes.map(|s| s as i64))
-        .bind(&asset.checksum.algorithm.to_string())
-        .bind(&asset.checksum.value)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
-        .bind(&asset.metadata.description)
-        .bind(&asset.metadata.license)
-        .bind(&asset.metadata.content_type)
-        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.source_repo.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.commit_hash.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.build_id.as_deref()))
-        .bind(&asset.deprecated_at)
-        .bind(serde_json::to_value(&asset.metadata.annotations)?)
-        .bind(Utc::now())
-        .execute(&mut *tx)
-        .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Asset {} not found", asset.id)));
-        }
-
-        // Update tags - delete and re-insert for simplicity
-        sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1")
-            .bind(&asset.id.to_string())
-            .execute(&mut *tx)
-            .await?;
-
-        for tag in &asset.metadata.tags {
-            sqlx::query(
-                r#"
-                INSERT INTO asset_tags (asset_id, tag)
-                VALUES ($1, $2)
-                "#,
-            )
-            .bind(&asset.id.to_string())
-            .bind(tag)
-            .execute(&mut *tx)
-            .await?;
-        }
-
+        Self::update_asset(&mut tx, &asset).await?;
         tx.commit().await?;
 
         debug!("Asset updated successfully");
-        Ok(asset)
+        Ok(Asset {
+            revision: asset.revision + 1,
+            ..asset
+        })
     }
 
     #[instrument(skip(self), fields(asset_id = %id))]
     async fn delete(&self, id: &AssetId) -> DbResult<()> {
         debug!("Deleting asset");
 
-        let result = sqlx::query("DELETE FROM assets WHERE id = $1")
-            .bind(&id.to_string())
-            .execute(&self.pool)
-            .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Asset {} not found", id)));
-        }
+        let mut conn = self.pool.acquire().await?;
+        Self::delete_asset(&mut conn, id).await?;
 
         debug!("Asset deleted successfully");
         Ok(())
@@ -510,7 +614,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, metadata, revision
             FROM assets
             WHERE name = $1
             ORDER BY created_at DESC
@@ -543,7 +647,7 @@ impl AssetRepository for PostgresAssetRepository {
                 a.signature_algorithm, a.signature_value, a.signature_key_id,
                 a.description, a.license, a.content_type,
                 a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
+                a.created_at, a.updated_at, a.deprecated_at, a.metadata, a.revision
             FROM assets a
             INNER JOIN asset_dependencies d ON a.id = d.dependency_id
             WHERE d.asset_id = $1
@@ -576,7 +680,7 @@ impl AssetRepository for PostgresAssetRepository {
                 a.signature_algorithm, a.signature_value, a.signature_key_id,
                 a.description, a.license, a.content_type,
                 a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
+                a.created_at, a.updated_at, a.deprecated_at, a.metadata, a.revision
             FROM assets a
             INNER JOIN asset_dependencies d ON a.id = d.asset_id
             WHERE d.dependency_id = $1
@@ -596,11 +700,49 @@ impl AssetRepository for PostgresAssetRepository {
         Ok(assets)
     }
 
+    #[instrument(skip(self, names))]
+    async fn find_by_names(&self, names: &[String]) -> DbResult<Vec<Asset>> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        debug!("Finding versions of {} assets by name", names.len());
+
+        let sql = format!(
+            "SELECT {} FROM assets a WHERE a.name = ANY($1)",
+            ASSET_COLUMNS
+        );
+        let rows = sqlx::query(&sql).bind(names).fetch_all(&self.pool).await?;
+
+        let mut assets = Vec::new();
+        for row in rows {
+            let asset = row_to_asset(row)?;
+            let asset = self.load_asset_relations(asset).await?;
+            assets.push(asset);
+        }
+
+        Ok(assets)
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn list_dependency_links(&self, ids: &[AssetId]) -> DbResult<Vec<DependencyLink>> {
+        self.dependency_links("asset_id", ids).await
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn list_reverse_dependency_links(
+        &self,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        self.dependency_links("dependency_id", ids).await
+    }
+
     #[instrument(skip(self), fields(asset_id = %id, tag = %tag))]
     async fn add_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
         debug!("Adding tag to asset");
 
-        sqlx::query(
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query(
             r#"
             INSERT INTO asset_tags (asset_id, tag)
             VALUES ($1, $2)
@@ -609,9 +751,14 @@ impl AssetRepository for PostgresAssetRepository {
         )
         .bind(&id.to_string())
         .bind(tag)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        if result.rows_affected() > 0 {
+            Self::touch_tags(&mut tx, id).await?;
+        }
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -619,12 +766,18 @@ impl AssetRepository for PostgresAssetRepository {
     async fn remove_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
         debug!("Removing tag from asset");
 
-        sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1 AND tag = $2")
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1 AND tag = $2")
             .bind(&id.to_string())
             .bind(tag)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
+        if result.rows_affected() > 0 {
+            Self::touch_tags(&mut tx, id).await?;
+        }
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -637,10 +790,7 @@ impl AssetRepository for PostgresAssetRepository {
             .fetch_all(&self.pool)
             .await?;
 
-        let tags = rows
-            .iter()
-            .map(|row| row.get::<String, _>("tag"))
-            .collect();
+        let tags = rows.iter().map(|row| row.get::<String, _>("tag")).collect();
 
         Ok(tags)
     }
@@ -653,10 +803,7 @@ impl AssetRepository for PostgresAssetRepository {
             .fetch_all(&self.pool)
             .await?;
 
-        let tags = rows
-            .iter()
-            .map(|row| row.get::<String, _>("tag"))
-            .collect();
+        let tags = rows.iter().map(|row| row.get::<String, _>("tag")).collect();
 
         Ok(tags)
     }
@@ -696,11 +843,7 @@ impl AssetRepository for PostgresAssetRepository {
     }
 
     #[instrument(skip(self))]
-    async fn remove_dependency(
-        &self,
-        asset_id: &AssetId,
-        dependency_id: &AssetId,
-    ) -> DbResult<()> {
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
         debug!("Removing dependency relationship");
 
         sqlx::query("DELETE FROM asset_dependencies WHERE asset_id = $1 AND dependency_id = $2")
@@ -742,6 +885,31 @@ impl AssetRepository for PostgresAssetRepository {
 }
 
 impl PostgresAssetRepository {
+    /// Load the dependency links whose `column` is one of `ids`
+    async fn dependency_links(
+        &self,
+        column: &'static str,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let id_strings: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        let sql = format!(
+            "SELECT asset_id, dependency_id FROM asset_dependencies WHERE {} = ANY($1)",
+            column
+        );
+        let rows = sqlx::query(&sql)
+            .bind(&id_strings)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter()
+            .map(|row| parse_dependency_link(row.get("asset_id"), row.get("dependency_id")))
+            .collect()
+    }
+
     /// Load tags and dependencies for an asset
     async fn load_asset_relations(&self, mut asset: Asset) -> DbResult<Asset> {
         // Load tags
@@ -749,12 +917,11 @@ impl PostgresAssetRepository {
         asset.metadata.tags = tags;
 
         // Load dependency references
-        let dep_rows = sqlx::query(
-            "SELECT dependency_id FROM asset_dependencies WHERE asset_id = $1"
-        )
-        .bind(&asset.id.to_string())
-        .fetch_all(&self.pool)
-        .await?;
+        let dep_rows =
+            sqlx::query("SELECT dependency_id FROM asset_dependencies WHERE asset_id = $1")
+                .bind(&asset.id.to_string())
+                .fetch_all(&self.pool)
+                .await?;
 
         asset.dependencies = dep_rows
             .iter()
@@ -798,25 +965,109 @@ impl PostgresAssetRepository {
 
         Ok(row.get("has_cycle"))
     }
+}
 
-    /// Count search results without pagination
-    async fn count_search_results(&self, query: &SearchQuery) -> DbResult<i64> {
-        // Simplified count query - in production, this should mirror the search logic
-        let mut sql = String::from("SELECT COUNT(*) as count FROM assets a WHERE 1=1");
+/// Get the trimmed search text of a query, if any
+fn search_text(query: &SearchQuery) -> Option<&str> {
+    query
+        .text
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
 
-        if query.exclude_deprecated {
-            sql.push_str(" AND a.deprecated_at IS NULL");
-        }
+/// Options passed to `ts_headline` when building snippets
+fn headline_options() -> String {
+    format!(
+        "StartSel={}, StopSel={}, MaxWords=35, MinWords=15, MaxFragments=2, FragmentDelimiter=\" ... \"",
+        SNIPPET_START, SNIPPET_END
+    )
+}
 
-        if !query.asset_types.is_empty() {
-            let types: Vec<String> = query.asset_types.iter().map(|t| t.to_string()).collect();
-            let placeholders: Vec<String> = types.iter().map(|t| format!("'{}'", t)).collect();
-            sql.push_str(&format!(" AND a.asset_type IN ({})", placeholders.join(", ")));
-        }
+/// Append the FROM clause for a search query
+///
+/// With search text, the parsed `tsquery` is joined in as `q` so the filter,
+/// rank and snippet expressions share a single parse.
+fn push_search_source<'a>(builder: &mut QueryBuilder<'a, Postgres>, text: Option<&'a str>) {
+    builder.push(" FROM assets a");
+    if let Some(text) = text {
+        builder
+            .push(", websearch_to_tsquery('english', ")
+            .push_bind(text)
+            .push(") q");
+    }
+    builder.push(" WHERE 1=1");
+}
 
-        let row = sqlx::query(&sql).fetch_one(&self.pool).await?;
+/// Append the WHERE conditions for a search query
+///
+/// Shared by the search and count queries so the reported total always
+/// matches the filters applied to the page.
+fn push_search_filters(builder: &mut QueryBuilder<'_, Postgres>, query: &SearchQuery) {
+    if search_text(query).is_some() {
+        builder.push(" AND a.search_vector @@ q");
+    }
 
-        Ok(row.get("count"))
+    if !query.asset_types.is_empty() {
+        let types: Vec<String> = query.asset_types.iter().map(|t| t.to_string()).collect();
+        builder
+            .push(" AND a.asset_type = ANY(")
+            .push_bind(types)
+            .push(")");
+    }
+
+    if let Some(ref author) = query.author {
+        builder.push(" AND a.author = ").push_bind(author.clone());
+    }
+
+    if let Some(ref backend) = query.storage_backend {
+        builder
+            .push(" AND a.storage_backend = ")
+            .push_bind(backend.clone());
+    }
+
+    if let Some(ref license) = query.license {
+        builder.push(" AND a.license = ").push_bind(license.clone());
+    }
+
+    // Sizes are stored as signed integers; larger bounds are clamped
+    if let Some(min) = query.min_size_bytes {
+        builder
+            .push(" AND a.size_bytes >= ")
+            .push_bind(i64::try_from(min).unwrap_or(i64::MAX));
+    }
+    if let Some(max) = query.max_size_bytes {
+        builder
+            .push(" AND a.size_bytes <= ")
+            .push_bind(i64::try_from(max).unwrap_or(i64::MAX));
+    }
+
+    if let Some(after) = query.created_after {
+        builder.push(" AND a.created_at >= ").push_bind(after);
+    }
+    if let Some(before) = query.created_before {
+        builder.push(" AND a.created_at < ").push_bind(before);
+    }
+
+    if query.exclude_deprecated {
+        builder.push(" AND a.deprecated_at IS NULL");
+    }
+
+    // Tag filter - must have all specified tags
+    if !query.tags.is_empty() {
+        builder
+            .push(" AND a.id IN (SELECT asset_id FROM asset_tags WHERE tag = ANY(")
+            .push_bind(query.tags.clone())
+            .push(") GROUP BY asset_id HAVING COUNT(DISTINCT tag) = ")
+            .push_bind(query.tags.len() as i64)
+            .push(")");
+    }
+
+    if !query.exclude_tags.is_empty() {
+        builder
+            .push(" AND NOT EXISTS (SELECT 1 FROM asset_tags t WHERE t.asset_id = a.id AND t.tag = ANY(")
+            .push_bind(query.exclude_tags.clone())
+            .push("))");
     }
 }
 
@@ -847,14 +1098,15 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
     let checksum_value: String = row.get("checksum_value");
 
     let metadata_json: JsonValue = row.get("metadata");
-    let annotations: HashMap<String, String> = serde_json::from_value(metadata_json)
-        .unwrap_or_default();
+    let annotations: HashMap<String, String> =
+        serde_json::from_value(metadata_json).unwrap_or_default();
 
     let created_at: DateTime<Utc> = row.get("created_at");
     let updated_at: DateTime<Utc> = row.get("updated_at");
     let deprecated_at: Option<DateTime<Utc>> = row.get("deprecated_at");
 
     let size_bytes: Option<i64> = row.get("size_bytes");
+    let revision: i64 = row.get("revision");
 
     let provenance = {
         let author: Option<String> = row.get("author");
@@ -898,6 +1150,21 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
         value: checksum_value,
     };
 
+    let signature = {
+        let algorithm: Option<String> = row.get("signature_algorithm");
+        let value: Option<String> = row.get("signature_value");
+        let key_id: Option<String> = row.get("signature_key_id");
+
+        match (algorithm, value, key_id) {
+            (Some(algorithm), Some(value), Some(key_id)) => Some(AssetSignature {
+                algorithm: parse_signature_algorithm(&algorithm)?,
+                key_id,
+                value,
+            }),
+            _ => None,
+        }
+    };
+
     Ok(Asset {
         id,
         asset_type,
@@ -905,15 +1172,30 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
         status,
         storage,
         checksum,
+        signature,
         provenance,
         dependencies: Vec::new(), // Loaded separately
         created_at,
         updated_at,
         deprecated_at,
+        revision: revision as u64,
     })
 }
 
-fn parse_asset_type(s: &str) -> DbResult<AssetType> {
+pub(crate) fn parse_dependency_link(
+    asset_id: &str,
+    dependency_id: &str,
+) -> DbResult<DependencyLink> {
+    let parse = |id: &str| {
+        AssetId::from_str(id).map_err(|e| DbError::InvalidData(format!("Invalid asset ID: {}", e)))
+    };
+    Ok(DependencyLink {
+        asset_id: parse(asset_id)?,
+        dependency_id: parse(dependency_id)?,
+    })
+}
+
+pub(crate) fn parse_asset_type(s: &str) -> DbResult<AssetType> {
     match s {
         "model" => Ok(AssetType::Model),
         "pipeline" => Ok(AssetType::Pipeline),
@@ -925,12 +1207,17 @@ fn parse_asset_type(s: &str) -> DbResult<AssetType> {
     }
 }
 
-fn parse_asset_status(s: &str) -> DbResult<AssetStatus> {
+pub(crate) fn parse_signature_algorithm(s: &str) -> DbResult<SignatureAlgorithm> {
+    SignatureAlgorithm::from_str(s)
+        .map_err(|e| DbError::InvalidData(format!("Invalid signature algorithm: {}", e)))
+}
+
+pub(crate) fn parse_asset_status(s: &str) -> DbResult<AssetStatus> {
     AssetStatus::from_str(s)
         .map_err(|e| DbError::InvalidData(format!("Invalid asset status: {}", e)))
 }
 
-fn parse_storage_backend_from_db(s: &str) -> DbResult<StorageBackend> {
+pub(crate) fn parse_storage_backend_from_db(s: &str) -> DbResult<StorageBackend> {
     // For simplicity in the database, we'll store just the backend type as a string
     // and reconstruct minimal backend config. In a real system, you'd store full JSON.
     match s {
@@ -961,7 +1248,7 @@ fn parse_storage_backend_from_db(s: &str) -> DbResult<StorageBackend> {
     }
 }
 
-fn parse_hash_algorithm(s: &str) -> DbResult<HashAlgorithm> {
+pub(crate) fn parse_hash_algorithm(s: &str) -> DbResult<HashAlgorithm> {
     HashAlgorithm::from_str(s)
         .map_err(|e| DbError::InvalidData(format!("Invalid hash algorithm: {}", e)))
 }
@@ -0,0 +1,372 @@
+//! Event-sourced asset projections
+//!
+//! Replays the events of a single asset to rebuild its state as of any point
+//! in the event log. Registration events carry a snapshot of the asset as
+//! registered, and update events carry the old and new values of every
+//! changed field, so folding the events in order reproduces each revision.
+//!
+//! Assets registered before snapshots were recorded cannot be replayed.
+
+use chrono::{DateTime, Utc};
+use llm_registry_core::{Asset, AssetId, EventType, FieldChange, RegistryEvent};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+use crate::error::{DbError, DbResult};
+use crate::event_store::{EventQuery, EventStore};
+
+/// Page size used when loading an asset's events
+const REPLAY_PAGE_SIZE: i64 = 500;
+
+/// Point in the event log to project an asset at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsOf {
+    /// Include every event at or before this time
+    Timestamp(DateTime<Utc>),
+    /// Include events up to and including this event
+    Event(String),
+}
+
+/// One entry in an asset's history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AssetRevision {
+    /// Revision of the asset after the event
+    pub revision: u64,
+
+    /// ID of the event that produced the revision
+    pub event_id: String,
+
+    /// Name of the event, e.g. `asset_updated`
+    pub event_type: String,
+
+    /// When the event occurred
+    pub timestamp: DateTime<Utc>,
+
+    /// User or service that triggered the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+
+    /// Fields changed by the event, empty for registration and deletion
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+/// State of a single asset rebuilt from its events
+#[derive(Debug, Clone, Default)]
+pub struct AssetProjection {
+    asset: Option<Asset>,
+    history: Vec<AssetRevision>,
+}
+
+impl AssetProjection {
+    /// Create an empty projection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replay events, oldest first
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a RegistryEvent>) -> DbResult<Self> {
+        let mut projection = Self::new();
+        for event in events {
+            projection.apply(event)?;
+        }
+        Ok(projection)
+    }
+
+    /// Apply the next event
+    ///
+    /// Events that do not change asset state, such as downloads and
+    /// verifications, are ignored.
+    pub fn apply(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        match &event.event_type {
+            EventType::AssetRegistered {
+                asset_id, snapshot, ..
+            } => {
+                let asset = snapshot
+                    .as_deref()
+                    .cloned()
+                    .ok_or_else(|| not_replayable(asset_id))?;
+                self.record(event, asset.revision, Vec::new());
+                self.asset = Some(asset);
+            }
+            EventType::AssetUpdated {
+                asset_id,
+                changes,
+                revision,
+                ..
+            } => {
+                let asset = self
+                    .asset
+                    .as_mut()
+                    .ok_or_else(|| not_replayable(asset_id))?;
+                for change in changes {
+                    asset.apply_change(change)?;
+                }
+                asset.updated_at = event.timestamp;
+                asset.revision = revision.unwrap_or(asset.revision + 1);
+
+                let revision = asset.revision;
+                self.record(event, revision, changes.clone());
+            }
+            EventType::AssetDeleted { .. } => {
+                if let Some(asset) = self.asset.take() {
+                    self.record(event, asset.revision, Vec::new());
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// The asset, or `None` if it was not registered yet or has been deleted
+    pub fn asset(&self) -> Option<&Asset> {
+        self.asset.as_ref()
+    }
+
+    /// Take the projected asset
+    pub fn into_asset(self) -> Option<Asset> {
+        self.asset
+    }
+
+    /// Revisions produced by the replayed events, oldest first
+    pub fn history(&self) -> &[AssetRevision] {
+        &self.history
+    }
+
+    fn record(&mut self, event: &RegistryEvent, revision: u64, changes: Vec<FieldChange>) {
+        self.history.push(AssetRevision {
+            revision,
+            event_id: event.event_id.clone(),
+            event_type: event.event_name().to_string(),
+            timestamp: event.timestamp,
+            actor: event.actor.clone(),
+            changes,
+        });
+    }
+}
+
+/// Builds asset projections from an event store
+#[derive(Clone)]
+pub struct ProjectionEngine {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl ProjectionEngine {
+    /// Create a projection engine reading from an event store
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+
+    /// Project an asset, replaying its events up to `as_of`
+    ///
+    /// Replays the whole history when `as_of` is `None`. Fails with
+    /// [`DbError::NotFound`] if `as_of` names an event that does not belong
+    /// to the asset.
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    pub async fn project(
+        &self,
+        asset_id: &AssetId,
+        as_of: Option<&AsOf>,
+    ) -> DbResult<AssetProjection> {
+        let events = self.asset_events(asset_id).await?;
+
+        let end = match as_of {
+            None => events.len(),
+            Some(AsOf::Timestamp(timestamp)) => {
+                events.partition_point(|event| event.timestamp <= *timestamp)
+            }
+            Some(AsOf::Event(event_id)) => {
+                events
+                    .iter()
+                    .position(|event| &event.event_id == event_id)
+                    .ok_or_else(|| {
+                        DbError::NotFound(format!(
+                            "Event {} not found for asset {}",
+                            event_id, asset_id
+                        ))
+                    })?
+                    + 1
+            }
+        };
+
+        debug!(events = end, "Replaying asset events");
+        AssetProjection::replay(&events[..end])
+    }
+
+    /// Rebuild an asset as of a point in the event log
+    ///
+    /// Returns `None` if the asset was not registered yet or had been
+    /// deleted at that point.
+    pub async fn asset_as_of(&self, asset_id: &AssetId, as_of: &AsOf) -> DbResult<Option<Asset>> {
+        Ok(self.project(asset_id, Some(as_of)).await?.into_asset())
+    }
+
+    /// List an asset's revisions with their field-level changes, oldest first
+    pub async fn history(&self, asset_id: &AssetId) -> DbResult<Vec<AssetRevision>> {
+        Ok(self.project(asset_id, None).await?.history)
+    }
+
+    /// Load all events of an asset, oldest first
+    async fn asset_events(&self, asset_id: &AssetId) -> DbResult<Vec<RegistryEvent>> {
+        let mut events = Vec::new();
+        let mut query = EventQuery::new()
+            .asset_id(*asset_id)
+            .limit(REPLAY_PAGE_SIZE)
+            .include_total(false);
+
+        loop {
+            let page = self.event_store.query(&query).await?;
+            events.extend(page.events);
+            match page.next_cursor {
+                Some(cursor) => query = query.cursor(cursor),
+                None => break,
+            }
+        }
+
+        // Queries return the newest events first
+        events.reverse();
+        Ok(events)
+    }
+}
+
+/// Error for an asset whose history predates event snapshots
+fn not_replayable(asset_id: &AssetId) -> DbError {
+    DbError::NotFound(format!(
+        "No replayable history for asset {}: it was registered before event snapshots were recorded",
+        asset_id
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::InMemoryEventStore;
+    use crate::test_support::{registered_event, test_asset};
+    use chrono::Duration;
+
+    fn registered(asset: &Asset, at: DateTime<Utc>) -> RegistryEvent {
+        RegistryEvent {
+            timestamp: at,
+            ..registered_event(asset)
+        }
+    }
+
+    fn updated(before: &Asset, after: &Asset, at: DateTime<Utc>) -> RegistryEvent {
+        let changes = before.diff(after);
+        RegistryEvent::builder(EventType::AssetUpdated {
+            asset_id: before.id,
+            asset_name: before.metadata.name.clone(),
+            updated_fields: changes.iter().map(|c| c.field.clone()).collect(),
+            changes,
+            revision: Some(before.revision + 1),
+        })
+        .timestamp(at)
+        .actor("bot")
+        .build()
+    }
+
+    #[tokio::test]
+    async fn test_asset_as_of_and_history() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let engine = ProjectionEngine::new(store.clone());
+        let start = Utc::now() - Duration::hours(3);
+
+        let v1 = test_asset("model", "1.0.0");
+        let mut v2 = v1.clone();
+        v2.metadata.description = Some("Production model".to_string());
+        let mut v3 = v2.clone();
+        v3.revision = 2;
+        v3.metadata.tags = vec!["prod".to_string()];
+
+        let first_update = updated(&v1, &v2, start + Duration::hours(1));
+        store.append(registered(&v1, start)).await.unwrap();
+        store.append(first_update.clone()).await.unwrap();
+        store
+            .append(updated(
+                &v3,
+                &{
+                    let mut v4 = v3.clone();
+                    v4.metadata.license = Some("MIT".to_string());
+                    v4
+                },
+                start + Duration::hours(2),
+            ))
+            .await
+            .unwrap();
+
+        // Before registration the asset did not exist
+        let before = AsOf::Timestamp(start - Duration::minutes(1));
+        assert!(engine.asset_as_of(&v1.id, &before).await.unwrap().is_none());
+
+        let at_start = engine
+            .asset_as_of(&v1.id, &AsOf::Timestamp(start))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(at_start.metadata.description.is_none());
+        assert_eq!(at_start.revision, 1);
+
+        let after_first = engine
+            .asset_as_of(&v1.id, &AsOf::Event(first_update.event_id.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            after_first.metadata.description.as_deref(),
+            Some("Production model")
+        );
+        assert!(after_first.metadata.license.is_none());
+        assert_eq!(after_first.revision, 2);
+
+        let latest = engine
+            .asset_as_of(&v1.id, &AsOf::Timestamp(Utc::now()))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.metadata.license.as_deref(), Some("MIT"));
+        assert_eq!(latest.revision, 3);
+
+        let history = engine.history(&v1.id).await.unwrap();
+        let revisions: Vec<u64> = history.iter().map(|r| r.revision).collect();
+        assert_eq!(revisions, [1, 2, 3]);
+        assert_eq!(history[1].changes[0].field, "description");
+        assert_eq!(history[2].actor.as_deref(), Some("bot"));
+
+        let unknown = AsOf::Event("01ARZ3NDEKTSV4RRFFQ69G5FAV".to_string());
+        assert!(engine
+            .asset_as_of(&v1.id, &unknown)
+            .await
+            .unwrap_err()
+            .is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_deleted_and_legacy_assets() {
+        let asset = test_asset("model", "1.0.0");
+        let deleted = RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: asset.id,
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        });
+
+        let projection =
+            AssetProjection::replay([&registered(&asset, Utc::now()), &deleted]).unwrap();
+        assert!(projection.asset().is_none());
+        assert_eq!(projection.history().len(), 2);
+        assert_eq!(projection.history()[1].event_type, "asset_deleted");
+
+        // Registrations recorded without a snapshot cannot be replayed
+        let legacy = RegistryEvent::new(EventType::AssetRegistered {
+            asset_id: asset.id,
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+            asset_type: "model".to_string(),
+            snapshot: None,
+        });
+        assert!(AssetProjection::replay([&legacy])
+            .unwrap_err()
+            .is_not_found());
+    }
+}
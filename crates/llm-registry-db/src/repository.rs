@@ -4,15 +4,22 @@
 //! allowing for different implementations (PostgreSQL, SQLite, in-memory, etc.).
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use llm_registry_core::{Asset, AssetId, AssetType};
 use semver::Version;
+use serde::Serialize;
+use std::collections::HashMap;
 
-use crate::error::DbResult;
+use crate::cursor::Cursor;
+use crate::error::{DbError, DbResult};
 
 /// Query parameters for searching assets
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct SearchQuery {
-    /// Text search across name, description, and annotations
+    /// Full-text search across name, tags, description, and annotations
+    ///
+    /// Every word must match, and double-quoted phrases must match as
+    /// written. Results carry highlighted snippets.
     pub text: Option<String>,
 
     /// Filter by asset types
@@ -27,6 +34,24 @@ pub struct SearchQuery {
     /// Filter by storage backend
     pub storage_backend: Option<String>,
 
+    /// Filter by license
+    pub license: Option<String>,
+
+    /// Exclude assets having any of these tags
+    pub exclude_tags: Vec<String>,
+
+    /// Minimum size in bytes (inclusive); assets without a size never match
+    pub min_size_bytes: Option<u64>,
+
+    /// Maximum size in bytes (inclusive); assets without a size never match
+    pub max_size_bytes: Option<u64>,
+
+    /// Only include assets created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only include assets created strictly before this time
+    pub created_before: Option<DateTime<Utc>>,
+
     /// Only include non-deprecated assets
     pub exclude_deprecated: bool,
 
@@ -34,8 +59,21 @@ pub struct SearchQuery {
     pub limit: i64,
 
     /// Number of results to skip (for pagination)
+    ///
+    /// Ignored when resuming from a cursor.
     pub offset: i64,
 
+    /// Resume after the last item of a previous page
+    ///
+    /// Must have been issued for the same sort. Not supported when ranking
+    /// by relevance, which pages by offset.
+    pub cursor: Option<Cursor>,
+
+    /// Count all matching assets
+    ///
+    /// Counting costs a second query; ranked searches are always counted.
+    pub include_total: bool,
+
     /// Sort field
     pub sort_by: SortField,
 
@@ -52,6 +90,7 @@ impl SearchQuery {
             sort_by: SortField::CreatedAt,
             sort_order: SortOrder::Descending,
             exclude_deprecated: true,
+            include_total: true,
             ..Default::default()
         }
     }
@@ -86,6 +125,36 @@ impl SearchQuery {
         self
     }
 
+    /// Set license filter
+    pub fn license(mut self, license: impl Into<String>) -> Self {
+        self.license = Some(license.into());
+        self
+    }
+
+    /// Exclude assets having a tag
+    pub fn exclude_tag(mut self, tag: impl Into<String>) -> Self {
+        self.exclude_tags.push(tag.into());
+        self
+    }
+
+    /// Set the inclusive size range in bytes
+    pub fn size_range(mut self, min: Option<u64>, max: Option<u64>) -> Self {
+        self.min_size_bytes = min;
+        self.max_size_bytes = max;
+        self
+    }
+
+    /// Set the creation time window (`after` inclusive, `before` exclusive)
+    pub fn created_between(
+        mut self,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.created_after = after;
+        self.created_before = before;
+        self
+    }
+
     /// Include or exclude deprecated assets
     pub fn exclude_deprecated(mut self, exclude: bool) -> Self {
         self.exclude_deprecated = exclude;
@@ -104,6 +173,18 @@ impl SearchQuery {
         self
     }
 
+    /// Resume after a cursor from a previous page
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Count all matching assets
+    pub fn include_total(mut self, include: bool) -> Self {
+        self.include_total = include;
+        self
+    }
+
     /// Set sort field
     pub fn sort_by(mut self, field: SortField) -> Self {
         self.sort_by = field;
@@ -115,10 +196,60 @@ impl SearchQuery {
         self.sort_order = order;
         self
     }
+
+    /// Field results are keyset-paginated by, or `None` when ranked by relevance
+    pub(crate) fn keyset_field(&self) -> Option<SortField> {
+        match self.sort_by {
+            SortField::Relevance
+                if self
+                    .text
+                    .as_deref()
+                    .is_some_and(|text| !search_terms(text).is_empty()) =>
+            {
+                None
+            }
+            SortField::Relevance => Some(SortField::CreatedAt),
+            field => Some(field),
+        }
+    }
+
+    /// The cursor to resume after, checked against the query's sort
+    pub(crate) fn resume_after(&self) -> DbResult<Option<&Cursor>> {
+        let Some(ref cursor) = self.cursor else {
+            return Ok(None);
+        };
+        let Some(field) = self.keyset_field() else {
+            return Err(DbError::InvalidQuery(
+                "Cursors are not supported when sorting by relevance".to_string(),
+            ));
+        };
+
+        cursor.check_asset_ordering(field, self.sort_order)?;
+        Ok(Some(cursor))
+    }
+
+    /// Whether the total number of matches should be counted
+    pub(crate) fn counts_total(&self) -> bool {
+        self.include_total || self.keyset_field().is_none()
+    }
+
+    /// Number of results to skip
+    pub(crate) fn effective_offset(&self) -> i64 {
+        if self.cursor.is_some() {
+            0
+        } else {
+            self.offset.max(0)
+        }
+    }
+
+    /// Number of rows to fetch: one past the limit, to detect a following page
+    pub(crate) fn fetch_limit(&self) -> i64 {
+        self.limit.max(0).saturating_add(1)
+    }
 }
 
 /// Fields that can be used for sorting
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SortField {
     /// Sort by creation timestamp
     CreatedAt,
@@ -130,6 +261,10 @@ pub enum SortField {
     Version,
     /// Sort by size in bytes
     SizeBytes,
+    /// Sort by text search relevance
+    ///
+    /// Falls back to creation timestamp when the query has no search text.
+    Relevance,
 }
 
 impl Default for SortField {
@@ -138,8 +273,23 @@ impl Default for SortField {
     }
 }
 
+impl SortField {
+    /// SQL expression for the field on the `assets a` table
+    ///
+    /// Assets without a size sort as `-1`, matching [`Cursor::for_asset`].
+    pub(crate) fn column(self) -> &'static str {
+        match self {
+            SortField::CreatedAt | SortField::Relevance => "a.created_at",
+            SortField::UpdatedAt => "a.updated_at",
+            SortField::Name => "a.name",
+            SortField::Version => "a.version",
+            SortField::SizeBytes => "COALESCE(a.size_bytes, -1)",
+        }
+    }
+}
+
 /// Sort order
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum SortOrder {
     /// Ascending order
     Ascending,
@@ -160,19 +310,58 @@ pub struct SearchResults {
     pub assets: Vec<Asset>,
 
     /// Total number of results (without pagination)
-    pub total: i64,
+    ///
+    /// `None` unless the query asked for a count.
+    pub total: Option<i64>,
 
     /// Current offset
     pub offset: i64,
 
     /// Current limit
     pub limit: i64,
+
+    /// Cursor for the next page, if there is one
+    ///
+    /// Always `None` when ranking by relevance; use the total instead.
+    pub next_cursor: Option<Cursor>,
+
+    /// Highlighted text matches for assets in this page, keyed by asset ID
+    ///
+    /// Matched words are wrapped in [`SNIPPET_START`] and [`SNIPPET_END`].
+    /// Empty when the query has no search text.
+    pub snippets: HashMap<AssetId, String>,
 }
 
 impl SearchResults {
+    /// Build a page from matches fetched with [`SearchQuery::fetch_limit`]
+    pub(crate) fn page(query: &SearchQuery, mut assets: Vec<Asset>, total: Option<i64>) -> Self {
+        let limit = query.limit.max(0) as usize;
+        let overflow = assets.len() > limit;
+        assets.truncate(limit);
+
+        let next_cursor = match query.keyset_field() {
+            Some(field) if overflow => assets
+                .last()
+                .map(|asset| Cursor::for_asset(asset, field, query.sort_order)),
+            _ => None,
+        };
+
+        Self {
+            assets,
+            total,
+            offset: query.effective_offset(),
+            limit: query.limit,
+            next_cursor,
+            snippets: HashMap::new(),
+        }
+    }
+
     /// Check if there are more results available
     pub fn has_more(&self) -> bool {
-        self.offset + self.assets.len() as i64 > self.total
+        self.next_cursor.is_some()
+            || self
+                .total
+                .is_some_and(|total| self.offset + (self.assets.len() as i64) < total)
     }
 
     /// Get the number of results in this page
@@ -181,6 +370,16 @@ impl SearchResults {
     }
 }
 
+/// A dependency link from an asset to one of its dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DependencyLink {
+    /// The dependent asset
+    pub asset_id: AssetId,
+
+    /// The asset it depends on
+    pub dependency_id: AssetId,
+}
+
 /// Repository trait for asset persistence operations
 ///
 /// This trait defines the interface for all asset database operations.
@@ -198,6 +397,19 @@ pub trait AssetRepository: Send + Sync {
     /// * `Err(DbError)` - For other database errors
     async fn create(&self, asset: Asset) -> DbResult<Asset>;
 
+    /// Create multiple assets atomically
+    ///
+    /// Assets are inserted in order, so an asset may depend on any asset
+    /// earlier in the batch. Either every asset is created or none are.
+    ///
+    /// # Arguments
+    /// * `assets` - The assets to create
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Asset>)` - The created assets, in input order
+    /// * `Err(DbError)` - If any asset fails to insert; nothing is persisted
+    async fn create_batch(&self, assets: Vec<Asset>) -> DbResult<Vec<Asset>>;
+
     /// Find an asset by its unique ID
     ///
     /// # Arguments
@@ -246,11 +458,13 @@ pub trait AssetRepository: Send + Sync {
     /// Update an existing asset
     ///
     /// # Arguments
-    /// * `asset` - The asset with updated fields (must have existing ID)
+    /// * `asset` - The asset with updated fields (must have existing ID), carrying
+    ///   the revision it was read at
     ///
     /// # Returns
-    /// * `Ok(Asset)` - The updated asset
+    /// * `Ok(Asset)` - The updated asset with its incremented revision
     /// * `Err(DbError::NotFound)` - If the asset doesn't exist
+    /// * `Err(DbError::Conflict)` - If the asset was updated since it was read
     /// * `Err(DbError)` - For other database errors
     async fn update(&self, asset: Asset) -> DbResult<Asset>;
 
@@ -292,6 +506,41 @@ pub trait AssetRepository: Send + Sync {
     /// * Vector of assets that depend on this asset
     async fn list_reverse_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>>;
 
+    /// Find all versions of several assets by name
+    ///
+    /// Batched counterpart of [`list_versions`](Self::list_versions) in a
+    /// single query; the order of the result is unspecified.
+    ///
+    /// # Arguments
+    /// * `names` - Asset names to look up
+    async fn find_by_names(&self, names: &[String]) -> DbResult<Vec<Asset>>;
+
+    /// Get the dependency links of several assets
+    ///
+    /// Batched counterpart of [`list_dependencies`](Self::list_dependencies)
+    /// in a single query.
+    ///
+    /// # Arguments
+    /// * `ids` - The dependent asset IDs
+    ///
+    /// # Returns
+    /// * Links whose `asset_id` is one of `ids`
+    async fn list_dependency_links(&self, ids: &[AssetId]) -> DbResult<Vec<DependencyLink>>;
+
+    /// Get the links of assets depending on several assets
+    ///
+    /// Batched counterpart of
+    /// [`list_reverse_dependencies`](Self::list_reverse_dependencies) in a
+    /// single query.
+    ///
+    /// # Arguments
+    /// * `ids` - The dependency asset IDs
+    ///
+    /// # Returns
+    /// * Links whose `dependency_id` is one of `ids`
+    async fn list_reverse_dependency_links(&self, ids: &[AssetId])
+        -> DbResult<Vec<DependencyLink>>;
+
     /// Add a tag to an asset
     ///
     /// # Arguments
@@ -339,11 +588,7 @@ pub trait AssetRepository: Send + Sync {
     /// # Arguments
     /// * `asset_id` - The asset that has the dependency
     /// * `dependency_id` - The dependency to remove
-    async fn remove_dependency(
-        &self,
-        asset_id: &AssetId,
-        dependency_id: &AssetId,
-    ) -> DbResult<()>;
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()>;
 
     /// Count total assets in the repository
     ///
@@ -368,6 +613,164 @@ pub trait AssetRepository: Send + Sync {
     async fn health_check(&self) -> DbResult<()>;
 }
 
+/// Marker inserted before a highlighted match in a snippet
+pub const SNIPPET_START: &str = "<mark>";
+
+/// Marker inserted after a highlighted match in a snippet
+pub const SNIPPET_END: &str = "</mark>";
+
+/// Maximum snippet length in bytes, before highlight markers are added
+const MAX_SNIPPET_LEN: usize = 200;
+
+/// Bytes of context kept before the first match in a truncated snippet
+const SNIPPET_LEAD: usize = 60;
+
+// Field weights for text relevance, matching PostgreSQL's default `ts_rank`
+// weights for the A (name), B (tags), C (description) and D (annotations)
+// labels of the search vector.
+pub(crate) const NAME_WEIGHT: f64 = 1.0;
+pub(crate) const TAG_WEIGHT: f64 = 0.4;
+pub(crate) const DESCRIPTION_WEIGHT: f64 = 0.2;
+pub(crate) const ANNOTATION_WEIGHT: f64 = 0.1;
+
+/// Split search text into lowercase terms
+///
+/// Used by backends without a native full-text engine. A double-quoted
+/// phrase is kept as a single term, so it only matches as written. Leading
+/// and trailing punctuation is dropped from every term.
+pub(crate) fn search_terms(text: &str) -> Vec<String> {
+    let normalize = |term: &str| {
+        term.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_ascii_lowercase()
+    };
+
+    let mut terms = Vec::new();
+    for (i, segment) in text.split('"').enumerate() {
+        if i % 2 == 1 {
+            let phrase = segment.split_whitespace().collect::<Vec<_>>().join(" ");
+            terms.push(normalize(&phrase));
+        } else {
+            terms.extend(segment.split_whitespace().map(normalize));
+        }
+    }
+    terms.retain(|term| !term.is_empty());
+    terms
+}
+
+/// Score an asset against search terms
+///
+/// Every term must occur in the name, a tag, the description or an
+/// annotation value; otherwise the asset does not match and `None` is
+/// returned. Each term contributes the weight of the best field it occurs in.
+pub(crate) fn text_relevance(asset: &Asset, terms: &[String]) -> Option<f64> {
+    let contains = |haystack: &str, term: &str| haystack.to_ascii_lowercase().contains(term);
+
+    terms.iter().try_fold(0.0, |score, term| {
+        let weight = if contains(&asset.metadata.name, term) {
+            NAME_WEIGHT
+        } else if asset.metadata.tags.iter().any(|tag| contains(tag, term)) {
+            TAG_WEIGHT
+        } else if asset
+            .metadata
+            .description
+            .as_deref()
+            .is_some_and(|d| contains(d, term))
+        {
+            DESCRIPTION_WEIGHT
+        } else if asset
+            .metadata
+            .annotations
+            .values()
+            .any(|value| contains(value, term))
+        {
+            ANNOTATION_WEIGHT
+        } else {
+            return None;
+        };
+        Some(score + weight)
+    })
+}
+
+/// Build a highlighted snippet for an asset matching search terms
+///
+/// Highlights matches in the description, or in the name when the
+/// description has none. Long descriptions are cut to a window around the
+/// first match.
+pub(crate) fn highlight_snippet(asset: &Asset, terms: &[String]) -> Option<String> {
+    let description = asset.metadata.description.as_deref().unwrap_or_default();
+    match_ranges(description, terms)
+        .map(|ranges| (description, ranges))
+        .or_else(|| {
+            match_ranges(&asset.metadata.name, terms).map(|ranges| (&*asset.metadata.name, ranges))
+        })
+        .map(|(text, ranges)| highlight(text, &ranges))
+}
+
+/// Find the merged byte ranges of all term matches in `text`
+fn match_ranges(text: &str, terms: &[String]) -> Option<Vec<(usize, usize)>> {
+    // ASCII lowercasing keeps byte offsets valid for the original text.
+    let lower = text.to_ascii_lowercase();
+    let mut ranges: Vec<(usize, usize)> = terms
+        .iter()
+        .flat_map(|term| {
+            lower
+                .match_indices(term.as_str())
+                .map(|(start, m)| (start, start + m.len()))
+        })
+        .collect();
+    if ranges.is_empty() {
+        return None;
+    }
+
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Some(merged)
+}
+
+/// Wrap match ranges in highlight markers, truncating long text
+fn highlight(text: &str, ranges: &[(usize, usize)]) -> String {
+    let (mut start, mut end) = (0, text.len());
+    if text.len() > MAX_SNIPPET_LEN {
+        start = floor_char_boundary(text, ranges[0].0.saturating_sub(SNIPPET_LEAD));
+        end = floor_char_boundary(text, (start + MAX_SNIPPET_LEN).min(text.len()));
+    }
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push_str("...");
+    }
+    let mut cursor = start;
+    for &(match_start, match_end) in ranges {
+        if match_start >= end {
+            break;
+        }
+        let match_end = match_end.min(end);
+        snippet.push_str(&text[cursor..match_start]);
+        snippet.push_str(SNIPPET_START);
+        snippet.push_str(&text[match_start..match_end]);
+        snippet.push_str(SNIPPET_END);
+        cursor = match_end;
+    }
+    snippet.push_str(&text[cursor..end]);
+    if end < text.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,15 +795,27 @@ mod tests {
     fn test_search_results_has_more() {
         let results = SearchResults {
             assets: vec![],
-            total: 100,
+            total: Some(100),
             offset: 0,
             limit: 50,
+            next_cursor: None,
+            snippets: HashMap::new(),
         };
 
-        // Since offset (0) + count (0) <= total (100), has_more should be false
-        // But the implementation has a bug - it should be offset + count < total
-        // For now, testing the current behavior
         assert_eq!(results.count(), 0);
+        assert!(results.has_more());
+
+        let last_page = SearchResults {
+            offset: 100,
+            ..results.clone()
+        };
+        assert!(!last_page.has_more());
+
+        let uncounted = SearchResults {
+            total: None,
+            ..results
+        };
+        assert!(!uncounted.has_more());
     }
 
     #[test]
@@ -408,4 +823,95 @@ mod tests {
         assert_eq!(SortField::default(), SortField::CreatedAt);
         assert_eq!(SortOrder::default(), SortOrder::Descending);
     }
+
+    fn searchable_asset(name: &str, description: Option<&str>, tags: &[&str]) -> Asset {
+        use llm_registry_core::{
+            AssetMetadata, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+        };
+
+        let mut metadata = AssetMetadata::new(name, Version::new(1, 0, 0));
+        metadata.description = description.map(str::to_string);
+        metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+        metadata
+            .annotations
+            .insert("framework".to_string(), "PyTorch".to_string());
+        let storage = StorageLocation::new(
+            StorageBackend::FileSystem {
+                base_path: "/tmp".to_string(),
+            },
+            format!("{}.bin", name),
+            None,
+        )
+        .unwrap();
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+        Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_search_terms() {
+        assert_eq!(
+            search_terms("  BERT \"base\" gpt-4 !! "),
+            vec!["bert", "base", "gpt-4"]
+        );
+        assert!(search_terms(" ... ").is_empty());
+    }
+
+    #[test]
+    fn test_search_terms_keep_phrases() {
+        assert_eq!(
+            search_terms("bert \"Masked   Language\" \"\" model"),
+            vec!["bert", "masked language", "model"]
+        );
+
+        let asset = searchable_asset("bert-base", Some("Masked language model"), &[]);
+        assert!(text_relevance(&asset, &search_terms("\"masked language\"")).is_some());
+        assert!(text_relevance(&asset, &search_terms("\"language masked\"")).is_none());
+    }
+
+    #[test]
+    fn test_text_relevance_weights_fields() {
+        let asset = searchable_asset("bert-base", Some("Masked language model"), &["nlp"]);
+
+        let name = text_relevance(&asset, &search_terms("bert")).unwrap();
+        let tag = text_relevance(&asset, &search_terms("nlp")).unwrap();
+        let description = text_relevance(&asset, &search_terms("language")).unwrap();
+        let annotation = text_relevance(&asset, &search_terms("pytorch")).unwrap();
+        assert!(name > tag && tag > description && description > annotation);
+
+        assert!(text_relevance(&asset, &search_terms("bert vision")).is_none());
+    }
+
+    #[test]
+    fn test_highlight_snippet() {
+        let asset = searchable_asset("bert-base", Some("A BERT model for language tasks"), &[]);
+        assert_eq!(
+            highlight_snippet(&asset, &search_terms("bert language")).unwrap(),
+            "A <mark>BERT</mark> model for <mark>language</mark> tasks"
+        );
+
+        // Falls back to the name when the description has no match
+        let asset = searchable_asset("bert-base", None, &[]);
+        assert_eq!(
+            highlight_snippet(&asset, &search_terms("base")).unwrap(),
+            "bert-<mark>base</mark>"
+        );
+    }
+
+    #[test]
+    fn test_highlight_snippet_truncates_long_text() {
+        let description = format!("{} needle {}", "x".repeat(300), "y".repeat(300));
+        let asset = searchable_asset("model", Some(&description), &[]);
+
+        let snippet = highlight_snippet(&asset, &search_terms("needle")).unwrap();
+        assert!(snippet.starts_with("...") && snippet.ends_with("..."));
+        assert!(snippet.contains("<mark>needle</mark>"));
+        assert!(snippet.len() <= MAX_SNIPPET_LEN + 6 + SNIPPET_START.len() + SNIPPET_END.len());
+    }
 }
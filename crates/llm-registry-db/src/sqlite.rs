@@ -0,0 +1,1453 @@
+//! SQLite implementation of AssetRepository
+//!
+//! This module provides a concrete implementation of the AssetRepository trait
+//! backed by SQLite. It mirrors the PostgreSQL implementation and is intended
+//! for local development, CI and single-node deployments that do not want to
+//! run a PostgreSQL server.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use llm_registry_core::{
+    Asset, AssetId, AssetMetadata, AssetReference, AssetSignature, AssetType, Checksum, Provenance,
+    StorageLocation,
+};
+use semver::Version;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::{QueryBuilder, Row, Sqlite};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::{debug, instrument};
+
+use crate::cursor::push_keyset_condition;
+use crate::error::{DbError, DbResult};
+use crate::postgres::{
+    parse_asset_status, parse_asset_type, parse_dependency_link, parse_hash_algorithm,
+    parse_signature_algorithm, parse_storage_backend_from_db,
+};
+use crate::repository::{
+    highlight_snippet, search_terms, AssetRepository, DependencyLink, SearchQuery, SearchResults,
+    SortOrder, ANNOTATION_WEIGHT, DESCRIPTION_WEIGHT, NAME_WEIGHT, TAG_WEIGHT,
+};
+
+/// Columns selected when loading an asset row
+const ASSET_COLUMNS: &str = r#"
+    a.id, a.name, a.version, a.asset_type, a.status,
+    a.storage_backend, a.storage_uri, a.storage_path, a.size_bytes,
+    a.checksum_algorithm, a.checksum_value,
+    a.signature_algorithm, a.signature_value, a.signature_key_id,
+    a.description, a.license, a.content_type,
+    a.author, a.source_repo, a.commit_hash, a.build_id,
+    a.created_at, a.updated_at, a.deprecated_at, a.metadata, a.revision
+"#;
+
+/// SQLite implementation of AssetRepository
+#[derive(Debug, Clone)]
+pub struct SqliteAssetRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAssetRepository {
+    /// Create a new SQLite asset repository
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get a reference to the connection pool
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Insert an asset with its tags and dependencies on the given connection
+    pub(crate) async fn insert_asset(
+        conn: &mut sqlx::SqliteConnection,
+        asset: &Asset,
+    ) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO assets (
+                id, name, version, asset_type, status,
+                storage_backend, storage_uri, storage_path, size_bytes,
+                checksum_algorithm, checksum_value,
+                signature_algorithm, signature_value, signature_key_id,
+                description, license, content_type,
+                author, source_repo, commit_hash, build_id,
+                created_at, updated_at, deprecated_at, metadata, revision
+            ) VALUES (
+                ?, ?, ?, ?, ?,
+                ?, ?, ?, ?,
+                ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?, ?,
+                ?, ?, ?, ?, ?
+            )
+            "#,
+        )
+        .bind(asset.id.to_string())
+        .bind(&asset.metadata.name)
+        .bind(asset.metadata.version.to_string())
+        .bind(asset.asset_type.to_string())
+        .bind(asset.status.to_string())
+        .bind(asset.storage.backend.to_string())
+        .bind(
+            asset
+                .storage
+                .uri
+                .clone()
+                .unwrap_or_else(|| asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
+        .bind(asset.metadata.size_bytes.map(|s| s as i64))
+        .bind(asset.checksum.algorithm.to_string())
+        .bind(&asset.checksum.value)
+        .bind(asset.signature.as_ref().map(|s| s.algorithm.to_string()))
+        .bind(asset.signature.as_ref().map(|s| s.value.as_str()))
+        .bind(asset.signature.as_ref().map(|s| s.key_id.as_str()))
+        .bind(&asset.metadata.description)
+        .bind(&asset.metadata.license)
+        .bind(&asset.metadata.content_type)
+        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
+        .bind(asset.created_at)
+        .bind(asset.updated_at)
+        .bind(asset.deprecated_at)
+        .bind(serde_json::to_string(&asset.metadata.annotations)?)
+        .bind(asset.revision as i64)
+        .execute(&mut *conn)
+        .await?;
+
+        for tag in &asset.metadata.tags {
+            sqlx::query("INSERT OR IGNORE INTO asset_tags (asset_id, tag) VALUES (?, ?)")
+                .bind(asset.id.to_string())
+                .bind(tag)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        for dep in &asset.dependencies {
+            let dep_id = dep.as_id().ok_or_else(|| {
+                DbError::InvalidData(
+                    "Dependency must be resolved to ID before persisting".to_string(),
+                )
+            })?;
+
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO asset_dependencies (asset_id, dependency_id, version_constraint)
+                VALUES (?, ?, ?)
+                "#,
+            )
+            .bind(asset.id.to_string())
+            .bind(dep_id.to_string())
+            .bind(dep.as_name_version().map(|(_, v)| v))
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Update an asset and replace its tags on the given connection
+    ///
+    /// The update only applies while the stored revision still equals
+    /// `asset.revision`, and increments it. A stale revision fails with
+    /// [`DbError::Conflict`].
+    pub(crate) async fn update_asset(
+        conn: &mut sqlx::SqliteConnection,
+        asset: &Asset,
+    ) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE assets SET
+                name = ?,
+                version = ?,
+                asset_type = ?,
+                status = ?,
+                storage_backend = ?,
+                storage_uri = ?,
+                storage_path = ?,
+                size_bytes = ?,
+                checksum_algorithm = ?,
+                checksum_value = ?,
+                signature_algorithm = ?,
+                signature_value = ?,
+                signature_key_id = ?,
+                description = ?,
+                license = ?,
+                content_type = ?,
+                author = ?,
+                source_repo = ?,
+                commit_hash = ?,
+                build_id = ?,
+                deprecated_at = ?,
+                metadata = ?,
+                updated_at = ?,
+                revision = revision + 1
+            WHERE id = ? AND revision = ?
+            "#,
+        )
+        .bind(&asset.metadata.name)
+        .bind(asset.metadata.version.to_string())
+        .bind(asset.asset_type.to_string())
+        .bind(asset.status.to_string())
+        .bind(asset.storage.backend.to_string())
+        .bind(
+            asset
+                .storage
+                .uri
+                .clone()
+                .unwrap_or_else(|| asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
+        .bind(asset.metadata.size_bytes.map(|s| s as i64))
+        .bind(asset.checksum.algorithm.to_string())
+        .bind(&asset.checksum.value)
+        .bind(asset.signature.as_ref().map(|s| s.algorithm.to_string()))
+        .bind(asset.signature.as_ref().map(|s| s.value.as_str()))
+        .bind(asset.signature.as_ref().map(|s| s.key_id.as_str()))
+        .bind(&asset.metadata.description)
+        .bind(&asset.metadata.license)
+        .bind(&asset.metadata.content_type)
+        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
+        .bind(asset.deprecated_at)
+        .bind(serde_json::to_string(&asset.metadata.annotations)?)
+        .bind(Utc::now())
+        .bind(asset.id.to_string())
+        .bind(asset.revision as i64)
+        .execute(&mut *conn)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            let current: Option<i64> =
+                sqlx::query_scalar("SELECT revision FROM assets WHERE id = ?")
+                    .bind(asset.id.to_string())
+                    .fetch_optional(&mut *conn)
+                    .await?;
+
+            return Err(match current {
+                Some(current) => {
+                    DbError::revision_conflict(asset.id, asset.revision, current as u64)
+                }
+                None => DbError::NotFound(format!("Asset {} not found", asset.id)),
+            });
+        }
+
+        // Update tags - delete and re-insert for simplicity
+        sqlx::query("DELETE FROM asset_tags WHERE asset_id = ?")
+            .bind(asset.id.to_string())
+            .execute(&mut *conn)
+            .await?;
+
+        for tag in &asset.metadata.tags {
+            sqlx::query("INSERT OR IGNORE INTO asset_tags (asset_id, tag) VALUES (?, ?)")
+                .bind(asset.id.to_string())
+                .bind(tag)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Bump an asset's revision after its tags changed
+    async fn touch_tags(conn: &mut sqlx::SqliteConnection, id: &AssetId) -> DbResult<()> {
+        sqlx::query("UPDATE assets SET updated_at = ?, revision = revision + 1 WHERE id = ?")
+            .bind(Utc::now())
+            .bind(id.to_string())
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete an asset on the given connection
+    ///
+    /// Tags and dependency edges are removed by `ON DELETE CASCADE`.
+    pub(crate) async fn delete_asset(
+        conn: &mut sqlx::SqliteConnection,
+        id: &AssetId,
+    ) -> DbResult<()> {
+        let result = sqlx::query("DELETE FROM assets WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *conn)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!("Asset {} not found", id)));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AssetRepository for SqliteAssetRepository {
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id, asset_name = %asset.metadata.name))]
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        debug!("Creating asset in database");
+
+        let mut tx = self.pool.begin().await?;
+        Self::insert_asset(&mut tx, &asset).await?;
+        tx.commit().await?;
+
+        debug!("Asset created successfully");
+        Ok(asset)
+    }
+
+    #[instrument(skip(self, assets), fields(count = assets.len()))]
+    async fn create_batch(&self, assets: Vec<Asset>) -> DbResult<Vec<Asset>> {
+        debug!("Creating asset batch in database");
+
+        let mut tx = self.pool.begin().await?;
+        for asset in &assets {
+            Self::insert_asset(&mut tx, asset).await?;
+        }
+        tx.commit().await?;
+
+        debug!("Asset batch created successfully");
+        Ok(assets)
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
+        debug!("Finding asset by ID");
+
+        let sql = format!("SELECT {} FROM assets a WHERE a.id = ?", ASSET_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_asset_relations(row_to_asset(row)?).await?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_name_and_version(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> DbResult<Option<Asset>> {
+        debug!("Finding asset by name and version");
+
+        let sql = format!(
+            "SELECT {} FROM assets a WHERE a.name = ? AND a.version = ?",
+            ASSET_COLUMNS
+        );
+        let row = sqlx::query(&sql)
+            .bind(name)
+            .bind(version.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_asset_relations(row_to_asset(row)?).await?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn find_by_ids(&self, ids: &[AssetId]) -> DbResult<Vec<Asset>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        debug!("Finding {} assets by IDs", ids.len());
+
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {} FROM assets a WHERE a.id IN (",
+            ASSET_COLUMNS
+        ));
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id.to_string());
+        }
+        separated.push_unseparated(")");
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        self.rows_to_assets(rows).await
+    }
+
+    #[instrument(skip(self, query))]
+    async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
+        debug!("Searching assets with filters");
+
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {} FROM assets a WHERE 1=1",
+            ASSET_COLUMNS
+        ));
+        push_search_filters(&mut builder, query);
+
+        let terms = query.text.as_deref().map(search_terms).unwrap_or_default();
+        let sort_order = match query.sort_order {
+            SortOrder::Ascending => "ASC",
+            SortOrder::Descending => "DESC",
+        };
+
+        let resume_after = query.resume_after()?;
+        match query.keyset_field() {
+            Some(field) => {
+                if let Some(cursor) = resume_after {
+                    push_keyset_condition(
+                        &mut builder,
+                        field.column(),
+                        "a.id",
+                        cursor,
+                        query.sort_order,
+                    );
+                }
+                builder.push(format!(
+                    " ORDER BY {} {}, a.id {}",
+                    field.column(),
+                    sort_order,
+                    sort_order
+                ));
+            }
+            None => {
+                builder.push(" ORDER BY ");
+                push_relevance(&mut builder, &terms);
+                builder.push(format!(" {}, a.created_at {}", sort_order, sort_order));
+            }
+        }
+        builder.push(" LIMIT ").push_bind(query.fetch_limit());
+        builder.push(" OFFSET ").push_bind(query.effective_offset());
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        let assets = self.rows_to_assets(rows).await?;
+
+        let total = if query.counts_total() {
+            let mut count_builder =
+                QueryBuilder::<Sqlite>::new("SELECT COUNT(*) AS count FROM assets a WHERE 1=1");
+            push_search_filters(&mut count_builder, query);
+            Some(
+                count_builder
+                    .build()
+                    .fetch_one(&self.pool)
+                    .await?
+                    .get("count"),
+            )
+        } else {
+            None
+        };
+
+        let mut results = SearchResults::page(query, assets, total);
+        results.snippets = results
+            .assets
+            .iter()
+            .filter_map(|asset| Some((asset.id, highlight_snippet(asset, &terms)?)))
+            .collect();
+
+        Ok(results)
+    }
+
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
+    async fn update(&self, asset: Asset) -> DbResult<Asset> {
+        debug!("Updating asset");
+
+        let mut tx = self.pool.begin().await?;
+        Self::update_asset(&mut tx, &asset).await?;
+        tx.commit().await?;
+
+        debug!("Asset updated successfully");
+        Ok(Asset {
+            revision: asset.revision + 1,
+            ..asset
+        })
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn delete(&self, id: &AssetId) -> DbResult<()> {
+        debug!("Deleting asset");
+
+        let mut conn = self.pool.acquire().await?;
+        Self::delete_asset(&mut conn, id).await?;
+
+        debug!("Asset deleted successfully");
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>> {
+        debug!("Listing versions for asset");
+
+        let sql = format!(
+            "SELECT {} FROM assets a WHERE a.name = ? ORDER BY a.created_at DESC",
+            ASSET_COLUMNS
+        );
+        let rows = sqlx::query(&sql).bind(name).fetch_all(&self.pool).await?;
+
+        self.rows_to_assets(rows).await
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn list_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        debug!("Listing dependencies");
+
+        let sql = format!(
+            r#"
+            SELECT {} FROM assets a
+            INNER JOIN asset_dependencies d ON a.id = d.dependency_id
+            WHERE d.asset_id = ?
+            "#,
+            ASSET_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+
+        self.rows_to_assets(rows).await
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn list_reverse_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        debug!("Listing reverse dependencies");
+
+        let sql = format!(
+            r#"
+            SELECT {} FROM assets a
+            INNER JOIN asset_dependencies d ON a.id = d.asset_id
+            WHERE d.dependency_id = ?
+            "#,
+            ASSET_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+
+        self.rows_to_assets(rows).await
+    }
+
+    #[instrument(skip(self, names))]
+    async fn find_by_names(&self, names: &[String]) -> DbResult<Vec<Asset>> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        debug!("Finding versions of {} assets by name", names.len());
+
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {} FROM assets a WHERE a.name IN (",
+            ASSET_COLUMNS
+        ));
+        let mut separated = builder.separated(", ");
+        for name in names {
+            separated.push_bind(name.as_str());
+        }
+        separated.push_unseparated(")");
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        self.rows_to_assets(rows).await
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn list_dependency_links(&self, ids: &[AssetId]) -> DbResult<Vec<DependencyLink>> {
+        self.dependency_links("asset_id", ids).await
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn list_reverse_dependency_links(
+        &self,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        self.dependency_links("dependency_id", ids).await
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id, tag = %tag))]
+    async fn add_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        debug!("Adding tag to asset");
+
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("INSERT OR IGNORE INTO asset_tags (asset_id, tag) VALUES (?, ?)")
+            .bind(id.to_string())
+            .bind(tag)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            Self::touch_tags(&mut tx, id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id, tag = %tag))]
+    async fn remove_tag(&self, id: &AssetId, tag: &str) -> DbResult<()> {
+        debug!("Removing tag from asset");
+
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("DELETE FROM asset_tags WHERE asset_id = ? AND tag = ?")
+            .bind(id.to_string())
+            .bind(tag)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            Self::touch_tags(&mut tx, id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(asset_id = %id))]
+    async fn get_tags(&self, id: &AssetId) -> DbResult<Vec<String>> {
+        debug!("Getting tags for asset");
+
+        let rows = sqlx::query("SELECT tag FROM asset_tags WHERE asset_id = ? ORDER BY tag")
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(|row| row.get::<String, _>("tag")).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_all_tags(&self) -> DbResult<Vec<String>> {
+        debug!("Listing all tags");
+
+        let rows = sqlx::query("SELECT DISTINCT tag FROM asset_tags ORDER BY tag")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(|row| row.get::<String, _>("tag")).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn add_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+        version_constraint: Option<&str>,
+    ) -> DbResult<()> {
+        debug!("Adding dependency relationship");
+
+        if self.would_create_cycle(asset_id, dependency_id).await? {
+            return Err(DbError::CircularDependency(format!(
+                "Adding dependency from {} to {} would create a cycle",
+                asset_id, dependency_id
+            )));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO asset_dependencies (asset_id, dependency_id, version_constraint)
+            VALUES (?, ?, ?)
+            ON CONFLICT (asset_id, dependency_id) DO UPDATE
+            SET version_constraint = excluded.version_constraint
+            "#,
+        )
+        .bind(asset_id.to_string())
+        .bind(dependency_id.to_string())
+        .bind(version_constraint)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
+        debug!("Removing dependency relationship");
+
+        sqlx::query("DELETE FROM asset_dependencies WHERE asset_id = ? AND dependency_id = ?")
+            .bind(asset_id.to_string())
+            .bind(dependency_id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn count_assets(&self) -> DbResult<i64> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM assets")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("count"))
+    }
+
+    #[instrument(skip(self))]
+    async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM assets WHERE asset_type = ?")
+            .bind(asset_type.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("count"))
+    }
+
+    #[instrument(skip(self))]
+    async fn health_check(&self) -> DbResult<()> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
+
+impl SqliteAssetRepository {
+    /// Convert rows to assets, loading tags and dependencies for each
+    async fn rows_to_assets(&self, rows: Vec<SqliteRow>) -> DbResult<Vec<Asset>> {
+        let mut assets = Vec::with_capacity(rows.len());
+        for row in rows {
+            let asset = row_to_asset(row)?;
+            assets.push(self.load_asset_relations(asset).await?);
+        }
+        Ok(assets)
+    }
+
+    /// Load the dependency links whose `column` is one of `ids`
+    async fn dependency_links(
+        &self,
+        column: &'static str,
+        ids: &[AssetId],
+    ) -> DbResult<Vec<DependencyLink>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT asset_id, dependency_id FROM asset_dependencies WHERE {} IN (",
+            column
+        ));
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id.to_string());
+        }
+        separated.push_unseparated(")");
+
+        let rows = builder.build().fetch_all(&self.pool).await?;
+        rows.iter()
+            .map(|row| parse_dependency_link(row.get("asset_id"), row.get("dependency_id")))
+            .collect()
+    }
+
+    /// Load tags and dependencies for an asset
+    async fn load_asset_relations(&self, mut asset: Asset) -> DbResult<Asset> {
+        asset.metadata.tags = self.get_tags(&asset.id).await?;
+
+        let dep_rows =
+            sqlx::query("SELECT dependency_id FROM asset_dependencies WHERE asset_id = ?")
+                .bind(asset.id.to_string())
+                .fetch_all(&self.pool)
+                .await?;
+
+        asset.dependencies = dep_rows
+            .iter()
+            .filter_map(|row| {
+                let dep_id_str: String = row.get("dependency_id");
+                AssetId::from_str(&dep_id_str)
+                    .ok()
+                    .map(AssetReference::by_id)
+            })
+            .collect();
+
+        Ok(asset)
+    }
+
+    /// Check if adding a dependency would create a cycle
+    async fn would_create_cycle(&self, from_id: &AssetId, to_id: &AssetId) -> DbResult<bool> {
+        if from_id == to_id {
+            return Ok(true);
+        }
+
+        let row = sqlx::query(
+            r#"
+            WITH RECURSIVE dep_tree(dependency_id, depth) AS (
+                SELECT dependency_id, 1
+                FROM asset_dependencies
+                WHERE asset_id = ?
+
+                UNION
+
+                SELECT d.dependency_id, dt.depth + 1
+                FROM asset_dependencies d
+                INNER JOIN dep_tree dt ON d.asset_id = dt.dependency_id
+                WHERE dt.depth < 100
+            )
+            SELECT COUNT(*) AS count
+            FROM dep_tree
+            WHERE dependency_id = ?
+            "#,
+        )
+        .bind(to_id.to_string())
+        .bind(from_id.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.get::<i64, _>("count") > 0)
+    }
+}
+
+/// Append the WHERE conditions for a search query
+///
+/// Shared by the search and count queries so the reported total always
+/// matches the filters applied to the page.
+fn push_search_filters(builder: &mut QueryBuilder<'_, Sqlite>, query: &SearchQuery) {
+    // Every search term must occur in the name, description, a tag or an
+    // annotation value
+    for term in query.text.as_deref().map(search_terms).unwrap_or_default() {
+        let pattern = format!("%{}%", term);
+        builder
+            .push(" AND (a.name LIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR a.description LIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR EXISTS (SELECT 1 FROM asset_tags t WHERE t.asset_id = a.id AND t.tag LIKE ")
+            .push_bind(pattern.clone())
+            .push(") OR EXISTS (SELECT 1 FROM json_each(a.metadata) j WHERE j.value LIKE ")
+            .push_bind(pattern)
+            .push("))");
+    }
+
+    if !query.asset_types.is_empty() {
+        builder.push(" AND a.asset_type IN (");
+        let mut separated = builder.separated(", ");
+        for asset_type in &query.asset_types {
+            separated.push_bind(asset_type.to_string());
+        }
+        separated.push_unseparated(")");
+    }
+
+    if let Some(ref author) = query.author {
+        builder.push(" AND a.author = ").push_bind(author.clone());
+    }
+
+    if let Some(ref backend) = query.storage_backend {
+        builder
+            .push(" AND a.storage_backend = ")
+            .push_bind(backend.clone());
+    }
+
+    if let Some(ref license) = query.license {
+        builder.push(" AND a.license = ").push_bind(license.clone());
+    }
+
+    // Sizes are stored as signed integers; larger bounds are clamped
+    if let Some(min) = query.min_size_bytes {
+        builder
+            .push(" AND a.size_bytes >= ")
+            .push_bind(i64::try_from(min).unwrap_or(i64::MAX));
+    }
+    if let Some(max) = query.max_size_bytes {
+        builder
+            .push(" AND a.size_bytes <= ")
+            .push_bind(i64::try_from(max).unwrap_or(i64::MAX));
+    }
+
+    if let Some(after) = query.created_after {
+        builder.push(" AND a.created_at >= ").push_bind(after);
+    }
+    if let Some(before) = query.created_before {
+        builder.push(" AND a.created_at < ").push_bind(before);
+    }
+
+    if query.exclude_deprecated {
+        builder.push(" AND a.deprecated_at IS NULL");
+    }
+
+    // Tag filter - must have all specified tags
+    if !query.tags.is_empty() {
+        builder.push(" AND a.id IN (SELECT asset_id FROM asset_tags WHERE tag IN (");
+        let mut separated = builder.separated(", ");
+        for tag in &query.tags {
+            separated.push_bind(tag.clone());
+        }
+        separated.push_unseparated(")");
+        builder
+            .push(" GROUP BY asset_id HAVING COUNT(DISTINCT tag) = ")
+            .push_bind(query.tags.len() as i64)
+            .push(")");
+    }
+
+    if !query.exclude_tags.is_empty() {
+        builder.push(
+            " AND NOT EXISTS (SELECT 1 FROM asset_tags t WHERE t.asset_id = a.id AND t.tag IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for tag in &query.exclude_tags {
+            separated.push_bind(tag.clone());
+        }
+        separated.push_unseparated("))");
+    }
+}
+
+/// Append a relevance score expression for matching search terms
+///
+/// Mirrors the in-memory scoring: each term adds the weight of the best
+/// field it occurs in. Only valid for rows that passed the text filter.
+fn push_relevance(builder: &mut QueryBuilder<'_, Sqlite>, terms: &[String]) {
+    builder.push("(");
+    let mut separated = builder.separated(" + ");
+    for term in terms {
+        let pattern = format!("%{}%", term);
+        separated
+            .push("CASE WHEN a.name LIKE ")
+            .push_bind_unseparated(pattern.clone())
+            .push_unseparated(format!(
+                " THEN {} WHEN EXISTS (SELECT 1 FROM asset_tags t WHERE t.asset_id = a.id AND t.tag LIKE ",
+                NAME_WEIGHT
+            ))
+            .push_bind_unseparated(pattern.clone())
+            .push_unseparated(format!(") THEN {} WHEN a.description LIKE ", TAG_WEIGHT))
+            .push_bind_unseparated(pattern)
+            .push_unseparated(format!(
+                " THEN {} ELSE {} END",
+                DESCRIPTION_WEIGHT, ANNOTATION_WEIGHT
+            ));
+    }
+    separated.push_unseparated(")");
+}
+
+/// Convert a database row to an Asset
+fn row_to_asset(row: SqliteRow) -> DbResult<Asset> {
+    let id_str: String = row.get("id");
+    let id = AssetId::from_str(&id_str)
+        .map_err(|e| DbError::InvalidData(format!("Invalid asset ID: {}", e)))?;
+
+    let version_str: String = row.get("version");
+    let version = Version::parse(&version_str)
+        .map_err(|e| DbError::InvalidData(format!("Invalid version: {}", e)))?;
+
+    let asset_type = parse_asset_type(row.get("asset_type"))?;
+    let status = parse_asset_status(row.get("status"))?;
+    let backend = parse_storage_backend_from_db(row.get("storage_backend"))?;
+
+    let storage_uri: String = row.get("storage_uri");
+    let storage_path: Option<String> = row.get("storage_path");
+
+    let checksum_algorithm = parse_hash_algorithm(row.get("checksum_algorithm"))?;
+    let checksum_value: String = row.get("checksum_value");
+
+    let metadata_json: String = row.get("metadata");
+    let annotations: HashMap<String, String> =
+        serde_json::from_str(&metadata_json).unwrap_or_default();
+
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let updated_at: DateTime<Utc> = row.get("updated_at");
+    let deprecated_at: Option<DateTime<Utc>> = row.get("deprecated_at");
+
+    let size_bytes: Option<i64> = row.get("size_bytes");
+    let revision: i64 = row.get("revision");
+
+    let provenance = {
+        let author: Option<String> = row.get("author");
+        let source_repo: Option<String> = row.get("source_repo");
+        let commit_hash: Option<String> = row.get("commit_hash");
+        let build_id: Option<String> = row.get("build_id");
+
+        if author.is_some() || source_repo.is_some() {
+            Some(Provenance {
+                author,
+                source_repo,
+                commit_hash,
+                build_id,
+                created_at: Utc::now(),
+                build_metadata: HashMap::new(),
+            })
+        } else {
+            None
+        }
+    };
+
+    let metadata = AssetMetadata {
+        name: row.get("name"),
+        version,
+        description: row.get("description"),
+        license: row.get("license"),
+        tags: Vec::new(), // Loaded separately
+        annotations,
+        size_bytes: size_bytes.map(|s| s as u64),
+        content_type: row.get("content_type"),
+    };
+
+    let storage = StorageLocation {
+        backend,
+        path: storage_path.unwrap_or_default(),
+        uri: Some(storage_uri),
+    };
+
+    let checksum = Checksum {
+        algorithm: checksum_algorithm,
+        value: checksum_value,
+    };
+
+    let signature = {
+        let algorithm: Option<String> = row.get("signature_algorithm");
+        let value: Option<String> = row.get("signature_value");
+        let key_id: Option<String> = row.get("signature_key_id");
+
+        match (algorithm, value, key_id) {
+            (Some(algorithm), Some(value), Some(key_id)) => Some(AssetSignature {
+                algorithm: parse_signature_algorithm(&algorithm)?,
+                key_id,
+                value,
+            }),
+            _ => None,
+        }
+    };
+
+    Ok(Asset {
+        id,
+        asset_type,
+        metadata,
+        status,
+        storage,
+        checksum,
+        signature,
+        provenance,
+        dependencies: Vec::new(), // Loaded separately
+        created_at,
+        updated_at,
+        deprecated_at,
+        revision: revision as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::{create_sqlite_pool, PoolConfig};
+    use crate::repository::SortField;
+    use crate::test_support::test_asset;
+
+    async fn setup() -> SqliteAssetRepository {
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        SqliteAssetRepository::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find() {
+        let repo = setup().await;
+        let mut asset = test_asset("gpt-model", "1.0.0");
+        asset.metadata.tags = vec!["llm".to_string(), "prod".to_string()];
+        asset.metadata.add_annotation("team", "ml");
+
+        repo.create(asset.clone()).await.unwrap();
+
+        let found = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert_eq!(found.metadata.name, "gpt-model");
+        assert_eq!(found.metadata.tags, vec!["llm", "prod"]);
+        assert_eq!(
+            found.metadata.annotations.get("team").map(String::as_str),
+            Some("ml")
+        );
+        assert_eq!(found.created_at, asset.created_at);
+
+        let by_name = repo
+            .find_by_name_and_version("gpt-model", &Version::new(1, 0, 0))
+            .await
+            .unwrap();
+        assert_eq!(by_name.map(|a| a.id), Some(asset.id));
+    }
+
+    #[tokio::test]
+    async fn test_create_duplicate_fails() {
+        let repo = setup().await;
+        repo.create(test_asset("dup", "1.0.0")).await.unwrap();
+
+        let err = repo.create(test_asset("dup", "1.0.0")).await.unwrap_err();
+        assert!(err.is_already_exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_rolls_back_on_failure() {
+        let repo = setup().await;
+        let base = test_asset("base", "1.0.0");
+        let mut derived = test_asset("derived", "1.0.0");
+        derived.dependencies = vec![AssetReference::by_id(base.id)];
+
+        repo.create_batch(vec![base, derived.clone()])
+            .await
+            .unwrap();
+        assert_eq!(repo.list_dependencies(&derived.id).await.unwrap().len(), 1);
+
+        let fresh = test_asset("fresh", "1.0.0");
+        let err = repo
+            .create_batch(vec![fresh.clone(), test_asset("base", "1.0.0")])
+            .await
+            .unwrap_err();
+        assert!(err.is_already_exists());
+        assert!(repo.find_by_id(&fresh.id).await.unwrap().is_none());
+        assert_eq!(repo.count_assets().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_and_total() {
+        let repo = setup().await;
+
+        let mut a = test_asset("alpha", "1.0.0");
+        a.metadata.tags = vec!["nlp".to_string(), "prod".to_string()];
+        let mut b = test_asset("beta", "1.0.0");
+        b.metadata.tags = vec!["nlp".to_string()];
+        let mut c = test_asset("gamma", "1.0.0");
+        c.asset_type = AssetType::Dataset;
+
+        repo.create(a.clone()).await.unwrap();
+        repo.create(b).await.unwrap();
+        repo.create(c).await.unwrap();
+
+        let results = repo
+            .search(&SearchQuery::new().tag("nlp").tag("prod"))
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(1));
+        assert_eq!(results.assets[0].id, a.id);
+
+        let results = repo
+            .search(&SearchQuery::new().asset_type(AssetType::Model).limit(1))
+            .await
+            .unwrap();
+        assert_eq!(results.count(), 1);
+        assert_eq!(results.total, Some(2));
+
+        let results = repo.search(&SearchQuery::new().text("GAM")).await.unwrap();
+        assert_eq!(results.total, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_search_relevance_and_snippets() {
+        let repo = setup().await;
+
+        let mut by_name = test_asset("llama-chat", "1.0.0");
+        by_name.metadata.description = Some("Dialogue model".to_string());
+        let mut by_tag = test_asset("mistral", "1.0.0");
+        by_tag.metadata.tags = vec!["chat".to_string()];
+        let mut by_description = test_asset("falcon", "1.0.0");
+        by_description.metadata.description = Some("Tuned for chat and instructions".to_string());
+        by_description.asset_type = AssetType::Dataset;
+        let mut by_annotation = test_asset("phi", "1.0.0");
+        by_annotation
+            .metadata
+            .annotations
+            .insert("use_case".to_string(), "chat".to_string());
+        by_annotation.asset_type = AssetType::Pipeline;
+
+        for asset in [&by_annotation, &by_description, &by_tag, &by_name] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        let results = repo
+            .search(
+                &SearchQuery::new()
+                    .text("chat")
+                    .sort_by(SortField::Relevance),
+            )
+            .await
+            .unwrap();
+        let ids: Vec<_> = results.assets.iter().map(|a| a.id).collect();
+        assert_eq!(
+            ids,
+            vec![by_name.id, by_tag.id, by_description.id, by_annotation.id]
+        );
+        assert_eq!(
+            results.snippets[&by_description.id],
+            "Tuned for <mark>chat</mark> and instructions"
+        );
+        assert_eq!(results.snippets[&by_name.id], "llama-<mark>chat</mark>");
+        assert!(!results.snippets.contains_key(&by_tag.id));
+
+        // Several asset types combine with OR
+        let results = repo
+            .search(
+                &SearchQuery::new()
+                    .text("chat")
+                    .asset_type(AssetType::Dataset)
+                    .asset_type(AssetType::Pipeline),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.total, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_search_structured_filters() {
+        let repo = setup().await;
+
+        let mut small = test_asset("small", "1.0.0");
+        small.metadata.size_bytes = Some(1_000);
+        small.metadata.license = Some("mit".to_string());
+        small.metadata.tags = vec!["stable".to_string()];
+        small.created_at = "2024-06-01T00:00:00Z".parse().unwrap();
+        let mut large = test_asset("large", "1.0.0");
+        large.metadata.size_bytes = Some(5_000_000_000);
+        large.metadata.license = Some("apache-2.0".to_string());
+        large.metadata.tags = vec!["stable".to_string(), "experimental".to_string()];
+        large.created_at = "2025-03-01T12:00:00Z".parse().unwrap();
+        let mut unsized_asset = test_asset("unsized", "1.0.0");
+        unsized_asset.metadata.size_bytes = None;
+        unsized_asset.metadata.description = Some("exact phrase here".to_string());
+
+        for asset in [&small, &large, &unsized_asset] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        let ids = |results: SearchResults| -> Vec<AssetId> {
+            results.assets.iter().map(|a| a.id).collect()
+        };
+
+        let query = SearchQuery::new().license("apache-2.0");
+        assert_eq!(ids(repo.search(&query).await.unwrap()), vec![large.id]);
+
+        let query = SearchQuery::new().size_range(Some(2_000_000_000), None);
+        assert_eq!(ids(repo.search(&query).await.unwrap()), vec![large.id]);
+
+        let query = SearchQuery::new().size_range(None, Some(1_000));
+        assert_eq!(ids(repo.search(&query).await.unwrap()), vec![small.id]);
+
+        let after = "2025-01-01T00:00:00Z".parse().unwrap();
+        let query = SearchQuery::new().created_between(Some(after), None);
+        let results = repo.search(&query).await.unwrap();
+        assert!(ids(results).contains(&large.id));
+        let query = SearchQuery::new().created_between(None, Some(after));
+        assert_eq!(ids(repo.search(&query).await.unwrap()), vec![small.id]);
+
+        let query = SearchQuery::new().tag("stable").exclude_tag("experimental");
+        assert_eq!(ids(repo.search(&query).await.unwrap()), vec![small.id]);
+
+        let query = SearchQuery::new().text("\"exact phrase\"");
+        assert_eq!(
+            ids(repo.search(&query).await.unwrap()),
+            vec![unsized_asset.id]
+        );
+        let query = SearchQuery::new().text("\"phrase exact\"");
+        assert_eq!(repo.search(&query).await.unwrap().total, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_search_cursor_pagination() {
+        let repo = setup().await;
+
+        // Equal sizes and missing sizes must still page without gaps
+        let mut expected = Vec::new();
+        for (i, size) in [Some(10), None, Some(10), Some(5), None]
+            .into_iter()
+            .enumerate()
+        {
+            let mut asset = test_asset(&format!("model-{}", i), "1.0.0");
+            asset.metadata.size_bytes = size;
+            repo.create(asset.clone()).await.unwrap();
+            expected.push(asset);
+        }
+        expected.sort_by_key(|a| (a.metadata.size_bytes, a.id.to_string()));
+
+        let query = SearchQuery::new()
+            .sort_by(SortField::SizeBytes)
+            .sort_order(SortOrder::Ascending)
+            .limit(2)
+            .include_total(false);
+        let mut seen = Vec::new();
+        let mut page = repo.search(&query).await.unwrap();
+        assert_eq!(page.total, None);
+        loop {
+            seen.extend(page.assets.iter().map(|a| a.id));
+            let Some(cursor) = page.next_cursor.clone() else {
+                break;
+            };
+            page = repo.search(&query.clone().cursor(cursor)).await.unwrap();
+        }
+        assert_eq!(seen, expected.iter().map(|a| a.id).collect::<Vec<_>>());
+
+        // Cursors only resume the sort they were issued for
+        let first = repo.search(&query).await.unwrap();
+        let cursor = first.next_cursor.unwrap();
+        let query = SearchQuery::new()
+            .sort_by(SortField::Name)
+            .cursor(cursor.clone());
+        assert!(matches!(
+            repo.search(&query).await,
+            Err(DbError::InvalidQuery(_))
+        ));
+        let query = SearchQuery::new()
+            .text("model")
+            .sort_by(SortField::Relevance)
+            .cursor(cursor);
+        assert!(matches!(
+            repo.search(&query).await,
+            Err(DbError::InvalidQuery(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete() {
+        let repo = setup().await;
+        let mut asset = test_asset("model", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        asset.metadata.description = Some("updated".to_string());
+        asset.metadata.tags = vec!["new".to_string()];
+        let updated = repo.update(asset.clone()).await.unwrap();
+        assert_eq!(updated.revision, 2);
+
+        let found = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert_eq!(found.metadata.description.as_deref(), Some("updated"));
+        assert_eq!(found.metadata.tags, vec!["new"]);
+        assert_eq!(found.revision, 2);
+
+        // Writing back the stale copy must not clobber the update
+        asset.metadata.description = Some("stale".to_string());
+        assert!(repo.update(asset.clone()).await.unwrap_err().is_conflict());
+        let found = repo.find_by_id(&asset.id).await.unwrap().unwrap();
+        assert_eq!(found.metadata.description.as_deref(), Some("updated"));
+
+        repo.delete(&asset.id).await.unwrap();
+        assert!(repo.find_by_id(&asset.id).await.unwrap().is_none());
+        assert!(repo.delete(&asset.id).await.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_dependencies_and_cycle_detection() {
+        let repo = setup().await;
+        let a = test_asset("a", "1.0.0");
+        let b = test_asset("b", "1.0.0");
+        let c = test_asset("c", "1.0.0");
+        for asset in [&a, &b, &c] {
+            repo.create(asset.clone()).await.unwrap();
+        }
+
+        repo.add_dependency(&a.id, &b.id, None).await.unwrap();
+        repo.add_dependency(&b.id, &c.id, Some("^1.0"))
+            .await
+            .unwrap();
+
+        let deps = repo.list_dependencies(&a.id).await.unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].id, b.id);
+
+        let dependents = repo.list_reverse_dependencies(&c.id).await.unwrap();
+        assert_eq!(dependents[0].id, b.id);
+
+        let mut links = repo
+            .list_dependency_links(&[a.id, b.id, c.id])
+            .await
+            .unwrap();
+        links.sort_by_key(|link| link.asset_id == b.id);
+        assert_eq!(
+            links,
+            vec![
+                DependencyLink {
+                    asset_id: a.id,
+                    dependency_id: b.id
+                },
+                DependencyLink {
+                    asset_id: b.id,
+                    dependency_id: c.id
+                },
+            ]
+        );
+        let reverse = repo.list_reverse_dependency_links(&[c.id]).await.unwrap();
+        assert_eq!(
+            reverse,
+            vec![DependencyLink {
+                asset_id: b.id,
+                dependency_id: c.id
+            }]
+        );
+
+        repo.create(test_asset("a", "2.0.0")).await.unwrap();
+        let names = vec!["a".to_string(), "c".to_string()];
+        assert_eq!(repo.find_by_names(&names).await.unwrap().len(), 3);
+
+        let err = repo.add_dependency(&c.id, &a.id, None).await.unwrap_err();
+        assert!(matches!(err, DbError::CircularDependency(_)));
+
+        // Deleting an asset cascades to its dependency edges
+        repo.delete(&b.id).await.unwrap();
+        assert!(repo.list_dependencies(&a.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tags() {
+        let repo = setup().await;
+        let asset = test_asset("tagged", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        repo.add_tag(&asset.id, "b").await.unwrap();
+        repo.add_tag(&asset.id, "a").await.unwrap();
+        repo.add_tag(&asset.id, "a").await.unwrap();
+        assert_eq!(repo.get_tags(&asset.id).await.unwrap(), vec!["a", "b"]);
+
+        repo.remove_tag(&asset.id, "b").await.unwrap();
+        assert_eq!(repo.list_all_tags().await.unwrap(), vec!["a"]);
+        assert_eq!(repo.count_by_type(&AssetType::Model).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_after_tag_changes() {
+        let repo = setup().await;
+        let asset = test_asset("tagged", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        repo.add_tag(&asset.id, "multilingual").await.unwrap();
+        repo.add_tag(&asset.id, "multilingual").await.unwrap();
+        let results = repo
+            .search(&SearchQuery::new().text("multilingual"))
+            .await
+            .unwrap();
+        assert_eq!(results.assets.len(), 1);
+        assert_eq!(results.assets[0].revision, 2);
+
+        repo.remove_tag(&asset.id, "multilingual").await.unwrap();
+        let results = repo
+            .search(&SearchQuery::new().text("multilingual"))
+            .await
+            .unwrap();
+        assert!(results.assets.is_empty());
+        assert_eq!(
+            repo.find_by_id(&asset.id).await.unwrap().unwrap().revision,
+            3
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Test fixtures
+//!
+//! Shared by the tests of this crate and, through the `test-support`
+//! feature, by the tests of crates built on it.
+
+use llm_registry_core::{Asset, EventType, RegistryEvent};
+
+pub use llm_registry_core::test_support::test_asset;
+
+/// The event recording the registration of an asset, with its snapshot
+pub fn registered_event(asset: &Asset) -> RegistryEvent {
+    RegistryEvent::new(EventType::AssetRegistered {
+        asset_id: asset.id,
+        asset_name: asset.metadata.name.clone(),
+        asset_version: asset.metadata.version.to_string(),
+        asset_type: asset.asset_type.to_string(),
+        snapshot: Some(Box::new(asset.clone())),
+    })
+}
@@ -0,0 +1,233 @@
+//! Unit of work for atomic asset and event writes
+//!
+//! A unit of work groups asset mutations and the events describing them into a
+//! single database transaction, so the event log never misses a committed
+//! change and never records a change that was rolled back. Events written
+//! through a unit of work are also queued in the transactional outbox, from
+//! which [`OutboxRelay`](crate::outbox::OutboxRelay) publishes them.
+
+use async_trait::async_trait;
+use llm_registry_core::{Asset, AssetId, RegistryEvent};
+use sqlx::PgPool;
+use tracing::{debug, instrument};
+
+use crate::error::DbResult;
+use crate::event_store::PostgresEventStore;
+use crate::postgres::PostgresAssetRepository;
+
+/// Factory for write transactions spanning assets and events
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// Begin a new write transaction
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>>;
+}
+
+/// A write transaction over assets and events
+///
+/// Nothing is visible to readers until [`WriteTransaction::commit`] succeeds.
+/// Dropping a transaction without committing rolls it back. Backends may defer
+/// constraint violations (duplicates, missing assets) until commit, so callers
+/// must treat an error from any method, including `commit`, as a rollback.
+#[async_trait]
+pub trait WriteTransaction: Send {
+    /// Insert a new asset with its tags and dependencies
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()>;
+
+    /// Update an existing asset and replace its tags
+    ///
+    /// `asset.revision` is the revision the change was based on. The stored
+    /// revision is incremented on success, and a stale revision fails with
+    /// [`DbError::Conflict`](crate::DbError::Conflict).
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()>;
+
+    /// Delete an asset and its tags and dependency edges
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()>;
+
+    /// Append an event to the event log and queue it for publication
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()>;
+
+    /// Append several events in order
+    async fn append_events(&mut self, events: &[RegistryEvent]) -> DbResult<()> {
+        for event in events {
+            self.append_event(event).await?;
+        }
+        Ok(())
+    }
+
+    /// Commit the transaction
+    ///
+    /// Returns the events appended in this transaction, in append order.
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>>;
+}
+
+/// PostgreSQL implementation of UnitOfWork
+#[derive(Debug, Clone)]
+pub struct PostgresUnitOfWork {
+    pool: PgPool,
+}
+
+impl PostgresUnitOfWork {
+    /// Create a new PostgreSQL unit of work
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>> {
+        Ok(Box::new(PostgresWriteTransaction {
+            tx: self.pool.begin().await?,
+            events: Vec::new(),
+        }))
+    }
+}
+
+/// An open PostgreSQL write transaction
+struct PostgresWriteTransaction {
+    tx: sqlx::Transaction<'static, sqlx::Postgres>,
+    events: Vec<RegistryEvent>,
+}
+
+#[async_trait]
+impl WriteTransaction for PostgresWriteTransaction {
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        PostgresAssetRepository::insert_asset(&mut self.tx, asset).await
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        PostgresAssetRepository::update_asset(&mut self.tx, asset).await
+    }
+
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()> {
+        PostgresAssetRepository::delete_asset(&mut self.tx, id).await
+    }
+
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        PostgresEventStore::insert_event(&mut self.tx, event).await?;
+        self.events.push(event.clone());
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(events = self.events.len()))]
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>> {
+        self.tx.commit().await?;
+        debug!("Unit of work committed");
+        Ok(self.events)
+    }
+}
+
+/// SQLite implementation of UnitOfWork
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone)]
+pub struct SqliteUnitOfWork {
+    pool: sqlx::SqlitePool,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteUnitOfWork {
+    /// Create a new SQLite unit of work
+    pub fn new(pool: sqlx::SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    async fn begin(&self) -> DbResult<Box<dyn WriteTransaction>> {
+        Ok(Box::new(SqliteWriteTransaction {
+            tx: self.pool.begin().await?,
+            events: Vec::new(),
+        }))
+    }
+}
+
+/// An open SQLite write transaction
+#[cfg(feature = "sqlite")]
+struct SqliteWriteTransaction {
+    tx: sqlx::Transaction<'static, sqlx::Sqlite>,
+    events: Vec<RegistryEvent>,
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl WriteTransaction for SqliteWriteTransaction {
+    async fn create_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        crate::sqlite::SqliteAssetRepository::insert_asset(&mut self.tx, asset).await
+    }
+
+    async fn update_asset(&mut self, asset: &Asset) -> DbResult<()> {
+        crate::sqlite::SqliteAssetRepository::update_asset(&mut self.tx, asset).await
+    }
+
+    async fn delete_asset(&mut self, id: &AssetId) -> DbResult<()> {
+        crate::sqlite::SqliteAssetRepository::delete_asset(&mut self.tx, id).await
+    }
+
+    async fn append_event(&mut self, event: &RegistryEvent) -> DbResult<()> {
+        crate::event_store::SqliteEventStore::insert_event(&mut self.tx, event).await?;
+        self.events.push(event.clone());
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(events = self.events.len()))]
+    async fn commit(self: Box<Self>) -> DbResult<Vec<RegistryEvent>> {
+        self.tx.commit().await?;
+        debug!("Unit of work committed");
+        Ok(self.events)
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::event_store::{EventStore, SqliteEventStore};
+    use crate::pool::{create_sqlite_pool, PoolConfig};
+    use crate::repository::AssetRepository;
+    use crate::sqlite::SqliteAssetRepository;
+    use crate::test_support::{registered_event, test_asset};
+
+    async fn setup() -> (SqliteUnitOfWork, SqliteAssetRepository, SqliteEventStore) {
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        (
+            SqliteUnitOfWork::new(pool.clone()),
+            SqliteAssetRepository::new(pool.clone()),
+            SqliteEventStore::new(pool),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_commit_writes_assets_and_events() {
+        let (uow, repo, events) = setup().await;
+        let asset = test_asset("committed", "1.0.0");
+
+        let mut tx = uow.begin().await.unwrap();
+        tx.create_asset(&asset).await.unwrap();
+        tx.append_event(&registered_event(&asset)).await.unwrap();
+        let committed = tx.commit().await.unwrap();
+
+        assert_eq!(committed.len(), 1);
+        assert!(repo.find_by_id(&asset.id).await.unwrap().is_some());
+        assert_eq!(events.count_events().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_rolls_back_events() {
+        let (uow, repo, events) = setup().await;
+        let asset = test_asset("existing", "1.0.0");
+        repo.create(asset.clone()).await.unwrap();
+
+        let mut tx = uow.begin().await.unwrap();
+        tx.append_event(&registered_event(&asset)).await.unwrap();
+        let mut duplicate = test_asset("existing", "1.0.0");
+        duplicate.id = AssetId::new();
+        assert!(tx.create_asset(&duplicate).await.is_err());
+        drop(tx);
+
+        assert_eq!(events.count_events().await.unwrap(), 0);
+        assert_eq!(repo.count_assets().await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,1314 @@
+//! Webhook subscriptions and delivery log
+//!
+//! Webhooks let consumers that cannot speak NATS receive registry events over
+//! HTTP. This module stores the subscriptions and a log of every delivery.
+//! Deliveries are queued when events are relayed from the outbox and claimed
+//! by the dispatcher when they fall due; claiming pushes a delivery's
+//! `next_attempt_at` forward by a lease, so replicas sharing a database never
+//! send the same delivery concurrently.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use llm_registry_core::RegistryEvent;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::{PgPool, Row};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::error::{DbError, DbResult};
+use crate::nats_publisher::EventMessage;
+
+/// A webhook subscription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Webhook {
+    /// Webhook ID
+    pub id: String,
+
+    /// Endpoint receiving deliveries
+    pub url: String,
+
+    /// Human-readable description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// HMAC-SHA256 signing secret, never serialized
+    #[serde(skip_serializing)]
+    pub secret: String,
+
+    /// Event names to deliver; empty delivers every event
+    pub event_types: Vec<String>,
+
+    /// Asset name patterns to deliver, where `*` matches any run of
+    /// characters; empty delivers events for every asset
+    pub asset_names: Vec<String>,
+
+    /// Deliver only events for assets with at least one of these tags;
+    /// empty delivers events for every asset
+    pub tags: Vec<String>,
+
+    /// Whether deliveries are sent
+    pub enabled: bool,
+
+    /// Failed delivery attempts since the last success
+    pub consecutive_failures: u32,
+
+    /// Why the webhook was disabled automatically, if it was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_reason: Option<String>,
+
+    /// Creation time
+    pub created_at: DateTime<Utc>,
+
+    /// Last update time
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Create an enabled webhook delivering every event
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ulid::Ulid::new().to_string(),
+            url: url.into(),
+            description: None,
+            secret: secret.into(),
+            event_types: Vec::new(),
+            asset_names: Vec::new(),
+            tags: Vec::new(),
+            enabled: true,
+            consecutive_failures: 0,
+            disabled_reason: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the event type filter accepts an event name
+    pub fn accepts_event_type(&self, event_name: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_name)
+    }
+
+    /// Whether the webhook filters on the event's asset
+    pub fn has_asset_filter(&self) -> bool {
+        !self.asset_names.is_empty() || !self.tags.is_empty()
+    }
+
+    /// Whether the asset filters accept an asset name and its tags
+    pub fn accepts_asset(&self, name: &str, tags: &[String]) -> bool {
+        let name_matches = self.asset_names.is_empty()
+            || self
+                .asset_names
+                .iter()
+                .any(|pattern| matches_pattern(pattern, name));
+        let tag_matches = self.tags.is_empty() || self.tags.iter().any(|tag| tags.contains(tag));
+
+        name_matches && tag_matches
+    }
+}
+
+/// Match a name against a pattern where `*` matches any run of characters
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard: the whole name must match
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// State of a webhook delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting for its first or next attempt
+    Pending,
+
+    /// Accepted by the endpoint
+    Succeeded,
+
+    /// Gave up after the maximum number of attempts
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// Name stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = DbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "succeeded" => Ok(DeliveryStatus::Succeeded),
+            "failed" => Ok(DeliveryStatus::Failed),
+            other => Err(DbError::InvalidData(format!(
+                "Invalid delivery status: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// A delivery of one event to one webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WebhookDelivery {
+    /// Delivery ID, sent with each attempt
+    pub id: String,
+
+    /// Receiving webhook
+    pub webhook_id: String,
+
+    /// Delivered event
+    pub event_id: String,
+
+    /// Name of the delivered event
+    pub event_type: String,
+
+    /// Request body, the event in the same envelope as NATS messages
+    pub payload: serde_json::Value,
+
+    /// Delivery state
+    pub status: DeliveryStatus,
+
+    /// Attempts made so far
+    pub attempts: u32,
+
+    /// When the next attempt is due
+    pub next_attempt_at: DateTime<Utc>,
+
+    /// HTTP status of the last attempt, if the endpoint responded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status_code: Option<u16>,
+
+    /// Error from the last failed attempt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    /// Delivery this one replays
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replay_of: Option<String>,
+
+    /// When the delivery was queued
+    pub created_at: DateTime<Utc>,
+
+    /// When the endpoint accepted the delivery
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    /// Queue an event for immediate delivery to a webhook
+    pub fn new(webhook_id: impl Into<String>, event: &RegistryEvent) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ulid::Ulid::new().to_string(),
+            webhook_id: webhook_id.into(),
+            event_id: event.event_id.clone(),
+            event_type: event.event_name().to_string(),
+            payload: serde_json::to_value(EventMessage::from(event))
+                .unwrap_or(serde_json::Value::Null),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status_code: None,
+            last_error: None,
+            replay_of: None,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    /// A new delivery sending the same payload again
+    pub fn replay(&self) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ulid::Ulid::new().to_string(),
+            webhook_id: self.webhook_id.clone(),
+            event_id: self.event_id.clone(),
+            event_type: self.event_type.clone(),
+            payload: self.payload.clone(),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status_code: None,
+            last_error: None,
+            replay_of: Some(self.id.clone()),
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+}
+
+/// Storage for webhooks and their deliveries
+#[async_trait]
+pub trait WebhookStore: Send + Sync {
+    /// Store a new webhook
+    async fn create_webhook(&self, webhook: &Webhook) -> DbResult<()>;
+
+    /// Get a webhook by ID
+    async fn get_webhook(&self, id: &str) -> DbResult<Option<Webhook>>;
+
+    /// List all webhooks, oldest first
+    async fn list_webhooks(&self) -> DbResult<Vec<Webhook>>;
+
+    /// Replace a stored webhook
+    async fn update_webhook(&self, webhook: &Webhook) -> DbResult<()>;
+
+    /// Delete a webhook and its delivery log, returning whether it existed
+    async fn delete_webhook(&self, id: &str) -> DbResult<bool>;
+
+    /// Record the outcome of a delivery attempt against its webhook
+    ///
+    /// A success resets the failure count. A failure increments it and
+    /// disables the webhook once it reaches `disable_after`. Returns whether
+    /// the webhook is still enabled.
+    async fn record_outcome(
+        &self,
+        webhook_id: &str,
+        success: bool,
+        disable_after: u32,
+    ) -> DbResult<bool>;
+
+    /// Queue deliveries, returning the number queued
+    ///
+    /// Deliveries for an event already queued for the same webhook, and
+    /// deliveries for deleted webhooks, are skipped, so relaying an event
+    /// again is harmless.
+    async fn enqueue_deliveries(&self, deliveries: &[WebhookDelivery]) -> DbResult<usize>;
+
+    /// Claim pending deliveries of enabled webhooks that are due at `now`
+    ///
+    /// Claimed deliveries are not due again until `lease` has passed, by
+    /// which time the dispatcher has recorded the attempt.
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>>;
+
+    /// Save the state of a delivery after an attempt
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()>;
+
+    /// Get a delivery by ID
+    async fn get_delivery(&self, id: &str) -> DbResult<Option<WebhookDelivery>>;
+
+    /// List a webhook's deliveries, newest first
+    async fn list_deliveries(&self, webhook_id: &str, limit: i64)
+        -> DbResult<Vec<WebhookDelivery>>;
+}
+
+/// Reason recorded when a webhook is disabled for failing
+fn disabled_reason(failures: u32) -> String {
+    format!(
+        "Disabled after {} consecutive failed delivery attempts",
+        failures
+    )
+}
+
+fn lease_until(now: DateTime<Utc>, lease: Duration) -> DbResult<DateTime<Utc>> {
+    chrono::Duration::from_std(lease)
+        .map(|lease| now + lease)
+        .map_err(|e| DbError::InvalidData(format!("Invalid delivery lease: {}", e)))
+}
+
+// ============================================================================
+// In-memory implementation
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct WebhookState {
+    webhooks: HashMap<String, Webhook>,
+    /// Deliveries in the order they were queued
+    deliveries: Vec<WebhookDelivery>,
+}
+
+/// In-memory implementation of WebhookStore
+#[derive(Debug, Default)]
+pub struct InMemoryWebhookStore {
+    state: RwLock<WebhookState>,
+}
+
+impl InMemoryWebhookStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookStore for InMemoryWebhookStore {
+    async fn create_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        let mut state = self.state.write().await;
+        if state.webhooks.contains_key(&webhook.id) {
+            return Err(DbError::AlreadyExists(format!(
+                "Webhook already exists: {}",
+                webhook.id
+            )));
+        }
+        state.webhooks.insert(webhook.id.clone(), webhook.clone());
+        Ok(())
+    }
+
+    async fn get_webhook(&self, id: &str) -> DbResult<Option<Webhook>> {
+        Ok(self.state.read().await.webhooks.get(id).cloned())
+    }
+
+    async fn list_webhooks(&self) -> DbResult<Vec<Webhook>> {
+        let state = self.state.read().await;
+        let mut webhooks: Vec<Webhook> = state.webhooks.values().cloned().collect();
+        webhooks.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+        Ok(webhooks)
+    }
+
+    async fn update_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        let mut state = self.state.write().await;
+        match state.webhooks.get_mut(&webhook.id) {
+            Some(stored) => {
+                *stored = webhook.clone();
+                Ok(())
+            }
+            None => Err(DbError::NotFound(format!(
+                "Webhook not found: {}",
+                webhook.id
+            ))),
+        }
+    }
+
+    async fn delete_webhook(&self, id: &str) -> DbResult<bool> {
+        let mut state = self.state.write().await;
+        let existed = state.webhooks.remove(id).is_some();
+        state.deliveries.retain(|d| d.webhook_id != id);
+        Ok(existed)
+    }
+
+    async fn record_outcome(
+        &self,
+        webhook_id: &str,
+        success: bool,
+        disable_after: u32,
+    ) -> DbResult<bool> {
+        let mut state = self.state.write().await;
+        let Some(webhook) = state.webhooks.get_mut(webhook_id) else {
+            return Ok(false);
+        };
+
+        if success {
+            webhook.consecutive_failures = 0;
+        } else {
+            webhook.consecutive_failures += 1;
+            if webhook.enabled && webhook.consecutive_failures >= disable_after {
+                webhook.enabled = false;
+                webhook.disabled_reason = Some(disabled_reason(webhook.consecutive_failures));
+                webhook.updated_at = Utc::now();
+            }
+        }
+        Ok(webhook.enabled)
+    }
+
+    async fn enqueue_deliveries(&self, deliveries: &[WebhookDelivery]) -> DbResult<usize> {
+        let mut state = self.state.write().await;
+        let mut queued = 0;
+        for delivery in deliveries {
+            let duplicate = delivery.replay_of.is_none()
+                && state.deliveries.iter().any(|d| {
+                    d.replay_of.is_none()
+                        && d.webhook_id == delivery.webhook_id
+                        && d.event_id == delivery.event_id
+                });
+            if duplicate || !state.webhooks.contains_key(&delivery.webhook_id) {
+                continue;
+            }
+            state.deliveries.push(delivery.clone());
+            queued += 1;
+        }
+        Ok(queued)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let until = lease_until(now, lease)?;
+        let mut guard = self.state.write().await;
+        let state = &mut *guard;
+
+        let mut due: Vec<&mut WebhookDelivery> = state
+            .deliveries
+            .iter_mut()
+            .filter(|d| {
+                d.status == DeliveryStatus::Pending
+                    && d.next_attempt_at <= now
+                    && state.webhooks.get(&d.webhook_id).is_some_and(|w| w.enabled)
+            })
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at);
+
+        Ok(due
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .map(|delivery| {
+                delivery.next_attempt_at = until;
+                delivery.clone()
+            })
+            .collect())
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        let mut state = self.state.write().await;
+        match state.deliveries.iter_mut().find(|d| d.id == delivery.id) {
+            Some(stored) => {
+                *stored = delivery.clone();
+                Ok(())
+            }
+            None => Err(DbError::NotFound(format!(
+                "Webhook delivery not found: {}",
+                delivery.id
+            ))),
+        }
+    }
+
+    async fn get_delivery(&self, id: &str) -> DbResult<Option<WebhookDelivery>> {
+        let state = self.state.read().await;
+        Ok(state.deliveries.iter().find(|d| d.id == id).cloned())
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let state = self.state.read().await;
+        Ok(state
+            .deliveries
+            .iter()
+            .rev()
+            .filter(|d| d.webhook_id == webhook_id)
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+// ============================================================================
+// PostgreSQL implementation
+// ============================================================================
+
+const WEBHOOK_COLUMNS: &str = "id, url, description, secret, event_types, asset_names, tags, \
+     enabled, consecutive_failures, disabled_reason, created_at, updated_at";
+
+const DELIVERY_COLUMNS: &str = "id, webhook_id, event_id, event_type, payload, status, attempts, \
+     next_attempt_at, last_status_code, last_error, replay_of, created_at, delivered_at";
+
+/// PostgreSQL implementation of WebhookStore
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookStore {
+    pool: PgPool,
+}
+
+impl PostgresWebhookStore {
+    /// Create a new PostgreSQL webhook store
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn pg_row_to_webhook(row: &sqlx::postgres::PgRow) -> Webhook {
+    Webhook {
+        id: row.get("id"),
+        url: row.get("url"),
+        description: row.get("description"),
+        secret: row.get("secret"),
+        event_types: row.get::<Json<Vec<String>>, _>("event_types").0,
+        asset_names: row.get::<Json<Vec<String>>, _>("asset_names").0,
+        tags: row.get::<Json<Vec<String>>, _>("tags").0,
+        enabled: row.get("enabled"),
+        consecutive_failures: row.get::<i32, _>("consecutive_failures") as u32,
+        disabled_reason: row.get("disabled_reason"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+fn pg_row_to_delivery(row: &sqlx::postgres::PgRow) -> DbResult<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        id: row.get("id"),
+        webhook_id: row.get("webhook_id"),
+        event_id: row.get("event_id"),
+        event_type: row.get("event_type"),
+        payload: row.get("payload"),
+        status: row.get::<String, _>("status").parse()?,
+        attempts: row.get::<i32, _>("attempts") as u32,
+        next_attempt_at: row.get("next_attempt_at"),
+        last_status_code: row
+            .get::<Option<i32>, _>("last_status_code")
+            .map(|code| code as u16),
+        last_error: row.get("last_error"),
+        replay_of: row.get("replay_of"),
+        created_at: row.get("created_at"),
+        delivered_at: row.get("delivered_at"),
+    })
+}
+
+#[async_trait]
+impl WebhookStore for PostgresWebhookStore {
+    async fn create_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhooks (
+                id, url, description, secret, event_types, asset_names, tags,
+                enabled, consecutive_failures, disabled_reason, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(&webhook.id)
+        .bind(&webhook.url)
+        .bind(&webhook.description)
+        .bind(&webhook.secret)
+        .bind(Json(&webhook.event_types))
+        .bind(Json(&webhook.asset_names))
+        .bind(Json(&webhook.tags))
+        .bind(webhook.enabled)
+        .bind(webhook.consecutive_failures as i32)
+        .bind(&webhook.disabled_reason)
+        .bind(webhook.created_at)
+        .bind(webhook.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_webhook(&self, id: &str) -> DbResult<Option<Webhook>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM webhooks WHERE id = $1",
+            WEBHOOK_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(pg_row_to_webhook))
+    }
+
+    async fn list_webhooks(&self) -> DbResult<Vec<Webhook>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM webhooks ORDER BY created_at, id",
+            WEBHOOK_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(pg_row_to_webhook).collect())
+    }
+
+    async fn update_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhooks
+            SET url = $2, description = $3, secret = $4, event_types = $5,
+                asset_names = $6, tags = $7, enabled = $8, consecutive_failures = $9,
+                disabled_reason = $10, updated_at = $11
+            WHERE id = $1
+            "#,
+        )
+        .bind(&webhook.id)
+        .bind(&webhook.url)
+        .bind(&webhook.description)
+        .bind(&webhook.secret)
+        .bind(Json(&webhook.event_types))
+        .bind(Json(&webhook.asset_names))
+        .bind(Json(&webhook.tags))
+        .bind(webhook.enabled)
+        .bind(webhook.consecutive_failures as i32)
+        .bind(&webhook.disabled_reason)
+        .bind(webhook.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!(
+                "Webhook not found: {}",
+                webhook.id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn delete_webhook(&self, id: &str) -> DbResult<bool> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_outcome(
+        &self,
+        webhook_id: &str,
+        success: bool,
+        disable_after: u32,
+    ) -> DbResult<bool> {
+        let row = if success {
+            sqlx::query(
+                "UPDATE webhooks SET consecutive_failures = 0 WHERE id = $1 RETURNING enabled",
+            )
+            .bind(webhook_id)
+            .fetch_optional(&self.pool)
+            .await?
+        } else {
+            sqlx::query(
+                r#"
+                UPDATE webhooks
+                SET consecutive_failures = consecutive_failures + 1,
+                    enabled = enabled AND consecutive_failures + 1 < $2,
+                    disabled_reason = CASE
+                        WHEN enabled AND consecutive_failures + 1 >= $2 THEN $3
+                        ELSE disabled_reason
+                    END,
+                    updated_at = CASE
+                        WHEN enabled AND consecutive_failures + 1 >= $2 THEN $4
+                        ELSE updated_at
+                    END
+                WHERE id = $1
+                RETURNING enabled
+                "#,
+            )
+            .bind(webhook_id)
+            .bind(disable_after as i32)
+            .bind(disabled_reason(disable_after))
+            .bind(Utc::now())
+            .fetch_optional(&self.pool)
+            .await?
+        };
+
+        Ok(row.is_some_and(|row| row.get("enabled")))
+    }
+
+    async fn enqueue_deliveries(&self, deliveries: &[WebhookDelivery]) -> DbResult<usize> {
+        let mut tx = self.pool.begin().await?;
+        let mut queued = 0;
+
+        for delivery in deliveries {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO webhook_deliveries (
+                    id, webhook_id, event_id, event_type, payload, status, attempts,
+                    next_attempt_at, replay_of, created_at
+                )
+                SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9, $10
+                WHERE EXISTS (SELECT 1 FROM webhooks WHERE id = $2)
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(&delivery.id)
+            .bind(&delivery.webhook_id)
+            .bind(&delivery.event_id)
+            .bind(&delivery.event_type)
+            .bind(&delivery.payload)
+            .bind(delivery.status.as_str())
+            .bind(delivery.attempts as i32)
+            .bind(delivery.next_attempt_at)
+            .bind(&delivery.replay_of)
+            .bind(delivery.created_at)
+            .execute(&mut *tx)
+            .await?;
+            queued += result.rows_affected() as usize;
+        }
+
+        tx.commit().await?;
+        Ok(queued)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $2
+            WHERE id IN (
+                SELECT d.id
+                FROM webhook_deliveries d
+                JOIN webhooks w ON w.id = d.webhook_id
+                WHERE d.status = 'pending' AND d.next_attempt_at <= $1 AND w.enabled
+                ORDER BY d.next_attempt_at
+                LIMIT $3
+                FOR UPDATE OF d SKIP LOCKED
+            )
+            RETURNING {}
+            "#,
+            DELIVERY_COLUMNS
+        ))
+        .bind(now)
+        .bind(lease_until(now, lease)?)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut deliveries = rows
+            .iter()
+            .map(pg_row_to_delivery)
+            .collect::<DbResult<Vec<_>>>()?;
+        deliveries.sort_by_key(|d| d.created_at);
+        Ok(deliveries)
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2, attempts = $3, next_attempt_at = $4, last_status_code = $5,
+                last_error = $6, delivered_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(&delivery.id)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i32)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_status_code.map(i32::from))
+        .bind(&delivery.last_error)
+        .bind(delivery.delivered_at)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!(
+                "Webhook delivery not found: {}",
+                delivery.id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get_delivery(&self, id: &str) -> DbResult<Option<WebhookDelivery>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM webhook_deliveries WHERE id = $1",
+            DELIVERY_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(pg_row_to_delivery).transpose()
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {} FROM webhook_deliveries
+            WHERE webhook_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+            DELIVERY_COLUMNS
+        ))
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(pg_row_to_delivery).collect()
+    }
+}
+
+// ============================================================================
+// SQLite implementation
+// ============================================================================
+
+/// SQLite implementation of WebhookStore
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone)]
+pub struct SqliteWebhookStore {
+    pool: sqlx::SqlitePool,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteWebhookStore {
+    /// Create a new SQLite webhook store
+    pub fn new(pool: sqlx::SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_row_to_webhook(row: &sqlx::sqlite::SqliteRow) -> Webhook {
+    Webhook {
+        id: row.get("id"),
+        url: row.get("url"),
+        description: row.get("description"),
+        secret: row.get("secret"),
+        event_types: row.get::<Json<Vec<String>>, _>("event_types").0,
+        asset_names: row.get::<Json<Vec<String>>, _>("asset_names").0,
+        tags: row.get::<Json<Vec<String>>, _>("tags").0,
+        enabled: row.get("enabled"),
+        consecutive_failures: row.get::<i32, _>("consecutive_failures") as u32,
+        disabled_reason: row.get("disabled_reason"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_row_to_delivery(row: &sqlx::sqlite::SqliteRow) -> DbResult<WebhookDelivery> {
+    let payload: String = row.get("payload");
+    Ok(WebhookDelivery {
+        id: row.get("id"),
+        webhook_id: row.get("webhook_id"),
+        event_id: row.get("event_id"),
+        event_type: row.get("event_type"),
+        payload: serde_json::from_str(&payload)
+            .map_err(|e| DbError::Serialization(format!("Invalid delivery payload: {}", e)))?,
+        status: row.get::<String, _>("status").parse()?,
+        attempts: row.get::<i32, _>("attempts") as u32,
+        next_attempt_at: row.get("next_attempt_at"),
+        last_status_code: row
+            .get::<Option<i32>, _>("last_status_code")
+            .map(|code| code as u16),
+        last_error: row.get("last_error"),
+        replay_of: row.get("replay_of"),
+        created_at: row.get("created_at"),
+        delivered_at: row.get("delivered_at"),
+    })
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl WebhookStore for SqliteWebhookStore {
+    async fn create_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhooks (
+                id, url, description, secret, event_types, asset_names, tags,
+                enabled, consecutive_failures, disabled_reason, created_at, updated_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&webhook.id)
+        .bind(&webhook.url)
+        .bind(&webhook.description)
+        .bind(&webhook.secret)
+        .bind(Json(&webhook.event_types))
+        .bind(Json(&webhook.asset_names))
+        .bind(Json(&webhook.tags))
+        .bind(webhook.enabled)
+        .bind(webhook.consecutive_failures as i32)
+        .bind(&webhook.disabled_reason)
+        .bind(webhook.created_at)
+        .bind(webhook.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_webhook(&self, id: &str) -> DbResult<Option<Webhook>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM webhooks WHERE id = ?",
+            WEBHOOK_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(sqlite_row_to_webhook))
+    }
+
+    async fn list_webhooks(&self) -> DbResult<Vec<Webhook>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM webhooks ORDER BY created_at, id",
+            WEBHOOK_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(sqlite_row_to_webhook).collect())
+    }
+
+    async fn update_webhook(&self, webhook: &Webhook) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhooks
+            SET url = ?, description = ?, secret = ?, event_types = ?, asset_names = ?,
+                tags = ?, enabled = ?, consecutive_failures = ?, disabled_reason = ?,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&webhook.url)
+        .bind(&webhook.description)
+        .bind(&webhook.secret)
+        .bind(Json(&webhook.event_types))
+        .bind(Json(&webhook.asset_names))
+        .bind(Json(&webhook.tags))
+        .bind(webhook.enabled)
+        .bind(webhook.consecutive_failures as i32)
+        .bind(&webhook.disabled_reason)
+        .bind(webhook.updated_at)
+        .bind(&webhook.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!(
+                "Webhook not found: {}",
+                webhook.id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn delete_webhook(&self, id: &str) -> DbResult<bool> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_outcome(
+        &self,
+        webhook_id: &str,
+        success: bool,
+        disable_after: u32,
+    ) -> DbResult<bool> {
+        let row = if success {
+            sqlx::query(
+                "UPDATE webhooks SET consecutive_failures = 0 WHERE id = ? RETURNING enabled",
+            )
+            .bind(webhook_id)
+            .fetch_optional(&self.pool)
+            .await?
+        } else {
+            sqlx::query(
+                r#"
+                UPDATE webhooks
+                SET consecutive_failures = consecutive_failures + 1,
+                    enabled = enabled AND consecutive_failures + 1 < ?1,
+                    disabled_reason = CASE
+                        WHEN enabled AND consecutive_failures + 1 >= ?1 THEN ?2
+                        ELSE disabled_reason
+                    END,
+                    updated_at = CASE
+                        WHEN enabled AND consecutive_failures + 1 >= ?1 THEN ?3
+                        ELSE updated_at
+                    END
+                WHERE id = ?4
+                RETURNING enabled
+                "#,
+            )
+            .bind(disable_after as i32)
+            .bind(disabled_reason(disable_after))
+            .bind(Utc::now())
+            .bind(webhook_id)
+            .fetch_optional(&self.pool)
+            .await?
+        };
+
+        Ok(row.is_some_and(|row| row.get("enabled")))
+    }
+
+    async fn enqueue_deliveries(&self, deliveries: &[WebhookDelivery]) -> DbResult<usize> {
+        let mut tx = self.pool.begin().await?;
+        let mut queued = 0;
+
+        for delivery in deliveries {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO webhook_deliveries (
+                    id, webhook_id, event_id, event_type, payload, status, attempts,
+                    next_attempt_at, replay_of, created_at
+                )
+                SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10
+                WHERE EXISTS (SELECT 1 FROM webhooks WHERE id = ?2)
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(&delivery.id)
+            .bind(&delivery.webhook_id)
+            .bind(&delivery.event_id)
+            .bind(&delivery.event_type)
+            .bind(delivery.payload.to_string())
+            .bind(delivery.status.as_str())
+            .bind(delivery.attempts as i32)
+            .bind(delivery.next_attempt_at)
+            .bind(&delivery.replay_of)
+            .bind(delivery.created_at)
+            .execute(&mut *tx)
+            .await?;
+            queued += result.rows_affected() as usize;
+        }
+
+        tx.commit().await?;
+        Ok(queued)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        // SQLite serializes writers, so the claim needs no row locks
+        let rows = sqlx::query(&format!(
+            r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = ?2
+            WHERE id IN (
+                SELECT d.id
+                FROM webhook_deliveries d
+                JOIN webhooks w ON w.id = d.webhook_id
+                WHERE d.status = 'pending' AND d.next_attempt_at <= ?1 AND w.enabled
+                ORDER BY d.next_attempt_at
+                LIMIT ?3
+            )
+            RETURNING {}
+            "#,
+            DELIVERY_COLUMNS
+        ))
+        .bind(now)
+        .bind(lease_until(now, lease)?)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut deliveries = rows
+            .iter()
+            .map(sqlite_row_to_delivery)
+            .collect::<DbResult<Vec<_>>>()?;
+        deliveries.sort_by_key(|d| d.created_at);
+        Ok(deliveries)
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> DbResult<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = ?, attempts = ?, next_attempt_at = ?, last_status_code = ?,
+                last_error = ?, delivered_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i32)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_status_code.map(i32::from))
+        .bind(&delivery.last_error)
+        .bind(delivery.delivered_at)
+        .bind(&delivery.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(format!(
+                "Webhook delivery not found: {}",
+                delivery.id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get_delivery(&self, id: &str) -> DbResult<Option<WebhookDelivery>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM webhook_deliveries WHERE id = ?",
+            DELIVERY_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(sqlite_row_to_delivery).transpose()
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: i64,
+    ) -> DbResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {} FROM webhook_deliveries
+            WHERE webhook_id = ?
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?
+            "#,
+            DELIVERY_COLUMNS
+        ))
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(sqlite_row_to_delivery).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_core::{AssetId, EventType};
+
+    fn deleted_event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_asset_name_patterns() {
+        assert!(matches_pattern("gpt-2", "gpt-2"));
+        assert!(!matches_pattern("gpt-2", "gpt-2-large"));
+        assert!(matches_pattern("gpt-*", "gpt-2-large"));
+        assert!(matches_pattern("*-large", "gpt-2-large"));
+        assert!(matches_pattern("gpt*large", "gpt-2-large"));
+        assert!(!matches_pattern("gpt*small", "gpt-2-large"));
+        assert!(matches_pattern("*", "anything"));
+
+        let mut webhook = Webhook::new("https://example.com/hook", "secret");
+        webhook.asset_names = vec!["llama-*".to_string()];
+        webhook.tags = vec!["prod".to_string(), "staging".to_string()];
+        assert!(webhook.accepts_asset("llama-3", &["staging".to_string()]));
+        assert!(!webhook.accepts_asset("llama-3", &["dev".to_string()]));
+        assert!(!webhook.accepts_asset("mistral", &["prod".to_string()]));
+    }
+
+    /// Exercise the store contract shared by every backend
+    async fn exercise_store(store: &dyn WebhookStore) {
+        let webhook = Webhook::new("https://example.com/hook", "secret");
+        store.create_webhook(&webhook).await.unwrap();
+        assert_eq!(
+            store.get_webhook(&webhook.id).await.unwrap(),
+            Some(webhook.clone())
+        );
+
+        // Relaying the same event twice queues it once
+        let event = deleted_event();
+        let delivery = WebhookDelivery::new(&webhook.id, &event);
+        let orphan = WebhookDelivery::new("missing", &event);
+        assert_eq!(
+            store
+                .enqueue_deliveries(&[delivery.clone(), orphan])
+                .await
+                .unwrap(),
+            1
+        );
+        let again = WebhookDelivery::new(&webhook.id, &event);
+        assert_eq!(store.enqueue_deliveries(&[again]).await.unwrap(), 0);
+
+        // A claimed delivery is not due again until its lease expires
+        let now = Utc::now();
+        let claimed = store
+            .claim_due_deliveries(now, Duration::from_secs(60), 10)
+            .await
+            .unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, delivery.id);
+        assert!(store
+            .claim_due_deliveries(now, Duration::from_secs(60), 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let mut attempted = claimed[0].clone();
+        attempted.status = DeliveryStatus::Failed;
+        attempted.attempts = 3;
+        attempted.last_status_code = Some(503);
+        store.update_delivery(&attempted).await.unwrap();
+
+        // Replays keep the log of the original delivery
+        let replay = attempted.replay();
+        assert_eq!(
+            store
+                .enqueue_deliveries(std::slice::from_ref(&replay))
+                .await
+                .unwrap(),
+            1
+        );
+        let log = store.list_deliveries(&webhook.id, 10).await.unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1].status, DeliveryStatus::Failed);
+        assert_eq!(log[1].last_status_code, Some(503));
+        assert_eq!(log[0].replay_of.as_deref(), Some(delivery.id.as_str()));
+
+        // Repeated failures disable the webhook, and its deliveries stop
+        assert!(store.record_outcome(&webhook.id, false, 2).await.unwrap());
+        assert!(!store.record_outcome(&webhook.id, false, 2).await.unwrap());
+        let disabled = store.get_webhook(&webhook.id).await.unwrap().unwrap();
+        assert_eq!(disabled.consecutive_failures, 2);
+        assert!(disabled.disabled_reason.is_some());
+        assert!(store
+            .claim_due_deliveries(Utc::now(), Duration::from_secs(60), 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert!(store.delete_webhook(&webhook.id).await.unwrap());
+        assert!(store.list_webhooks().await.unwrap().is_empty());
+        assert!(store.get_delivery(&replay.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store() {
+        exercise_store(&InMemoryWebhookStore::new()).await;
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_sqlite_store() {
+        let pool =
+            crate::pool::create_sqlite_pool(&crate::pool::PoolConfig::new("sqlite::memory:"))
+                .await
+                .unwrap();
+        exercise_store(&SqliteWebhookStore::new(pool)).await;
+    }
+}
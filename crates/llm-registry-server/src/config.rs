@@ -7,6 +7,7 @@
 //! - Command-line arguments
 
 use config::{Config, ConfigError, Environment, File};
+use llm_registry_db::{DatabaseBackend, DbResult};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -29,6 +30,34 @@ pub struct ServerConfig {
     /// CORS settings
     #[serde(default)]
     pub cors: CorsConfig,
+
+    /// Asset signing settings
+    #[serde(default)]
+    pub signing: SigningConfig,
+
+    /// NATS event publishing settings
+    #[serde(default)]
+    pub nats: NatsConfig,
+
+    /// Search index settings
+    #[serde(default)]
+    pub search: SearchConfig,
+
+    /// Audit log settings
+    #[serde(default)]
+    pub audit: AuditConfig,
+
+    /// Asset cache settings
+    #[serde(default)]
+    pub cache: AssetCacheConfig,
+
+    /// Webhook delivery settings
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+
+    /// Asset content storage settings
+    #[serde(default)]
+    pub content: ContentConfig,
 }
 
 /// HTTP server configuration
@@ -121,6 +150,9 @@ impl Default for GrpcServerConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     /// Database connection URL
+    ///
+    /// The scheme selects the backend: `postgres://` / `postgresql://` for
+    /// PostgreSQL, `sqlite://path/to/file.db` or `sqlite::memory:` for SQLite.
     pub url: String,
 
     /// Maximum number of connections in the pool
@@ -182,6 +214,13 @@ impl Default for DatabaseConfig {
     }
 }
 
+impl DatabaseConfig {
+    /// Get the database backend selected by the URL scheme
+    pub fn backend(&self) -> DbResult<DatabaseBackend> {
+        DatabaseBackend::from_url(&self.url)
+    }
+}
+
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -252,6 +291,402 @@ impl Default for CorsConfig {
     }
 }
 
+/// Asset signing configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SigningConfig {
+    /// Public keys trusted to sign assets
+    #[serde(default)]
+    pub trusted_keys: Vec<TrustedKeyConfig>,
+}
+
+/// Trusted public key configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedKeyConfig {
+    /// Key identifier referenced by asset signatures
+    pub key_id: String,
+
+    /// Base64-encoded Ed25519 public key
+    pub public_key: String,
+
+    /// Optional key owner
+    #[serde(default)]
+    pub owner: Option<String>,
+}
+
+/// NATS event publishing configuration
+///
+/// When enabled, committed events are relayed from the database outbox to
+/// NATS with at-least-once delivery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NatsConfig {
+    /// Enable the outbox relay
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// NATS server URL
+    #[serde(default = "default_nats_url")]
+    pub url: String,
+
+    /// Publish through JetStream and wait for acknowledgements
+    #[serde(default = "default_true")]
+    pub jetstream: bool,
+
+    /// Maximum number of events relayed per pass
+    #[serde(default = "default_outbox_batch_size")]
+    pub batch_size: i64,
+
+    /// Delay between relay passes in milliseconds
+    #[serde(default = "default_outbox_poll_interval")]
+    pub poll_interval_ms: u64,
+
+    /// How long claimed events are hidden from other relays, in milliseconds
+    #[serde(default = "default_outbox_claim_timeout")]
+    pub claim_timeout_ms: u64,
+
+    /// Failed delivery attempts after which an event is dead-lettered
+    #[serde(default = "default_outbox_max_attempts")]
+    pub max_attempts: i32,
+}
+
+fn default_nats_url() -> String {
+    "nats://localhost:4222".to_string()
+}
+
+fn default_outbox_batch_size() -> i64 {
+    100
+}
+
+fn default_outbox_poll_interval() -> u64 {
+    1000
+}
+
+fn default_outbox_claim_timeout() -> u64 {
+    60_000
+}
+
+fn default_outbox_max_attempts() -> i32 {
+    20
+}
+
+impl Default for NatsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: default_nats_url(),
+            jetstream: default_true(),
+            batch_size: default_outbox_batch_size(),
+            poll_interval_ms: default_outbox_poll_interval(),
+            claim_timeout_ms: default_outbox_claim_timeout(),
+            max_attempts: default_outbox_max_attempts(),
+        }
+    }
+}
+
+/// Search index configuration
+///
+/// When enabled, search, autocomplete and facets are served from a Tantivy
+/// index kept in sync with registry events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// Serve search from the Tantivy index
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Directory for the on-disk index (in-memory when unset)
+    #[serde(default)]
+    pub index_path: Option<String>,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_true(),
+            index_path: None,
+        }
+    }
+}
+
+/// Asset cache configuration
+///
+/// When enabled, asset reads are served from an in-process cache in front
+/// of an optional shared Redis cache. Writes evict cached entries and can
+/// notify other replicas over NATS.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetCacheConfig {
+    /// Enable the asset cache
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Redis URL for the shared tier (in-process only when unset)
+    #[serde(default)]
+    pub redis_url: Option<String>,
+
+    /// Maximum number of assets cached in process
+    #[serde(default = "default_cache_capacity")]
+    pub local_capacity: u64,
+
+    /// In-process asset TTL in seconds
+    #[serde(default = "default_local_cache_ttl")]
+    pub local_ttl_seconds: u64,
+
+    /// Redis asset TTL in seconds
+    #[serde(default = "default_redis_cache_ttl")]
+    pub redis_ttl_seconds: u64,
+
+    /// In-process search result TTL in seconds
+    #[serde(default = "default_search_cache_ttl")]
+    pub search_ttl_seconds: u64,
+
+    /// Broadcast invalidations to other replicas through the NATS server
+    #[serde(default)]
+    pub nats_invalidation: bool,
+
+    /// NATS subject invalidations are broadcast on
+    #[serde(default = "default_invalidation_subject")]
+    pub invalidation_subject: String,
+}
+
+fn default_cache_capacity() -> u64 {
+    10_000
+}
+
+fn default_local_cache_ttl() -> u64 {
+    60
+}
+
+fn default_redis_cache_ttl() -> u64 {
+    900
+}
+
+fn default_search_cache_ttl() -> u64 {
+    30
+}
+
+fn default_invalidation_subject() -> String {
+    llm_registry_db::DEFAULT_INVALIDATION_SUBJECT.to_string()
+}
+
+impl Default for AssetCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redis_url: None,
+            local_capacity: default_cache_capacity(),
+            local_ttl_seconds: default_local_cache_ttl(),
+            redis_ttl_seconds: default_redis_cache_ttl(),
+            search_ttl_seconds: default_search_cache_ttl(),
+            nats_invalidation: false,
+            invalidation_subject: default_invalidation_subject(),
+        }
+    }
+}
+
+/// Webhook delivery configuration
+///
+/// Webhooks can always be managed through the API; when enabled, committed
+/// events are relayed from the outbox to matching webhooks and their
+/// deliveries are sent by a background dispatcher.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Enable webhook delivery
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Attempts before a delivery is marked failed
+    #[serde(default = "default_webhook_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in seconds, doubled for each later retry
+    #[serde(default = "default_webhook_initial_backoff")]
+    pub initial_backoff_seconds: u64,
+
+    /// Upper bound on the retry delay in seconds
+    #[serde(default = "default_webhook_max_backoff")]
+    pub max_backoff_seconds: u64,
+
+    /// Consecutive failed attempts after which a webhook is disabled
+    #[serde(default = "default_webhook_disable_after")]
+    pub disable_after_failures: u32,
+
+    /// Request timeout in seconds
+    #[serde(default = "default_webhook_request_timeout")]
+    pub request_timeout_seconds: u64,
+
+    /// Maximum number of requests in flight
+    #[serde(default = "default_webhook_concurrency")]
+    pub concurrency: usize,
+
+    /// Delay between dispatcher passes in milliseconds
+    #[serde(default = "default_webhook_poll_interval")]
+    pub poll_interval_ms: u64,
+
+    /// Hosts webhooks may target even though they are loopback, link-local
+    /// or private
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+}
+
+fn default_webhook_max_attempts() -> u32 {
+    8
+}
+
+fn default_webhook_initial_backoff() -> u64 {
+    10
+}
+
+fn default_webhook_max_backoff() -> u64 {
+    3600
+}
+
+fn default_webhook_disable_after() -> u32 {
+    20
+}
+
+fn default_webhook_request_timeout() -> u64 {
+    10
+}
+
+fn default_webhook_concurrency() -> usize {
+    8
+}
+
+fn default_webhook_poll_interval() -> u64 {
+    1000
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_attempts: default_webhook_max_attempts(),
+            initial_backoff_seconds: default_webhook_initial_backoff(),
+            max_backoff_seconds: default_webhook_max_backoff(),
+            disable_after_failures: default_webhook_disable_after(),
+            request_timeout_seconds: default_webhook_request_timeout(),
+            concurrency: default_webhook_concurrency(),
+            poll_interval_ms: default_webhook_poll_interval(),
+            allowed_hosts: Vec::new(),
+        }
+    }
+}
+
+/// Audit log configuration
+///
+/// When a checkpoint key is set, signed checkpoints of the hash-chained
+/// audit log are taken periodically. Its public key is trusted for
+/// checkpoint verification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// Key identifier recorded with each checkpoint
+    #[serde(default)]
+    pub checkpoint_key_id: Option<String>,
+
+    /// Base64-encoded Ed25519 secret key signing checkpoints
+    #[serde(default)]
+    pub checkpoint_signing_key: Option<String>,
+
+    /// Seconds between automatic checkpoints
+    #[serde(default = "default_checkpoint_interval")]
+    pub checkpoint_interval_seconds: u64,
+}
+
+fn default_checkpoint_interval() -> u64 {
+    3600
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            checkpoint_key_id: None,
+            checkpoint_signing_key: None,
+            checkpoint_interval_seconds: default_checkpoint_interval(),
+        }
+    }
+}
+
+/// Where asset content is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentBackend {
+    /// Content is not stored; assets only point at content stored elsewhere
+    None,
+    /// Content is stored in a local directory
+    FileSystem,
+    /// Content is stored in an S3 bucket or an S3-compatible service
+    S3,
+}
+
+/// Asset content storage configuration
+///
+/// With a backend configured, the registry accepts uploads on
+/// `POST /v1/assets/{id}/content` and serves them on
+/// `GET /v1/assets/{id}/content`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentConfig {
+    /// Storage backend
+    #[serde(default = "default_content_backend")]
+    pub backend: ContentBackend,
+
+    /// Directory content is stored under with the filesystem backend
+    #[serde(default = "default_content_path")]
+    pub path: String,
+
+    /// Bucket content is stored in with the S3 backend
+    #[serde(default)]
+    pub s3_bucket: Option<String>,
+
+    /// S3 region
+    #[serde(default = "default_s3_region")]
+    pub s3_region: String,
+
+    /// Endpoint URL of an S3-compatible service, such as MinIO
+    #[serde(default)]
+    pub s3_endpoint: Option<String>,
+
+    /// S3 access key ID
+    #[serde(default)]
+    pub s3_access_key_id: Option<String>,
+
+    /// S3 secret access key
+    #[serde(default)]
+    pub s3_secret_access_key: Option<String>,
+
+    /// Size of multipart upload parts in MiB
+    #[serde(default = "default_s3_part_size_mb")]
+    pub s3_part_size_mb: usize,
+}
+
+fn default_content_backend() -> ContentBackend {
+    ContentBackend::None
+}
+
+fn default_content_path() -> String {
+    "data/content".to_string()
+}
+
+fn default_s3_region() -> String {
+    "us-east-1".to_string()
+}
+
+fn default_s3_part_size_mb() -> usize {
+    llm_registry_db::DEFAULT_PART_SIZE / (1024 * 1024)
+}
+
+impl Default for ContentConfig {
+    fn default() -> Self {
+        Self {
+            backend: default_content_backend(),
+            path: default_content_path(),
+            s3_bucket: None,
+            s3_region: default_s3_region(),
+            s3_endpoint: None,
+            s3_access_key_id: None,
+            s3_secret_access_key: None,
+            s3_part_size_mb: default_s3_part_size_mb(),
+        }
+    }
+}
+
 impl ServerConfig {
     /// Load configuration from files and environment
     ///
@@ -275,7 +710,9 @@ impl ServerConfig {
             // Start with default config
             .add_source(File::from(config_dir.join("default.toml")).required(false))
             // Add environment-specific config
-            .add_source(File::from(config_dir.join(format!("{}.toml", environment))).required(false))
+            .add_source(
+                File::from(config_dir.join(format!("{}.toml", environment))).required(false),
+            )
             // Add environment variables with prefix LLM_REGISTRY
             // e.g., LLM_REGISTRY_SERVER__PORT=8080
             .add_source(
@@ -316,6 +753,13 @@ impl Default for ServerConfig {
             database: DatabaseConfig::default(),
             logging: LoggingConfig::default(),
             cors: CorsConfig::default(),
+            signing: SigningConfig::default(),
+            nats: NatsConfig::default(),
+            search: SearchConfig::default(),
+            audit: AuditConfig::default(),
+            cache: AssetCacheConfig::default(),
+            webhooks: WebhookConfig::default(),
+            content: ContentConfig::default(),
         }
     }
 }
@@ -365,6 +809,18 @@ mod tests {
         assert_eq!(config.min_connections, 2);
     }
 
+    #[test]
+    fn test_database_config_backend() {
+        let mut config = DatabaseConfig::default();
+        assert_eq!(config.backend().unwrap(), DatabaseBackend::Postgres);
+
+        config.url = "sqlite://registry.db".to_string();
+        assert_eq!(config.backend().unwrap(), DatabaseBackend::Sqlite);
+
+        config.url = "mysql://localhost/registry".to_string();
+        assert!(config.backend().is_err());
+    }
+
     #[test]
     fn test_logging_config_default() {
         let config = LoggingConfig::default();
@@ -373,6 +829,36 @@ mod tests {
         assert!(config.include_timestamps);
     }
 
+    #[test]
+    fn test_signing_config_default() {
+        let config = ServerConfig::default();
+        assert!(config.signing.trusted_keys.is_empty());
+    }
+
+    #[test]
+    fn test_nats_config_default() {
+        let config = NatsConfig::default();
+        assert!(!config.enabled);
+        assert!(config.jetstream);
+        assert_eq!(config.batch_size, 100);
+        assert_eq!(config.max_attempts, 20);
+    }
+
+    #[test]
+    fn test_search_config_default() {
+        let config = SearchConfig::default();
+        assert!(config.enabled);
+        assert!(config.index_path.is_none());
+    }
+
+    #[test]
+    fn test_content_config_default() {
+        let config = ContentConfig::default();
+        assert_eq!(config.backend, ContentBackend::None);
+        assert_eq!(config.s3_region, "us-east-1");
+        assert_eq!(config.s3_part_size_mb, 8);
+    }
+
     #[test]
     fn test_get_environment_default() {
         // Clear env var for test
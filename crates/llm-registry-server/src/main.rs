@@ -11,16 +11,26 @@ mod tracing_setup;
 use anyhow::{Context, Result};
 use clap::Parser;
 use llm_registry_api::build_api_server;
-use llm_registry_db::{create_pool, PoolConfig, PostgresAssetRepository, PostgresEventStore};
-use llm_registry_service::ServiceRegistry;
-use sqlx::PgPool;
+use llm_registry_db::{
+    connect_nats, create_pool, create_sqlite_pool, AssetCache, AssetRepository, BlobStore,
+    CacheConfig as RedisCacheConfig, CachedAssetRepository, CachedUnitOfWork, DatabaseBackend,
+    EventPublisher, EventStore, FanoutPublisher, FileSystemBlobStore, LocalCacheConfig,
+    NatsEventPublisher, NatsPublisherConfig, OutboxRelay, OutboxRelayConfig, OutboxStore,
+    PoolConfig, PostgresAssetRepository, PostgresEventStore, PostgresUnitOfWork,
+    PostgresWebhookStore, RedisCache, S3BlobStore, S3Config, SqliteAssetRepository,
+    SqliteEventStore, SqliteUnitOfWork, SqliteWebhookStore, UnitOfWork, WebhookStore,
+};
+use llm_registry_service::{
+    spawn_checkpoint_task, CheckpointSigner, InMemoryTrustedKeyStore, ServiceRegistryBuilder,
+    TantivySearchService, TrustedKey, WebhookDispatcher, WebhookDispatcherConfig, WebhookPublisher,
+};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::signal;
 use tracing::info;
 
-use config::ServerConfig;
+use config::{ContentBackend, ServerConfig};
 
 /// Command-line arguments
 #[derive(Parser, Debug)]
@@ -91,16 +101,67 @@ async fn main() -> Result<()> {
     info!("Server: {}", config.bind_address());
     info!("Database: {}", mask_database_url(&config.database.url));
 
-    // Setup database connection pool
+    // Setup database connection pool and repositories
     // (migrations are run automatically by PoolConfig if enabled)
-    let pool = setup_database(&config).await?;
+    let mut storage = setup_database(&config).await?;
 
-    // Create repositories
-    let asset_repository = Arc::new(PostgresAssetRepository::new(pool.clone()));
-    let event_store = Arc::new(PostgresEventStore::new(pool.clone()));
+    // Serve asset reads through the cache if it is enabled
+    let cache_listener_handle = setup_cache(&config, &mut storage).await?;
+
+    // Relay committed events from the outbox to NATS and webhooks
+    let relay_handle = setup_outbox_relay(&config, &storage).await?;
+
+    // Send queued webhook deliveries
+    let dispatcher_handle = setup_webhook_dispatcher(&config, storage.webhooks.clone())?;
+
+    // Load the audit checkpoint key and trusted signing keys
+    let checkpoint_signer = setup_checkpoint_signer(&config)?;
+    let key_store = Arc::new(setup_key_store(&config, checkpoint_signer.as_ref())?);
+
+    // Open the search index if enabled
+    let search_index = setup_search_index(&config, storage.repository.clone())?;
+
+    // Open the store for asset content if one is configured
+    let blob_store = setup_blob_store(&config)?;
 
     // Create service registry (wrapped in Arc for sharing between servers)
-    let services = Arc::new(ServiceRegistry::new(asset_repository, event_store));
+    let mut builder = ServiceRegistryBuilder::new()
+        .repository(storage.repository)
+        .event_store(storage.event_store)
+        .unit_of_work(storage.unit_of_work)
+        .webhook_store(storage.webhooks)
+        .webhook_allowed_hosts(config.webhooks.allowed_hosts.clone())
+        .key_store(key_store);
+    if let Some(index) = &search_index {
+        builder = builder.search_service(index.clone());
+    }
+    if let Some(store) = blob_store {
+        builder = builder.blob_store(store);
+    }
+    let checkpoints_enabled = checkpoint_signer.is_some();
+    if let Some(signer) = checkpoint_signer {
+        builder = builder.checkpoint_signer(signer);
+    }
+    let services = Arc::new(builder.build().map_err(|e| anyhow::anyhow!(e))?);
+
+    // Periodically sign checkpoints of the audit chain
+    let checkpoint_handle = checkpoints_enabled.then(|| {
+        spawn_checkpoint_task(
+            services.audit().clone(),
+            Duration::from_secs(config.audit.checkpoint_interval_seconds),
+        )
+    });
+
+    // Rebuild the search index and keep it in sync with registry events
+    let search_sync_handle = match search_index {
+        Some(index) => Some(
+            index
+                .start_sync(services.events().clone())
+                .await
+                .context("Failed to build search index")?,
+        ),
+        None => None,
+    };
 
     // Build API server
     let app = build_api_server((*services).clone());
@@ -168,13 +229,39 @@ async fn main() -> Result<()> {
         http_result?;
     }
 
+    relay_handle.abort();
+    if let Some(handle) = dispatcher_handle {
+        handle.abort();
+    }
+    if let Some(handle) = search_sync_handle {
+        handle.abort();
+    }
+    if let Some(handle) = checkpoint_handle {
+        handle.abort();
+    }
+    if let Some(handle) = cache_listener_handle {
+        handle.abort();
+    }
+
     info!("Server shutdown complete");
     Ok(())
 }
 
-/// Setup database connection pool
-async fn setup_database(config: &ServerConfig) -> Result<PgPool> {
-    info!("Connecting to database");
+/// Storage handles created for the configured database
+struct Storage {
+    repository: Arc<dyn AssetRepository>,
+    event_store: Arc<dyn EventStore>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox: Arc<dyn OutboxStore>,
+    webhooks: Arc<dyn WebhookStore>,
+}
+
+/// Setup the database connection pool and repositories
+///
+/// The backend is selected by the scheme of the configured database URL.
+async fn setup_database(config: &ServerConfig) -> Result<Storage> {
+    let backend = config.database.backend()?;
+    info!("Connecting to {} database", backend);
 
     let pool_config = PoolConfig::new(&config.database.url)
         .min_connections(config.database.min_connections)
@@ -185,12 +272,283 @@ async fn setup_database(config: &ServerConfig) -> Result<PgPool> {
         .run_migrations(config.database.run_migrations)
         .enable_logging(config.logging.level != "error");
 
-    let pool = create_pool(&pool_config)
-        .await
-        .context("Failed to create database connection pool")?;
+    let storage = match backend {
+        DatabaseBackend::Postgres => {
+            let pool = create_pool(&pool_config)
+                .await
+                .context("Failed to create database connection pool")?;
+            let event_store = Arc::new(PostgresEventStore::new(pool.clone()));
+            Storage {
+                repository: Arc::new(PostgresAssetRepository::new(pool.clone())),
+                event_store: event_store.clone(),
+                unit_of_work: Arc::new(PostgresUnitOfWork::new(pool.clone())),
+                outbox: event_store,
+                webhooks: Arc::new(PostgresWebhookStore::new(pool)),
+            }
+        }
+        DatabaseBackend::Sqlite => {
+            let pool = create_sqlite_pool(&pool_config)
+                .await
+                .context("Failed to create database connection pool")?;
+            let event_store = Arc::new(SqliteEventStore::new(pool.clone()));
+            Storage {
+                repository: Arc::new(SqliteAssetRepository::new(pool.clone())),
+                event_store: event_store.clone(),
+                unit_of_work: Arc::new(SqliteUnitOfWork::new(pool.clone())),
+                outbox: event_store,
+                webhooks: Arc::new(SqliteWebhookStore::new(pool)),
+            }
+        }
+    };
 
     info!("Database connection established");
-    Ok(pool)
+    Ok(storage)
+}
+
+/// Wrap the repository and unit of work in the asset cache if it is enabled
+///
+/// Returns the task evicting entries invalidated by other replicas, if NATS
+/// invalidation is enabled.
+async fn setup_cache(
+    config: &ServerConfig,
+    storage: &mut Storage,
+) -> Result<Option<tokio::task::JoinHandle<()>>> {
+    if !config.cache.enabled {
+        info!("Asset cache disabled");
+        return Ok(None);
+    }
+
+    let local = LocalCacheConfig::default()
+        .with_max_capacity(config.cache.local_capacity)
+        .with_asset_ttl(Duration::from_secs(config.cache.local_ttl_seconds))
+        .with_search_ttl(Duration::from_secs(config.cache.search_ttl_seconds));
+    let mut cache = AssetCache::new(local).with_metrics(Arc::new(metrics::PrometheusCacheMetrics));
+
+    if let Some(url) = &config.cache.redis_url {
+        let redis = RedisCache::new(
+            RedisCacheConfig::new(url)
+                .with_default_ttl(Duration::from_secs(config.cache.redis_ttl_seconds)),
+        )
+        .await
+        .context("Failed to connect to Redis")?;
+        cache = cache.with_redis(redis);
+    }
+
+    let listener = if config.cache.nats_invalidation {
+        let client = connect_nats(
+            &NatsPublisherConfig::new(&config.nats.url).with_client_name("llm-registry-cache"),
+        )
+        .await
+        .context("Failed to connect to NATS for cache invalidation")?;
+        cache = cache.with_nats_invalidation(client, &config.cache.invalidation_subject);
+        Some(
+            cache
+                .spawn_invalidation_listener()
+                .await
+                .context("Failed to subscribe to cache invalidations")?,
+        )
+    } else {
+        None
+    };
+
+    info!(
+        "Asset cache enabled ({})",
+        if config.cache.redis_url.is_some() {
+            "in-process and Redis"
+        } else {
+            "in-process"
+        }
+    );
+    storage.repository = Arc::new(CachedAssetRepository::new(
+        storage.repository.clone(),
+        cache.clone(),
+    ));
+    storage.unit_of_work = Arc::new(CachedUnitOfWork::new(storage.unit_of_work.clone(), cache));
+    Ok(listener)
+}
+
+/// Start the outbox relay
+///
+/// Every committed event is queued in the outbox, so the relay runs even
+/// when neither NATS publishing nor webhooks are enabled. It then publishes
+/// to no destination and only prunes the outbox.
+async fn setup_outbox_relay(
+    config: &ServerConfig,
+    storage: &Storage,
+) -> Result<tokio::task::JoinHandle<()>> {
+    let mut publishers: Vec<Arc<dyn EventPublisher>> = Vec::new();
+
+    if config.nats.enabled {
+        let publisher = NatsEventPublisher::new(
+            NatsPublisherConfig::new(&config.nats.url).with_jetstream(config.nats.jetstream),
+        )
+        .await
+        .context("Failed to connect to NATS")?;
+        publishers.push(Arc::new(publisher));
+    } else {
+        info!("NATS event publishing disabled");
+    }
+
+    if config.webhooks.enabled {
+        publishers.push(Arc::new(WebhookPublisher::new(
+            storage.webhooks.clone(),
+            storage.repository.clone(),
+        )));
+    }
+
+    if publishers.is_empty() {
+        info!("No event destinations enabled, pruning the outbox");
+    }
+
+    let relay_config = OutboxRelayConfig::default()
+        .with_batch_size(config.nats.batch_size)
+        .with_poll_interval(Duration::from_millis(config.nats.poll_interval_ms))
+        .with_claim_timeout(Duration::from_millis(config.nats.claim_timeout_ms))
+        .with_max_attempts(config.nats.max_attempts);
+
+    Ok(OutboxRelay::new(
+        storage.outbox.clone(),
+        Arc::new(FanoutPublisher::new(publishers)),
+    )
+    .with_config(relay_config)
+    .spawn())
+}
+
+/// Start the webhook dispatcher if webhooks are enabled
+fn setup_webhook_dispatcher(
+    config: &ServerConfig,
+    store: Arc<dyn WebhookStore>,
+) -> Result<Option<tokio::task::JoinHandle<()>>> {
+    if !config.webhooks.enabled {
+        info!("Webhook delivery disabled");
+        return Ok(None);
+    }
+
+    let webhooks = &config.webhooks;
+    let dispatcher_config = WebhookDispatcherConfig::default()
+        .with_max_attempts(webhooks.max_attempts)
+        .with_backoff(
+            Duration::from_secs(webhooks.initial_backoff_seconds),
+            Duration::from_secs(webhooks.max_backoff_seconds),
+        )
+        .with_disable_after_failures(webhooks.disable_after_failures)
+        .with_request_timeout(Duration::from_secs(webhooks.request_timeout_seconds))
+        .with_concurrency(webhooks.concurrency)
+        .with_poll_interval(Duration::from_millis(webhooks.poll_interval_ms))
+        .with_allowed_hosts(webhooks.allowed_hosts.clone());
+
+    let dispatcher = WebhookDispatcher::new(store, dispatcher_config)
+        .context("Failed to create webhook dispatcher")?;
+    info!("Webhook delivery enabled");
+    Ok(Some(dispatcher.spawn()))
+}
+
+/// Open the search index if it is enabled
+fn setup_search_index(
+    config: &ServerConfig,
+    repository: Arc<dyn AssetRepository>,
+) -> Result<Option<Arc<TantivySearchService>>> {
+    if !config.search.enabled {
+        info!("Search index disabled");
+        return Ok(None);
+    }
+
+    let index = match &config.search.index_path {
+        Some(path) => {
+            info!("Opening search index at {}", path);
+            TantivySearchService::open(repository, path)
+        }
+        None => {
+            info!("Using in-memory search index");
+            TantivySearchService::in_memory(repository)
+        }
+    }
+    .context("Failed to open search index")?;
+
+    Ok(Some(Arc::new(index)))
+}
+
+/// Open the blob store asset content is kept in, if one is configured
+fn setup_blob_store(config: &ServerConfig) -> Result<Option<Arc<dyn BlobStore>>> {
+    let content = &config.content;
+    match content.backend {
+        ContentBackend::None => {
+            info!("Asset content storage disabled");
+            Ok(None)
+        }
+        ContentBackend::FileSystem => {
+            info!("Storing asset content under {}", content.path);
+            Ok(Some(Arc::new(FileSystemBlobStore::new(&content.path))))
+        }
+        ContentBackend::S3 => {
+            let bucket = content
+                .s3_bucket
+                .as_deref()
+                .context("content.s3_bucket is required with the S3 backend")?;
+            let access_key_id = content
+                .s3_access_key_id
+                .as_deref()
+                .context("content.s3_access_key_id is required with the S3 backend")?;
+            let secret_access_key = content
+                .s3_secret_access_key
+                .as_deref()
+                .context("content.s3_secret_access_key is required with the S3 backend")?;
+
+            let mut s3_config =
+                S3Config::new(bucket, &content.s3_region, access_key_id, secret_access_key)
+                    .part_size(content.s3_part_size_mb * 1024 * 1024);
+            if let Some(endpoint) = &content.s3_endpoint {
+                s3_config = s3_config.endpoint(endpoint);
+            }
+            let store = S3BlobStore::new(s3_config).context("Failed to create S3 blob store")?;
+            info!("Storing asset content in S3 bucket {}", bucket);
+            Ok(Some(Arc::new(store)))
+        }
+    }
+}
+
+/// Load the audit checkpoint signing key if one is configured
+fn setup_checkpoint_signer(config: &ServerConfig) -> Result<Option<CheckpointSigner>> {
+    let Some(secret_key) = &config.audit.checkpoint_signing_key else {
+        info!("Audit checkpoint signing disabled");
+        return Ok(None);
+    };
+    let key_id = config
+        .audit
+        .checkpoint_key_id
+        .as_deref()
+        .context("audit.checkpoint_key_id is required with a checkpoint signing key")?;
+
+    let signer = CheckpointSigner::from_base64(key_id, secret_key)
+        .with_context(|| format!("Invalid checkpoint signing key: {}", key_id))?;
+    info!("Signing audit checkpoints with key {}", key_id);
+    Ok(Some(signer))
+}
+
+/// Build the trusted key store from the signing configuration
+///
+/// The checkpoint signer's public key is trusted so its checkpoints verify.
+fn setup_key_store(
+    config: &ServerConfig,
+    checkpoint_signer: Option<&CheckpointSigner>,
+) -> Result<InMemoryTrustedKeyStore> {
+    let mut keys = config
+        .signing
+        .trusted_keys
+        .iter()
+        .map(|k| {
+            let key = TrustedKey::ed25519(&k.key_id, &k.public_key)
+                .with_context(|| format!("Invalid trusted key: {}", k.key_id))?;
+            Ok(match &k.owner {
+                Some(owner) => key.with_owner(owner),
+                None => key,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    keys.extend(checkpoint_signer.map(CheckpointSigner::trusted_key));
+
+    info!("Loaded {} trusted signing key(s)", keys.len());
+    Ok(InMemoryTrustedKeyStore::with_keys(keys))
 }
 
 /// Graceful shutdown signal handler
@@ -225,7 +583,10 @@ async fn shutdown_signal(timeout_seconds: u64) {
     }
 
     // Give the server time to finish processing requests
-    info!("Waiting up to {} seconds for graceful shutdown", timeout_seconds);
+    info!(
+        "Waiting up to {} seconds for graceful shutdown",
+        timeout_seconds
+    );
 }
 
 /// Mask sensitive parts of database URL for logging
@@ -261,4 +622,45 @@ mod tests {
         let masked = mask_database_url(url);
         assert_eq!(masked, "postgresql://localhost:5432/dbname");
     }
+
+    #[tokio::test]
+    async fn test_outbox_is_pruned_without_destinations() {
+        use llm_registry_core::{AssetId, EventType, RegistryEvent};
+
+        let mut config = ServerConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.nats.poll_interval_ms = 10;
+        assert!(!config.nats.enabled && !config.webhooks.enabled);
+
+        let storage = setup_database(&config).await.unwrap();
+        let events = (0..3)
+            .map(|_| {
+                RegistryEvent::new(EventType::AssetDeleted {
+                    asset_id: AssetId::new(),
+                    asset_name: "model".to_string(),
+                    asset_version: "1.0.0".to_string(),
+                })
+            })
+            .collect();
+        storage.event_store.append_batch(events).await.unwrap();
+        assert_eq!(storage.outbox.count_pending().await.unwrap(), 3);
+
+        let relay = setup_outbox_relay(&config, &storage).await.unwrap();
+        let mut pending = 3;
+        for _ in 0..100 {
+            pending = storage.outbox.count_pending().await.unwrap();
+            if pending == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        relay.abort();
+        assert_eq!(pending, 0);
+        assert!(storage
+            .outbox
+            .fetch_dead_lettered(10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -3,10 +3,11 @@
 //! This module provides comprehensive metrics collection using Prometheus
 //! for monitoring application performance, health, and business metrics.
 
+use llm_registry_db::CacheMetrics;
 use once_cell::sync::Lazy;
 use prometheus::{
-    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, HistogramVec,
-    IntCounterVec, IntGaugeVec, Registry, TextEncoder, Encoder,
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, Encoder,
+    HistogramVec, IntCounterVec, IntGaugeVec, Registry, TextEncoder,
 };
 use std::time::Instant;
 
@@ -232,6 +233,15 @@ pub fn record_cache_operation(operation: &str, result: &str) {
         .inc();
 }
 
+/// Asset cache metrics sink recording through [`record_cache_operation`]
+pub struct PrometheusCacheMetrics;
+
+impl CacheMetrics for PrometheusCacheMetrics {
+    fn record(&self, operation: &str, result: &str) {
+        record_cache_operation(operation, result);
+    }
+}
+
 /// Record asset operation
 pub fn record_asset_operation(operation: &str, success: bool) {
     let status = if success { "success" } else { "error" };
@@ -263,9 +273,7 @@ pub fn update_db_pool_metrics(pool_name: &str, active: usize, idle: usize) {
 
 /// Update total assets metric
 pub fn update_assets_total(status: &str, count: i64) {
-    ASSETS_TOTAL
-        .with_label_values(&[status])
-        .set(count);
+    ASSETS_TOTAL.with_label_values(&[status]).set(count);
 }
 
 #[cfg(test)]
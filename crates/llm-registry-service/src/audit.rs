@@ -0,0 +1,351 @@
+//! Audit service
+//!
+//! This module verifies the tamper-evident event chain kept by the event
+//! store and takes signed checkpoints of its head. Checkpoints are signed
+//! with the registry's Ed25519 checkpoint key and verified against the
+//! trusted key store, so the signing key is never stored next to the events
+//! it protects.
+
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::Utc;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier};
+use llm_registry_db::{
+    verify_chain, BreakKind, BrokenLink, ChainCheckpoint, ChainVerification, EventStore,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+
+use crate::error::{ServiceError, ServiceResult};
+use crate::signing::{TrustedKey, TrustedKeyStore};
+
+/// Trait for audit log verification and checkpointing
+#[async_trait]
+pub trait AuditService: Send + Sync {
+    /// Verify the event chain and its checkpoints
+    ///
+    /// The report names the first broken link in chain order.
+    async fn verify_chain(&self) -> ServiceResult<ChainVerification>;
+
+    /// Sign and store a checkpoint of the current chain head
+    ///
+    /// Returns `None` if the chain is empty or its head already has a
+    /// checkpoint.
+    async fn create_checkpoint(&self) -> ServiceResult<Option<ChainCheckpoint>>;
+
+    /// List checkpoints in sequence order
+    async fn list_checkpoints(&self) -> ServiceResult<Vec<ChainCheckpoint>>;
+}
+
+/// Ed25519 key signing audit chain checkpoints
+#[derive(Clone)]
+pub struct CheckpointSigner {
+    key_id: String,
+    signing_key: SigningKey,
+}
+
+impl CheckpointSigner {
+    /// Create a signer from an Ed25519 signing key
+    pub fn new(key_id: impl Into<String>, signing_key: SigningKey) -> Self {
+        Self {
+            key_id: key_id.into(),
+            signing_key,
+        }
+    }
+
+    /// Create a signer from a base64-encoded 32-byte Ed25519 secret key
+    pub fn from_base64(key_id: impl Into<String>, secret_key: &str) -> ServiceResult<Self> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(secret_key)
+            .map_err(|e| ServiceError::InvalidInput(format!("Invalid base64 secret key: {}", e)))?;
+        let bytes: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+            ServiceError::InvalidInput(format!(
+                "Invalid secret key length: expected 32 bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+
+        Ok(Self::new(key_id, SigningKey::from_bytes(&bytes)))
+    }
+
+    /// Identifier recorded with each checkpoint
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Trusted key verifying this signer's checkpoints
+    pub fn trusted_key(&self) -> TrustedKey {
+        let public_key = base64::engine::general_purpose::STANDARD
+            .encode(self.signing_key.verifying_key().to_bytes());
+        TrustedKey::ed25519(&self.key_id, public_key)
+            .expect("an Ed25519 verifying key is a valid trusted key")
+    }
+
+    fn sign(&self, message: &[u8]) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.signing_key.sign(message).to_bytes())
+    }
+}
+
+impl std::fmt::Debug for CheckpointSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckpointSigner")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Default implementation of AuditService
+pub struct DefaultAuditService {
+    event_store: Arc<dyn EventStore>,
+    key_store: Arc<dyn TrustedKeyStore>,
+    signer: Option<CheckpointSigner>,
+}
+
+impl DefaultAuditService {
+    /// Create a new audit service
+    ///
+    /// Without a signer, the chain can be verified but no checkpoints are
+    /// taken.
+    pub fn new(event_store: Arc<dyn EventStore>, key_store: Arc<dyn TrustedKeyStore>) -> Self {
+        Self {
+            event_store,
+            key_store,
+            signer: None,
+        }
+    }
+
+    /// Sign checkpoints with the given key
+    ///
+    /// Its public key must be in the trusted key store for the checkpoints
+    /// to verify.
+    pub fn with_signer(mut self, signer: CheckpointSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Check a checkpoint signature against the trusted key store
+    async fn check_signature(&self, checkpoint: &ChainCheckpoint) -> ServiceResult<Option<String>> {
+        let Some(key) = self.key_store.get_key(&checkpoint.key_id).await? else {
+            return Ok(Some(format!(
+                "Unknown checkpoint key: {}",
+                checkpoint.key_id
+            )));
+        };
+        if key.revoked {
+            return Ok(Some(format!(
+                "Checkpoint key has been revoked: {}",
+                key.key_id
+            )));
+        }
+
+        let valid = base64::engine::general_purpose::STANDARD
+            .decode(&checkpoint.signature)
+            .ok()
+            .and_then(|bytes| Signature::from_slice(&bytes).ok())
+            .is_some_and(|signature| {
+                key.verifying_key().is_ok_and(|verifying_key| {
+                    verifying_key
+                        .verify(&checkpoint.signed_message(), &signature)
+                        .is_ok()
+                })
+            });
+
+        Ok((!valid).then(|| "Checkpoint signature does not verify".to_string()))
+    }
+}
+
+#[async_trait]
+impl AuditService for DefaultAuditService {
+    #[instrument(skip(self))]
+    async fn verify_chain(&self) -> ServiceResult<ChainVerification> {
+        let checkpoints = self.event_store.checkpoints().await?;
+
+        // Only checkpoints with valid signatures vouch for the chain
+        let mut signed = Vec::with_capacity(checkpoints.len());
+        let mut forged = None;
+        for checkpoint in checkpoints {
+            match self.check_signature(&checkpoint).await? {
+                None => signed.push(checkpoint),
+                Some(detail) => {
+                    forged.get_or_insert(BrokenLink {
+                        sequence: checkpoint.sequence,
+                        event_id: None,
+                        kind: BreakKind::InvalidSignature,
+                        detail,
+                    });
+                }
+            }
+        }
+
+        let mut report = verify_chain(self.event_store.as_ref(), &signed).await?;
+        if let Some(broken) = forged {
+            report.report_break(broken);
+        }
+
+        match &report.first_broken_link {
+            Some(broken) => warn!(
+                sequence = broken.sequence,
+                kind = ?broken.kind,
+                "Audit chain verification failed: {}",
+                broken.detail
+            ),
+            None => debug!(events = report.events_verified, "Audit chain verified"),
+        }
+        Ok(report)
+    }
+
+    #[instrument(skip(self))]
+    async fn create_checkpoint(&self) -> ServiceResult<Option<ChainCheckpoint>> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ServiceError::InvalidInput("Checkpoint signing key is not configured".to_string())
+        })?;
+
+        let Some(head) = self.event_store.chain_head().await? else {
+            return Ok(None);
+        };
+        let checkpoints = self.event_store.checkpoints().await?;
+        if checkpoints
+            .last()
+            .is_some_and(|c| c.sequence >= head.sequence)
+        {
+            return Ok(None);
+        }
+
+        let created_at = Utc::now();
+        let checkpoint = ChainCheckpoint {
+            sequence: head.sequence,
+            signature: signer.sign(&ChainCheckpoint::message(
+                head.sequence,
+                &head.hash,
+                created_at,
+            )),
+            hash: head.hash,
+            key_id: signer.key_id.clone(),
+            created_at,
+        };
+        self.event_store.save_checkpoint(&checkpoint).await?;
+
+        info!(
+            sequence = checkpoint.sequence,
+            "Audit chain checkpoint created"
+        );
+        Ok(Some(checkpoint))
+    }
+
+    async fn list_checkpoints(&self) -> ServiceResult<Vec<ChainCheckpoint>> {
+        Ok(self.event_store.checkpoints().await?)
+    }
+}
+
+/// Take audit chain checkpoints periodically on a background task
+pub fn spawn_checkpoint_task(audit: Arc<dyn AuditService>, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = audit.create_checkpoint().await {
+                warn!(error = %e, "Failed to create audit chain checkpoint");
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::InMemoryTrustedKeyStore;
+    use llm_registry_core::{AssetId, EventType, RegistryEvent};
+    use llm_registry_db::InMemoryEventStore;
+
+    fn deleted_event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    fn setup() -> (Arc<InMemoryEventStore>, DefaultAuditService) {
+        let signer = CheckpointSigner::new("registry", SigningKey::from_bytes(&[7u8; 32]));
+        let key_store = Arc::new(InMemoryTrustedKeyStore::with_keys([signer.trusted_key()]));
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let audit = DefaultAuditService::new(event_store.clone(), key_store).with_signer(signer);
+        (event_store, audit)
+    }
+
+    #[tokio::test]
+    async fn test_checkpoints_sign_chain_head() {
+        let (event_store, audit) = setup();
+        assert!(audit.create_checkpoint().await.unwrap().is_none());
+
+        event_store
+            .append_batch(vec![deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+        let checkpoint = audit.create_checkpoint().await.unwrap().unwrap();
+        assert_eq!(checkpoint.sequence, 2);
+        assert_eq!(checkpoint.key_id, "registry");
+
+        // The head is already checkpointed
+        assert!(audit.create_checkpoint().await.unwrap().is_none());
+
+        event_store.append(deleted_event()).await.unwrap();
+        audit.create_checkpoint().await.unwrap().unwrap();
+        assert_eq!(audit.list_checkpoints().await.unwrap().len(), 2);
+
+        let report = audit.verify_chain().await.unwrap();
+        assert!(report.valid);
+        assert_eq!(report.events_verified, 3);
+        assert_eq!(report.checkpoints_verified, 2);
+    }
+
+    #[tokio::test]
+    async fn test_forged_checkpoint_detected() {
+        let (event_store, audit) = setup();
+        event_store
+            .append_batch(vec![deleted_event(), deleted_event()])
+            .await
+            .unwrap();
+        let head = event_store.chain_head().await.unwrap().unwrap();
+
+        // A checkpoint signed with another key does not vouch for the chain
+        let forger = CheckpointSigner::new("registry", SigningKey::from_bytes(&[9u8; 32]));
+        let created_at = Utc::now();
+        event_store
+            .save_checkpoint(&ChainCheckpoint {
+                sequence: head.sequence,
+                hash: head.hash.clone(),
+                key_id: "registry".to_string(),
+                signature: forger.sign(&ChainCheckpoint::message(
+                    head.sequence,
+                    &head.hash,
+                    created_at,
+                )),
+                created_at,
+            })
+            .await
+            .unwrap();
+
+        let report = audit.verify_chain().await.unwrap();
+        assert!(!report.valid);
+        assert_eq!(report.events_verified, 2);
+        let broken = report.first_broken_link.unwrap();
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.kind, BreakKind::InvalidSignature);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_requires_signer() {
+        let audit = DefaultAuditService::new(
+            Arc::new(InMemoryEventStore::new()),
+            Arc::new(InMemoryTrustedKeyStore::new()),
+        );
+        assert!(matches!(
+            audit.create_checkpoint().await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+        assert!(audit.verify_chain().await.unwrap().valid);
+    }
+}
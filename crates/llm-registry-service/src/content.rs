@@ -0,0 +1,630 @@
+//! Asset content service
+//!
+//! Stores the bytes of assets in a [`BlobStore`] and serves them back.
+//! Uploads are hashed as they stream through with the algorithm of the
+//! asset's registered checksum; content that does not match is never
+//! committed to the store. Once stored, the asset's storage location points
+//! at the registry's own store. Uploads to signed assets must match the
+//! signed size and content type.
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use llm_registry_core::{
+    Asset, AssetId, Checksum, ChecksumHasher, EventType, RegistryEvent, StorageLocation,
+};
+use llm_registry_db::{AssetRepository, BlobStore, ByteRange, ByteStream, EventStore, UnitOfWork};
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, instrument, warn};
+
+use crate::error::{ServiceError, ServiceResult};
+
+/// Byte range requested by a client, before it is resolved against the size
+/// of the content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    /// From an offset to the last byte, or to the end if unbounded
+    From {
+        /// Offset of the first byte
+        start: u64,
+        /// Offset of the last byte, inclusive
+        end: Option<u64>,
+    },
+    /// The last bytes of the content
+    Suffix(u64),
+}
+
+impl RangeRequest {
+    /// Resolve the range against the content size
+    ///
+    /// Returns `None` if no byte of the content lies in the range. An end
+    /// past the content is clamped to its last byte.
+    pub fn resolve(&self, size: u64) -> Option<ByteRange> {
+        let last = size.checked_sub(1)?;
+        match *self {
+            RangeRequest::From { start, end } => {
+                let end = end.unwrap_or(last).min(last);
+                (start <= end).then(|| ByteRange::new(start, end))
+            }
+            RangeRequest::Suffix(0) => None,
+            RangeRequest::Suffix(length) => Some(ByteRange::new(size.saturating_sub(length), last)),
+        }
+    }
+}
+
+/// Stored content of an asset, or a range of it
+pub struct AssetContent {
+    /// Asset the content belongs to
+    pub asset: Asset,
+
+    /// Size of the whole content in bytes
+    pub size: u64,
+
+    /// Range being read, or `None` for the whole content
+    pub range: Option<ByteRange>,
+
+    /// Bytes of the content or range
+    pub body: ByteStream,
+}
+
+impl fmt::Debug for AssetContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetContent")
+            .field("asset", &self.asset.id)
+            .field("size", &self.size)
+            .field("range", &self.range)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Service for storing and reading asset content
+#[async_trait]
+pub trait ContentService: Send + Sync {
+    /// Store the content of an asset, replacing any stored before
+    ///
+    /// The content must match the asset's registered checksum. On success
+    /// the asset's storage location, size and (if given) content type are
+    /// updated.
+    async fn upload_content(
+        &self,
+        asset_id: &AssetId,
+        content: ByteStream,
+        content_type: Option<String>,
+    ) -> ServiceResult<Asset>;
+
+    /// Read the stored content of an asset, or a range of it
+    ///
+    /// Downloads starting at the first byte are recorded as
+    /// `asset_downloaded` events; reads resuming part way through are not.
+    async fn download_content(
+        &self,
+        asset_id: &AssetId,
+        range: Option<RangeRequest>,
+        downloader: Option<String>,
+    ) -> ServiceResult<AssetContent>;
+}
+
+/// Default implementation of ContentService
+pub struct DefaultContentService {
+    repository: Arc<dyn AssetRepository>,
+    event_store: Arc<dyn EventStore>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    blob_store: Option<Arc<dyn BlobStore>>,
+}
+
+impl DefaultContentService {
+    /// Create a new content service
+    ///
+    /// The asset changes made by an upload are written through
+    /// `unit_of_work` together with their events. Without a blob store,
+    /// uploads and downloads are rejected as unsupported.
+    pub fn new(
+        repository: Arc<dyn AssetRepository>,
+        event_store: Arc<dyn EventStore>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self {
+            repository,
+            event_store,
+            unit_of_work,
+            blob_store: None,
+        }
+    }
+
+    /// Store content in a blob store
+    pub fn with_blob_store(mut self, blob_store: Arc<dyn BlobStore>) -> Self {
+        self.blob_store = Some(blob_store);
+        self
+    }
+
+    /// Key the content of an asset is stored under
+    pub fn content_key(asset_id: &AssetId) -> String {
+        format!("assets/{}", asset_id)
+    }
+
+    fn blob_store(&self) -> ServiceResult<&Arc<dyn BlobStore>> {
+        self.blob_store
+            .as_ref()
+            .ok_or_else(|| ServiceError::Unsupported("No blob store is configured".to_string()))
+    }
+
+    async fn find_asset(&self, asset_id: &AssetId) -> ServiceResult<Asset> {
+        self.repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))
+    }
+
+    /// Append an event that records no change to the asset, logging failures
+    async fn emit(&self, event_type: EventType) {
+        if let Err(e) = self
+            .event_store
+            .append(RegistryEvent::new(event_type))
+            .await
+        {
+            warn!("Failed to emit content event: {}", e);
+        }
+    }
+}
+
+/// State of a stream being checked against a checksum
+struct Verification {
+    content: ByteStream,
+    hasher: Option<ChecksumHasher>,
+    expected: Checksum,
+    mismatch: Arc<Mutex<Option<Checksum>>>,
+}
+
+/// Hash content as it streams, failing at its end if it does not match
+///
+/// The failure makes the blob store discard the content. The checksum
+/// actually computed is left in `mismatch`.
+fn verify_stream(
+    content: ByteStream,
+    expected: Checksum,
+    mismatch: Arc<Mutex<Option<Checksum>>>,
+) -> ByteStream {
+    let verification = Verification {
+        content,
+        hasher: Some(expected.algorithm().hasher()),
+        expected,
+        mismatch,
+    };
+
+    stream::unfold(verification, |mut verification| async move {
+        let hasher = verification.hasher.as_mut()?;
+        let item = match verification.content.next().await {
+            Some(Ok(chunk)) => {
+                hasher.update(&chunk);
+                return Some((Ok(chunk), verification));
+            }
+            Some(Err(e)) => Err(e),
+            None => {
+                let actual = verification.hasher.take()?.finalize();
+                if verification.expected.verify(&actual) {
+                    return None;
+                }
+                *verification
+                    .mismatch
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner()) = Some(actual);
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "checksum mismatch",
+                ))
+            }
+        };
+        verification.hasher = None;
+        Some((item, verification))
+    })
+    .boxed()
+}
+
+/// Refuse content that would change the signed fields of an asset
+///
+/// The signing payload covers the size and content type, so uploading to a
+/// signed asset may only confirm them. The size is checked once the content
+/// is stored; an unsigned size can never be filled in.
+fn check_signed_fields(asset: &Asset, content_type: Option<&str>) -> ServiceResult<()> {
+    if !asset.is_signed() {
+        return Ok(());
+    }
+    if asset.metadata.size_bytes.is_none() {
+        return Err(ServiceError::ValidationFailed(
+            "Cannot upload content to a signed asset registered without a size".to_string(),
+        ));
+    }
+    match content_type {
+        Some(content_type) if asset.metadata.content_type.as_deref() != Some(content_type) => {
+            Err(ServiceError::ValidationFailed(format!(
+                "Content type {} does not match the signed content type",
+                content_type
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[async_trait]
+impl ContentService for DefaultContentService {
+    #[instrument(skip(self, content), fields(asset_id = %asset_id))]
+    async fn upload_content(
+        &self,
+        asset_id: &AssetId,
+        content: ByteStream,
+        content_type: Option<String>,
+    ) -> ServiceResult<Asset> {
+        debug!("Uploading asset content");
+
+        let blob_store = self.blob_store()?;
+        let mut asset = self.find_asset(asset_id).await?;
+        check_signed_fields(&asset, content_type.as_deref())?;
+        let key = Self::content_key(asset_id);
+        let expected = asset.checksum.clone();
+
+        let mismatch = Arc::new(Mutex::new(None));
+        let body = verify_stream(content, expected.clone(), mismatch.clone());
+        let size = match blob_store.put(&key, body).await {
+            Ok(size) => size,
+            Err(e) => {
+                let actual = mismatch.lock().unwrap_or_else(|e| e.into_inner()).take();
+                let Some(actual) = actual else {
+                    return Err(e.into());
+                };
+                self.emit(EventType::ChecksumFailed {
+                    asset_id: *asset_id,
+                    expected: expected.value().to_string(),
+                    actual: actual.value().to_string(),
+                })
+                .await;
+                return Err(ServiceError::ChecksumVerificationFailed(format!(
+                    "expected {}, got {}",
+                    expected, actual
+                )));
+            }
+        };
+
+        if asset.is_signed() && asset.metadata.size_bytes != Some(size) {
+            if let Err(e) = blob_store.delete(&key).await {
+                warn!("Failed to delete rejected content: {}", e);
+            }
+            return Err(ServiceError::ValidationFailed(format!(
+                "Content size {} does not match the signed size",
+                size
+            )));
+        }
+
+        let before = asset.clone();
+        asset.storage = StorageLocation::new(blob_store.backend(), key, None)?;
+        asset.metadata.size_bytes = Some(size);
+        if content_type.is_some() {
+            asset.metadata.content_type = content_type;
+        }
+        asset.updated_at = chrono::Utc::now();
+
+        // Record the verification and the field-level update in the same
+        // transaction as the update
+        let changes = before.diff(&asset);
+        let mut events = vec![RegistryEvent::new(EventType::ChecksumVerified {
+            asset_id: *asset_id,
+            success: true,
+            algorithm: expected.algorithm().to_string(),
+        })];
+        if !changes.is_empty() {
+            events.push(RegistryEvent::new(EventType::AssetUpdated {
+                asset_id: *asset_id,
+                asset_name: asset.metadata.name.clone(),
+                updated_fields: changes.iter().map(|c| c.field.clone()).collect(),
+                changes,
+                revision: Some(before.revision + 1),
+            }));
+        }
+
+        let mut tx = self.unit_of_work.begin().await?;
+        tx.update_asset(&asset).await?;
+        tx.append_events(&events).await?;
+        tx.commit().await?;
+
+        Ok(Asset {
+            revision: before.revision + 1,
+            ..asset
+        })
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn download_content(
+        &self,
+        asset_id: &AssetId,
+        range: Option<RangeRequest>,
+        downloader: Option<String>,
+    ) -> ServiceResult<AssetContent> {
+        debug!("Downloading asset content");
+
+        let blob_store = self.blob_store()?;
+        let asset = self.find_asset(asset_id).await?;
+        let key = Self::content_key(asset_id);
+
+        let size = blob_store.size(&key).await?.ok_or_else(|| {
+            ServiceError::NotFound(format!("No content is stored for asset {}", asset_id))
+        })?;
+        let range = match range {
+            Some(range) => Some(
+                range
+                    .resolve(size)
+                    .ok_or(ServiceError::RangeNotSatisfiable { size })?,
+            ),
+            None => None,
+        };
+        let body = blob_store.get(&key, range).await?;
+
+        if range.map_or(true, |range| range.start == 0) {
+            self.emit(EventType::AssetDownloaded {
+                asset_id: *asset_id,
+                asset_name: asset.metadata.name.clone(),
+                asset_version: asset.metadata.version.to_string(),
+                downloader,
+            })
+            .await;
+        }
+
+        Ok(AssetContent {
+            asset,
+            size,
+            range,
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::utils::{encode_public_key, sign_asset};
+    use crate::signing::{
+        DefaultSignatureService, InMemoryTrustedKeyStore, SignatureService, TrustedKey,
+    };
+    use bytes::Bytes;
+    use ed25519_dalek::SigningKey;
+    use llm_registry_core::{
+        AssetMetadata, AssetType, HashAlgorithm, StorageBackend, StorageLocation,
+    };
+    use llm_registry_db::{
+        FileSystemBlobStore, InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork,
+    };
+
+    const CONTENT: &[u8] = b"model weights";
+
+    struct Fixture {
+        _dir: tempfile::TempDir,
+        repository: Arc<InMemoryAssetRepository>,
+        event_store: Arc<InMemoryEventStore>,
+        service: DefaultContentService,
+        asset: Asset,
+    }
+
+    async fn fixture() -> Fixture {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let unit_of_work = Arc::new(InMemoryUnitOfWork::new(
+            repository.clone(),
+            event_store.clone(),
+        ));
+        let service =
+            DefaultContentService::new(repository.clone(), event_store.clone(), unit_of_work)
+                .with_blob_store(Arc::new(FileSystemBlobStore::new(dir.path())));
+
+        let storage = StorageLocation::new(
+            StorageBackend::S3 {
+                bucket: "external".to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+            },
+            "model.bin".to_string(),
+            None,
+        )
+        .unwrap();
+        let checksum =
+            Checksum::new(HashAlgorithm::SHA256, HashAlgorithm::SHA256.digest(CONTENT)).unwrap();
+        let metadata = AssetMetadata::new("content-model", semver::Version::new(1, 0, 0));
+        let asset = Asset::builder(AssetType::Model, metadata, storage, checksum)
+            .build()
+            .unwrap();
+        let asset = repository.create(asset).await.unwrap();
+
+        Fixture {
+            _dir: dir,
+            repository,
+            event_store,
+            service,
+            asset,
+        }
+    }
+
+    fn body(chunks: Vec<&'static [u8]>) -> ByteStream {
+        stream::iter(chunks.into_iter().map(|c| Ok(Bytes::from_static(c)))).boxed()
+    }
+
+    async fn read(content: AssetContent) -> Vec<u8> {
+        content
+            .body
+            .map(|chunk| chunk.unwrap().to_vec())
+            .concat()
+            .await
+    }
+
+    async fn event_names(fixture: &Fixture) -> Vec<String> {
+        let events = fixture
+            .event_store
+            .get_asset_events(&fixture.asset.id, 100)
+            .await
+            .unwrap();
+        events.iter().map(|e| e.event_name().to_string()).collect()
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let from = |start, end| RangeRequest::From { start, end };
+        assert_eq!(from(0, Some(4)).resolve(10), Some(ByteRange::new(0, 4)));
+        assert_eq!(from(5, None).resolve(10), Some(ByteRange::new(5, 9)));
+        assert_eq!(from(5, Some(99)).resolve(10), Some(ByteRange::new(5, 9)));
+        assert_eq!(from(10, None).resolve(10), None);
+        assert_eq!(
+            RangeRequest::Suffix(3).resolve(10),
+            Some(ByteRange::new(7, 9))
+        );
+        assert_eq!(
+            RangeRequest::Suffix(30).resolve(10),
+            Some(ByteRange::new(0, 9))
+        );
+        assert_eq!(RangeRequest::Suffix(0).resolve(10), None);
+        assert_eq!(from(0, None).resolve(0), None);
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_download() {
+        let fixture = fixture().await;
+        let id = fixture.asset.id;
+
+        let updated = fixture
+            .service
+            .upload_content(
+                &id,
+                body(vec![b"model ", b"weights"]),
+                Some("application/x-safetensors".into()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.metadata.size_bytes, Some(CONTENT.len() as u64));
+        assert_eq!(
+            updated.metadata.content_type.as_deref(),
+            Some("application/x-safetensors")
+        );
+        assert!(matches!(
+            updated.storage.backend,
+            StorageBackend::FileSystem { .. }
+        ));
+        assert_eq!(
+            updated.storage.path,
+            DefaultContentService::content_key(&id)
+        );
+
+        let content = fixture
+            .service
+            .download_content(&id, None, Some("alice".into()))
+            .await
+            .unwrap();
+        assert_eq!(content.size, CONTENT.len() as u64);
+        assert_eq!(read(content).await, CONTENT);
+
+        let range = RangeRequest::From {
+            start: 6,
+            end: None,
+        };
+        let content = fixture
+            .service
+            .download_content(&id, Some(range), None)
+            .await
+            .unwrap();
+        assert_eq!(content.range, Some(ByteRange::new(6, 12)));
+        assert_eq!(read(content).await, b"weights");
+
+        let err = fixture
+            .service
+            .download_content(
+                &id,
+                Some(RangeRequest::From {
+                    start: 99,
+                    end: None,
+                }),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ServiceError::RangeNotSatisfiable { size: 13 }
+        ));
+
+        // Only the download from the first byte is recorded
+        let names = event_names(&fixture).await;
+        assert_eq!(names.iter().filter(|n| *n == "asset_downloaded").count(), 1);
+        assert!(names.contains(&"checksum_verified".to_string()));
+        assert!(names.contains(&"asset_updated".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_checksum_mismatch() {
+        let fixture = fixture().await;
+        let id = fixture.asset.id;
+
+        let err = fixture
+            .service
+            .upload_content(&id, body(vec![b"tampered weights"]), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::ChecksumVerificationFailed(_)));
+
+        let err = fixture
+            .service
+            .download_content(&id, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::NotFound(_)));
+        assert_eq!(event_names(&fixture).await, ["checksum_failed"]);
+    }
+
+    #[tokio::test]
+    async fn test_upload_keeps_signature_valid() {
+        let fixture = fixture().await;
+        let id = fixture.asset.id;
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let key = TrustedKey::ed25519("release-key", encode_public_key(&signing_key)).unwrap();
+        let signatures = DefaultSignatureService::new(
+            fixture.repository.clone(),
+            Arc::new(InMemoryTrustedKeyStore::with_keys([key])),
+        );
+        let upload = |content_type: &str| {
+            fixture
+                .service
+                .upload_content(&id, body(vec![CONTENT]), Some(content_type.into()))
+        };
+
+        // An unsigned size cannot be filled in
+        let mut asset = fixture.asset.clone();
+        asset.signature = Some(sign_asset(&signing_key, "release-key", &asset));
+        let asset = fixture.repository.update(asset).await.unwrap();
+        let err = upload("application/octet-stream").await.unwrap_err();
+        assert!(matches!(err, ServiceError::ValidationFailed(_)));
+
+        let mut asset = asset;
+        asset.metadata.size_bytes = Some(CONTENT.len() as u64);
+        asset.metadata.content_type = Some("application/octet-stream".into());
+        asset.signature = Some(sign_asset(&signing_key, "release-key", &asset));
+        fixture.repository.update(asset).await.unwrap();
+
+        let err = upload("application/x-safetensors").await.unwrap_err();
+        assert!(matches!(err, ServiceError::ValidationFailed(_)));
+
+        upload("application/octet-stream").await.unwrap();
+        let result = signatures.verify_asset_signature(&id).await.unwrap();
+        assert!(result.verified, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_without_blob_store() {
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let service = DefaultContentService::new(
+            repository.clone(),
+            event_store.clone(),
+            Arc::new(InMemoryUnitOfWork::new(repository, event_store)),
+        );
+        let err = service
+            .download_content(&AssetId::new(), None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::Unsupported(_)));
+    }
+}
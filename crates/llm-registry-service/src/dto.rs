@@ -5,11 +5,12 @@
 
 use chrono::{DateTime, Utc};
 use llm_registry_core::{
-    Asset, AssetId, AssetReference, AssetStatus, AssetType, Checksum,
-    HashAlgorithm, Provenance, StorageLocation,
+    Asset, AssetId, AssetReference, AssetSignature, AssetStatus, AssetType, Checksum,
+    HashAlgorithm, Provenance, RegistryEvent, SignatureAlgorithm, StorageLocation,
 };
+use llm_registry_db::{SortField as DbSortField, SortOrder as DbSortOrder};
 use semver::Version;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -18,6 +19,7 @@ use std::collections::HashMap;
 
 /// Request to register a new asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RegisterAssetRequest {
     /// Asset type
     pub asset_type: AssetType,
@@ -26,6 +28,7 @@ pub struct RegisterAssetRequest {
     pub name: String,
 
     /// Semantic version
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "1.0.0"))]
     pub version: Version,
 
     /// Optional description
@@ -65,10 +68,15 @@ pub struct RegisterAssetRequest {
     /// Content type / MIME type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+
+    /// Optional signature over the asset's signing payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<AssetSignature>,
 }
 
 /// Response from registering an asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RegisterAssetResponse {
     /// The registered asset
     pub asset: Asset,
@@ -78,12 +86,54 @@ pub struct RegisterAssetResponse {
     pub warnings: Vec<String>,
 }
 
+/// Error for a single item of a batch registration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemError {
+    /// Zero-based position of the item in the batch
+    pub index: usize,
+
+    /// Asset name of the item
+    pub name: String,
+
+    /// Asset version of the item
+    pub version: String,
+
+    /// Error message
+    pub message: String,
+}
+
+/// Response from registering a batch of assets
+///
+/// A batch is all-or-nothing: when `errors` is non-empty, no asset was registered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchRegisterResponse {
+    /// The registered assets, in request order
+    pub assets: Vec<Asset>,
+
+    /// Per-item errors that caused the batch to be rejected
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<BatchItemError>,
+
+    /// Any warnings generated during registration, prefixed with the item index
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl BatchRegisterResponse {
+    /// Check whether the batch was registered
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 // ============================================================================
 // Search DTOs
 // ============================================================================
 
 /// Search query parameters
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
 pub struct SearchAssetsRequest {
     /// Text search across name, description, and annotations
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,6 +155,37 @@ pub struct SearchAssetsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage_backend: Option<String>,
 
+    /// Filter by license
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+
+    /// Exclude assets having any of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tags: Vec<String>,
+
+    /// Minimum size in bytes (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size_bytes: Option<u64>,
+
+    /// Maximum size in bytes (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size_bytes: Option<u64>,
+
+    /// Only include assets created at or after this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only include assets created before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Structured query string, e.g. `type:model tag:llama size>2GB "exact phrase"`
+    ///
+    /// Compiled into the other filters by the search service; see
+    /// [`crate::search_query`] for the grammar.
+    #[serde(rename = "q", skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
     /// Only include non-deprecated assets
     #[serde(default = "default_exclude_deprecated")]
     pub exclude_deprecated: bool,
@@ -113,10 +194,21 @@ pub struct SearchAssetsRequest {
     #[serde(default = "default_limit")]
     pub limit: i64,
 
-    /// Number of results to skip
+    /// Number of results to skip; ignored when `cursor` is set
     #[serde(default)]
     pub offset: i64,
 
+    /// Opaque cursor from a previous response's `next_cursor`
+    ///
+    /// Must be used with the same sort. Not supported when sorting by
+    /// relevance with search text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+
+    /// Count all matching assets, which costs a second query
+    #[serde(default = "default_include_total")]
+    pub include_total: bool,
+
     /// Sort field
     #[serde(default)]
     pub sort_by: SortField,
@@ -134,8 +226,39 @@ fn default_limit() -> i64 {
     50
 }
 
+fn default_include_total() -> bool {
+    true
+}
+
+impl Default for SearchAssetsRequest {
+    fn default() -> Self {
+        Self {
+            text: None,
+            asset_types: Vec::new(),
+            tags: Vec::new(),
+            author: None,
+            storage_backend: None,
+            license: None,
+            exclude_tags: Vec::new(),
+            min_size_bytes: None,
+            max_size_bytes: None,
+            created_after: None,
+            created_before: None,
+            query: None,
+            exclude_deprecated: default_exclude_deprecated(),
+            limit: default_limit(),
+            offset: 0,
+            cursor: None,
+            include_total: default_include_total(),
+            sort_by: SortField::default(),
+            sort_order: SortOrder::default(),
+        }
+    }
+}
+
 /// Fields to sort by
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SortField {
     CreatedAt,
@@ -143,6 +266,8 @@ pub enum SortField {
     Name,
     Version,
     SizeBytes,
+    /// Text search relevance; creation time when there is no search text
+    Relevance,
 }
 
 impl Default for SortField {
@@ -151,8 +276,22 @@ impl Default for SortField {
     }
 }
 
+impl From<SortField> for DbSortField {
+    fn from(field: SortField) -> Self {
+        match field {
+            SortField::CreatedAt => DbSortField::CreatedAt,
+            SortField::UpdatedAt => DbSortField::UpdatedAt,
+            SortField::Name => DbSortField::Name,
+            SortField::Version => DbSortField::Version,
+            SortField::SizeBytes => DbSortField::SizeBytes,
+            SortField::Relevance => DbSortField::Relevance,
+        }
+    }
+}
+
 /// Sort order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SortOrder {
     Ascending,
@@ -165,14 +304,24 @@ impl Default for SortOrder {
     }
 }
 
+impl From<SortOrder> for DbSortOrder {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::Ascending => DbSortOrder::Ascending,
+            SortOrder::Descending => DbSortOrder::Descending,
+        }
+    }
+}
+
 /// Search results response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchAssetsResponse {
     /// Matching assets
     pub assets: Vec<Asset>,
 
-    /// Total number of results (without pagination)
-    pub total: i64,
+    /// Total number of results (without pagination), if counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
 
     /// Current offset
     pub offset: i64,
@@ -182,6 +331,47 @@ pub struct SearchAssetsResponse {
 
     /// Whether there are more results
     pub has_more: bool,
+
+    /// Cursor for fetching the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Highlighted text matches for returned assets, keyed by asset ID
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub snippets: HashMap<AssetId, String>,
+}
+
+/// Number of matching assets sharing a facet value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FacetCount {
+    /// Facet value
+    pub value: String,
+
+    /// Number of matching assets with this value
+    pub count: u64,
+}
+
+/// Facet counts for the assets matching a search
+///
+/// Each list is ordered by descending count, then by value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SearchFacets {
+    /// Counts by asset type
+    pub asset_types: Vec<FacetCount>,
+
+    /// Counts by license
+    pub licenses: Vec<FacetCount>,
+
+    /// Counts by provenance author
+    pub authors: Vec<FacetCount>,
+
+    /// Counts by tag
+    pub tags: Vec<FacetCount>,
+
+    /// Counts by storage backend
+    pub storage_backends: Vec<FacetCount>,
 }
 
 // ============================================================================
@@ -203,8 +393,21 @@ pub struct ValidateAssetRequest {
     pub policies: Vec<String>,
 }
 
+/// Request to validate a registered asset
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(default)]
+pub struct ValidatePoliciesRequest {
+    /// Policies to apply; all policies when empty
+    pub policies: Vec<String>,
+
+    /// Whether to also check that dependencies exist
+    pub deep: bool,
+}
+
 /// Validation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ValidationResult {
     /// Whether validation passed
     pub valid: bool,
@@ -220,6 +423,7 @@ pub struct ValidationResult {
 
 /// Validation error
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ValidationError {
     /// Field or context where error occurred
     pub field: String,
@@ -234,6 +438,7 @@ pub struct ValidationError {
 
 /// Validation warning
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ValidationWarning {
     /// Field or context where warning occurred
     pub field: String,
@@ -257,8 +462,17 @@ pub struct VerifyIntegrityRequest {
     pub computed_checksum: Option<Checksum>,
 }
 
+/// Request to verify a registered asset against a computed checksum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VerifyChecksumRequest {
+    /// Checksum computed over the asset content
+    pub checksum: Checksum,
+}
+
 /// Integrity verification result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct IntegrityVerificationResult {
     /// Whether integrity check passed
     pub verified: bool,
@@ -277,6 +491,7 @@ pub struct IntegrityVerificationResult {
 
 /// Request to compute checksum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ComputeChecksumRequest {
     /// Data to hash (base64 encoded)
     pub data: String,
@@ -288,11 +503,42 @@ pub struct ComputeChecksumRequest {
 
 /// Checksum computation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ComputeChecksumResponse {
     /// Computed checksum
     pub checksum: Checksum,
 }
 
+// ============================================================================
+// Signature DTOs
+// ============================================================================
+
+/// Result of signature verification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SignatureVerificationResult {
+    /// Asset that was verified
+    pub asset_id: AssetId,
+
+    /// Whether the signature is present and valid
+    pub verified: bool,
+
+    /// Signature algorithm (if the asset is signed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<SignatureAlgorithm>,
+
+    /// Signing key identifier (if the asset is signed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+
+    /// Error message if verification failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Verification timestamp
+    pub verified_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Versioning DTOs
 // ============================================================================
@@ -310,6 +556,7 @@ pub struct ListVersionsRequest {
 
 /// Response with asset versions
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ListVersionsResponse {
     /// Asset name
     pub name: String,
@@ -319,13 +566,17 @@ pub struct ListVersionsResponse {
 
     /// Latest active version
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub latest: Option<Version>,
 }
 
 /// Information about a specific version
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = AssetVersionInfo))]
 pub struct VersionInfo {
     /// Version number
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "1.0.0"))]
     pub version: Version,
 
     /// Asset ID
@@ -342,6 +593,20 @@ pub struct VersionInfo {
     pub deprecated_at: Option<DateTime<Utc>>,
 }
 
+/// Request to deprecate an asset version
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DeprecateVersionRequest {
+    /// Reason for deprecation
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// Version of the same asset to use instead
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, example = "1.3.0"))]
+    pub alternative: Option<Version>,
+}
+
 /// Request to check for version conflicts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckVersionConflictRequest {
@@ -388,6 +653,7 @@ fn default_max_depth() -> i32 {
 
 /// Dependency graph response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct DependencyGraphResponse {
     /// Root asset ID
     pub root: AssetId,
@@ -401,6 +667,7 @@ pub struct DependencyGraphResponse {
 
 /// Node in dependency graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct DependencyNode {
     /// Asset ID
     pub asset_id: AssetId,
@@ -409,6 +676,7 @@ pub struct DependencyNode {
     pub name: String,
 
     /// Asset version
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub version: Version,
 
     /// Depth from root (0 = direct dependency)
@@ -425,6 +693,7 @@ pub struct DependencyNode {
 
 /// Request to update asset metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateAssetRequest {
     /// Asset ID
     pub asset_id: AssetId,
@@ -456,10 +725,19 @@ pub struct UpdateAssetRequest {
     /// New status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<AssetStatus>,
+
+    /// Revision the change is based on
+    ///
+    /// When set, the update fails with a conflict if the asset has been
+    /// modified since. When unset, the patch is reapplied to the latest
+    /// revision if a concurrent update wins the race.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_revision: Option<u64>,
 }
 
 /// Response from updating an asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateAssetResponse {
     /// Updated asset
     pub asset: Asset,
@@ -468,6 +746,184 @@ pub struct UpdateAssetResponse {
     pub updated_fields: Vec<String>,
 }
 
+// ============================================================================
+// Event DTOs
+// ============================================================================
+
+/// Maximum number of events in one page
+pub const MAX_EVENT_PAGE_SIZE: i64 = 1000;
+
+/// Event log query parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct ListEventsRequest {
+    /// Filter by asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<AssetId>,
+
+    /// Filter by event types, comma-separated, e.g. `asset_registered,asset_deleted`
+    #[serde(
+        default,
+        deserialize_with = "comma_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    #[cfg_attr(feature = "openapi", param(value_type = Option<String>))]
+    pub event_types: Vec<String>,
+
+    /// Filter by the user or service that triggered the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+
+    /// Only include events that occurred after this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurred_after: Option<DateTime<Utc>>,
+
+    /// Only include events that occurred before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurred_before: Option<DateTime<Utc>>,
+
+    /// Maximum number of events, at most 1000
+    #[serde(default = "default_event_limit")]
+    pub limit: i64,
+
+    /// Opaque cursor from a previous response's `next_cursor`
+    ///
+    /// Must be used with the same sort order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+
+    /// Count all matching events, which costs a second query
+    #[serde(default)]
+    pub include_total: bool,
+
+    /// Order by time; newest first by default
+    #[serde(default)]
+    pub sort_order: SortOrder,
+}
+
+fn default_event_limit() -> i64 {
+    100
+}
+
+/// Deserialize a comma-separated list, dropping empty items
+fn comma_separated<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    Ok(value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+impl Default for ListEventsRequest {
+    fn default() -> Self {
+        Self {
+            asset_id: None,
+            event_types: Vec::new(),
+            actor: None,
+            occurred_after: None,
+            occurred_before: None,
+            limit: default_event_limit(),
+            cursor: None,
+            include_total: false,
+            sort_order: SortOrder::default(),
+        }
+    }
+}
+
+/// Page of events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEventsResponse {
+    /// Events in this page
+    pub events: Vec<RegistryEvent>,
+
+    /// Total number of matching events, if counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+
+    /// Page size
+    pub limit: i64,
+
+    /// Whether more events follow
+    pub has_more: bool,
+
+    /// Cursor for the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+// ============================================================================
+// Webhook DTOs
+// ============================================================================
+
+/// Request to create a webhook subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateWebhookRequest {
+    /// HTTP or HTTPS endpoint receiving deliveries
+    pub url: String,
+
+    /// Secret used to sign deliveries (at least 16 characters)
+    pub secret: String,
+
+    /// Human-readable description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Event names to deliver; empty delivers every event
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_types: Vec<String>,
+
+    /// Asset name patterns to deliver, where `*` matches any run of characters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub asset_names: Vec<String>,
+
+    /// Deliver only events for assets with at least one of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Request to update a webhook subscription
+///
+/// Unset fields are left unchanged. Re-enabling a webhook resets its
+/// failure count.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateWebhookRequest {
+    /// New endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// New signing secret
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    /// New description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// New event type filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<String>>,
+
+    /// New asset name filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_names: Option<Vec<String>>,
+
+    /// New tag filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    /// Enable or disable deliveries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,8 @@ use llm_registry_core::RegistryError;
 use llm_registry_db::DbError;
 use thiserror::Error;
 
+use crate::search_query::QueryParseError;
+
 /// Result type alias for service operations
 pub type ServiceResult<T> = std::result::Result<T, ServiceError>;
 
@@ -29,6 +31,10 @@ pub enum ServiceError {
     #[error("Checksum verification failed: {0}")]
     ChecksumVerificationFailed(String),
 
+    /// Signature verification failed
+    #[error("Signature verification failed: {0}")]
+    SignatureVerificationFailed(String),
+
     /// Circular dependency detected
     #[error("Circular dependency detected: {0}")]
     CircularDependency(String),
@@ -41,6 +47,10 @@ pub enum ServiceError {
     #[error("Version conflict: {0}")]
     VersionConflict(String),
 
+    /// Asset was modified since the revision the change was based on
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     /// Policy validation failed
     #[error("Policy validation failed: {policy_name}: {message}")]
     PolicyValidationFailed {
@@ -52,10 +62,22 @@ pub enum ServiceError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// Malformed search query string
+    #[error("Invalid search query: {0}")]
+    InvalidQuery(#[from] QueryParseError),
+
     /// Operation not permitted
     #[error("Operation not permitted: {0}")]
     NotPermitted(String),
 
+    /// Requested range lies outside the content
+    #[error("Range not satisfiable for content of {size} bytes")]
+    RangeNotSatisfiable { size: u64 },
+
+    /// Operation not supported by this service implementation
+    #[error("Not supported: {0}")]
+    Unsupported(String),
+
     /// Database error
     #[error("Database error: {0}")]
     Database(String),
@@ -111,6 +133,7 @@ impl From<DbError> for ServiceError {
                     }
                 }
             }
+            DbError::Conflict(msg) => ServiceError::Conflict(msg),
             DbError::ConstraintViolation(msg) => ServiceError::ValidationFailed(msg),
             DbError::ForeignKeyViolation(msg) => ServiceError::ValidationFailed(msg),
             DbError::UniqueViolation(msg) => ServiceError::ValidationFailed(msg),
@@ -124,6 +147,7 @@ impl From<DbError> for ServiceError {
             DbError::InvalidQuery(msg) => ServiceError::InvalidInput(msg),
             DbError::Configuration(msg) => ServiceError::Internal(msg),
             DbError::Cache(msg) => ServiceError::Internal(msg),
+            DbError::Storage(msg) => ServiceError::Internal(msg),
             DbError::Migration(msg) => ServiceError::Internal(msg),
             DbError::Internal(msg) => ServiceError::Internal(msg),
             DbError::Domain(err) => ServiceError::from(err),
@@ -144,6 +168,12 @@ impl From<serde_json::Error> for ServiceError {
     }
 }
 
+impl From<tantivy::TantivyError> for ServiceError {
+    fn from(err: tantivy::TantivyError) -> Self {
+        ServiceError::Internal(format!("Search index error: {}", err))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +190,10 @@ mod tests {
         let db_err = DbError::NotFound("asset not found".to_string());
         let service_err: ServiceError = db_err.into();
         assert!(matches!(service_err, ServiceError::NotFound(_)));
+
+        let db_err = DbError::revision_conflict("asset-123", 1, 2);
+        let service_err: ServiceError = db_err.into();
+        assert!(matches!(service_err, ServiceError::Conflict(_)));
     }
 
     #[test]
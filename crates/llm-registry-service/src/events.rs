@@ -0,0 +1,418 @@
+//! Event service
+//!
+//! This module exposes registry events to API consumers: querying the event
+//! log and subscribing to live events. Subscriptions can resume from a
+//! previously seen event ID and transparently catch up from the event store
+//! when a slow consumer falls behind the in-process broadcast. Replay follows
+//! the audit chain sequence, which is assigned in commit order, so events
+//! sharing a timestamp are never skipped. Assets can also be rebuilt from
+//! their events as of any point in the log.
+
+use async_trait::async_trait;
+use llm_registry_core::{Asset, AssetId, RegistryEvent};
+use llm_registry_db::{
+    AsOf, AssetRevision, BroadcastEventStore, Cursor, EventQuery, EventQueryResults, EventStore,
+    ProjectionEngine,
+};
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{debug, instrument, warn};
+
+use crate::dto::{ListEventsRequest, ListEventsResponse, MAX_EVENT_PAGE_SIZE};
+use crate::error::{ServiceError, ServiceResult};
+
+/// Page size used when replaying events from the store
+const REPLAY_PAGE_SIZE: i64 = 500;
+
+/// Maximum number of events replayed when resuming or catching up
+pub const MAX_REPLAY_EVENTS: usize = 10_000;
+
+/// Trait for event query and subscription operations
+#[async_trait]
+pub trait EventService: Send + Sync {
+    /// Query the event log
+    async fn query_events(&self, query: EventQuery) -> ServiceResult<EventQueryResults>;
+
+    /// List events matching the request's filters, one page at a time
+    async fn list_events(&self, request: ListEventsRequest) -> ServiceResult<ListEventsResponse>;
+
+    /// Get a single event by ID
+    async fn get_event(&self, event_id: &str) -> ServiceResult<Option<RegistryEvent>>;
+
+    /// Subscribe to live events
+    ///
+    /// When `last_event_id` is set, events appended after that event are
+    /// replayed from the store before live events are delivered.
+    async fn subscribe(&self, last_event_id: Option<&str>) -> ServiceResult<EventSubscription>;
+
+    /// Rebuild an asset from its events as of a point in the log
+    ///
+    /// Returns `None` if the asset did not exist at that point.
+    async fn asset_as_of(&self, asset_id: &AssetId, as_of: &AsOf) -> ServiceResult<Option<Asset>>;
+
+    /// List an asset's revisions with their field-level changes, oldest first
+    async fn asset_history(&self, asset_id: &AssetId) -> ServiceResult<Vec<AssetRevision>>;
+}
+
+/// Default implementation of EventService
+pub struct DefaultEventService {
+    event_store: Arc<BroadcastEventStore>,
+}
+
+impl DefaultEventService {
+    /// Create a new event service
+    ///
+    /// Only events appended through `event_store` are delivered to subscribers,
+    /// so every other service must share the same broadcasting store.
+    pub fn new(event_store: Arc<BroadcastEventStore>) -> Self {
+        Self { event_store }
+    }
+
+    fn projections(&self) -> ProjectionEngine {
+        ProjectionEngine::new(self.event_store.clone())
+    }
+}
+
+#[async_trait]
+impl EventService for DefaultEventService {
+    #[instrument(skip(self, query))]
+    async fn query_events(&self, query: EventQuery) -> ServiceResult<EventQueryResults> {
+        Ok(self.event_store.query(&query).await?)
+    }
+
+    #[instrument(skip(self, request))]
+    async fn list_events(&self, request: ListEventsRequest) -> ServiceResult<ListEventsResponse> {
+        if !(1..=MAX_EVENT_PAGE_SIZE).contains(&request.limit) {
+            return Err(ServiceError::InvalidInput(format!(
+                "Limit must be between 1 and {}",
+                MAX_EVENT_PAGE_SIZE
+            )));
+        }
+
+        let mut query = EventQuery::new()
+            .limit(request.limit)
+            .include_total(request.include_total)
+            .sort_order(request.sort_order.into());
+        query.asset_id = request.asset_id;
+        query.event_types = request.event_types;
+        query.actor = request.actor;
+        query.after = request.occurred_after;
+        query.before = request.occurred_before;
+        if let Some(ref cursor) = request.cursor {
+            query = query.cursor(Cursor::decode(cursor)?);
+        }
+
+        let results = self.event_store.query(&query).await?;
+        let has_more = results.has_more();
+
+        Ok(ListEventsResponse {
+            events: results.events,
+            total: results.total,
+            limit: results.limit,
+            has_more,
+            next_cursor: results.next_cursor.as_ref().map(Cursor::encode),
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn get_event(&self, event_id: &str) -> ServiceResult<Option<RegistryEvent>> {
+        Ok(self.event_store.get_event(event_id).await?)
+    }
+
+    #[instrument(skip(self))]
+    async fn subscribe(&self, last_event_id: Option<&str>) -> ServiceResult<EventSubscription> {
+        // Subscribe before replaying so no event falls between the two.
+        let receiver = self.event_store.subscribe();
+        let store: Arc<dyn EventStore> = self.event_store.clone();
+
+        let mut subscription = EventSubscription {
+            store,
+            receiver,
+            pending: VecDeque::new(),
+            replayed: HashSet::new(),
+            cursor: 0,
+            last_live: None,
+        };
+
+        match last_event_id {
+            Some(event_id) => {
+                subscription.cursor = match self.event_store.chain_link(event_id).await? {
+                    Some(link) => link.sequence,
+                    None => {
+                        // Events appended before the audit chain precede every
+                        // chained event, so replay the whole chain.
+                        self.event_store.get_event(event_id).await?.ok_or_else(|| {
+                            ServiceError::InvalidInput(format!("Unknown event ID: {}", event_id))
+                        })?;
+                        0
+                    }
+                };
+                subscription.catch_up().await?;
+            }
+            None => {
+                if let Some(head) = self.event_store.chain_head().await? {
+                    subscription.cursor = head.sequence;
+                }
+            }
+        }
+
+        Ok(subscription)
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn asset_as_of(&self, asset_id: &AssetId, as_of: &AsOf) -> ServiceResult<Option<Asset>> {
+        Ok(self.projections().asset_as_of(asset_id, as_of).await?)
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn asset_history(&self, asset_id: &AssetId) -> ServiceResult<Vec<AssetRevision>> {
+        Ok(self.projections().history(asset_id).await?)
+    }
+}
+
+/// A live subscription to registry events
+///
+/// Events are delivered in append order. If the subscriber falls behind the
+/// broadcast buffer, the missed events are replayed from the event store in
+/// chain order and duplicates between the replay and the live buffer are
+/// dropped.
+pub struct EventSubscription {
+    store: Arc<dyn EventStore>,
+    receiver: broadcast::Receiver<RegistryEvent>,
+    pending: VecDeque<RegistryEvent>,
+    replayed: HashSet<String>,
+    /// Chain sequence of the last replayed event
+    cursor: u64,
+    /// Last live event delivered since the cursor was updated
+    last_live: Option<String>,
+}
+
+impl EventSubscription {
+    /// Wait for the next event
+    ///
+    /// Returns `Ok(None)` once the event source has shut down.
+    pub async fn next(&mut self) -> ServiceResult<Option<RegistryEvent>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+
+            match self.receiver.recv().await {
+                Ok(event) => {
+                    if self.replayed.contains(&event.event_id) {
+                        continue;
+                    }
+                    self.last_live = Some(event.event_id.clone());
+                    return Ok(Some(event));
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(skipped, "Event subscriber lagged, catching up from store");
+                    self.catch_up().await?;
+                }
+                Err(RecvError::Closed) => return Ok(None),
+            }
+        }
+    }
+
+    /// Replay events after the cursor from the store in chain order
+    async fn catch_up(&mut self) -> ServiceResult<()> {
+        // Live events carry no chain position; look up the last one delivered
+        // so it is not replayed.
+        if let Some(event_id) = self.last_live.take() {
+            if let Some(link) = self.store.chain_link(&event_id).await? {
+                self.cursor = self.cursor.max(link.sequence);
+            }
+        }
+
+        let mut events = Vec::new();
+        loop {
+            let page = self
+                .store
+                .chain_events(self.cursor, REPLAY_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            for chained in page {
+                self.cursor = chained.link.sequence;
+                events.push(chained.event);
+            }
+
+            if events.len() > MAX_REPLAY_EVENTS {
+                return Err(ServiceError::InvalidInput(format!(
+                    "More than {} events to replay; resubscribe without a resume point",
+                    MAX_REPLAY_EVENTS
+                )));
+            }
+            if page_len < REPLAY_PAGE_SIZE as usize {
+                break;
+            }
+        }
+        debug!(count = events.len(), "Replaying events");
+
+        self.replayed = events.iter().map(|e| e.event_id.clone()).collect();
+        self.pending = events.into();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use llm_registry_core::{AssetId, EventType};
+    use llm_registry_db::InMemoryEventStore;
+
+    fn create_service(capacity: usize) -> (DefaultEventService, Arc<BroadcastEventStore>) {
+        let store = Arc::new(BroadcastEventStore::with_capacity(
+            Arc::new(InMemoryEventStore::new()),
+            capacity,
+        ));
+        (DefaultEventService::new(store.clone()), store)
+    }
+
+    fn event_at(offset_ms: i64) -> RegistryEvent {
+        RegistryEvent::builder(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+        .timestamp(Utc::now() + chrono::Duration::milliseconds(offset_ms))
+        .build()
+    }
+
+    #[tokio::test]
+    async fn test_list_events_filters_and_pages() {
+        let (service, store) = create_service(16);
+        for offset in [-30, -20, -10] {
+            store.append(event_at(offset)).await.unwrap();
+        }
+        let tagged = RegistryEvent::builder(EventType::Custom {
+            name: "export_requested".to_string(),
+            data: Default::default(),
+        })
+        .actor("auditor@example.com")
+        .build();
+        store.append(tagged).await.unwrap();
+
+        let request = ListEventsRequest {
+            event_types: vec!["asset_deleted".to_string()],
+            limit: 2,
+            ..Default::default()
+        };
+        let first = service.list_events(request.clone()).await.unwrap();
+        assert_eq!(first.events.len(), 2);
+        assert!(first.has_more);
+
+        let second = service
+            .list_events(ListEventsRequest {
+                cursor: first.next_cursor,
+                ..request
+            })
+            .await
+            .unwrap();
+        assert_eq!(second.events.len(), 1);
+        assert!(!second.has_more);
+        assert!(second.events[0].timestamp < first.events[1].timestamp);
+
+        let by_actor = service
+            .list_events(ListEventsRequest {
+                actor: Some("auditor@example.com".to_string()),
+                include_total: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_actor.total, Some(1));
+        assert_eq!(by_actor.events[0].event_name(), "export_requested");
+
+        let err = service
+            .list_events(ListEventsRequest {
+                limit: 0,
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_receives_live_events() {
+        let (service, store) = create_service(16);
+        let mut subscription = service.subscribe(None).await.unwrap();
+
+        let event = store.append(event_at(0)).await.unwrap();
+        let received = subscription.next().await.unwrap().unwrap();
+        assert_eq!(received.event_id, event.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resumes_after_event() {
+        let (service, store) = create_service(16);
+        let first = store.append(event_at(-30)).await.unwrap();
+        let second = store.append(event_at(-20)).await.unwrap();
+        let third = store.append(event_at(-10)).await.unwrap();
+
+        let mut subscription = service.subscribe(Some(&first.event_id)).await.unwrap();
+        let live = store.append(event_at(0)).await.unwrap();
+
+        for expected in [&second, &third, &live] {
+            let received = subscription.next().await.unwrap().unwrap();
+            assert_eq!(received.event_id, expected.event_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resumes_between_events_sharing_a_timestamp() {
+        let (service, store) = create_service(16);
+        let timestamp = Utc::now();
+        let mut first = event_at(0);
+        first.timestamp = timestamp;
+        let mut second = event_at(0);
+        second.timestamp = timestamp;
+        // Append in reverse ID order so neither timestamp nor ID order matches
+        // the commit order.
+        if first.event_id < second.event_id {
+            std::mem::swap(&mut first, &mut second);
+        }
+        let first = store.append(first).await.unwrap();
+        let second = store.append(second).await.unwrap();
+
+        let mut subscription = service.subscribe(None).await.unwrap();
+        let live = store.append(event_at(10)).await.unwrap();
+        assert_eq!(
+            subscription.next().await.unwrap().unwrap().event_id,
+            live.event_id
+        );
+        drop(subscription);
+
+        // Reconnect after the first event: the second shares its timestamp.
+        let mut resumed = service.subscribe(Some(&first.event_id)).await.unwrap();
+        for expected in [&second, &live] {
+            let received = resumed.next().await.unwrap().unwrap();
+            assert_eq!(received.event_id, expected.event_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_unknown_event_id() {
+        let (service, _) = create_service(16);
+        let result = service.subscribe(Some("01HN9XWZP8XQYZVJ4KFQY6XQZV")).await;
+        assert!(matches!(result, Err(ServiceError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_lagged_subscriber_catches_up() {
+        let (service, store) = create_service(2);
+        let mut subscription = service.subscribe(None).await.unwrap();
+
+        let mut appended = Vec::new();
+        for i in 1..=5 {
+            appended.push(store.append(event_at(i)).await.unwrap());
+        }
+
+        for expected in &appended {
+            let received = subscription.next().await.unwrap().unwrap();
+            assert_eq!(received.event_id, expected.event_id);
+        }
+    }
+}
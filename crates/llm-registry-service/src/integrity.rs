@@ -5,7 +5,7 @@
 
 use async_trait::async_trait;
 use llm_registry_core::{Asset, AssetId, Checksum, EventType, HashAlgorithm, RegistryEvent};
-use llm_registry_db::{AssetRepository, EventStore};
+use llm_registry_db::{AssetRepository, EventStore, UnitOfWork};
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
@@ -19,65 +19,72 @@ use crate::error::{ServiceError, ServiceResult};
 #[async_trait]
 pub trait IntegrityService: Send + Sync {
     /// Compute checksum for provided data
-    async fn compute_checksum(&self, request: ComputeChecksumRequest) -> ServiceResult<ComputeChecksumResponse>;
+    async fn compute_checksum(
+        &self,
+        request: ComputeChecksumRequest,
+    ) -> ServiceResult<ComputeChecksumResponse>;
 
     /// Verify asset integrity against stored checksum
-    async fn verify_integrity(&self, request: VerifyIntegrityRequest) -> ServiceResult<IntegrityVerificationResult>;
+    async fn verify_integrity(
+        &self,
+        request: VerifyIntegrityRequest,
+    ) -> ServiceResult<IntegrityVerificationResult>;
 
     /// Verify checksum matches expected value
-    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum) -> ServiceResult<bool>;
+    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum)
+        -> ServiceResult<bool>;
 
     /// Recompute and update asset checksum
-    async fn update_checksum(&self, asset_id: &AssetId, new_checksum: Checksum) -> ServiceResult<Asset>;
+    async fn update_checksum(
+        &self,
+        asset_id: &AssetId,
+        new_checksum: Checksum,
+    ) -> ServiceResult<Asset>;
 }
 
 /// Default implementation of IntegrityService
 pub struct DefaultIntegrityService {
     repository: Arc<dyn AssetRepository>,
     event_store: Arc<dyn EventStore>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl DefaultIntegrityService {
     /// Create a new integrity service
-    pub fn new(repository: Arc<dyn AssetRepository>, event_store: Arc<dyn EventStore>) -> Self {
+    ///
+    /// Checksum updates are written through `unit_of_work` together with
+    /// their events; verification results are appended to `event_store`.
+    pub fn new(
+        repository: Arc<dyn AssetRepository>,
+        event_store: Arc<dyn EventStore>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+    ) -> Self {
         Self {
             repository,
             event_store,
+            unit_of_work,
         }
     }
 
     /// Hash data using the specified algorithm
     fn hash_data(data: &[u8], algorithm: HashAlgorithm) -> String {
-        match algorithm {
-            HashAlgorithm::SHA256 => {
-                use sha2::{Digest, Sha256};
-                let mut hasher = Sha256::new();
-                hasher.update(data);
-                format!("{:x}", hasher.finalize())
-            }
-            HashAlgorithm::SHA3_256 => {
-                use sha3::{Digest, Sha3_256};
-                let mut hasher = Sha3_256::new();
-                hasher.update(data);
-                format!("{:x}", hasher.finalize())
-            }
-            HashAlgorithm::BLAKE3 => {
-                let hash = blake3::hash(data);
-                hash.to_hex().to_string()
-            }
-        }
+        algorithm.digest(data)
     }
 }
 
 #[async_trait]
 impl IntegrityService for DefaultIntegrityService {
     #[instrument(skip(self, request))]
-    async fn compute_checksum(&self, request: ComputeChecksumRequest) -> ServiceResult<ComputeChecksumResponse> {
+    async fn compute_checksum(
+        &self,
+        request: ComputeChecksumRequest,
+    ) -> ServiceResult<ComputeChecksumResponse> {
         debug!("Computing checksum with algorithm: {:?}", request.algorithm);
 
         // Decode base64 data
         use base64::Engine;
-        let data = base64::engine::general_purpose::STANDARD.decode(&request.data)
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(&request.data)
             .map_err(|e| ServiceError::InvalidInput(format!("Invalid base64 data: {}", e)))?;
 
         // Compute hash
@@ -91,7 +98,10 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self, request), fields(asset_id = %request.asset_id))]
-    async fn verify_integrity(&self, request: VerifyIntegrityRequest) -> ServiceResult<IntegrityVerificationResult> {
+    async fn verify_integrity(
+        &self,
+        request: VerifyIntegrityRequest,
+    ) -> ServiceResult<IntegrityVerificationResult> {
         debug!("Verifying integrity for asset");
 
         // Fetch the asset
@@ -160,7 +170,11 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum) -> ServiceResult<bool> {
+    async fn verify_checksum(
+        &self,
+        asset_id: &AssetId,
+        computed: &Checksum,
+    ) -> ServiceResult<bool> {
         debug!("Verifying checksum");
 
         let asset = self
@@ -194,7 +208,11 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn update_checksum(&self, asset_id: &AssetId, new_checksum: Checksum) -> ServiceResult<Asset> {
+    async fn update_checksum(
+        &self,
+        asset_id: &AssetId,
+        new_checksum: Checksum,
+    ) -> ServiceResult<Asset> {
         debug!("Updating asset checksum");
 
         // Fetch the asset
@@ -205,24 +223,28 @@ impl IntegrityService for DefaultIntegrityService {
             .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
 
         // Update checksum
+        let before = asset.clone();
         asset.checksum = new_checksum;
         asset.updated_at = chrono::Utc::now();
 
-        // Persist the update
-        let updated = self.repository.update(asset).await?;
-
-        // Emit update event
+        // Persist the update with its event
         let event = RegistryEvent::new(EventType::AssetUpdated {
             asset_id: *asset_id,
-            asset_name: updated.metadata.name.clone(),
+            asset_name: asset.metadata.name.clone(),
             updated_fields: vec!["checksum".to_string()],
+            changes: before.diff(&asset),
+            revision: Some(before.revision + 1),
         });
 
-        if let Err(e) = self.event_store.append(event).await {
-            warn!("Failed to emit asset update event: {}", e);
-        }
+        let mut tx = self.unit_of_work.begin().await?;
+        tx.update_asset(&asset).await?;
+        tx.append_event(&event).await?;
+        tx.commit().await?;
 
-        Ok(updated)
+        Ok(Asset {
+            revision: before.revision + 1,
+            ..asset
+        })
     }
 }
 
@@ -8,10 +8,14 @@
 //! The service layer is organized into the following components:
 //!
 //! - **RegistrationService**: Asset registration with validation and dependency resolution
-//! - **SearchService**: Search and query operations
+//! - **SearchService**: Search and query operations, optionally backed by a Tantivy index
 //! - **ValidationService**: Schema and policy validation
 //! - **IntegrityService**: Checksum computation and verification
 //! - **VersioningService**: Version management and conflict detection
+//! - **SignatureService**: Asset signature verification against trusted keys
+//! - **EventService**: Event log queries and live event subscriptions
+//! - **ContentService**: Asset content upload and download through a blob store
+//! - **WebhookService**: Webhook subscriptions and their delivery log
 //!
 //! # Example
 //!
@@ -28,6 +32,7 @@
 //! # async fn example(
 //! #     repository: Arc<dyn llm_registry_db::AssetRepository>,
 //! #     event_store: Arc<dyn llm_registry_db::EventStore>,
+//! #     unit_of_work: Arc<dyn llm_registry_db::UnitOfWork>,
 //! # ) {
 //! // Create service instances
 //! let validation_service = Arc::new(DefaultValidationService::new(
@@ -38,16 +43,18 @@
 //! let integrity_service = Arc::new(DefaultIntegrityService::new(
 //!     repository.clone(),
 //!     event_store.clone(),
+//!     unit_of_work.clone(),
 //! ));
 //!
 //! let versioning_service = Arc::new(DefaultVersioningService::new(
 //!     repository.clone(),
-//!     event_store.clone(),
+//!     unit_of_work.clone(),
 //! ));
 //!
 //! let registration_service = Arc::new(DefaultRegistrationService::new(
 //!     repository.clone(),
 //!     event_store.clone(),
+//!     unit_of_work.clone(),
 //!     validation_service.clone(),
 //!     integrity_service.clone(),
 //!     versioning_service.clone(),
@@ -57,13 +64,22 @@
 //! # }
 //! ```
 
+pub mod audit;
+pub mod content;
 pub mod dto;
 pub mod error;
+pub mod events;
 pub mod integrity;
 pub mod registration;
 pub mod search;
+pub mod search_index;
+pub mod search_query;
+pub mod signing;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod validation;
 pub mod versioning;
+pub mod webhooks;
 
 // LLM-Dev-Ops upstream integration adapters (Phase 2B)
 pub mod adapters;
@@ -73,18 +89,36 @@ pub use dto::*;
 pub use error::{ServiceError, ServiceResult};
 
 // Re-export service traits and implementations
+pub use audit::{spawn_checkpoint_task, AuditService, CheckpointSigner, DefaultAuditService};
+pub use content::{AssetContent, ContentService, DefaultContentService, RangeRequest};
+pub use events::{DefaultEventService, EventService, EventSubscription};
 pub use integrity::{DefaultIntegrityService, IntegrityService};
+pub use llm_registry_db::{
+    AsOf, AssetRevision, BlobStore, BreakKind, BrokenLink, ByteRange, ByteStream, ChainCheckpoint,
+    ChainLink, ChainVerification, Cursor, DeliveryStatus, DependencyLink, EventQuery,
+    EventQueryResults, Webhook, WebhookDelivery,
+};
 pub use registration::{DefaultRegistrationService, RegistrationService};
 pub use search::{DefaultSearchService, SearchService};
-pub use validation::{DefaultValidationService, ValidationService};
+pub use search_index::TantivySearchService;
+pub use search_query::{parse_query, ParsedQuery, QueryParseError};
+pub use signing::{
+    DefaultSignatureService, InMemoryTrustedKeyStore, SignatureService, TrustedKey, TrustedKeyStore,
+};
+pub use validation::{DefaultValidationService, ValidationService, VALIDATION_POLICIES};
 pub use versioning::{DefaultVersioningService, VersioningService};
+pub use webhooks::{
+    sign_payload, DefaultWebhookService, WebhookDispatcher, WebhookDispatcherConfig,
+    WebhookPublisher, WebhookService,
+};
 
 // Re-export upstream adapters for convenience
-pub use adapters::{
-    ConfigManagerAdapter, ObservatoryAdapter, SchemaRegistryAdapter,
-};
+pub use adapters::{ConfigManagerAdapter, ObservatoryAdapter, SchemaRegistryAdapter};
 
-use llm_registry_db::{AssetRepository, EventStore};
+use llm_registry_db::{
+    AssetRepository, BroadcastEventStore, BroadcastUnitOfWork, EventStore, InMemoryAssetRepository,
+    InMemoryEventStore, InMemoryUnitOfWork, InMemoryWebhookStore, UnitOfWork, WebhookStore,
+};
 use std::sync::Arc;
 
 /// Service registry that holds all service instances
@@ -103,15 +137,30 @@ pub struct ServiceRegistry {
     pub integrity: Arc<dyn IntegrityService>,
     /// Versioning service
     pub versioning: Arc<dyn VersioningService>,
+    /// Signature service
+    pub signing: Arc<dyn SignatureService>,
+    /// Event service
+    pub events: Arc<dyn EventService>,
+    /// Audit service
+    pub audit: Arc<dyn AuditService>,
+    /// Webhook service
+    pub webhooks: Arc<dyn WebhookService>,
+    /// Content service
+    pub content: Arc<dyn ContentService>,
 }
 
 impl ServiceRegistry {
     /// Create a new service registry with default implementations
     ///
+    /// No blob store is configured, so asset content cannot be uploaded or
+    /// downloaded; use [`ServiceRegistryBuilder::blob_store`] for that.
+    ///
     /// # Arguments
     ///
     /// * `repository` - Asset repository implementation
     /// * `event_store` - Event store implementation
+    /// * `unit_of_work` - Unit of work committing asset writes with their
+    ///   events, over the same storage as `repository` and `event_store`
     ///
     /// # Example
     ///
@@ -122,14 +171,23 @@ impl ServiceRegistry {
     /// # async fn example(
     /// #     repository: Arc<dyn llm_registry_db::AssetRepository>,
     /// #     event_store: Arc<dyn llm_registry_db::EventStore>,
+    /// #     unit_of_work: Arc<dyn llm_registry_db::UnitOfWork>,
     /// # ) {
-    /// let services = ServiceRegistry::new(repository, event_store);
+    /// let services = ServiceRegistry::new(repository, event_store, unit_of_work);
     /// # }
     /// ```
     pub fn new(
         repository: Arc<dyn AssetRepository>,
         event_store: Arc<dyn EventStore>,
+        unit_of_work: Arc<dyn UnitOfWork>,
     ) -> Self {
+        // Broadcast every appended event to live subscribers
+        let broadcast = Arc::new(BroadcastEventStore::new(event_store));
+        let events = Arc::new(DefaultEventService::new(broadcast.clone()));
+        let unit_of_work: Arc<dyn UnitOfWork> =
+            Arc::new(BroadcastUnitOfWork::new(unit_of_work, &broadcast));
+        let event_store: Arc<dyn EventStore> = broadcast;
+
         // Create shared service instances
         let validation = Arc::new(DefaultValidationService::new(
             repository.clone(),
@@ -139,21 +197,44 @@ impl ServiceRegistry {
         let integrity = Arc::new(DefaultIntegrityService::new(
             repository.clone(),
             event_store.clone(),
+            unit_of_work.clone(),
         ));
 
         let versioning = Arc::new(DefaultVersioningService::new(
             repository.clone(),
-            event_store.clone(),
+            unit_of_work.clone(),
         ));
 
         let search = Arc::new(DefaultSearchService::new(repository.clone()));
 
-        let registration = Arc::new(DefaultRegistrationService::new(
+        let key_store: Arc<dyn TrustedKeyStore> = Arc::new(InMemoryTrustedKeyStore::new());
+        let signing = Arc::new(DefaultSignatureService::new(
             repository.clone(),
-            event_store.clone(),
-            validation.clone(),
-            integrity.clone(),
-            versioning.clone(),
+            key_store.clone(),
+        ));
+
+        let audit = Arc::new(DefaultAuditService::new(event_store.clone(), key_store));
+
+        let webhooks = Arc::new(DefaultWebhookService::new(Arc::new(
+            InMemoryWebhookStore::new(),
+        )));
+
+        let registration = Arc::new(
+            DefaultRegistrationService::new(
+                repository.clone(),
+                event_store.clone(),
+                unit_of_work.clone(),
+                validation.clone(),
+                integrity.clone(),
+                versioning.clone(),
+            )
+            .with_signature_service(signing.clone()),
+        );
+
+        let content = Arc::new(DefaultContentService::new(
+            repository,
+            event_store,
+            unit_of_work,
         ));
 
         Self {
@@ -162,19 +243,58 @@ impl ServiceRegistry {
             validation,
             integrity,
             versioning,
+            signing,
+            events,
+            audit,
+            webhooks,
+            content,
         }
     }
 
+    /// Create a service registry backed by in-memory storage
+    ///
+    /// Useful for embedding the registry in-process and for tests. All state
+    /// is lost when the registry is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use llm_registry_service::ServiceRegistry;
+    ///
+    /// let services = ServiceRegistry::in_memory();
+    /// ```
+    pub fn in_memory() -> Self {
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let unit_of_work = Arc::new(InMemoryUnitOfWork::new(
+            repository.clone(),
+            event_store.clone(),
+        ));
+
+        ServiceRegistryBuilder::new()
+            .repository(repository)
+            .event_store(event_store)
+            .unit_of_work(unit_of_work)
+            .build()
+            .expect("repository, event store and unit of work are set")
+    }
+
     /// Create a service registry with custom implementations
     ///
     /// This allows for dependency injection of custom service implementations
     /// for testing or specialized behavior.
+    #[allow(clippy::too_many_arguments)]
     pub fn with_services(
         registration: Arc<dyn RegistrationService>,
         search: Arc<dyn SearchService>,
         validation: Arc<dyn ValidationService>,
         integrity: Arc<dyn IntegrityService>,
         versioning: Arc<dyn VersioningService>,
+        signing: Arc<dyn SignatureService>,
+        events: Arc<dyn EventService>,
+        audit: Arc<dyn AuditService>,
+        webhooks: Arc<dyn WebhookService>,
+        content: Arc<dyn ContentService>,
     ) -> Self {
         Self {
             registration,
@@ -182,6 +302,11 @@ impl ServiceRegistry {
             validation,
             integrity,
             versioning,
+            signing,
+            events,
+            audit,
+            webhooks,
+            content,
         }
     }
 
@@ -209,17 +334,53 @@ impl ServiceRegistry {
     pub fn versioning(&self) -> &Arc<dyn VersioningService> {
         &self.versioning
     }
+
+    /// Get the signature service
+    pub fn signing(&self) -> &Arc<dyn SignatureService> {
+        &self.signing
+    }
+
+    /// Get the event service
+    pub fn events(&self) -> &Arc<dyn EventService> {
+        &self.events
+    }
+
+    /// Get the audit service
+    pub fn audit(&self) -> &Arc<dyn AuditService> {
+        &self.audit
+    }
+
+    /// Get the webhook service
+    pub fn webhooks(&self) -> &Arc<dyn WebhookService> {
+        &self.webhooks
+    }
+
+    /// Get the content service
+    pub fn content(&self) -> &Arc<dyn ContentService> {
+        &self.content
+    }
 }
 
 /// Builder for ServiceRegistry with custom configuration
 pub struct ServiceRegistryBuilder {
     repository: Option<Arc<dyn AssetRepository>>,
     event_store: Option<Arc<dyn EventStore>>,
+    unit_of_work: Option<Arc<dyn UnitOfWork>>,
     validation: Option<Arc<dyn ValidationService>>,
     integrity: Option<Arc<dyn IntegrityService>>,
     versioning: Option<Arc<dyn VersioningService>>,
     search: Option<Arc<dyn SearchService>>,
     registration: Option<Arc<dyn RegistrationService>>,
+    key_store: Option<Arc<dyn TrustedKeyStore>>,
+    signing: Option<Arc<dyn SignatureService>>,
+    events: Option<Arc<dyn EventService>>,
+    audit: Option<Arc<dyn AuditService>>,
+    checkpoint_signer: Option<CheckpointSigner>,
+    webhook_store: Option<Arc<dyn WebhookStore>>,
+    webhook_allowed_hosts: Vec<String>,
+    webhooks: Option<Arc<dyn WebhookService>>,
+    blob_store: Option<Arc<dyn BlobStore>>,
+    content: Option<Arc<dyn ContentService>>,
 }
 
 impl ServiceRegistryBuilder {
@@ -228,11 +389,22 @@ impl ServiceRegistryBuilder {
         Self {
             repository: None,
             event_store: None,
+            unit_of_work: None,
             validation: None,
             integrity: None,
             versioning: None,
             search: None,
             registration: None,
+            key_store: None,
+            signing: None,
+            events: None,
+            audit: None,
+            checkpoint_signer: None,
+            webhook_store: None,
+            webhook_allowed_hosts: Vec::new(),
+            webhooks: None,
+            blob_store: None,
+            content: None,
         }
     }
 
@@ -248,6 +420,15 @@ impl ServiceRegistryBuilder {
         self
     }
 
+    /// Set the unit of work used to commit asset writes with their events
+    ///
+    /// It must write to the same storage as the repository and event store.
+    /// Required.
+    pub fn unit_of_work(mut self, unit_of_work: Arc<dyn UnitOfWork>) -> Self {
+        self.unit_of_work = Some(unit_of_work);
+        self
+    }
+
     /// Set a custom validation service
     pub fn validation_service(mut self, service: Arc<dyn ValidationService>) -> Self {
         self.validation = Some(service);
@@ -278,17 +459,96 @@ impl ServiceRegistryBuilder {
         self
     }
 
+    /// Set the trusted key store used for signature verification
+    pub fn key_store(mut self, key_store: Arc<dyn TrustedKeyStore>) -> Self {
+        self.key_store = Some(key_store);
+        self
+    }
+
+    /// Set a custom signature service
+    pub fn signature_service(mut self, service: Arc<dyn SignatureService>) -> Self {
+        self.signing = Some(service);
+        self
+    }
+
+    /// Set a custom event service
+    pub fn event_service(mut self, service: Arc<dyn EventService>) -> Self {
+        self.events = Some(service);
+        self
+    }
+
+    /// Set a custom audit service
+    pub fn audit_service(mut self, service: Arc<dyn AuditService>) -> Self {
+        self.audit = Some(service);
+        self
+    }
+
+    /// Set the key used to sign audit chain checkpoints
+    ///
+    /// Its public key must be in the trusted key store for the checkpoints
+    /// to verify.
+    pub fn checkpoint_signer(mut self, signer: CheckpointSigner) -> Self {
+        self.checkpoint_signer = Some(signer);
+        self
+    }
+
+    /// Set the store holding webhooks and their delivery log
+    ///
+    /// Defaults to an in-memory store.
+    pub fn webhook_store(mut self, store: Arc<dyn WebhookStore>) -> Self {
+        self.webhook_store = Some(store);
+        self
+    }
+
+    /// Allow webhook URLs on these hosts even if they are internal
+    ///
+    /// By default, webhooks may not target loopback, link-local or private
+    /// addresses.
+    pub fn webhook_allowed_hosts(mut self, hosts: Vec<String>) -> Self {
+        self.webhook_allowed_hosts = hosts;
+        self
+    }
+
+    /// Set a custom webhook service
+    pub fn webhook_service(mut self, service: Arc<dyn WebhookService>) -> Self {
+        self.webhooks = Some(service);
+        self
+    }
+
+    /// Set the store holding asset content
+    ///
+    /// Without one, asset content cannot be uploaded or downloaded.
+    pub fn blob_store(mut self, store: Arc<dyn BlobStore>) -> Self {
+        self.blob_store = Some(store);
+        self
+    }
+
+    /// Set a custom content service
+    pub fn content_service(mut self, service: Arc<dyn ContentService>) -> Self {
+        self.content = Some(service);
+        self
+    }
+
     /// Build the service registry
     ///
     /// This will create default implementations for any services not explicitly set.
     ///
     /// # Errors
     ///
-    /// Returns an error if repository or event_store are not set and custom services
-    /// requiring them are not provided.
+    /// Returns an error if the repository, event store or unit of work are not set.
     pub fn build(self) -> Result<ServiceRegistry, String> {
         let repository = self.repository.ok_or("Repository is required")?;
         let event_store = self.event_store.ok_or("Event store is required")?;
+        let unit_of_work = self.unit_of_work.ok_or("Unit of work is required")?;
+
+        // Broadcast every appended event to live subscribers
+        let broadcast = Arc::new(BroadcastEventStore::new(event_store));
+        let events = self
+            .events
+            .unwrap_or_else(|| Arc::new(DefaultEventService::new(broadcast.clone())));
+        let unit_of_work: Arc<dyn UnitOfWork> =
+            Arc::new(BroadcastUnitOfWork::new(unit_of_work, &broadcast));
+        let event_store: Arc<dyn EventStore> = broadcast;
 
         // Create or use provided services
         let validation = self.validation.unwrap_or_else(|| {
@@ -302,13 +562,14 @@ impl ServiceRegistryBuilder {
             Arc::new(DefaultIntegrityService::new(
                 repository.clone(),
                 event_store.clone(),
+                unit_of_work.clone(),
             ))
         });
 
         let versioning = self.versioning.unwrap_or_else(|| {
             Arc::new(DefaultVersioningService::new(
                 repository.clone(),
-                event_store.clone(),
+                unit_of_work.clone(),
             ))
         });
 
@@ -316,14 +577,62 @@ impl ServiceRegistryBuilder {
             .search
             .unwrap_or_else(|| Arc::new(DefaultSearchService::new(repository.clone())));
 
+        let key_store = self
+            .key_store
+            .unwrap_or_else(|| Arc::new(InMemoryTrustedKeyStore::new()));
+
+        let signing = self.signing.unwrap_or_else(|| {
+            Arc::new(DefaultSignatureService::new(
+                repository.clone(),
+                key_store.clone(),
+            ))
+        });
+
+        let checkpoint_signer = self.checkpoint_signer;
+        let audit = self.audit.unwrap_or_else(|| {
+            let service = DefaultAuditService::new(event_store.clone(), key_store);
+            Arc::new(match checkpoint_signer {
+                Some(signer) => service.with_signer(signer),
+                None => service,
+            })
+        });
+
+        let webhook_store = self.webhook_store;
+        let webhook_allowed_hosts = self.webhook_allowed_hosts;
+        let webhooks = self.webhooks.unwrap_or_else(|| {
+            Arc::new(
+                DefaultWebhookService::new(
+                    webhook_store.unwrap_or_else(|| Arc::new(InMemoryWebhookStore::new())),
+                )
+                .with_allowed_hosts(webhook_allowed_hosts),
+            )
+        });
+
         let registration = self.registration.unwrap_or_else(|| {
-            Arc::new(DefaultRegistrationService::new(
+            Arc::new(
+                DefaultRegistrationService::new(
+                    repository.clone(),
+                    event_store.clone(),
+                    unit_of_work.clone(),
+                    validation.clone(),
+                    integrity.clone(),
+                    versioning.clone(),
+                )
+                .with_signature_service(signing.clone()),
+            )
+        });
+
+        let blob_store = self.blob_store;
+        let content = self.content.unwrap_or_else(|| {
+            let service = DefaultContentService::new(
                 repository.clone(),
                 event_store.clone(),
-                validation.clone(),
-                integrity.clone(),
-                versioning.clone(),
-            ))
+                unit_of_work.clone(),
+            );
+            Arc::new(match blob_store {
+                Some(blob_store) => service.with_blob_store(blob_store),
+                None => service,
+            })
         });
 
         Ok(ServiceRegistry {
@@ -332,6 +641,11 @@ impl ServiceRegistryBuilder {
             validation,
             integrity,
             versioning,
+            signing,
+            events,
+            audit,
+            webhooks,
+            content,
         })
     }
 }
@@ -352,4 +666,44 @@ mod tests {
         // Actual functionality would require mock implementations
         let _builder = ServiceRegistryBuilder::new();
     }
+
+    #[tokio::test]
+    async fn test_in_memory_registry_search() {
+        let services = ServiceRegistry::in_memory();
+
+        let results = services
+            .search()
+            .search_assets(SearchAssetsRequest::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results.total, Some(0));
+        assert!(results.assets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_registry_broadcasts_service_events() {
+        let services = ServiceRegistry::in_memory();
+        let mut subscription = services.events().subscribe(None).await.unwrap();
+
+        test_support::register_model(&services, "broadcast-model", "1.0.0").await;
+
+        let registered = async {
+            loop {
+                let event = subscription.next().await.unwrap().unwrap();
+                if event.event_name() == "asset_registered" {
+                    return event;
+                }
+            }
+        };
+        let event = tokio::time::timeout(std::time::Duration::from_secs(1), registered)
+            .await
+            .expect("asset_registered event was not broadcast");
+        assert!(services
+            .events()
+            .get_event(&event.event_id)
+            .await
+            .unwrap()
+            .is_some());
+    }
 }
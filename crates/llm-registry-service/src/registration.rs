@@ -5,38 +5,90 @@
 
 use async_trait::async_trait;
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, DependencyGraph, EventType, RegistryEvent,
+    Asset, AssetId, AssetMetadata, AssetReference, DependencyGraph, EventType, RegistryEvent,
 };
-use llm_registry_db::{AssetRepository, EventStore};
+use llm_registry_db::{AssetRepository, DbError, EventStore, UnitOfWork};
+use semver::Version;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, instrument, warn};
 
 use crate::dto::{
-    RegisterAssetRequest, RegisterAssetResponse, UpdateAssetRequest, UpdateAssetResponse,
-    ValidateAssetRequest, ValidationResult,
+    BatchItemError, BatchRegisterResponse, RegisterAssetRequest, RegisterAssetResponse,
+    UpdateAssetRequest, UpdateAssetResponse, ValidateAssetRequest, ValidationError,
+    ValidationResult,
 };
 use crate::error::{ServiceError, ServiceResult};
 use crate::integrity::IntegrityService;
+use crate::signing::SignatureService;
 use crate::validation::ValidationService;
 use crate::versioning::VersioningService;
 
+/// Maximum number of assets accepted in a single batch registration
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// Attempts at an update without an expected revision before a conflict is
+/// returned to the caller
+const MAX_UPDATE_ATTEMPTS: u32 = 3;
+
+/// Assets earlier in a batch, by name and version
+type PendingAssets = HashMap<(String, Version), AssetId>;
+
 /// Trait for asset registration operations
 #[async_trait]
 pub trait RegistrationService: Send + Sync {
     /// Register a new asset with full validation
-    async fn register_asset(&self, request: RegisterAssetRequest) -> ServiceResult<RegisterAssetResponse>;
+    async fn register_asset(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<RegisterAssetResponse>;
+
+    /// Register a batch of assets atomically
+    ///
+    /// Items may depend on items earlier in the batch by name and version.
+    /// If any item fails, nothing is registered and the per-item errors are
+    /// returned in the response.
+    async fn register_batch(
+        &self,
+        requests: Vec<RegisterAssetRequest>,
+    ) -> ServiceResult<BatchRegisterResponse>;
+
+    /// Check a registration request without persisting anything
+    ///
+    /// Runs the checks `register_asset` would run and reports the ones that
+    /// fail as validation errors. Policy checks emit `PolicyValidated` events
+    /// for the would-be asset, whose ID is never persisted.
+    async fn validate_registration(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<ValidationResult>;
 
     /// Update an existing asset
-    async fn update_asset(&self, request: UpdateAssetRequest) -> ServiceResult<UpdateAssetResponse>;
+    async fn update_asset(&self, request: UpdateAssetRequest)
+        -> ServiceResult<UpdateAssetResponse>;
 
     /// Delete an asset
-    async fn delete_asset(&self, asset_id: &AssetId) -> ServiceResult<()>;
+    ///
+    /// With an `expected_revision`, the deletion fails with a conflict if the
+    /// asset has been modified since that revision.
+    async fn delete_asset(
+        &self,
+        asset_id: &AssetId,
+        expected_revision: Option<u64>,
+    ) -> ServiceResult<()>;
 
     /// Validate dependencies before registration
-    async fn validate_dependencies(&self, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<ValidationResult>;
+    async fn validate_dependencies(
+        &self,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<ValidationResult>;
 
     /// Check for circular dependencies
-    async fn check_circular_dependencies(&self, asset_id: &AssetId, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<()>;
+    async fn check_circular_dependencies(
+        &self,
+        asset_id: &AssetId,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<()>;
 }
 
 /// Default implementation of RegistrationService
@@ -46,13 +98,19 @@ pub struct DefaultRegistrationService {
     validation_service: Arc<dyn ValidationService>,
     integrity_service: Arc<dyn IntegrityService>,
     versioning_service: Arc<dyn VersioningService>,
+    signature_service: Option<Arc<dyn SignatureService>>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl DefaultRegistrationService {
     /// Create a new registration service
+    ///
+    /// Assets are written through `unit_of_work` together with their events,
+    /// which must use the same storage as `repository` and `event_store`.
     pub fn new(
         repository: Arc<dyn AssetRepository>,
         event_store: Arc<dyn EventStore>,
+        unit_of_work: Arc<dyn UnitOfWork>,
         validation_service: Arc<dyn ValidationService>,
         integrity_service: Arc<dyn IntegrityService>,
         versioning_service: Arc<dyn VersioningService>,
@@ -63,9 +121,32 @@ impl DefaultRegistrationService {
             validation_service,
             integrity_service,
             versioning_service,
+            signature_service: None,
+            unit_of_work,
         }
     }
 
+    /// Verify signatures on registration against the given signature service
+    pub fn with_signature_service(mut self, signature_service: Arc<dyn SignatureService>) -> Self {
+        self.signature_service = Some(signature_service);
+        self
+    }
+
+    /// Verify a request signature against the built asset
+    async fn verify_signature(&self, asset: &Asset) -> ServiceResult<()> {
+        let Some(ref signature) = asset.signature else {
+            return Ok(());
+        };
+
+        let signature_service = self.signature_service.as_ref().ok_or_else(|| {
+            ServiceError::SignatureVerificationFailed(
+                "Signature verification is not configured".to_string(),
+            )
+        })?;
+
+        signature_service.verify(asset, signature).await
+    }
+
     /// Build asset metadata from request
     fn build_metadata(&self, request: &RegisterAssetRequest) -> ServiceResult<AssetMetadata> {
         let mut builder = AssetMetadata::builder(request.name.clone(), request.version.clone());
@@ -89,63 +170,123 @@ impl DefaultRegistrationService {
             builder = builder.content_type(ct.clone());
         }
 
-        builder.build().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid metadata: {}", e))
-        })
+        builder
+            .build()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid metadata: {}", e)))
     }
 
-    /// Emit asset registered event
-    async fn emit_registered_event(&self, asset: &Asset) {
-        let event = RegistryEvent::new(EventType::AssetRegistered {
+    /// Build the events emitted when an asset is registered
+    fn registration_events(asset: &Asset) -> Vec<RegistryEvent> {
+        let mut events: Vec<RegistryEvent> = asset
+            .dependencies
+            .iter()
+            .map(|dep| {
+                RegistryEvent::new(EventType::DependencyAdded {
+                    asset_id: asset.id,
+                    dependency_id: dep.as_id().copied(),
+                    dependency_name: dep
+                        .as_name_version()
+                        .map(|(name, version)| format!("{}@{}", name, version)),
+                })
+            })
+            .collect();
+
+        events.push(RegistryEvent::new(EventType::AssetRegistered {
             asset_id: asset.id,
             asset_name: asset.metadata.name.clone(),
             asset_version: asset.metadata.version.to_string(),
             asset_type: asset.asset_type.to_string(),
-        });
+            snapshot: Some(Box::new(asset.clone())),
+        }));
 
-        if let Err(e) = self.event_store.append(event).await {
-            warn!("Failed to emit asset registered event: {}", e);
-        }
+        events
     }
 
-    /// Emit asset updated event
-    async fn emit_updated_event(&self, asset: &Asset, updated_fields: Vec<String>) {
-        let event = RegistryEvent::new(EventType::AssetUpdated {
-            asset_id: asset.id,
-            asset_name: asset.metadata.name.clone(),
+    /// Build the event emitted when `before` is updated to `after`
+    ///
+    /// The event records the revision the update produces along with the
+    /// field-level changes, so that the asset can be rebuilt from its events.
+    fn updated_event(before: &Asset, after: &Asset, updated_fields: Vec<String>) -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetUpdated {
+            asset_id: after.id,
+            asset_name: after.metadata.name.clone(),
             updated_fields,
-        });
-
-        if let Err(e) = self.event_store.append(event).await {
-            warn!("Failed to emit asset updated event: {}", e);
-        }
+            changes: before.diff(after),
+            revision: Some(before.revision + 1),
+        })
     }
 
-    /// Emit asset deleted event
-    async fn emit_deleted_event(&self, asset: &Asset) {
-        let event = RegistryEvent::new(EventType::AssetDeleted {
+    /// Build the event emitted when an asset is deleted
+    fn deleted_event(asset: &Asset) -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
             asset_id: asset.id,
             asset_name: asset.metadata.name.clone(),
             asset_version: asset.metadata.version.to_string(),
-        });
+        })
+    }
 
-        if let Err(e) = self.event_store.append(event).await {
-            warn!("Failed to emit asset deleted event: {}", e);
+    /// Persist newly registered assets with their registration events
+    async fn persist_registered(&self, assets: Vec<Asset>) -> ServiceResult<Vec<Asset>> {
+        let events: Vec<RegistryEvent> =
+            assets.iter().flat_map(Self::registration_events).collect();
+
+        let mut tx = self.unit_of_work.begin().await?;
+        for asset in &assets {
+            tx.create_asset(asset).await?;
         }
+        tx.append_events(&events).await?;
+        tx.commit().await?;
+
+        Ok(assets)
+    }
+
+    /// Persist an asset update with its event
+    async fn persist_update(&self, asset: Asset, event: RegistryEvent) -> ServiceResult<Asset> {
+        let mut tx = self.unit_of_work.begin().await?;
+        tx.update_asset(&asset).await?;
+        tx.append_event(&event).await?;
+        tx.commit().await?;
+
+        Ok(Asset {
+            revision: asset.revision + 1,
+            ..asset
+        })
+    }
+
+    /// Persist an asset deletion with its event
+    async fn persist_delete(&self, asset: &Asset) -> ServiceResult<()> {
+        let event = Self::deleted_event(asset);
+
+        let mut tx = self.unit_of_work.begin().await?;
+        tx.delete_asset(&asset.id).await?;
+        tx.append_event(&event).await?;
+        tx.commit().await?;
+
+        Ok(())
     }
 
     /// Validate asset before registration
-    async fn validate_for_registration(&self, asset: &Asset) -> ServiceResult<Vec<String>> {
+    ///
+    /// Deep validation also checks that dependencies exist in the repository,
+    /// so it must be skipped for assets depending on unpersisted batch items.
+    async fn validate_for_registration(
+        &self,
+        asset: &Asset,
+        deep: bool,
+    ) -> ServiceResult<Vec<String>> {
         let mut warnings = Vec::new();
 
         // Validate the asset structure
         let validation_request = ValidateAssetRequest {
             asset: asset.clone(),
-            deep: true,
+            deep,
             policies: vec![],
         };
 
-        let validation_result = self.validation_service.validate_asset(validation_request).await?;
+        let validation_result = self
+            .validation_service
+            .validate_asset(validation_request)
+            .await?;
 
         if !validation_result.valid {
             return Err(ServiceError::ValidationFailed(format!(
@@ -164,7 +305,11 @@ impl DefaultRegistrationService {
 
     /// Check if asset already exists
     async fn check_duplicate(&self, name: &str, version: &semver::Version) -> ServiceResult<()> {
-        if let Some(_existing) = self.repository.find_by_name_and_version(name, version).await? {
+        if let Some(_existing) = self
+            .repository
+            .find_by_name_and_version(name, version)
+            .await?
+        {
             return Err(ServiceError::AlreadyExists {
                 name: name.to_string(),
                 version: version.to_string(),
@@ -172,24 +317,17 @@ impl DefaultRegistrationService {
         }
         Ok(())
     }
-}
-
-#[async_trait]
-impl RegistrationService for DefaultRegistrationService {
-    #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
-    async fn register_asset(&self, request: RegisterAssetRequest) -> ServiceResult<RegisterAssetResponse> {
-        info!("Registering asset: {}@{}", request.name, request.version);
-
-        // Check for duplicate
-        self.check_duplicate(&request.name, &request.version).await?;
 
+    /// Build an unpersisted asset from a registration request
+    fn build_asset(&self, request: &RegisterAssetRequest) -> ServiceResult<Asset> {
         // Build metadata
-        let metadata = self.build_metadata(&request)?;
+        let metadata = self.build_metadata(request)?;
 
         // Validate asset type
-        request.asset_type.validate().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid asset type: {}", e))
-        })?;
+        request
+            .asset_type
+            .validate()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid asset type: {}", e)))?;
 
         // Build the asset
         let mut asset_builder = Asset::builder(
@@ -203,49 +341,131 @@ impl RegistrationService for DefaultRegistrationService {
             asset_builder = asset_builder.provenance(prov);
         }
 
+        if let Some(sig) = request.signature.clone() {
+            asset_builder = asset_builder.signature(sig);
+        }
+
         asset_builder = asset_builder.dependencies(request.dependencies.clone());
 
-        let asset = asset_builder.build().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Failed to build asset: {}", e))
-        })?;
+        asset_builder
+            .build()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Failed to build asset: {}", e)))
+    }
+
+    /// Resolve dependency references to asset IDs
+    ///
+    /// Name/version references must name an exact version and are looked up
+    /// first among `pending` batch items, then in the repository.
+    async fn resolve_dependencies(
+        &self,
+        dependencies: &[AssetReference],
+        pending: &PendingAssets,
+    ) -> ServiceResult<Vec<AssetReference>> {
+        let mut resolved = Vec::with_capacity(dependencies.len());
+
+        for dep in dependencies {
+            let Some((name, version)) = dep.as_name_version() else {
+                resolved.push(dep.clone());
+                continue;
+            };
+
+            let version = Version::parse(version).map_err(|e| {
+                ServiceError::ValidationFailed(format!(
+                    "Dependency {}@{} must reference an exact version: {}",
+                    name, version, e
+                ))
+            })?;
+
+            let key = (name.to_string(), version);
+            let dep_id = match pending.get(&key) {
+                Some(id) => *id,
+                None => self
+                    .repository
+                    .find_by_name_and_version(name, &key.1)
+                    .await?
+                    .map(|asset| asset.id)
+                    .ok_or_else(|| {
+                        ServiceError::DependencyNotFound(format!("{}@{}", name, key.1))
+                    })?,
+            };
+
+            resolved.push(AssetReference::by_id(dep_id));
+        }
+
+        Ok(resolved)
+    }
+
+    /// Build and validate one batch item without persisting it
+    async fn prepare_batch_item(
+        &self,
+        request: &RegisterAssetRequest,
+        pending: &PendingAssets,
+    ) -> ServiceResult<(Asset, Vec<String>)> {
+        if pending.contains_key(&(request.name.clone(), request.version.clone())) {
+            return Err(ServiceError::AlreadyExists {
+                name: request.name.clone(),
+                version: request.version.to_string(),
+            });
+        }
+        self.check_duplicate(&request.name, &request.version)
+            .await?;
+
+        let mut asset = self.build_asset(request)?;
+        asset.dependencies = self
+            .resolve_dependencies(&asset.dependencies, pending)
+            .await?;
+
+        // Batch items can only depend on existing assets or earlier items, so
+        // they cannot introduce a cycle.
+        for dep_id in asset.dependencies.iter().filter_map(|d| d.as_id()) {
+            if !pending.values().any(|id| id == dep_id)
+                && self.repository.find_by_id(dep_id).await?.is_none()
+            {
+                return Err(ServiceError::DependencyNotFound(dep_id.to_string()));
+            }
+        }
+
+        self.verify_signature(&asset).await?;
+        let warnings = self.validate_for_registration(&asset, false).await?;
+
+        Ok((asset, warnings))
+    }
+}
+
+#[async_trait]
+impl RegistrationService for DefaultRegistrationService {
+    #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
+    async fn register_asset(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<RegisterAssetResponse> {
+        info!("Registering asset: {}@{}", request.name, request.version);
+
+        // Check for duplicate
+        self.check_duplicate(&request.name, &request.version)
+            .await?;
+
+        // Build the asset and resolve name/version dependencies
+        let mut asset = self.build_asset(&request)?;
+        asset.dependencies = self
+            .resolve_dependencies(&asset.dependencies, &PendingAssets::new())
+            .await?;
+
+        // Verify signature
+        self.verify_signature(&asset).await?;
 
         // Validate dependencies
         if !asset.dependencies.is_empty() {
             self.validate_dependencies(&asset.dependencies).await?;
-            self.check_circular_dependencies(&asset.id, &asset.dependencies).await?;
+            self.check_circular_dependencies(&asset.id, &asset.dependencies)
+                .await?;
         }
 
         // Full validation
-        let warnings = self.validate_for_registration(&asset).await?;
-
-        // Persist the asset
-        let created = self.repository.create(asset).await?;
-
-        // Emit dependencies added events
-        for dep in &created.dependencies {
-            if let Some(dep_id) = dep.as_id() {
-                let event = RegistryEvent::new(EventType::DependencyAdded {
-                    asset_id: created.id,
-                    dependency_id: Some(*dep_id),
-                    dependency_name: None,
-                });
-                if let Err(e) = self.event_store.append(event).await {
-                    warn!("Failed to emit dependency added event: {}", e);
-                }
-            } else if let Some((name, version)) = dep.as_name_version() {
-                let event = RegistryEvent::new(EventType::DependencyAdded {
-                    asset_id: created.id,
-                    dependency_id: None,
-                    dependency_name: Some(format!("{}@{}", name, version)),
-                });
-                if let Err(e) = self.event_store.append(event).await {
-                    warn!("Failed to emit dependency added event: {}", e);
-                }
-            }
-        }
+        let warnings = self.validate_for_registration(&asset, true).await?;
 
-        // Emit registration event
-        self.emit_registered_event(&created).await;
+        // Persist the asset with its dependency and registration events
+        let created = self.persist_registered(vec![asset]).await?.swap_remove(0);
 
         info!("Asset registered successfully: {}", created.id);
 
@@ -255,85 +475,175 @@ impl RegistrationService for DefaultRegistrationService {
         })
     }
 
-    #[instrument(skip(self, request), fields(asset_id = %request.asset_id))]
-    async fn update_asset(&self, request: UpdateAssetRequest) -> ServiceResult<UpdateAssetResponse> {
-        debug!("Updating asset: {}", request.asset_id);
-
-        // Fetch existing asset
-        let mut asset = self
-            .repository
-            .find_by_id(&request.asset_id)
-            .await?
-            .ok_or_else(|| ServiceError::NotFound(request.asset_id.to_string()))?;
-
-        let mut updated_fields = Vec::new();
+    #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
+    async fn validate_registration(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<ValidationResult> {
+        debug!("Dry-run registration: {}@{}", request.name, request.version);
 
-        // Update description
-        if let Some(desc) = request.description {
-            asset.metadata.description = Some(desc);
-            updated_fields.push("description".to_string());
-        }
+        let mut errors = Vec::new();
 
-        // Update license
-        if let Some(license) = request.license {
-            asset.metadata.license = Some(license);
-            updated_fields.push("license".to_string());
+        if let Err(e) = self.check_duplicate(&request.name, &request.version).await {
+            errors.push(rejection("version", e)?);
         }
 
-        // Add tags
-        for tag in request.add_tags {
-            if !asset.metadata.tags.contains(&tag) {
-                asset.metadata.add_tag(tag);
-                updated_fields.push("tags".to_string());
+        let mut asset = match self.build_asset(&request) {
+            Ok(asset) => asset,
+            Err(e) => {
+                errors.push(rejection("asset", e)?);
+                return Ok(ValidationResult {
+                    valid: false,
+                    errors,
+                    warnings: vec![],
+                });
             }
+        };
+
+        match self
+            .resolve_dependencies(&asset.dependencies, &PendingAssets::new())
+            .await
+        {
+            Ok(resolved) => asset.dependencies = resolved,
+            Err(e) => errors.push(rejection("dependencies", e)?),
         }
 
-        // Remove tags
-        for tag in request.remove_tags {
-            asset.metadata.tags.retain(|t| t != &tag);
-            updated_fields.push("tags".to_string());
+        if let Err(e) = self.verify_signature(&asset).await {
+            errors.push(rejection("signature", e)?);
         }
 
-        // Add/update annotations
-        for (key, value) in request.add_annotations {
-            asset.metadata.add_annotation(key, value);
-            updated_fields.push("annotations".to_string());
+        // A new asset cannot close a dependency cycle, so the full
+        // validation is the last check `register_asset` would run.
+        let validation = self
+            .validation_service
+            .validate_asset(ValidateAssetRequest {
+                asset,
+                deep: true,
+                policies: vec![],
+            })
+            .await?;
+        errors.extend(validation.errors);
+
+        Ok(ValidationResult {
+            valid: errors.is_empty(),
+            errors,
+            warnings: validation.warnings,
+        })
+    }
+
+    #[instrument(skip(self, requests), fields(count = requests.len()))]
+    async fn register_batch(
+        &self,
+        requests: Vec<RegisterAssetRequest>,
+    ) -> ServiceResult<BatchRegisterResponse> {
+        info!("Registering batch of {} assets", requests.len());
+
+        if requests.len() > MAX_BATCH_SIZE {
+            return Err(ServiceError::InvalidInput(format!(
+                "Batch contains {} assets, maximum is {}",
+                requests.len(),
+                MAX_BATCH_SIZE
+            )));
         }
 
-        // Remove annotations
-        for key in request.remove_annotations {
-            asset.metadata.annotations.remove(&key);
-            updated_fields.push("annotations".to_string());
+        let mut pending = PendingAssets::new();
+        let mut assets = Vec::with_capacity(requests.len());
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (index, request) in requests.iter().enumerate() {
+            match self.prepare_batch_item(request, &pending).await {
+                Ok((asset, item_warnings)) => {
+                    pending.insert((request.name.clone(), request.version.clone()), asset.id);
+                    warnings.extend(
+                        item_warnings
+                            .into_iter()
+                            .map(|w| format!("item {}: {}", index, w)),
+                    );
+                    assets.push(asset);
+                }
+                Err(e) => errors.push(BatchItemError {
+                    index,
+                    name: request.name.clone(),
+                    version: request.version.to_string(),
+                    message: e.to_string(),
+                }),
+            }
         }
 
-        // Update status
-        if let Some(status) = request.status {
-            asset.set_status(status);
-            updated_fields.push("status".to_string());
+        if !errors.is_empty() {
+            warn!("Rejecting batch with {} failed items", errors.len());
+            return Ok(BatchRegisterResponse {
+                assets: Vec::new(),
+                errors,
+                warnings,
+            });
         }
 
-        // Update timestamp
-        asset.updated_at = chrono::Utc::now();
+        // Persist all assets and their events in a single transaction
+        let created = self.persist_registered(assets).await?;
 
-        // Validate updated asset
-        asset.validate().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Updated asset is invalid: {}", e))
-        })?;
+        info!("Batch registered successfully: {} assets", created.len());
 
-        // Persist the update
-        let updated = self.repository.update(asset).await?;
+        Ok(BatchRegisterResponse {
+            assets: created,
+            errors: Vec::new(),
+            warnings,
+        })
+    }
 
-        // Emit update event
-        self.emit_updated_event(&updated, updated_fields.clone()).await;
+    #[instrument(skip(self, request), fields(asset_id = %request.asset_id))]
+    async fn update_asset(
+        &self,
+        request: UpdateAssetRequest,
+    ) -> ServiceResult<UpdateAssetResponse> {
+        debug!("Updating asset: {}", request.asset_id);
 
-        Ok(UpdateAssetResponse {
-            asset: updated,
-            updated_fields,
-        })
+        let mut attempt = 1;
+        loop {
+            // Fetch existing asset
+            let mut asset = self
+                .repository
+                .find_by_id(&request.asset_id)
+                .await?
+                .ok_or_else(|| ServiceError::NotFound(request.asset_id.to_string()))?;
+            check_revision(&asset, request.expected_revision)?;
+
+            let before = asset.clone();
+            let updated_fields = apply_update(&mut asset, &request);
+
+            // Validate updated asset
+            asset.validate().map_err(|e| {
+                ServiceError::ValidationFailed(format!("Updated asset is invalid: {}", e))
+            })?;
+
+            // Persist the update with its event
+            let event = Self::updated_event(&before, &asset, updated_fields.clone());
+            match self.persist_update(asset, event).await {
+                // Without an expected revision the caller asked for the patch to
+                // be applied, so reapply it on top of the concurrent update
+                Err(ServiceError::Conflict(msg))
+                    if request.expected_revision.is_none() && attempt < MAX_UPDATE_ATTEMPTS =>
+                {
+                    debug!(attempt, "Retrying update after conflict: {}", msg);
+                    attempt += 1;
+                }
+                result => {
+                    return Ok(UpdateAssetResponse {
+                        asset: result?,
+                        updated_fields,
+                    })
+                }
+            }
+        }
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn delete_asset(&self, asset_id: &AssetId) -> ServiceResult<()> {
+    async fn delete_asset(
+        &self,
+        asset_id: &AssetId,
+        expected_revision: Option<u64>,
+    ) -> ServiceResult<()> {
         debug!("Deleting asset: {}", asset_id);
 
         // Fetch the asset first for event emission
@@ -342,6 +652,7 @@ impl RegistrationService for DefaultRegistrationService {
             .find_by_id(asset_id)
             .await?
             .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+        check_revision(&asset, expected_revision)?;
 
         // Check if any assets depend on this one
         let dependents = self.repository.list_reverse_dependencies(asset_id).await?;
@@ -352,11 +663,8 @@ impl RegistrationService for DefaultRegistrationService {
             )));
         }
 
-        // Delete from repository
-        self.repository.delete(asset_id).await?;
-
-        // Emit deletion event
-        self.emit_deleted_event(&asset).await;
+        // Delete from repository with the deletion event
+        self.persist_delete(&asset).await?;
 
         info!("Asset deleted successfully: {}", asset_id);
 
@@ -364,7 +672,10 @@ impl RegistrationService for DefaultRegistrationService {
     }
 
     #[instrument(skip(self, dependencies), fields(dep_count = dependencies.len()))]
-    async fn validate_dependencies(&self, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<ValidationResult> {
+    async fn validate_dependencies(
+        &self,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating dependencies");
 
         let mut errors = Vec::new();
@@ -435,7 +746,10 @@ impl RegistrationService for DefaultRegistrationService {
                     graph
                         .add_dependencies(*dep_id, dep_asset.dependencies.clone())
                         .map_err(|e| {
-                            ServiceError::Internal(format!("Failed to add dependencies to graph: {}", e))
+                            ServiceError::Internal(format!(
+                                "Failed to add dependencies to graph: {}",
+                                e
+                            ))
                         })?;
                 }
             }
@@ -466,11 +780,101 @@ impl RegistrationService for DefaultRegistrationService {
     }
 }
 
-// TODO: Complete mock implementations for unit tests
-#[cfg(all(test, feature = "incomplete_tests"))]
+/// Report a failed registration check as a validation error
+///
+/// Errors that say nothing about the request, such as database failures,
+/// are returned as they are.
+fn rejection(field: &str, error: ServiceError) -> ServiceResult<ValidationError> {
+    let code = match error {
+        ServiceError::AlreadyExists { .. } => "ALREADY_EXISTS",
+        ServiceError::ValidationFailed(_) => "VALIDATION_FAILED",
+        ServiceError::InvalidInput(_) => "INVALID_INPUT",
+        ServiceError::DependencyNotFound(_) => "DEPENDENCY_NOT_FOUND",
+        ServiceError::SignatureVerificationFailed(_) => "SIGNATURE_INVALID",
+        _ => return Err(error),
+    };
+
+    Ok(ValidationError {
+        field: field.to_string(),
+        message: error.to_string(),
+        code: Some(code.to_string()),
+    })
+}
+
+/// Fail with a conflict if the asset is not at the expected revision
+fn check_revision(asset: &Asset, expected_revision: Option<u64>) -> ServiceResult<()> {
+    match expected_revision {
+        Some(expected) if expected != asset.revision => {
+            Err(DbError::revision_conflict(asset.id, expected, asset.revision).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Apply an update request to an asset, returning the updated field names
+fn apply_update(asset: &mut Asset, request: &UpdateAssetRequest) -> Vec<String> {
+    let mut updated_fields = Vec::new();
+
+    // Update description
+    if let Some(desc) = &request.description {
+        asset.metadata.description = Some(desc.clone());
+        updated_fields.push("description".to_string());
+    }
+
+    // Update license
+    if let Some(license) = &request.license {
+        asset.metadata.license = Some(license.clone());
+        updated_fields.push("license".to_string());
+    }
+
+    // Add tags
+    for tag in &request.add_tags {
+        if !asset.metadata.tags.contains(tag) {
+            asset.metadata.add_tag(tag.clone());
+            updated_fields.push("tags".to_string());
+        }
+    }
+
+    // Remove tags
+    for tag in &request.remove_tags {
+        asset.metadata.tags.retain(|t| t != tag);
+        updated_fields.push("tags".to_string());
+    }
+
+    // Add/update annotations
+    for (key, value) in &request.add_annotations {
+        asset.metadata.add_annotation(key.clone(), value.clone());
+        updated_fields.push("annotations".to_string());
+    }
+
+    // Remove annotations
+    for key in &request.remove_annotations {
+        asset.metadata.annotations.remove(key);
+        updated_fields.push("annotations".to_string());
+    }
+
+    // Update status
+    if let Some(status) = request.status {
+        asset.set_status(status);
+        updated_fields.push("status".to_string());
+    }
+
+    // Update timestamp
+    asset.updated_at = chrono::Utc::now();
+
+    updated_fields
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
-    use llm_registry_core::{AssetType, Checksum, HashAlgorithm, StorageBackend, StorageLocation};
+    use crate::integrity::DefaultIntegrityService;
+    use crate::validation::DefaultValidationService;
+    use crate::versioning::DefaultVersioningService;
+    use llm_registry_core::{
+        AssetReference, AssetType, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+    };
+    use llm_registry_db::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
     use semver::Version;
 
     fn create_test_request() -> RegisterAssetRequest {
@@ -497,18 +901,39 @@ mod tests {
             dependencies: vec![],
             size_bytes: Some(1024),
             content_type: Some("application/octet-stream".to_string()),
+            signature: None,
         }
     }
 
+    fn create_service() -> (DefaultRegistrationService, Arc<InMemoryEventStore>) {
+        let memory = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let unit_of_work = Arc::new(InMemoryUnitOfWork::new(memory.clone(), event_store.clone()));
+        let repository: Arc<dyn AssetRepository> = memory;
+        let events: Arc<dyn EventStore> = event_store.clone();
+
+        let service = DefaultRegistrationService::new(
+            repository.clone(),
+            events.clone(),
+            unit_of_work.clone(),
+            Arc::new(DefaultValidationService::new(
+                repository.clone(),
+                events.clone(),
+            )),
+            Arc::new(DefaultIntegrityService::new(
+                repository.clone(),
+                events,
+                unit_of_work.clone(),
+            )),
+            Arc::new(DefaultVersioningService::new(repository, unit_of_work)),
+        );
+
+        (service, event_store)
+    }
+
     #[test]
     fn test_build_metadata() {
-        let service = DefaultRegistrationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
-            validation_service: Arc::new(MockValidationService),
-            integrity_service: Arc::new(MockIntegrityService),
-            versioning_service: Arc::new(MockVersioningService),
-        };
+        let (service, _) = create_service();
 
         let request = create_test_request();
         let metadata = service.build_metadata(&request).unwrap();
@@ -519,69 +944,298 @@ mod tests {
         assert_eq!(metadata.license.as_deref(), Some("MIT"));
     }
 
-    // Mock implementations
-    struct MockRepository;
-    struct MockEventStore;
-    struct MockValidationService;
-    struct MockIntegrityService;
-    struct MockVersioningService;
+    #[tokio::test]
+    async fn test_register_asset_persists_and_emits_event() {
+        let (service, event_store) = create_service();
 
-    #[async_trait]
-    impl llm_registry_db::AssetRepository for MockRepository {
-        async fn save(&self, _asset: &Asset) -> DbResult<()> {
-            Ok(())
-        }
-        async fn find_by_id(&self, _id: &str) -> DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn find_by_name_and_version(&self, _name: &str, _version: &Version) -> DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn list(&self, _offset: i64, _limit: i64) -> DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn delete(&self, _id: &str) -> DbResult<()> {
-            Ok(())
-        }
-        async fn update(&self, _asset: &Asset) -> DbResult<()> {
-            Ok(())
-        }
+        let response = service.register_asset(create_test_request()).await.unwrap();
+        assert_eq!(response.asset.metadata.tags, vec!["test"]);
+
+        assert_eq!(
+            event_store.count_by_type("asset_registered").await.unwrap(),
+            1
+        );
+
+        let err = service
+            .register_asset(create_test_request())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::AlreadyExists { .. }));
     }
 
-    #[async_trait]
-    impl llm_registry_db::EventStore for MockEventStore {
-        async fn append(&self, _event: &Event) -> DbResult<()> {
-            Ok(())
-        }
-        async fn get_by_asset_id(&self, _asset_id: &str, _offset: i64, _limit: i64) -> DbResult<Vec<Event>> {
-            Ok(vec![])
-        }
-        async fn get_by_type(&self, _event_type: &str, _offset: i64, _limit: i64) -> DbResult<Vec<Event>> {
-            Ok(vec![])
-        }
+    #[tokio::test]
+    async fn test_register_asset_with_dependency() {
+        let (service, event_store) = create_service();
+
+        let base = service
+            .register_asset(create_test_request())
+            .await
+            .unwrap()
+            .asset;
+
+        let mut request = create_test_request();
+        request.name = "pipeline".to_string();
+        request.dependencies = vec![AssetReference::by_id(base.id)];
+        let pipeline = service.register_asset(request).await.unwrap().asset;
+
+        assert_eq!(pipeline.dependencies.len(), 1);
+        assert_eq!(
+            event_store.count_by_type("dependency_added").await.unwrap(),
+            1
+        );
     }
 
-    #[async_trait]
-    impl ValidationService for MockValidationService {
-        async fn validate_registration(&self, _request: &RegisterAssetRequest) -> ServiceResult<()> {
-            Ok(())
-        }
+    #[tokio::test]
+    async fn test_register_signed_asset_without_signature_service() {
+        let (service, _) = create_service();
+
+        let mut request = create_test_request();
+        request.signature =
+            Some(llm_registry_core::AssetSignature::ed25519("key-1", "c2ln").unwrap());
+
+        let err = service.register_asset(request).await.unwrap_err();
+        assert!(matches!(err, ServiceError::SignatureVerificationFailed(_)));
     }
 
-    #[async_trait]
-    impl IntegrityService for MockIntegrityService {
-        async fn verify_checksum(&self, _asset: &Asset) -> ServiceResult<bool> {
-            Ok(true)
-        }
+    #[tokio::test]
+    async fn test_validate_registration_persists_nothing() {
+        let (service, event_store) = create_service();
+
+        let result = service
+            .validate_registration(create_test_request())
+            .await
+            .unwrap();
+        assert!(result.valid, "{:?}", result.errors);
+        assert_eq!(
+            event_store.count_by_type("asset_registered").await.unwrap(),
+            0
+        );
+        assert_eq!(
+            event_store.count_by_type("policy_validated").await.unwrap(),
+            3
+        );
+
+        // Dry runs do not register, so the same request still passes
+        let result = service
+            .validate_registration(create_test_request())
+            .await
+            .unwrap();
+        assert!(result.valid);
+
+        service.register_asset(create_test_request()).await.unwrap();
+        let mut request = create_test_request();
+        request.dependencies = vec![AssetReference::by_name_version("missing", "1.0.0").unwrap()];
+        request.signature =
+            Some(llm_registry_core::AssetSignature::ed25519("key-1", "c2ln").unwrap());
+
+        let result = service.validate_registration(request).await.unwrap();
+        assert!(!result.valid);
+        let codes: Vec<_> = result
+            .errors
+            .iter()
+            .filter_map(|e| e.code.as_deref())
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                "ALREADY_EXISTS",
+                "DEPENDENCY_NOT_FOUND",
+                "SIGNATURE_INVALID"
+            ]
+        );
     }
 
-    #[async_trait]
-    impl VersioningService for MockVersioningService {
-        async fn validate_version(&self, _name: &str, _version: &Version) -> ServiceResult<()> {
-            Ok(())
-        }
-        async fn get_latest_version(&self, _name: &str) -> ServiceResult<Option<Version>> {
-            Ok(None)
+    #[tokio::test]
+    async fn test_register_asset_resolves_name_version_dependency() {
+        let (service, _) = create_service();
+
+        let base = service
+            .register_asset(create_test_request())
+            .await
+            .unwrap()
+            .asset;
+
+        let mut request = create_test_request();
+        request.name = "pipeline".to_string();
+        request.dependencies =
+            vec![AssetReference::by_name_version("test-model", "1.0.0").unwrap()];
+        let pipeline = service.register_asset(request).await.unwrap().asset;
+
+        assert_eq!(pipeline.dependencies, vec![AssetReference::by_id(base.id)]);
+    }
+
+    #[tokio::test]
+    async fn test_register_batch_resolves_in_batch_dependencies() {
+        let (service, event_store) = create_service();
+
+        let mut checkpoint = create_test_request();
+        checkpoint.name = "checkpoint".to_string();
+        checkpoint.dependencies =
+            vec![AssetReference::by_name_version("test-model", "1.0.0").unwrap()];
+
+        let response = service
+            .register_batch(vec![create_test_request(), checkpoint])
+            .await
+            .unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.assets.len(), 2);
+        assert_eq!(
+            response.assets[1].dependencies,
+            vec![AssetReference::by_id(response.assets[0].id)]
+        );
+        assert_eq!(
+            event_store.count_by_type("asset_registered").await.unwrap(),
+            2
+        );
+        assert_eq!(
+            event_store.count_by_type("dependency_added").await.unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_batch_is_all_or_nothing() {
+        let (service, event_store) = create_service();
+
+        let mut orphan = create_test_request();
+        orphan.name = "orphan".to_string();
+        orphan.dependencies = vec![AssetReference::by_name_version("missing", "1.0.0").unwrap()];
+
+        let response = service
+            .register_batch(vec![create_test_request(), orphan, create_test_request()])
+            .await
+            .unwrap();
+
+        assert!(!response.is_success());
+        assert!(response.assets.is_empty());
+        let failed: Vec<usize> = response.errors.iter().map(|e| e.index).collect();
+        assert_eq!(failed, vec![1, 2]);
+        assert!(response.errors[0].message.contains("missing@1.0.0"));
+
+        // Nothing from the batch was persisted
+        assert!(service.register_asset(create_test_request()).await.is_ok());
+        assert_eq!(
+            event_store.count_by_type("asset_registered").await.unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_check_expected_revision() {
+        let (service, _) = create_service();
+        let asset = service
+            .register_asset(create_test_request())
+            .await
+            .unwrap()
+            .asset;
+        assert_eq!(asset.revision, 1);
+
+        let tag_update = |tag: &str, expected_revision| UpdateAssetRequest {
+            asset_id: asset.id,
+            description: None,
+            license: None,
+            add_tags: vec![tag.to_string()],
+            remove_tags: vec![],
+            add_annotations: Default::default(),
+            remove_annotations: vec![],
+            status: None,
+            expected_revision,
+        };
+
+        let updated = service
+            .update_asset(tag_update("first", Some(1)))
+            .await
+            .unwrap();
+        assert_eq!(updated.asset.revision, 2);
+
+        // A writer still holding revision 1 is rejected
+        let err = service
+            .update_asset(tag_update("stale", Some(1)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::Conflict(_)));
+
+        // Without an expected revision the patch applies to the latest revision
+        let updated = service
+            .update_asset(tag_update("second", None))
+            .await
+            .unwrap();
+        assert_eq!(updated.asset.revision, 3);
+        assert!(updated.asset.metadata.tags.contains(&"first".to_string()));
+        assert!(updated.asset.metadata.tags.contains(&"second".to_string()));
+
+        let err = service.delete_asset(&asset.id, Some(2)).await.unwrap_err();
+        assert!(matches!(err, ServiceError::Conflict(_)));
+        service.delete_asset(&asset.id, Some(3)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commits_writes_with_events() {
+        use llm_registry_db::{
+            create_sqlite_pool, OutboxStore, PoolConfig, SqliteAssetRepository, SqliteEventStore,
+            SqliteUnitOfWork,
+        };
+
+        let pool = create_sqlite_pool(&PoolConfig::new("sqlite::memory:"))
+            .await
+            .unwrap();
+        let repository: Arc<dyn AssetRepository> =
+            Arc::new(SqliteAssetRepository::new(pool.clone()));
+        let event_store = Arc::new(SqliteEventStore::new(pool.clone()));
+        let events: Arc<dyn EventStore> = event_store.clone();
+        let unit_of_work: Arc<dyn UnitOfWork> = Arc::new(SqliteUnitOfWork::new(pool));
+
+        let service = DefaultRegistrationService::new(
+            repository.clone(),
+            events.clone(),
+            unit_of_work.clone(),
+            Arc::new(DefaultValidationService::new(
+                repository.clone(),
+                events.clone(),
+            )),
+            Arc::new(DefaultIntegrityService::new(
+                repository.clone(),
+                events,
+                unit_of_work.clone(),
+            )),
+            Arc::new(DefaultVersioningService::new(
+                repository.clone(),
+                unit_of_work,
+            )),
+        );
+
+        let asset = service
+            .register_asset(create_test_request())
+            .await
+            .unwrap()
+            .asset;
+        service
+            .update_asset(UpdateAssetRequest {
+                asset_id: asset.id,
+                description: Some("Updated".to_string()),
+                license: None,
+                add_tags: vec![],
+                remove_tags: vec![],
+                add_annotations: Default::default(),
+                remove_annotations: vec![],
+                status: None,
+                expected_revision: Some(asset.revision),
+            })
+            .await
+            .unwrap();
+        service.delete_asset(&asset.id, None).await.unwrap();
+
+        assert!(repository.find_by_id(&asset.id).await.unwrap().is_none());
+        for event_type in ["asset_registered", "asset_updated", "asset_deleted"] {
+            assert_eq!(event_store.count_by_type(event_type).await.unwrap(), 1);
         }
+        // Every committed event is queued for the outbox relay
+        let total = event_store.count_events().await.unwrap();
+        assert_eq!(event_store.count_pending().await.unwrap(), total);
+
+        // A failed write records no event
+        assert!(service.delete_asset(&asset.id, None).await.is_err());
+        assert_eq!(event_store.count_events().await.unwrap(), total);
     }
 }
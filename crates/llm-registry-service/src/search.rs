@@ -5,14 +5,17 @@
 
 use async_trait::async_trait;
 use llm_registry_core::{Asset, AssetId, AssetType};
-use llm_registry_db::{AssetRepository, SearchQuery, SortField as DbSortField, SortOrder as DbSortOrder};
+use llm_registry_db::{
+    AssetRepository, Cursor, DependencyLink, SearchQuery, SortField as DbSortField,
+    SortOrder as DbSortOrder,
+};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, instrument};
 
 use crate::dto::{
     DependencyGraphResponse, DependencyNode, GetDependencyGraphRequest, SearchAssetsRequest,
-    SearchAssetsResponse, SortField, SortOrder,
+    SearchAssetsResponse, SearchFacets, SortField, SortOrder,
 };
 use crate::error::{ServiceError, ServiceResult};
 
@@ -20,16 +23,29 @@ use crate::error::{ServiceError, ServiceResult};
 #[async_trait]
 pub trait SearchService: Send + Sync {
     /// Search for assets with filters
-    async fn search_assets(&self, request: SearchAssetsRequest) -> ServiceResult<SearchAssetsResponse>;
+    ///
+    /// A structured query string in the request is compiled into its
+    /// filters with [`SearchAssetsRequest::resolve_query`].
+    async fn search_assets(
+        &self,
+        request: SearchAssetsRequest,
+    ) -> ServiceResult<SearchAssetsResponse>;
 
     /// Get asset by ID
     async fn get_asset(&self, asset_id: &AssetId) -> ServiceResult<Option<Asset>>;
 
     /// Get asset by name and version
-    async fn get_asset_by_name_version(&self, name: &str, version: &str) -> ServiceResult<Option<Asset>>;
+    async fn get_asset_by_name_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> ServiceResult<Option<Asset>>;
 
     /// Get dependency graph for an asset
-    async fn get_dependency_graph(&self, request: GetDependencyGraphRequest) -> ServiceResult<DependencyGraphResponse>;
+    async fn get_dependency_graph(
+        &self,
+        request: GetDependencyGraphRequest,
+    ) -> ServiceResult<DependencyGraphResponse>;
 
     /// Get all tags in the registry
     async fn list_all_tags(&self) -> ServiceResult<Vec<String>>;
@@ -42,6 +58,45 @@ pub trait SearchService: Send + Sync {
 
     /// Get reverse dependencies (assets that depend on this asset)
     async fn get_reverse_dependencies(&self, asset_id: &AssetId) -> ServiceResult<Vec<Asset>>;
+
+    /// Get several assets by ID in one lookup
+    ///
+    /// Missing IDs are skipped; the order of the result is unspecified.
+    async fn get_assets(&self, asset_ids: &[AssetId]) -> ServiceResult<Vec<Asset>>;
+
+    /// Get all versions of several assets by name in one lookup
+    async fn get_assets_by_names(&self, names: &[String]) -> ServiceResult<Vec<Asset>>;
+
+    /// Get the direct dependency links of several assets in one lookup
+    async fn get_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>>;
+
+    /// Get the links of assets directly depending on several assets in one lookup
+    async fn get_reverse_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>>;
+
+    /// Suggest names of non-deprecated assets starting with a prefix
+    ///
+    /// Requires a search index; the default implementation is unsupported.
+    async fn autocomplete(&self, _prefix: &str, _limit: usize) -> ServiceResult<Vec<String>> {
+        Err(ServiceError::Unsupported(
+            "Autocomplete requires the search index".to_string(),
+        ))
+    }
+
+    /// Count the assets matching a search by type, license, author, tag and storage backend
+    ///
+    /// Pagination and sorting in the request are ignored. Requires a search
+    /// index; the default implementation is unsupported.
+    async fn facets(&self, _request: SearchAssetsRequest) -> ServiceResult<SearchFacets> {
+        Err(ServiceError::Unsupported(
+            "Faceted search requires the search index".to_string(),
+        ))
+    }
 }
 
 /// Default implementation of SearchService
@@ -57,21 +112,12 @@ impl DefaultSearchService {
 
     /// Convert DTO sort field to DB sort field
     fn convert_sort_field(&self, field: SortField) -> DbSortField {
-        match field {
-            SortField::CreatedAt => DbSortField::CreatedAt,
-            SortField::UpdatedAt => DbSortField::UpdatedAt,
-            SortField::Name => DbSortField::Name,
-            SortField::Version => DbSortField::Version,
-            SortField::SizeBytes => DbSortField::SizeBytes,
-        }
+        field.into()
     }
 
     /// Convert DTO sort order to DB sort order
     fn convert_sort_order(&self, order: SortOrder) -> DbSortOrder {
-        match order {
-            SortOrder::Ascending => DbSortOrder::Ascending,
-            SortOrder::Descending => DbSortOrder::Descending,
-        }
+        order.into()
     }
 
     /// Build dependency graph recursively
@@ -84,50 +130,50 @@ impl DefaultSearchService {
         nodes: &'a mut HashMap<AssetId, DependencyNode>,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ServiceResult<()>> + 'a + Send>> {
         Box::pin(async move {
-        // Check depth limit
-        if max_depth >= 0 && current_depth >= max_depth {
-            return Ok(());
-        }
-
-        // Avoid cycles
-        if visited.contains(asset_id) {
-            return Ok(());
-        }
-        visited.insert(*asset_id);
-
-        // Get the asset
-        let asset = match self.repository.find_by_id(asset_id).await? {
-            Some(a) => a,
-            None => return Ok(()), // Skip if asset not found
-        };
-
-        // Get dependencies
-        let deps = self.repository.list_dependencies(asset_id).await?;
-        let dep_ids: Vec<AssetId> = deps.iter().map(|d| d.id).collect();
-
-        // Create node
-        let node = DependencyNode {
-            asset_id: *asset_id,
-            name: asset.metadata.name.clone(),
-            version: asset.metadata.version.clone(),
-            depth: current_depth,
-            dependencies: dep_ids.clone(),
-        };
-        nodes.insert(*asset_id, node);
-
-        // Recursively process dependencies
-        for dep in deps {
-            self.build_dependency_graph_recursive(
-                &dep.id,
-                max_depth,
-                current_depth + 1,
-                visited,
-                nodes,
-            )
-            .await?;
-        }
-
-        Ok(())
+            // Check depth limit
+            if max_depth >= 0 && current_depth >= max_depth {
+                return Ok(());
+            }
+
+            // Avoid cycles
+            if visited.contains(asset_id) {
+                return Ok(());
+            }
+            visited.insert(*asset_id);
+
+            // Get the asset
+            let asset = match self.repository.find_by_id(asset_id).await? {
+                Some(a) => a,
+                None => return Ok(()), // Skip if asset not found
+            };
+
+            // Get dependencies
+            let deps = self.repository.list_dependencies(asset_id).await?;
+            let dep_ids: Vec<AssetId> = deps.iter().map(|d| d.id).collect();
+
+            // Create node
+            let node = DependencyNode {
+                asset_id: *asset_id,
+                name: asset.metadata.name.clone(),
+                version: asset.metadata.version.clone(),
+                depth: current_depth,
+                dependencies: dep_ids.clone(),
+            };
+            nodes.insert(*asset_id, node);
+
+            // Recursively process dependencies
+            for dep in deps {
+                self.build_dependency_graph_recursive(
+                    &dep.id,
+                    max_depth,
+                    current_depth + 1,
+                    visited,
+                    nodes,
+                )
+                .await?;
+            }
+
+            Ok(())
         })
     }
 }
@@ -135,17 +181,27 @@ impl DefaultSearchService {
 #[async_trait]
 impl SearchService for DefaultSearchService {
     #[instrument(skip(self, request))]
-    async fn search_assets(&self, request: SearchAssetsRequest) -> ServiceResult<SearchAssetsResponse> {
+    async fn search_assets(
+        &self,
+        request: SearchAssetsRequest,
+    ) -> ServiceResult<SearchAssetsResponse> {
         debug!("Searching assets with query");
 
+        let request = request.resolve_query()?;
+
         // Convert DTO request to DB query
         let mut query = SearchQuery::new()
             .limit(request.limit)
             .offset(request.offset)
+            .include_total(request.include_total)
             .sort_by(self.convert_sort_field(request.sort_by))
             .sort_order(self.convert_sort_order(request.sort_order))
             .exclude_deprecated(request.exclude_deprecated);
 
+        if let Some(ref cursor) = request.cursor {
+            query = query.cursor(Cursor::decode(cursor)?);
+        }
+
         if let Some(text) = request.text {
             query = query.text(text);
         }
@@ -166,6 +222,18 @@ impl SearchService for DefaultSearchService {
             query = query.storage_backend(backend);
         }
 
+        if let Some(license) = request.license {
+            query = query.license(license);
+        }
+
+        for tag in request.exclude_tags {
+            query = query.exclude_tag(tag);
+        }
+
+        query = query
+            .size_range(request.min_size_bytes, request.max_size_bytes)
+            .created_between(request.created_after, request.created_before);
+
         // Execute search
         let results = self.repository.search(&query).await?;
         let has_more = results.has_more();
@@ -176,6 +244,8 @@ impl SearchService for DefaultSearchService {
             offset: results.offset,
             limit: results.limit,
             has_more,
+            next_cursor: results.next_cursor.as_ref().map(Cursor::encode),
+            snippets: results.snippets,
         })
     }
 
@@ -189,7 +259,11 @@ impl SearchService for DefaultSearchService {
     }
 
     #[instrument(skip(self), fields(name = %name, version = %version))]
-    async fn get_asset_by_name_version(&self, name: &str, version: &str) -> ServiceResult<Option<Asset>> {
+    async fn get_asset_by_name_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> ServiceResult<Option<Asset>> {
         debug!("Getting asset by name and version");
 
         let semver = semver::Version::parse(version)
@@ -202,7 +276,10 @@ impl SearchService for DefaultSearchService {
     }
 
     #[instrument(skip(self, request), fields(asset_id = %request.asset_id, max_depth = request.max_depth))]
-    async fn get_dependency_graph(&self, request: GetDependencyGraphRequest) -> ServiceResult<DependencyGraphResponse> {
+    async fn get_dependency_graph(
+        &self,
+        request: GetDependencyGraphRequest,
+    ) -> ServiceResult<DependencyGraphResponse> {
         debug!("Building dependency graph");
 
         let mut visited = HashSet::new();
@@ -220,7 +297,9 @@ impl SearchService for DefaultSearchService {
         // Check if truncated
         let truncated = if request.max_depth >= 0 {
             // If max_depth is set, we might have truncated
-            nodes.values().any(|n| n.depth == request.max_depth - 1 && !n.dependencies.is_empty())
+            nodes
+                .values()
+                .any(|n| n.depth == request.max_depth - 1 && !n.dependencies.is_empty())
         } else {
             false
         };
@@ -274,6 +353,40 @@ impl SearchService for DefaultSearchService {
             .await
             .map_err(Into::into)
     }
+
+    async fn get_assets(&self, asset_ids: &[AssetId]) -> ServiceResult<Vec<Asset>> {
+        self.repository
+            .find_by_ids(asset_ids)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn get_assets_by_names(&self, names: &[String]) -> ServiceResult<Vec<Asset>> {
+        self.repository
+            .find_by_names(names)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn get_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>> {
+        self.repository
+            .list_dependency_links(asset_ids)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn get_reverse_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>> {
+        self.repository
+            .list_reverse_dependency_links(asset_ids)
+            .await
+            .map_err(Into::into)
+    }
 }
 
 /// Utility functions for search operations
@@ -285,16 +398,16 @@ pub mod utils {
         terms.join(" ")
     }
 
-    /// Parse and validate a search query string
+    /// Split a plain search query into lowercase words
+    ///
+    /// For field filters, phrases and ranges use
+    /// [`parse_query`](crate::search_query::parse_query).
     pub fn parse_search_query(query: &str) -> ServiceResult<Vec<String>> {
         if query.trim().is_empty() {
             return Err(ServiceError::InvalidInput("Empty search query".to_string()));
         }
 
-        let terms: Vec<String> = query
-            .split_whitespace()
-            .map(|s| s.to_lowercase())
-            .collect();
+        let terms: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
 
         Ok(terms)
     }
@@ -316,9 +429,18 @@ pub mod utils {
             tags: vec![],
             author: None,
             storage_backend: None,
+            license: None,
+            exclude_tags: vec![],
+            min_size_bytes: None,
+            max_size_bytes: None,
+            created_after: None,
+            created_before: None,
+            query: None,
             exclude_deprecated: true,
             limit: 50,
             offset: 0,
+            cursor: None,
+            include_total: true,
             sort_by: SortField::CreatedAt,
             sort_order: SortOrder::Descending,
         }
@@ -0,0 +1,1226 @@
+//! Tantivy-backed search service
+//!
+//! This module provides a [`SearchService`] that answers asset searches from a
+//! local Tantivy index. The index is rebuilt from the asset repository at
+//! startup and kept in sync from registry events. On top of the repository
+//! search it adds fuzzy text matching, prefix autocomplete and facet counts.
+//! Lookups by ID, by name and over dependencies still go to the repository.
+
+use async_trait::async_trait;
+use llm_registry_core::{Asset, AssetId, AssetType, EventType, RegistryEvent};
+use llm_registry_db::{
+    AssetRepository, Cursor, CursorKey, DependencyLink, SearchQuery, SortField as DbSortField,
+    SortOrder as DbSortOrder, SNIPPET_END, SNIPPET_START,
+};
+use semver::Version;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Bound;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tantivy::collector::{Count, FacetCollector, FacetCounts, TopDocs};
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{
+    AllQuery, BooleanQuery, BoostQuery, FuzzyTermQuery, Occur, PhraseQuery, Query, RangeQuery,
+    TermQuery,
+};
+use tantivy::schema::{
+    Facet, FacetOptions, Field, IndexRecordOption, Schema, Value, INDEXED, STORED, STRING, TEXT,
+};
+use tantivy::{
+    DocAddress, Index, IndexReader, IndexWriter, ReloadPolicy, SnippetGenerator, TantivyDocument,
+    Term,
+};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+
+use crate::dto::{
+    DependencyGraphResponse, FacetCount, GetDependencyGraphRequest, SearchAssetsRequest,
+    SearchAssetsResponse, SearchFacets, SortField, SortOrder,
+};
+use crate::error::{ServiceError, ServiceResult};
+use crate::events::{EventService, EventSubscription};
+use crate::search::{DefaultSearchService, SearchService};
+
+/// Memory budget for the index writer
+const WRITER_MEMORY_BYTES: usize = 50_000_000;
+
+/// Page size used when loading assets to rebuild the index
+const REBUILD_PAGE_SIZE: i64 = 500;
+
+/// Delay between attempts to resubscribe after the event subscription fails
+const RESYNC_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Score multiplier for fuzzy matches relative to exact term matches
+const FUZZY_BOOST: f32 = 0.5;
+
+/// Facet roots, one per facet dimension
+const ASSET_TYPE_FACET: &str = "asset_type";
+const LICENSE_FACET: &str = "license";
+const AUTHOR_FACET: &str = "author";
+const TAG_FACET: &str = "tag";
+const STORAGE_BACKEND_FACET: &str = "storage_backend";
+
+/// Handles to the fields of the index schema
+#[derive(Debug, Clone, Copy)]
+struct IndexFields {
+    id: Field,
+    name: Field,
+    description: Field,
+    tags_text: Field,
+    annotations: Field,
+    asset_type: Field,
+    tags: Field,
+    author: Field,
+    license: Field,
+    storage_backend: Field,
+    deprecated: Field,
+    facets: Field,
+    version: Field,
+    created_at: Field,
+    updated_at: Field,
+    size_bytes: Field,
+}
+
+impl IndexFields {
+    /// Build the index schema
+    fn schema() -> Schema {
+        let mut builder = Schema::builder();
+        builder.add_text_field("id", STRING | STORED);
+        builder.add_text_field("name", TEXT | STORED);
+        builder.add_text_field("description", TEXT | STORED);
+        builder.add_text_field("tags_text", TEXT);
+        builder.add_text_field("annotations", TEXT);
+        builder.add_text_field("asset_type", STRING);
+        builder.add_text_field("tags", STRING);
+        builder.add_text_field("author", STRING);
+        builder.add_text_field("license", STRING);
+        builder.add_text_field("storage_backend", STRING);
+        builder.add_bool_field("deprecated", INDEXED);
+        builder.add_facet_field("facets", FacetOptions::default());
+        builder.add_text_field("version", STORED);
+        builder.add_i64_field("created_at", INDEXED | STORED);
+        builder.add_i64_field("updated_at", STORED);
+        builder.add_u64_field("size_bytes", INDEXED | STORED);
+        builder.build()
+    }
+
+    /// Resolve the field handles of an index schema
+    fn resolve(schema: &Schema) -> ServiceResult<Self> {
+        Ok(Self {
+            id: schema.get_field("id")?,
+            name: schema.get_field("name")?,
+            description: schema.get_field("description")?,
+            tags_text: schema.get_field("tags_text")?,
+            annotations: schema.get_field("annotations")?,
+            asset_type: schema.get_field("asset_type")?,
+            tags: schema.get_field("tags")?,
+            author: schema.get_field("author")?,
+            license: schema.get_field("license")?,
+            storage_backend: schema.get_field("storage_backend")?,
+            deprecated: schema.get_field("deprecated")?,
+            facets: schema.get_field("facets")?,
+            version: schema.get_field("version")?,
+            created_at: schema.get_field("created_at")?,
+            updated_at: schema.get_field("updated_at")?,
+            size_bytes: schema.get_field("size_bytes")?,
+        })
+    }
+
+    /// Text fields searched for free text, with their score boosts
+    fn text_fields(&self) -> [(Field, f32); 4] {
+        [
+            (self.name, 3.0),
+            (self.tags_text, 2.0),
+            (self.description, 1.0),
+            (self.annotations, 0.5),
+        ]
+    }
+}
+
+/// A matching document with the stored values used for sorting
+struct Hit {
+    address: DocAddress,
+    score: f32,
+    id: String,
+    name: String,
+    version: Option<Version>,
+    created_at: i64,
+    updated_at: i64,
+    size_bytes: Option<u64>,
+}
+
+/// Search service backed by a local Tantivy index
+///
+/// The index only holds what is needed to match, sort and facet; returned
+/// assets are always loaded from the repository.
+pub struct TantivySearchService {
+    repository: Arc<dyn AssetRepository>,
+    lookups: DefaultSearchService,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    fields: IndexFields,
+}
+
+impl TantivySearchService {
+    /// Create a search service with an index held in memory
+    pub fn in_memory(repository: Arc<dyn AssetRepository>) -> ServiceResult<Self> {
+        Self::with_index(repository, Index::create_in_ram(IndexFields::schema()))
+    }
+
+    /// Create a search service with an index stored in a directory
+    ///
+    /// The directory is created if needed and an existing index in it is
+    /// reused. Call [`TantivySearchService::rebuild`] or
+    /// [`TantivySearchService::start_sync`] to bring it up to date.
+    pub fn open(
+        repository: Arc<dyn AssetRepository>,
+        path: impl AsRef<Path>,
+    ) -> ServiceResult<Self> {
+        let path = path.as_ref();
+        std::fs::create_dir_all(path).map_err(|e| {
+            ServiceError::Internal(format!(
+                "Failed to create search index directory {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let directory = MmapDirectory::open(path).map_err(tantivy::TantivyError::from)?;
+        let index = Index::open_or_create(directory, IndexFields::schema())?;
+        Self::with_index(repository, index)
+    }
+
+    fn with_index(repository: Arc<dyn AssetRepository>, index: Index) -> ServiceResult<Self> {
+        let fields = IndexFields::resolve(&index.schema())?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+        let writer = index.writer_with_num_threads(1, WRITER_MEMORY_BYTES)?;
+
+        Ok(Self {
+            lookups: DefaultSearchService::new(repository.clone()),
+            repository,
+            reader,
+            writer: Mutex::new(writer),
+            fields,
+        })
+    }
+
+    /// Replace the index contents with every asset in the repository
+    ///
+    /// Returns the number of assets indexed.
+    #[instrument(skip(self))]
+    pub async fn rebuild(&self) -> ServiceResult<usize> {
+        let mut assets = Vec::new();
+        let mut cursor = None;
+        loop {
+            let mut query = SearchQuery::new()
+                .exclude_deprecated(false)
+                .include_total(false)
+                .sort_by(DbSortField::CreatedAt)
+                .sort_order(DbSortOrder::Ascending)
+                .limit(REBUILD_PAGE_SIZE);
+            if let Some(cursor) = cursor.take() {
+                query = query.cursor(cursor);
+            }
+            let page = self.repository.search(&query).await?;
+            assets.extend(page.assets);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        let mut writer = self.writer()?;
+        writer.delete_all_documents()?;
+        for asset in &assets {
+            writer.add_document(self.document(asset))?;
+        }
+        writer.commit()?;
+        drop(writer);
+        self.reader.reload()?;
+
+        debug!(count = assets.len(), "Search index rebuilt");
+        Ok(assets.len())
+    }
+
+    /// Update the index for a registry event
+    ///
+    /// Asset lifecycle events re-index the asset from the repository, or drop
+    /// it from the index once it no longer exists. Other events are ignored.
+    #[instrument(skip(self, event), fields(event_id = %event.event_id))]
+    pub async fn apply_event(&self, event: &RegistryEvent) -> ServiceResult<()> {
+        let asset_id = match &event.event_type {
+            EventType::AssetRegistered { asset_id, .. }
+            | EventType::AssetUpdated { asset_id, .. }
+            | EventType::AssetStatusChanged { asset_id, .. }
+            | EventType::AssetDeleted { asset_id, .. } => asset_id,
+            _ => return Ok(()),
+        };
+        let asset = self.repository.find_by_id(asset_id).await?;
+
+        let mut writer = self.writer()?;
+        writer.delete_term(Term::from_field_text(self.fields.id, &asset_id.to_string()));
+        if let Some(asset) = asset {
+            writer.add_document(self.document(&asset))?;
+        }
+        writer.commit()?;
+        drop(writer);
+        self.reader.reload()?;
+
+        Ok(())
+    }
+
+    /// Rebuild the index and keep it in sync with registry events
+    ///
+    /// Subscribes before rebuilding so no change is missed, and returns once
+    /// the rebuilt index is searchable. The returned task applies live events
+    /// until the event source shuts down; if the subscription fails, for
+    /// example after falling too far behind, it resubscribes and rebuilds.
+    pub async fn start_sync(
+        self: Arc<Self>,
+        events: Arc<dyn EventService>,
+    ) -> ServiceResult<JoinHandle<()>> {
+        let subscription = self.resync(events.as_ref()).await?;
+        Ok(tokio::spawn(async move {
+            self.run_sync(events, subscription).await;
+        }))
+    }
+
+    async fn resync(&self, events: &dyn EventService) -> ServiceResult<EventSubscription> {
+        let subscription = events.subscribe(None).await?;
+        let count = self.rebuild().await?;
+        info!(count, "Search index synchronized with repository");
+        Ok(subscription)
+    }
+
+    async fn run_sync(&self, events: Arc<dyn EventService>, mut subscription: EventSubscription) {
+        loop {
+            match subscription.next().await {
+                Ok(Some(event)) => {
+                    if let Err(e) = self.apply_event(&event).await {
+                        warn!(error = %e, event_id = %event.event_id, "Failed to update search index");
+                    }
+                }
+                Ok(None) => {
+                    debug!("Event source closed, stopping search index sync");
+                    return;
+                }
+                Err(e) => {
+                    warn!(error = %e, "Search index subscription failed, rebuilding");
+                    subscription = loop {
+                        match self.resync(events.as_ref()).await {
+                            Ok(subscription) => break subscription,
+                            Err(e) => {
+                                warn!(error = %e, "Failed to rebuild search index, retrying");
+                                tokio::time::sleep(RESYNC_RETRY_DELAY).await;
+                            }
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    fn writer(&self) -> ServiceResult<MutexGuard<'_, IndexWriter>> {
+        self.writer
+            .lock()
+            .map_err(|_| ServiceError::Internal("Search index writer lock poisoned".to_string()))
+    }
+
+    /// Build the index document for an asset
+    fn document(&self, asset: &Asset) -> TantivyDocument {
+        let f = &self.fields;
+        let metadata = &asset.metadata;
+        let asset_type = asset.asset_type.to_string();
+        let backend = asset.storage.backend.to_string();
+        let author = asset.provenance.as_ref().and_then(|p| p.author.as_deref());
+
+        let mut doc = TantivyDocument::default();
+        doc.add_text(f.id, asset.id);
+        doc.add_text(f.name, &metadata.name);
+        if let Some(description) = &metadata.description {
+            doc.add_text(f.description, description);
+        }
+        for value in metadata.annotations.values() {
+            doc.add_text(f.annotations, value);
+        }
+        doc.add_text(f.asset_type, &asset_type);
+        doc.add_text(f.storage_backend, &backend);
+        doc.add_bool(f.deprecated, asset.deprecated_at.is_some());
+        doc.add_text(f.version, &metadata.version);
+        doc.add_i64(f.created_at, asset.created_at.timestamp_micros());
+        doc.add_i64(f.updated_at, asset.updated_at.timestamp_micros());
+        if let Some(size) = metadata.size_bytes {
+            doc.add_u64(f.size_bytes, size);
+        }
+
+        doc.add_facet(f.facets, Facet::from_path([ASSET_TYPE_FACET, &asset_type]));
+        doc.add_facet(
+            f.facets,
+            Facet::from_path([STORAGE_BACKEND_FACET, &backend]),
+        );
+        if let Some(license) = &metadata.license {
+            doc.add_text(f.license, license);
+            doc.add_facet(f.facets, Facet::from_path([LICENSE_FACET, license]));
+        }
+        if let Some(author) = author {
+            doc.add_text(f.author, author);
+            doc.add_facet(f.facets, Facet::from_path([AUTHOR_FACET, author]));
+        }
+        for tag in &metadata.tags {
+            doc.add_text(f.tags_text, tag);
+            doc.add_text(f.tags, tag);
+            doc.add_facet(f.facets, Facet::from_path([TAG_FACET, tag]));
+        }
+
+        doc
+    }
+
+    /// Build the query for a search request
+    ///
+    /// Every text word must match some text field, exactly or within a small
+    /// edit distance, and every phrase must match some text field exactly.
+    /// The structured filters are applied as required or excluded clauses.
+    fn build_query(&self, request: &SearchAssetsRequest, text: &[Vec<String>]) -> Box<dyn Query> {
+        let f = &self.fields;
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+        for words in text {
+            let [text] = words.as_slice() else {
+                let phrases = f
+                    .text_fields()
+                    .into_iter()
+                    .map(|(field, boost)| {
+                        let terms = words
+                            .iter()
+                            .map(|word| Term::from_field_text(field, word))
+                            .collect();
+                        let query = BoostQuery::new(Box::new(PhraseQuery::new(terms)), boost);
+                        (Occur::Should, Box::new(query) as Box<dyn Query>)
+                    })
+                    .collect();
+                clauses.push((Occur::Must, Box::new(BooleanQuery::new(phrases))));
+                continue;
+            };
+
+            let mut alternatives: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+            for (field, boost) in f.text_fields() {
+                let term = Term::from_field_text(field, text);
+                alternatives.push((
+                    Occur::Should,
+                    Box::new(BoostQuery::new(Box::new(term_query(term.clone())), boost)),
+                ));
+                if let Some(distance) = fuzzy_distance(text) {
+                    alternatives.push((
+                        Occur::Should,
+                        Box::new(BoostQuery::new(
+                            Box::new(FuzzyTermQuery::new(term, distance, true)),
+                            boost * FUZZY_BOOST,
+                        )),
+                    ));
+                }
+            }
+            clauses.push((Occur::Must, Box::new(BooleanQuery::new(alternatives))));
+        }
+
+        if !request.asset_types.is_empty() {
+            let types = request
+                .asset_types
+                .iter()
+                .map(|t| {
+                    let term = Term::from_field_text(f.asset_type, &t.to_string());
+                    (Occur::Should, Box::new(term_query(term)) as Box<dyn Query>)
+                })
+                .collect();
+            clauses.push((Occur::Must, Box::new(BooleanQuery::new(types))));
+        }
+
+        for tag in &request.tags {
+            clauses.push(required_term(Term::from_field_text(f.tags, tag)));
+        }
+        if let Some(author) = &request.author {
+            clauses.push(required_term(Term::from_field_text(f.author, author)));
+        }
+        if let Some(backend) = &request.storage_backend {
+            clauses.push(required_term(Term::from_field_text(
+                f.storage_backend,
+                backend,
+            )));
+        }
+        if let Some(license) = &request.license {
+            clauses.push(required_term(Term::from_field_text(f.license, license)));
+        }
+        if request.min_size_bytes.is_some() || request.max_size_bytes.is_some() {
+            let range = RangeQuery::new_u64_bounds(
+                "size_bytes".to_string(),
+                request
+                    .min_size_bytes
+                    .map_or(Bound::Unbounded, Bound::Included),
+                request
+                    .max_size_bytes
+                    .map_or(Bound::Unbounded, Bound::Included),
+            );
+            clauses.push((Occur::Must, Box::new(range)));
+        }
+        if request.created_after.is_some() || request.created_before.is_some() {
+            let range = RangeQuery::new_i64_bounds(
+                "created_at".to_string(),
+                request
+                    .created_after
+                    .map_or(Bound::Unbounded, |t| Bound::Included(t.timestamp_micros())),
+                request
+                    .created_before
+                    .map_or(Bound::Unbounded, |t| Bound::Excluded(t.timestamp_micros())),
+            );
+            clauses.push((Occur::Must, Box::new(range)));
+        }
+        if request.exclude_deprecated {
+            clauses.push(required_term(Term::from_field_bool(f.deprecated, false)));
+        }
+
+        // Exclusions alone match nothing, so they apply on top of everything
+        if clauses.is_empty() {
+            clauses.push((Occur::Must, Box::new(AllQuery)));
+        }
+        for tag in &request.exclude_tags {
+            let term = Term::from_field_text(f.tags, tag);
+            clauses.push((Occur::MustNot, Box::new(term_query(term))));
+        }
+
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    /// Collect every document matching a query with its sort values
+    fn collect_hits(&self, query: &dyn Query) -> ServiceResult<Vec<Hit>> {
+        let f = &self.fields;
+        let searcher = self.reader.searcher();
+        let count = searcher.search(query, &Count)?;
+        let top_docs = searcher.search(query, &TopDocs::with_limit(count.max(1)))?;
+
+        let mut hits = Vec::with_capacity(top_docs.len());
+        for (score, address) in top_docs {
+            let doc: TantivyDocument = searcher.doc(address)?;
+            let text = |field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let number = |field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or_default()
+            };
+
+            hits.push(Hit {
+                address,
+                score,
+                id: text(f.id),
+                name: text(f.name),
+                version: Version::parse(&text(f.version)).ok(),
+                created_at: number(f.created_at),
+                updated_at: number(f.updated_at),
+                size_bytes: doc.get_first(f.size_bytes).and_then(|v| v.as_u64()),
+            });
+        }
+        Ok(hits)
+    }
+
+    /// Build highlighted snippets for the given hits
+    ///
+    /// Highlights exact term matches in the description, or in the name when
+    /// the description has none.
+    fn snippets(&self, terms: &[String], hits: &[Hit]) -> ServiceResult<HashMap<AssetId, String>> {
+        let mut snippets = HashMap::new();
+        if terms.is_empty() {
+            return Ok(snippets);
+        }
+
+        let f = &self.fields;
+        let searcher = self.reader.searcher();
+        let exact_terms: Vec<(Occur, Box<dyn Query>)> = terms
+            .iter()
+            .flat_map(|term| {
+                [f.description, f.name].map(|field| {
+                    let query = term_query(Term::from_field_text(field, term));
+                    (Occur::Should, Box::new(query) as Box<dyn Query>)
+                })
+            })
+            .collect();
+        let query = BooleanQuery::new(exact_terms);
+        let description = SnippetGenerator::create(&searcher, &query, f.description)?;
+        let name = SnippetGenerator::create(&searcher, &query, f.name)?;
+
+        for hit in hits {
+            let Ok(asset_id) = AssetId::from_str(&hit.id) else {
+                continue;
+            };
+            let doc: TantivyDocument = searcher.doc(hit.address)?;
+            let mut snippet = description.snippet_from_doc(&doc);
+            if snippet.is_empty() {
+                snippet = name.snippet_from_doc(&doc);
+            }
+            if !snippet.is_empty() {
+                snippet.set_snippet_prefix_postfix(SNIPPET_START, SNIPPET_END);
+                snippets.insert(asset_id, snippet.to_html());
+            }
+        }
+        Ok(snippets)
+    }
+}
+
+#[async_trait]
+impl SearchService for TantivySearchService {
+    #[instrument(skip(self, request), fields(text = ?request.text))]
+    async fn search_assets(
+        &self,
+        request: SearchAssetsRequest,
+    ) -> ServiceResult<SearchAssetsResponse> {
+        debug!("Searching assets in the search index");
+
+        let request = request.resolve_query()?;
+        let text = request
+            .text
+            .as_deref()
+            .map(text_clauses)
+            .unwrap_or_default();
+        let terms = text.concat();
+        let query = self.build_query(&request, &text);
+        let mut hits = self.collect_hits(query.as_ref())?;
+
+        hits.sort_by(|a, b| {
+            let ordering = compare_hits(a, b, request.sort_by, !terms.is_empty());
+            match request.sort_order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+
+        // Ranked results page by offset; everything else by cursor
+        let sort_order = db_sort_order(request.sort_order);
+        let keyset_field = match request.sort_by {
+            SortField::Relevance if !terms.is_empty() => None,
+            field => Some(db_sort_field(field)),
+        };
+        let total = (request.include_total || keyset_field.is_none()).then_some(hits.len() as i64);
+
+        let mut offset = request.offset.max(0);
+        if let Some(ref token) = request.cursor {
+            let cursor = Cursor::decode(token)?;
+            let Some(field) = keyset_field else {
+                return Err(ServiceError::InvalidInput(
+                    "Cursors are not supported when sorting by relevance".to_string(),
+                ));
+            };
+            cursor.check_asset_ordering(field, sort_order)?;
+            hits.retain(|hit| {
+                let position = compare_hit_to_cursor(hit, field, &cursor);
+                match request.sort_order {
+                    SortOrder::Ascending => position == Ordering::Greater,
+                    SortOrder::Descending => position == Ordering::Less,
+                }
+            });
+            offset = 0;
+        }
+
+        let limit = request.limit.max(0) as usize;
+        let remaining = hits.len().saturating_sub(offset as usize);
+        let page: Vec<Hit> = hits.into_iter().skip(offset as usize).take(limit).collect();
+
+        let ids: Vec<AssetId> = page
+            .iter()
+            .filter_map(|hit| AssetId::from_str(&hit.id).ok())
+            .collect();
+        let mut found: HashMap<AssetId, Asset> = self
+            .repository
+            .find_by_ids(&ids)
+            .await?
+            .into_iter()
+            .map(|asset| (asset.id, asset))
+            .collect();
+        let assets: Vec<Asset> = ids.iter().filter_map(|id| found.remove(id)).collect();
+        let snippets = self.snippets(&terms, &page)?;
+
+        let next_cursor = match keyset_field {
+            Some(field) if remaining > limit => assets
+                .last()
+                .map(|asset| Cursor::for_asset(asset, field, sort_order).encode()),
+            _ => None,
+        };
+
+        Ok(SearchAssetsResponse {
+            has_more: remaining > limit,
+            assets,
+            total,
+            offset,
+            limit: request.limit,
+            next_cursor,
+            snippets,
+        })
+    }
+
+    async fn get_asset(&self, asset_id: &AssetId) -> ServiceResult<Option<Asset>> {
+        self.lookups.get_asset(asset_id).await
+    }
+
+    async fn get_asset_by_name_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> ServiceResult<Option<Asset>> {
+        self.lookups.get_asset_by_name_version(name, version).await
+    }
+
+    async fn get_dependency_graph(
+        &self,
+        request: GetDependencyGraphRequest,
+    ) -> ServiceResult<DependencyGraphResponse> {
+        self.lookups.get_dependency_graph(request).await
+    }
+
+    async fn list_all_tags(&self) -> ServiceResult<Vec<String>> {
+        self.lookups.list_all_tags().await
+    }
+
+    async fn search_by_tags(&self, tags: Vec<String>) -> ServiceResult<Vec<Asset>> {
+        self.lookups.search_by_tags(tags).await
+    }
+
+    async fn get_assets_by_type(&self, asset_type: AssetType) -> ServiceResult<Vec<Asset>> {
+        self.lookups.get_assets_by_type(asset_type).await
+    }
+
+    async fn get_reverse_dependencies(&self, asset_id: &AssetId) -> ServiceResult<Vec<Asset>> {
+        self.lookups.get_reverse_dependencies(asset_id).await
+    }
+
+    async fn get_assets(&self, asset_ids: &[AssetId]) -> ServiceResult<Vec<Asset>> {
+        self.lookups.get_assets(asset_ids).await
+    }
+
+    async fn get_assets_by_names(&self, names: &[String]) -> ServiceResult<Vec<Asset>> {
+        self.lookups.get_assets_by_names(names).await
+    }
+
+    async fn get_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>> {
+        self.lookups.get_dependency_links(asset_ids).await
+    }
+
+    async fn get_reverse_dependency_links(
+        &self,
+        asset_ids: &[AssetId],
+    ) -> ServiceResult<Vec<DependencyLink>> {
+        self.lookups.get_reverse_dependency_links(asset_ids).await
+    }
+
+    #[instrument(skip(self))]
+    async fn autocomplete(&self, prefix: &str, limit: usize) -> ServiceResult<Vec<String>> {
+        let words = query_terms(prefix);
+        let Some((last, complete)) = words.split_last() else {
+            return Ok(Vec::new());
+        };
+
+        let f = &self.fields;
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = complete
+            .iter()
+            .map(|word| required_term(Term::from_field_text(f.name, word)))
+            .collect();
+        clauses.push((
+            Occur::Must,
+            Box::new(FuzzyTermQuery::new_prefix(
+                Term::from_field_text(f.name, last),
+                0,
+                true,
+            )),
+        ));
+        clauses.push(required_term(Term::from_field_bool(f.deprecated, false)));
+
+        // Several versions share a name, so collect every match and dedupe
+        let names: BTreeSet<String> = self
+            .collect_hits(&BooleanQuery::new(clauses))?
+            .into_iter()
+            .map(|hit| hit.name)
+            .collect();
+        Ok(names.into_iter().take(limit).collect())
+    }
+
+    #[instrument(skip(self, request))]
+    async fn facets(&self, request: SearchAssetsRequest) -> ServiceResult<SearchFacets> {
+        let request = request.resolve_query()?;
+        let text = request
+            .text
+            .as_deref()
+            .map(text_clauses)
+            .unwrap_or_default();
+        let query = self.build_query(&request, &text);
+
+        let mut collector = FacetCollector::for_field("facets");
+        for root in [
+            ASSET_TYPE_FACET,
+            LICENSE_FACET,
+            AUTHOR_FACET,
+            TAG_FACET,
+            STORAGE_BACKEND_FACET,
+        ] {
+            collector.add_facet(Facet::from_path([root]));
+        }
+        let counts = self.reader.searcher().search(query.as_ref(), &collector)?;
+
+        Ok(SearchFacets {
+            asset_types: facet_counts(&counts, ASSET_TYPE_FACET),
+            licenses: facet_counts(&counts, LICENSE_FACET),
+            authors: facet_counts(&counts, AUTHOR_FACET),
+            tags: facet_counts(&counts, TAG_FACET),
+            storage_backends: facet_counts(&counts, STORAGE_BACKEND_FACET),
+        })
+    }
+}
+
+/// Split free text into terms the way the default tokenizer does
+fn query_terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Split free text into single words and the words of double-quoted phrases
+fn text_clauses(text: &str) -> Vec<Vec<String>> {
+    let mut clauses = Vec::new();
+    for (i, segment) in text.split('"').enumerate() {
+        if i % 2 == 1 {
+            clauses.push(query_terms(segment));
+        } else {
+            clauses.extend(query_terms(segment).into_iter().map(|word| vec![word]));
+        }
+    }
+    clauses.retain(|words| !words.is_empty());
+    clauses
+}
+
+/// Edit distance allowed for fuzzy matching of a term
+///
+/// Short terms must match exactly; fuzzy matching them returns noise.
+fn fuzzy_distance(term: &str) -> Option<u8> {
+    match term.chars().count() {
+        0..=3 => None,
+        4..=7 => Some(1),
+        _ => Some(2),
+    }
+}
+
+fn term_query(term: Term) -> TermQuery {
+    TermQuery::new(term, IndexRecordOption::WithFreqs)
+}
+
+fn required_term(term: Term) -> (Occur, Box<dyn Query>) {
+    (
+        Occur::Must,
+        Box::new(TermQuery::new(term, IndexRecordOption::Basic)),
+    )
+}
+
+/// Compare two hits by a sort field, falling back to ID for a stable order
+fn compare_hits(a: &Hit, b: &Hit, field: SortField, has_text: bool) -> Ordering {
+    let ordering = match field {
+        SortField::Relevance if has_text => a
+            .score
+            .total_cmp(&b.score)
+            .then_with(|| a.created_at.cmp(&b.created_at)),
+        SortField::CreatedAt | SortField::Relevance => a.created_at.cmp(&b.created_at),
+        SortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+        SortField::Name => a.name.cmp(&b.name),
+        SortField::Version => a.version.cmp(&b.version),
+        SortField::SizeBytes => a.size_bytes.cmp(&b.size_bytes),
+    };
+
+    ordering.then_with(|| a.id.cmp(&b.id))
+}
+
+/// Compare a hit's position with a cursor in ascending sort order
+///
+/// The index stores timestamps in microseconds, so cursor timestamps are
+/// compared at that precision.
+fn compare_hit_to_cursor(hit: &Hit, field: DbSortField, cursor: &Cursor) -> Ordering {
+    let ordering = match &cursor.key {
+        CursorKey::Timestamp(timestamp) => {
+            let micros = match field {
+                DbSortField::UpdatedAt => hit.updated_at,
+                _ => hit.created_at,
+            };
+            micros.cmp(&timestamp.timestamp_micros())
+        }
+        CursorKey::Text(version) if field == DbSortField::Version => {
+            hit.version.cmp(&Version::parse(version).ok())
+        }
+        CursorKey::Text(name) => hit.name.as_str().cmp(name),
+        CursorKey::Integer(size) => hit
+            .size_bytes
+            .map_or(-1, |size| i64::try_from(size).unwrap_or(i64::MAX))
+            .cmp(size),
+    };
+
+    ordering.then_with(|| hit.id.as_str().cmp(&cursor.id))
+}
+
+/// Convert a DTO sort field to the repository sort field
+fn db_sort_field(field: SortField) -> DbSortField {
+    match field {
+        SortField::CreatedAt | SortField::Relevance => DbSortField::CreatedAt,
+        SortField::UpdatedAt => DbSortField::UpdatedAt,
+        SortField::Name => DbSortField::Name,
+        SortField::Version => DbSortField::Version,
+        SortField::SizeBytes => DbSortField::SizeBytes,
+    }
+}
+
+/// Convert a DTO sort order to the repository sort order
+fn db_sort_order(order: SortOrder) -> DbSortOrder {
+    order.into()
+}
+
+/// Extract the counts below a facet root, most frequent first
+fn facet_counts(counts: &FacetCounts, root: &str) -> Vec<FacetCount> {
+    let mut values: Vec<FacetCount> = counts
+        .get(Facet::from_path([root]))
+        .filter_map(|(facet, count)| {
+            let value = facet.to_path().last()?.to_string();
+            Some(FacetCount { value, count })
+        })
+        .collect();
+    values.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::RegisterAssetRequest;
+    use crate::test_support::register_request;
+    use crate::ServiceRegistryBuilder;
+    use llm_registry_core::{Checksum, HashAlgorithm, Provenance};
+    use llm_registry_db::{InMemoryAssetRepository, InMemoryEventStore, InMemoryUnitOfWork};
+
+    fn test_asset(name: &str, asset_type: AssetType, tags: &[&str]) -> Asset {
+        let mut asset = llm_registry_core::test_support::test_asset(name, "1.0.0");
+        asset.asset_type = asset_type;
+        asset.metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+        asset
+    }
+
+    async fn indexed(assets: Vec<Asset>) -> TantivySearchService {
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        for asset in assets {
+            repository.create(asset).await.unwrap();
+        }
+        let service = TantivySearchService::in_memory(repository).unwrap();
+        service.rebuild().await.unwrap();
+        service
+    }
+
+    fn text_request(text: &str) -> SearchAssetsRequest {
+        SearchAssetsRequest {
+            text: Some(text.to_string()),
+            sort_by: SortField::Relevance,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fuzzy_search_ranks_and_highlights() {
+        let mut described = test_asset("mistral-7b", AssetType::Model, &[]);
+        described.metadata.description = Some("Fine-tuned from llama weights".to_string());
+        let named = test_asset("llama-2-7b", AssetType::Model, &["chat"]);
+        let unrelated = test_asset("resnet-50", AssetType::Model, &["vision"]);
+        let service = indexed(vec![described.clone(), named.clone(), unrelated]).await;
+
+        // "llamma" is one edit away from "llama"
+        let response = service.search_assets(text_request("llamma")).await.unwrap();
+        let ids: Vec<_> = response.assets.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![named.id, described.id]);
+        assert_eq!(response.total, Some(2));
+
+        let response = service.search_assets(text_request("llama")).await.unwrap();
+        assert_eq!(
+            response.snippets[&described.id],
+            "Fine-tuned from <mark>llama</mark> weights"
+        );
+        assert_eq!(response.snippets[&named.id], "<mark>llama</mark>-2-7b");
+    }
+
+    #[tokio::test]
+    async fn test_filters_and_pagination() {
+        let mut deprecated = test_asset("old-model", AssetType::Model, &["nlp"]);
+        deprecated.deprecated_at = Some(chrono::Utc::now());
+        let service = indexed(vec![
+            test_asset("bert", AssetType::Model, &["nlp", "base"]),
+            test_asset("squad", AssetType::Dataset, &["nlp"]),
+            test_asset("etl", AssetType::Pipeline, &["nlp"]),
+            deprecated,
+        ])
+        .await;
+
+        let response = service
+            .search_assets(SearchAssetsRequest {
+                asset_types: vec![AssetType::Model, AssetType::Dataset],
+                tags: vec!["nlp".to_string()],
+                sort_by: SortField::Name,
+                sort_order: SortOrder::Ascending,
+                limit: 1,
+                offset: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, Some(2));
+        assert_eq!(response.assets[0].metadata.name, "squad");
+        assert!(!response.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_pagination() {
+        let service = indexed(
+            ["delta", "alpha", "charlie", "bravo"]
+                .into_iter()
+                .map(|name| test_asset(name, AssetType::Model, &[]))
+                .collect(),
+        )
+        .await;
+
+        let request = SearchAssetsRequest {
+            sort_by: SortField::Name,
+            sort_order: SortOrder::Ascending,
+            limit: 3,
+            include_total: false,
+            ..Default::default()
+        };
+        let first = service.search_assets(request.clone()).await.unwrap();
+        assert_eq!(first.total, None);
+        assert!(first.has_more);
+
+        let second = service
+            .search_assets(SearchAssetsRequest {
+                cursor: first.next_cursor.clone(),
+                ..request
+            })
+            .await
+            .unwrap();
+        let names: Vec<_> = second
+            .assets
+            .iter()
+            .map(|a| a.metadata.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["delta"]);
+        assert!(second.next_cursor.is_none());
+
+        let err = service
+            .search_assets(SearchAssetsRequest {
+                cursor: first.next_cursor,
+                ..text_request("alpha")
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_structured_query() {
+        let mut llama = test_asset("llama-7b", AssetType::Model, &["llama"]);
+        llama.metadata.license = Some("apache-2.0".to_string());
+        llama.metadata.size_bytes = Some(13_000_000_000);
+        llama.metadata.description = Some("Exact phrase match".to_string());
+        llama.created_at = "2025-02-01T00:00:00Z".parse().unwrap();
+        let mut experimental = llama.clone();
+        experimental.id = AssetId::new();
+        experimental.metadata.name = "llama-7b-exp".to_string();
+        experimental.metadata.tags.push("experimental".to_string());
+        let mut small = llama.clone();
+        small.id = AssetId::new();
+        small.metadata.name = "llama-tiny".to_string();
+        small.metadata.size_bytes = Some(500_000_000);
+        let mut old = llama.clone();
+        old.id = AssetId::new();
+        old.metadata.name = "llama-old".to_string();
+        old.created_at = "2024-06-01T00:00:00Z".parse().unwrap();
+        let mut mit = llama.clone();
+        mit.id = AssetId::new();
+        mit.metadata.name = "llama-mit".to_string();
+        mit.metadata.license = Some("mit".to_string());
+        let service = indexed(vec![llama.clone(), experimental, small, old, mit]).await;
+
+        let query = "type:model tag:llama license:apache-2.0 size>2GB created>2025-01-01 \
+                     \"exact phrase\" -tag:experimental";
+        let response = service
+            .search_assets(SearchAssetsRequest {
+                query: Some(query.to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, Some(1));
+        assert_eq!(response.assets[0].id, llama.id);
+
+        let response = service
+            .search_assets(SearchAssetsRequest {
+                query: Some("\"phrase exact\"".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, Some(0));
+
+        let err = service
+            .facets(SearchAssetsRequest {
+                query: Some("tag:llama size>big".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::InvalidQuery(e) if e.position == 15));
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_suggests_distinct_names() {
+        let mut v2 = test_asset("llama-chat", AssetType::Model, &[]);
+        v2.metadata.version = Version::new(2, 0, 0);
+        let service = indexed(vec![
+            test_asset("llama-chat", AssetType::Model, &[]),
+            v2,
+            test_asset("llama-code", AssetType::Model, &[]),
+            test_asset("mistral", AssetType::Model, &[]),
+        ])
+        .await;
+
+        let names = service.autocomplete("Lla", 10).await.unwrap();
+        assert_eq!(names, vec!["llama-chat", "llama-code"]);
+
+        let names = service.autocomplete("llama ch", 10).await.unwrap();
+        assert_eq!(names, vec!["llama-chat"]);
+
+        assert!(service.autocomplete("  ", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_facet_counts() {
+        let mut licensed = test_asset("bert", AssetType::Model, &["nlp", "base"]);
+        licensed.metadata.license = Some("apache-2.0".to_string());
+        licensed.provenance = Some(Provenance::builder().author("ml-team").build().unwrap());
+        let service = indexed(vec![
+            licensed,
+            test_asset("gpt", AssetType::Model, &["nlp"]),
+            test_asset("squad", AssetType::Dataset, &["nlp"]),
+        ])
+        .await;
+
+        let facets = service
+            .facets(SearchAssetsRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            facets.asset_types,
+            vec![
+                FacetCount {
+                    value: "model".to_string(),
+                    count: 2
+                },
+                FacetCount {
+                    value: "dataset".to_string(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            facets.tags[0],
+            FacetCount {
+                value: "nlp".to_string(),
+                count: 3
+            }
+        );
+        assert_eq!(
+            facets.licenses,
+            vec![FacetCount {
+                value: "apache-2.0".to_string(),
+                count: 1
+            }]
+        );
+        assert_eq!(facets.authors[0].value, "ml-team");
+        assert_eq!(facets.storage_backends[0].count, 3);
+
+        // Facets follow the search filters
+        let facets = service
+            .facets(SearchAssetsRequest {
+                asset_types: vec![AssetType::Dataset],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            facets.tags,
+            vec![FacetCount {
+                value: "nlp".to_string(),
+                count: 1
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_follows_registry_events() {
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let existing = test_asset("existing", AssetType::Model, &[]);
+        repository.create(existing).await.unwrap();
+
+        let search = Arc::new(TantivySearchService::in_memory(repository.clone()).unwrap());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(event_store.clone())
+            .unit_of_work(Arc::new(InMemoryUnitOfWork::new(repository, event_store)))
+            .search_service(search.clone())
+            .build()
+            .unwrap();
+        let sync = search
+            .clone()
+            .start_sync(services.events().clone())
+            .await
+            .unwrap();
+
+        // Rebuilt at startup
+        assert_eq!(
+            search.autocomplete("exist", 10).await.unwrap(),
+            vec!["existing"]
+        );
+
+        let registered = services
+            .registration()
+            .register_asset(RegisterAssetRequest {
+                checksum: Checksum::new(HashAlgorithm::SHA256, "b".repeat(64)).unwrap(),
+                ..register_request("fresh-model", "1.0.0")
+            })
+            .await
+            .unwrap()
+            .asset;
+
+        let mut found = false;
+        for _ in 0..100 {
+            let response = services
+                .search()
+                .search_assets(text_request("fresh"))
+                .await
+                .unwrap();
+            if response.assets.iter().any(|a| a.id == registered.id) {
+                found = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(found, "registered asset was not indexed");
+        sync.abort();
+    }
+}
@@ -0,0 +1,614 @@
+//! Structured search query language
+//!
+//! This module parses the query strings accepted by every API (the `q`
+//! parameter on REST, `query` on GraphQL and gRPC) into search filters, so a
+//! single search box behaves the same in every client. A query is a sequence
+//! of whitespace-separated clauses:
+//!
+//! | Clause               | Meaning                                              |
+//! |----------------------|------------------------------------------------------|
+//! | `llama`              | Free text; every word must match                     |
+//! | `"exact phrase"`     | Free text matched as written                         |
+//! | `type:model`         | Asset type; repeat to match any of several types     |
+//! | `tag:llama`          | Required tag; repeat to require several tags         |
+//! | `-tag:experimental`  | Excluded tag                                         |
+//! | `license:apache-2.0` | License                                              |
+//! | `author:ml-team`     | Author                                               |
+//! | `backend:S3`         | Storage backend                                      |
+//! | `size>2GB`           | Size, compared with `>`, `>=`, `<`, `<=` or `:`      |
+//! | `created>2025-01-01` | Creation date or RFC 3339 time, same operators       |
+//!
+//! Field values may be double-quoted to include spaces. Sizes accept decimal
+//! (`KB`, `MB`, `GB`, `TB`) and binary (`KiB`, `MiB`, `GiB`, `TiB`) units.
+//! Words that are not filters, such as `mistral:7b`, `llama-3` or `-draft`,
+//! are free text. Errors in filters report the character position of the
+//! offending clause.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use llm_registry_core::AssetType;
+use std::fmt;
+use thiserror::Error;
+
+use crate::dto::SearchAssetsRequest;
+use crate::error::ServiceResult;
+
+/// Error raised for a malformed query string
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} at position {position}")]
+pub struct QueryParseError {
+    /// Zero-based character offset of the error in the query string
+    pub position: usize,
+
+    /// Description of the problem
+    pub message: String,
+}
+
+impl QueryParseError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+/// Filters compiled from a query string
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedQuery {
+    /// Free text, with phrases kept in double quotes
+    pub text: Option<String>,
+
+    /// Asset types (any of)
+    pub asset_types: Vec<AssetType>,
+
+    /// Required tags
+    pub tags: Vec<String>,
+
+    /// Excluded tags
+    pub exclude_tags: Vec<String>,
+
+    /// License
+    pub license: Option<String>,
+
+    /// Author
+    pub author: Option<String>,
+
+    /// Storage backend
+    pub storage_backend: Option<String>,
+
+    /// Minimum size in bytes (inclusive)
+    pub min_size_bytes: Option<u64>,
+
+    /// Maximum size in bytes (inclusive)
+    pub max_size_bytes: Option<u64>,
+
+    /// Created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Created strictly before this time
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl ParsedQuery {
+    /// Merge the filters into a search request
+    ///
+    /// Text is appended to the request's text and lists are extended.
+    /// Single-valued filters from the query replace those in the request,
+    /// and ranges are narrowed to their intersection.
+    pub fn apply_to(self, request: &mut SearchAssetsRequest) {
+        if let Some(text) = self.text {
+            request.text = Some(match request.text.take() {
+                Some(existing) if !existing.trim().is_empty() => format!("{} {}", existing, text),
+                _ => text,
+            });
+        }
+        request.asset_types.extend(self.asset_types);
+        request.tags.extend(self.tags);
+        request.exclude_tags.extend(self.exclude_tags);
+
+        if self.license.is_some() {
+            request.license = self.license;
+        }
+        if self.author.is_some() {
+            request.author = self.author;
+        }
+        if self.storage_backend.is_some() {
+            request.storage_backend = self.storage_backend;
+        }
+
+        request.min_size_bytes = narrow(request.min_size_bytes, self.min_size_bytes, u64::max);
+        request.max_size_bytes = narrow(request.max_size_bytes, self.max_size_bytes, u64::min);
+        request.created_after = narrow(request.created_after, self.created_after, Ord::max);
+        request.created_before = narrow(request.created_before, self.created_before, Ord::min);
+    }
+}
+
+impl SearchAssetsRequest {
+    /// Compile the query string in `query`, if any, into the request's filters
+    pub fn resolve_query(mut self) -> ServiceResult<Self> {
+        if let Some(query) = self.query.take() {
+            parse_query(&query)?.apply_to(&mut self);
+        }
+        Ok(self)
+    }
+}
+
+/// Parse a query string
+pub fn parse_query(input: &str) -> Result<ParsedQuery, QueryParseError> {
+    Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+        query: ParsedQuery::default(),
+        text: Vec::new(),
+    }
+    .parse()
+}
+
+/// Combine two optional bounds, keeping the tighter one
+fn narrow<T>(current: Option<T>, new: Option<T>, tighter: fn(T, T) -> T) -> Option<T> {
+    match (current, new) {
+        (Some(a), Some(b)) => Some(tighter(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Comparison operator of a field clause
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Operator::Eq => ":",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+        };
+        f.write_str(symbol)
+    }
+}
+
+/// Fields accepted in field clauses
+const FIELDS: [&str; 7] = [
+    "type", "tag", "license", "author", "backend", "size", "created",
+];
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    query: ParsedQuery,
+    text: Vec<String>,
+}
+
+impl Parser {
+    fn parse(mut self) -> Result<ParsedQuery, QueryParseError> {
+        loop {
+            while self.peek().is_some_and(char::is_whitespace) {
+                self.pos += 1;
+            }
+            if self.peek().is_none() {
+                break;
+            }
+            self.clause()?;
+        }
+
+        if !self.text.is_empty() {
+            self.query.text = Some(self.text.join(" "));
+        }
+        Ok(self.query)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn at_boundary(&self) -> bool {
+        self.peek().map_or(true, char::is_whitespace)
+    }
+
+    fn clause(&mut self) -> Result<(), QueryParseError> {
+        let start = self.pos;
+        let negated = self.peek() == Some('-');
+        if negated {
+            self.pos += 1;
+        }
+
+        if !negated && self.peek() == Some('"') {
+            let phrase = self.quoted()?;
+            let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+            if phrase.is_empty() {
+                return Err(QueryParseError::new(start, "empty phrase"));
+            }
+            self.text.push(format!("\"{}\"", phrase));
+            return Ok(());
+        }
+
+        let name_start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        {
+            self.pos += 1;
+        }
+        if self.pos > name_start {
+            let name: String = self.chars[name_start..self.pos].iter().collect();
+            let op_start = self.pos;
+            if FIELDS.contains(&name.to_ascii_lowercase().as_str()) {
+                if let Some(op) = self.operator() {
+                    return self.filter(start, negated, name_start, &name, op_start, op);
+                }
+            }
+        }
+
+        // Anything that is not a filter, such as `mistral:7b` or `-draft`,
+        // is searched for as written
+        self.pos = start;
+        let word = self.bare().replace('"', "");
+        if !word.is_empty() {
+            self.text.push(word);
+        }
+        Ok(())
+    }
+
+    /// Consume a comparison operator, if one is next
+    fn operator(&mut self) -> Option<Operator> {
+        let op = match self.peek()? {
+            ':' | '=' => Operator::Eq,
+            '>' if self.chars.get(self.pos + 1) == Some(&'=') => Operator::Ge,
+            '<' if self.chars.get(self.pos + 1) == Some(&'=') => Operator::Le,
+            '>' => Operator::Gt,
+            '<' => Operator::Lt,
+            _ => return None,
+        };
+        self.pos += if matches!(op, Operator::Ge | Operator::Le) {
+            2
+        } else {
+            1
+        };
+        Some(op)
+    }
+
+    /// Consume a double-quoted string, returning its contents
+    fn quoted(&mut self) -> Result<String, QueryParseError> {
+        let start = self.pos;
+        self.pos += 1;
+        let content_start = self.pos;
+        while self.peek().is_some_and(|c| c != '"') {
+            self.pos += 1;
+        }
+        if self.peek().is_none() {
+            return Err(QueryParseError::new(start, "unterminated quote"));
+        }
+        let content = self.chars[content_start..self.pos].iter().collect();
+        self.pos += 1;
+
+        if !self.at_boundary() {
+            return Err(QueryParseError::new(
+                self.pos,
+                "expected whitespace after closing quote",
+            ));
+        }
+        Ok(content)
+    }
+
+    /// Consume characters up to the next whitespace
+    fn bare(&mut self) -> String {
+        let start = self.pos;
+        while !self.at_boundary() {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn filter(
+        &mut self,
+        start: usize,
+        negated: bool,
+        name_start: usize,
+        name: &str,
+        op_start: usize,
+        op: Operator,
+    ) -> Result<(), QueryParseError> {
+        let field = name.to_ascii_lowercase();
+
+        let value_start = self.pos;
+        let value = if self.peek() == Some('"') {
+            self.quoted()?
+        } else {
+            self.bare()
+        };
+        if value.trim().is_empty() {
+            return Err(QueryParseError::new(
+                value_start,
+                format!("missing value for '{}'", field),
+            ));
+        }
+
+        if negated && field != "tag" {
+            return Err(QueryParseError::new(
+                start,
+                "'-' is only supported for tag filters",
+            ));
+        }
+        if op != Operator::Eq && !matches!(field.as_str(), "size" | "created") {
+            return Err(QueryParseError::new(
+                op_start,
+                format!("'{}' does not support '{}'", field, op),
+            ));
+        }
+
+        let query = &mut self.query;
+        match field.as_str() {
+            "type" => query.asset_types.push(parse_asset_type(&value)),
+            "tag" if negated => query.exclude_tags.push(value),
+            "tag" => query.tags.push(value),
+            "license" => set_once(&mut query.license, value, &field, name_start)?,
+            "author" => set_once(&mut query.author, value, &field, name_start)?,
+            "backend" => set_once(&mut query.storage_backend, value, &field, name_start)?,
+            "size" => {
+                let size = parse_size(&value, value_start)?;
+                let (min, max) = match op {
+                    Operator::Eq => (Some(size), Some(size)),
+                    Operator::Gt => (Some(size.saturating_add(1)), None),
+                    Operator::Ge => (Some(size), None),
+                    Operator::Lt => {
+                        let max = size.checked_sub(1).ok_or_else(|| {
+                            QueryParseError::new(value_start, "no size is less than 0")
+                        })?;
+                        (None, Some(max))
+                    }
+                    Operator::Le => (None, Some(size)),
+                };
+                query.min_size_bytes = narrow(query.min_size_bytes, min, u64::max);
+                query.max_size_bytes = narrow(query.max_size_bytes, max, u64::min);
+            }
+            _ => {
+                let (from, until) = parse_time(&value, value_start)?;
+                let (after, before) = match op {
+                    Operator::Eq => (Some(from), Some(until)),
+                    Operator::Gt => (Some(until), None),
+                    Operator::Ge => (Some(from), None),
+                    Operator::Lt => (None, Some(from)),
+                    Operator::Le => (None, Some(until)),
+                };
+                query.created_after = narrow(query.created_after, after, Ord::max);
+                query.created_before = narrow(query.created_before, before, Ord::min);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn set_once(
+    slot: &mut Option<String>,
+    value: String,
+    field: &str,
+    position: usize,
+) -> Result<(), QueryParseError> {
+    if slot.is_some() {
+        return Err(QueryParseError::new(
+            position,
+            format!("'{}' can only be given once", field),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Map a type name to an asset type; unknown names are custom types
+fn parse_asset_type(value: &str) -> AssetType {
+    match value.to_ascii_lowercase().as_str() {
+        "model" => AssetType::Model,
+        "pipeline" => AssetType::Pipeline,
+        "test_suite" => AssetType::TestSuite,
+        "policy" => AssetType::Policy,
+        "dataset" => AssetType::Dataset,
+        _ => AssetType::Custom(value.to_string()),
+    }
+}
+
+/// Parse a size such as `2GB`, `1.5GiB` or `1024`, in bytes
+fn parse_size(value: &str, position: usize) -> Result<u64, QueryParseError> {
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| QueryParseError::new(position, format!("invalid size '{}'", value)))?;
+    let multiplier: f64 = match unit.to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "kb" => 1e3,
+        "mb" => 1e6,
+        "gb" => 1e9,
+        "tb" => 1e12,
+        "kib" => 1024.0,
+        "mib" => 1024.0 * 1024.0,
+        "gib" => 1024.0 * 1024.0 * 1024.0,
+        "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => {
+            return Err(QueryParseError::new(
+                position + split,
+                format!("unknown size unit '{}'", unit),
+            ))
+        }
+    };
+
+    let bytes = (number * multiplier).round();
+    if bytes >= u64::MAX as f64 {
+        return Err(QueryParseError::new(position, "size is too large"));
+    }
+    Ok(bytes as u64)
+}
+
+/// Parse a date or time into the half-open interval it covers
+///
+/// A date covers the whole UTC day; a time covers a single microsecond, the
+/// precision timestamps are stored with.
+fn parse_time(
+    value: &str,
+    position: usize,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), QueryParseError> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let from = date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+        return Ok((from, from + Duration::days(1)));
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        let time = time.with_timezone(&Utc);
+        return Ok((time, time + Duration::microseconds(1)));
+    }
+    Err(QueryParseError::new(
+        position,
+        format!(
+            "invalid time '{}' (expected YYYY-MM-DD or an RFC 3339 timestamp)",
+            value
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_full_query() {
+        let query = parse_query(
+            "type:model tag:llama license:apache-2.0 size>2GB author:ml-team \
+             created>2025-01-01 \"exact  phrase\" -tag:experimental chat",
+        )
+        .unwrap();
+
+        assert_eq!(query.text.as_deref(), Some("\"exact phrase\" chat"));
+        assert_eq!(query.asset_types, vec![AssetType::Model]);
+        assert_eq!(query.tags, vec!["llama"]);
+        assert_eq!(query.exclude_tags, vec!["experimental"]);
+        assert_eq!(query.license.as_deref(), Some("apache-2.0"));
+        assert_eq!(query.author.as_deref(), Some("ml-team"));
+        assert_eq!(query.min_size_bytes, Some(2_000_000_001));
+        assert_eq!(query.max_size_bytes, None);
+        assert_eq!(query.created_after, Some(time("2025-01-02T00:00:00Z")));
+        assert_eq!(query.created_before, None);
+    }
+
+    #[test]
+    fn test_parse_ranges_and_quoted_values() {
+        let query = parse_query(
+            "size>=1.5GiB size<=10gb created<=2025-01-31 created>=2025-01-01T12:00:00+02:00 \
+             author:\"ML Team\" type:dataset TYPE:custom-kind",
+        )
+        .unwrap();
+
+        assert_eq!(query.min_size_bytes, Some(1_610_612_736));
+        assert_eq!(query.max_size_bytes, Some(10_000_000_000));
+        assert_eq!(query.created_after, Some(time("2025-01-01T10:00:00Z")));
+        assert_eq!(query.created_before, Some(time("2025-02-01T00:00:00Z")));
+        assert_eq!(query.author.as_deref(), Some("ML Team"));
+        assert_eq!(
+            query.asset_types,
+            vec![
+                AssetType::Dataset,
+                AssetType::Custom("custom-kind".to_string())
+            ]
+        );
+        assert!(query.text.is_none());
+
+        let query = parse_query("size:100 created:2025-03-01 gpt-4 http").unwrap();
+        assert_eq!(query.min_size_bytes, Some(100));
+        assert_eq!(query.max_size_bytes, Some(100));
+        assert_eq!(query.created_after, Some(time("2025-03-01T00:00:00Z")));
+        assert_eq!(query.created_before, Some(time("2025-03-02T00:00:00Z")));
+        assert_eq!(query.text.as_deref(), Some("gpt-4 http"));
+    }
+
+    #[test]
+    fn test_parse_errors_report_positions() {
+        let cases = [
+            ("tag:a \"open", 6, "unterminated quote"),
+            ("tag:", 4, "missing value for 'tag'"),
+            ("tag>x", 3, "'tag' does not support '>'"),
+            ("size>2XB", 6, "unknown size unit 'XB'"),
+            ("size<big", 5, "invalid size 'big'"),
+            ("created>yesterday", 8, "invalid time 'yesterday'"),
+            (
+                "llama -license:mit",
+                6,
+                "'-' is only supported for tag filters",
+            ),
+            ("author:a author:b", 9, "'author' can only be given once"),
+            ("\"a\"b", 3, "expected whitespace after closing quote"),
+        ];
+
+        for (input, position, message) in cases {
+            let err = parse_query(input).unwrap_err();
+            assert_eq!(err.position, position, "position for {:?}: {}", input, err);
+            assert!(
+                err.message.starts_with(message),
+                "message for {:?}: {}",
+                input,
+                err.message
+            );
+        }
+
+        // Positions count characters, not bytes
+        let err = parse_query("модель size:x").unwrap_err();
+        assert_eq!(err.position, 12);
+        assert_eq!(err.to_string(), format!("{} at position 12", err.message));
+    }
+
+    #[test]
+    fn test_unrecognised_clauses_are_text() {
+        let query = parse_query("mistral:7b llama-3 -draft colour:red - tag:chat").unwrap();
+        assert_eq!(
+            query.text.as_deref(),
+            Some("mistral:7b llama-3 -draft colour:red -")
+        );
+        assert_eq!(query.tags, vec!["chat"]);
+
+        let query = parse_query("-\"not a phrase\" http://host/x a:b:c").unwrap();
+        assert_eq!(
+            query.text.as_deref(),
+            Some("-not a phrase http://host/x a:b:c")
+        );
+    }
+
+    #[test]
+    fn test_resolve_query_merges_into_request() {
+        let request = SearchAssetsRequest {
+            text: Some("base".to_string()),
+            tags: vec!["nlp".to_string()],
+            min_size_bytes: Some(5_000),
+            author: Some("someone".to_string()),
+            query: Some("bert tag:encoder size>=1000 size<=1MB author:ml-team".to_string()),
+            ..Default::default()
+        }
+        .resolve_query()
+        .unwrap();
+
+        assert!(request.query.is_none());
+        assert_eq!(request.text.as_deref(), Some("base bert"));
+        assert_eq!(request.tags, vec!["nlp", "encoder"]);
+        assert_eq!(request.min_size_bytes, Some(5_000));
+        assert_eq!(request.max_size_bytes, Some(1_000_000));
+        assert_eq!(request.author.as_deref(), Some("ml-team"));
+
+        let err = SearchAssetsRequest {
+            query: Some("size>".to_string()),
+            ..Default::default()
+        }
+        .resolve_query()
+        .unwrap_err();
+        assert!(err.to_string().contains("at position 5"));
+    }
+}
@@ -0,0 +1,406 @@
+//! Signing service
+//!
+//! This module provides Ed25519 signature verification for assets against a
+//! store of trusted public keys.
+
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use llm_registry_core::{Asset, AssetId, AssetSignature, SignatureAlgorithm};
+use llm_registry_db::AssetRepository;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, instrument};
+
+use crate::dto::SignatureVerificationResult;
+use crate::error::{ServiceError, ServiceResult};
+
+/// A public key trusted to sign assets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustedKey {
+    /// Key identifier referenced by `AssetSignature::key_id`
+    pub key_id: String,
+
+    /// Signature algorithm this key is used with
+    #[serde(default)]
+    pub algorithm: SignatureAlgorithm,
+
+    /// Base64-encoded raw public key
+    pub public_key: String,
+
+    /// Optional owner of the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Whether the key has been revoked
+    #[serde(default)]
+    pub revoked: bool,
+
+    /// When the key was added
+    pub added_at: DateTime<Utc>,
+}
+
+impl TrustedKey {
+    /// Create a new Ed25519 trusted key from a base64-encoded public key
+    pub fn ed25519(
+        key_id: impl Into<String>,
+        public_key: impl Into<String>,
+    ) -> ServiceResult<Self> {
+        let key = Self {
+            key_id: key_id.into(),
+            algorithm: SignatureAlgorithm::Ed25519,
+            public_key: public_key.into(),
+            owner: None,
+            revoked: false,
+            added_at: Utc::now(),
+        };
+        key.verifying_key()?;
+        Ok(key)
+    }
+
+    /// Set the key owner
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Decode the public key
+    pub fn verifying_key(&self) -> ServiceResult<VerifyingKey> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&self.public_key)
+            .map_err(|e| ServiceError::InvalidInput(format!("Invalid base64 public key: {}", e)))?;
+
+        let bytes: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+            ServiceError::InvalidInput(format!(
+                "Invalid public key length: expected {} bytes, got {}",
+                self.algorithm.public_key_length(),
+                bytes.len()
+            ))
+        })?;
+
+        VerifyingKey::from_bytes(&bytes)
+            .map_err(|e| ServiceError::InvalidInput(format!("Invalid public key: {}", e)))
+    }
+}
+
+/// Trait for trusted signing key storage
+#[async_trait]
+pub trait TrustedKeyStore: Send + Sync {
+    /// Get a key by ID
+    async fn get_key(&self, key_id: &str) -> ServiceResult<Option<TrustedKey>>;
+
+    /// Add or replace a key
+    async fn add_key(&self, key: TrustedKey) -> ServiceResult<()>;
+
+    /// Revoke a key so it is no longer accepted for verification
+    async fn revoke_key(&self, key_id: &str) -> ServiceResult<()>;
+
+    /// List all keys
+    async fn list_keys(&self) -> ServiceResult<Vec<TrustedKey>>;
+}
+
+/// In-memory implementation of TrustedKeyStore
+#[derive(Debug, Default)]
+pub struct InMemoryTrustedKeyStore {
+    keys: RwLock<HashMap<String, TrustedKey>>,
+}
+
+impl InMemoryTrustedKeyStore {
+    /// Create an empty key store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a key store pre-populated with keys
+    pub fn with_keys(keys: impl IntoIterator<Item = TrustedKey>) -> Self {
+        Self {
+            keys: RwLock::new(keys.into_iter().map(|k| (k.key_id.clone(), k)).collect()),
+        }
+    }
+}
+
+#[async_trait]
+impl TrustedKeyStore for InMemoryTrustedKeyStore {
+    async fn get_key(&self, key_id: &str) -> ServiceResult<Option<TrustedKey>> {
+        Ok(self.keys.read().await.get(key_id).cloned())
+    }
+
+    async fn add_key(&self, key: TrustedKey) -> ServiceResult<()> {
+        key.verifying_key()?;
+        self.keys.write().await.insert(key.key_id.clone(), key);
+        Ok(())
+    }
+
+    async fn revoke_key(&self, key_id: &str) -> ServiceResult<()> {
+        let mut keys = self.keys.write().await;
+        let key = keys
+            .get_mut(key_id)
+            .ok_or_else(|| ServiceError::NotFound(format!("Trusted key: {}", key_id)))?;
+        key.revoked = true;
+        Ok(())
+    }
+
+    async fn list_keys(&self) -> ServiceResult<Vec<TrustedKey>> {
+        let mut keys: Vec<_> = self.keys.read().await.values().cloned().collect();
+        keys.sort_by(|a, b| a.key_id.cmp(&b.key_id));
+        Ok(keys)
+    }
+}
+
+/// Trait for asset signature operations
+#[async_trait]
+pub trait SignatureService: Send + Sync {
+    /// Verify a signature against an asset's signing payload
+    async fn verify(&self, asset: &Asset, signature: &AssetSignature) -> ServiceResult<()>;
+
+    /// Get the signature of a stored asset
+    async fn get_signature(&self, asset_id: &AssetId) -> ServiceResult<Option<AssetSignature>>;
+
+    /// Verify the signature of a stored asset
+    async fn verify_asset_signature(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<SignatureVerificationResult>;
+
+    /// Get the trusted key store
+    fn key_store(&self) -> &Arc<dyn TrustedKeyStore>;
+}
+
+/// Default implementation of SignatureService
+pub struct DefaultSignatureService {
+    repository: Arc<dyn AssetRepository>,
+    key_store: Arc<dyn TrustedKeyStore>,
+}
+
+impl DefaultSignatureService {
+    /// Create a new signature service
+    pub fn new(repository: Arc<dyn AssetRepository>, key_store: Arc<dyn TrustedKeyStore>) -> Self {
+        Self {
+            repository,
+            key_store,
+        }
+    }
+}
+
+#[async_trait]
+impl SignatureService for DefaultSignatureService {
+    #[instrument(skip(self, asset, signature), fields(asset_id = %asset.id, key_id = %signature.key_id))]
+    async fn verify(&self, asset: &Asset, signature: &AssetSignature) -> ServiceResult<()> {
+        debug!("Verifying asset signature");
+
+        signature
+            .validate()
+            .map_err(|e| ServiceError::SignatureVerificationFailed(e.to_string()))?;
+
+        let key = self
+            .key_store
+            .get_key(&signature.key_id)
+            .await?
+            .ok_or_else(|| {
+                ServiceError::SignatureVerificationFailed(format!(
+                    "Unknown signing key: {}",
+                    signature.key_id
+                ))
+            })?;
+
+        verify_signature(&key, asset, signature)
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn get_signature(&self, asset_id: &AssetId) -> ServiceResult<Option<AssetSignature>> {
+        let asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        Ok(asset.signature)
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn verify_asset_signature(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<SignatureVerificationResult> {
+        let asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        let Some(signature) = asset.signature.clone() else {
+            return Ok(SignatureVerificationResult {
+                asset_id: *asset_id,
+                verified: false,
+                algorithm: None,
+                key_id: None,
+                error: Some("Asset is not signed".to_string()),
+                verified_at: Utc::now(),
+            });
+        };
+
+        let error = match self.verify(&asset, &signature).await {
+            Ok(()) => None,
+            Err(ServiceError::SignatureVerificationFailed(msg)) => Some(msg),
+            Err(e) => return Err(e),
+        };
+
+        Ok(SignatureVerificationResult {
+            asset_id: *asset_id,
+            verified: error.is_none(),
+            algorithm: Some(signature.algorithm),
+            key_id: Some(signature.key_id),
+            error,
+            verified_at: Utc::now(),
+        })
+    }
+
+    fn key_store(&self) -> &Arc<dyn TrustedKeyStore> {
+        &self.key_store
+    }
+}
+
+/// Verify a signature over an asset's signing payload with a trusted key
+pub fn verify_signature(
+    key: &TrustedKey,
+    asset: &Asset,
+    signature: &AssetSignature,
+) -> ServiceResult<()> {
+    if key.revoked {
+        return Err(ServiceError::SignatureVerificationFailed(format!(
+            "Signing key has been revoked: {}",
+            key.key_id
+        )));
+    }
+
+    if key.algorithm != signature.algorithm {
+        return Err(ServiceError::SignatureVerificationFailed(format!(
+            "Algorithm mismatch: key {} uses {}, signature uses {}",
+            key.key_id, key.algorithm, signature.algorithm
+        )));
+    }
+
+    let verifying_key = key.verifying_key()?;
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(&signature.value)
+        .map_err(|e| {
+            ServiceError::SignatureVerificationFailed(format!("Invalid base64 signature: {}", e))
+        })?;
+    let sig = Signature::from_slice(&bytes).map_err(|_| {
+        ServiceError::SignatureVerificationFailed(format!(
+            "Invalid signature length: expected {} bytes, got {}",
+            signature.algorithm.signature_length(),
+            bytes.len()
+        ))
+    })?;
+
+    verifying_key
+        .verify(&asset.signing_payload(), &sig)
+        .map_err(|_| {
+            ServiceError::SignatureVerificationFailed("Signature does not match asset".to_string())
+        })
+}
+
+/// Utility functions for producing signatures
+pub mod utils {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    /// Sign an asset's signing payload with an Ed25519 key
+    pub fn sign_asset(signing_key: &SigningKey, key_id: &str, asset: &Asset) -> AssetSignature {
+        let signature = signing_key.sign(&asset.signing_payload());
+        AssetSignature {
+            algorithm: SignatureAlgorithm::Ed25519,
+            key_id: key_id.to_string(),
+            value: base64::engine::general_purpose::STANDARD.encode(signature.to_bytes()),
+        }
+    }
+
+    /// Encode the public half of an Ed25519 key for a trusted key store
+    pub fn encode_public_key(signing_key: &SigningKey) -> String {
+        base64::engine::general_purpose::STANDARD.encode(signing_key.verifying_key().to_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::utils::{encode_public_key, sign_asset};
+    use super::*;
+    use ed25519_dalek::SigningKey;
+    use llm_registry_core::test_support::test_asset;
+
+    fn trusted_key(signing_key: &SigningKey) -> TrustedKey {
+        TrustedKey::ed25519("release-key", encode_public_key(signing_key)).unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let asset = test_asset("signed-model", "1.0.0");
+        let signature = sign_asset(&signing_key, "release-key", &asset);
+
+        assert!(verify_signature(&trusted_key(&signing_key), &asset, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_checksum() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut asset = test_asset("signed-model", "1.0.0");
+        let signature = sign_asset(&signing_key, "release-key", &asset);
+
+        asset.checksum.value =
+            "a3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string();
+
+        let result = verify_signature(&trusted_key(&signing_key), &asset, &signature);
+        assert!(matches!(
+            result,
+            Err(ServiceError::SignatureVerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let other_key = SigningKey::from_bytes(&[9u8; 32]);
+        let asset = test_asset("signed-model", "1.0.0");
+        let signature = sign_asset(&other_key, "release-key", &asset);
+
+        assert!(verify_signature(&trusted_key(&signing_key), &asset, &signature).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_revoked_key() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let asset = test_asset("signed-model", "1.0.0");
+        let signature = sign_asset(&signing_key, "release-key", &asset);
+
+        let mut key = trusted_key(&signing_key);
+        key.revoked = true;
+
+        assert!(verify_signature(&key, &asset, &signature).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_key_store() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let store = InMemoryTrustedKeyStore::new();
+
+        store.add_key(trusted_key(&signing_key)).await.unwrap();
+        assert!(store.get_key("release-key").await.unwrap().is_some());
+
+        store.revoke_key("release-key").await.unwrap();
+        assert!(store.get_key("release-key").await.unwrap().unwrap().revoked);
+
+        assert!(store
+            .add_key(TrustedKey {
+                public_key: "bm90LWEta2V5".to_string(),
+                ..trusted_key(&signing_key)
+            })
+            .await
+            .is_err());
+    }
+}
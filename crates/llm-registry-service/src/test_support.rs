@@ -0,0 +1,49 @@
+//! Test fixtures
+//!
+//! Shared by the tests of this crate and, through the `test-support`
+//! feature, by the tests of crates built on it.
+
+use llm_registry_core::{
+    Asset, AssetType, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+};
+
+use crate::{RegisterAssetRequest, ServiceRegistry};
+
+/// A valid request registering a model with no dependencies
+///
+/// Tests needing more override fields with struct update syntax.
+pub fn register_request(name: &str, version: &str) -> RegisterAssetRequest {
+    RegisterAssetRequest {
+        asset_type: AssetType::Model,
+        name: name.to_string(),
+        version: semver::Version::parse(version).unwrap(),
+        description: None,
+        license: None,
+        tags: vec![],
+        annotations: Default::default(),
+        storage: StorageLocation::new(
+            StorageBackend::FileSystem {
+                base_path: "/models".to_string(),
+            },
+            format!("{}.bin", name),
+            None,
+        )
+        .unwrap(),
+        checksum: Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap(),
+        provenance: None,
+        dependencies: vec![],
+        size_bytes: None,
+        content_type: None,
+        signature: None,
+    }
+}
+
+/// Register the model described by [`register_request`]
+pub async fn register_model(services: &ServiceRegistry, name: &str, version: &str) -> Asset {
+    services
+        .registration()
+        .register_asset(register_request(name, version))
+        .await
+        .unwrap()
+        .asset
+}
@@ -12,11 +12,26 @@ use tracing::{debug, instrument, warn};
 use crate::dto::{ValidateAssetRequest, ValidationError, ValidationResult, ValidationWarning};
 use crate::error::{ServiceError, ServiceResult};
 
+/// Policies known to the default validation service
+pub const VALIDATION_POLICIES: &[&str] = &["license", "size", "schema"];
+
 /// Trait for validation operations
 #[async_trait]
 pub trait ValidationService: Send + Sync {
     /// Validate an asset according to schema and business rules
-    async fn validate_asset(&self, request: ValidateAssetRequest) -> ServiceResult<ValidationResult>;
+    async fn validate_asset(
+        &self,
+        request: ValidateAssetRequest,
+    ) -> ServiceResult<ValidationResult>;
+
+    /// Validate a registered asset against the given policies, or all
+    /// policies when none are given
+    async fn validate_registered_asset(
+        &self,
+        asset_id: &AssetId,
+        policies: Vec<String>,
+        deep: bool,
+    ) -> ServiceResult<ValidationResult>;
 
     /// Validate asset metadata
     async fn validate_metadata(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
@@ -25,7 +40,11 @@ pub trait ValidationService: Send + Sync {
     async fn validate_dependencies(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
 
     /// Apply policy validation
-    async fn validate_policy(&self, asset: &Asset, policy_name: &str) -> ServiceResult<ValidationResult>;
+    async fn validate_policy(
+        &self,
+        asset: &Asset,
+        policy_name: &str,
+    ) -> ServiceResult<ValidationResult>;
 
     /// Validate all policies for an asset
     async fn validate_all_policies(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
@@ -47,7 +66,13 @@ impl DefaultValidationService {
     }
 
     /// Emit policy validation event
-    async fn emit_policy_event(&self, asset_id: AssetId, policy_name: String, passed: bool, message: Option<String>) {
+    async fn emit_policy_event(
+        &self,
+        asset_id: AssetId,
+        policy_name: String,
+        passed: bool,
+        message: Option<String>,
+    ) {
         let event = RegistryEvent::new(EventType::PolicyValidated {
             asset_id,
             policy_name,
@@ -198,7 +223,12 @@ impl DefaultValidationService {
         } else if let Some(ref license) = asset.metadata.license {
             // List of approved licenses (example)
             let approved_licenses = vec![
-                "MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "ISC", "CC0-1.0",
+                "MIT",
+                "Apache-2.0",
+                "GPL-3.0",
+                "BSD-3-Clause",
+                "ISC",
+                "CC0-1.0",
             ];
 
             if !approved_licenses.iter().any(|&l| license.contains(l)) {
@@ -273,7 +303,10 @@ impl DefaultValidationService {
 #[async_trait]
 impl ValidationService for DefaultValidationService {
     #[instrument(skip(self, request))]
-    async fn validate_asset(&self, request: ValidateAssetRequest) -> ServiceResult<ValidationResult> {
+    async fn validate_asset(
+        &self,
+        request: ValidateAssetRequest,
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating asset: {}", request.asset.id);
 
         let mut results = Vec::new();
@@ -303,6 +336,27 @@ impl ValidationService for DefaultValidationService {
         Ok(self.merge_results(results))
     }
 
+    #[instrument(skip(self, policies), fields(asset_id = %asset_id))]
+    async fn validate_registered_asset(
+        &self,
+        asset_id: &AssetId,
+        policies: Vec<String>,
+        deep: bool,
+    ) -> ServiceResult<ValidationResult> {
+        let asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        self.validate_asset(ValidateAssetRequest {
+            asset,
+            deep,
+            policies,
+        })
+        .await
+    }
+
     #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
     async fn validate_metadata(&self, asset: &Asset) -> ServiceResult<ValidationResult> {
         debug!("Validating asset metadata");
@@ -377,7 +431,11 @@ impl ValidationService for DefaultValidationService {
     }
 
     #[instrument(skip(self, asset), fields(asset_id = %asset.id, policy = %policy_name))]
-    async fn validate_policy(&self, asset: &Asset, policy_name: &str) -> ServiceResult<ValidationResult> {
+    async fn validate_policy(
+        &self,
+        asset: &Asset,
+        policy_name: &str,
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating policy: {}", policy_name);
 
         let result = match policy_name {
@@ -412,10 +470,9 @@ impl ValidationService for DefaultValidationService {
     async fn validate_all_policies(&self, asset: &Asset) -> ServiceResult<ValidationResult> {
         debug!("Validating all policies");
 
-        let policies = vec!["license", "size", "schema"];
         let mut results = Vec::new();
 
-        for policy in policies {
+        for policy in VALIDATION_POLICIES {
             results.push(self.validate_policy(asset, policy).await?);
         }
 
@@ -426,7 +483,10 @@ impl ValidationService for DefaultValidationService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use llm_registry_core::{AssetMetadata, Checksum, HashAlgorithm, StorageBackend, StorageLocation};
+    use llm_registry_core::{
+        AssetMetadata, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+    };
+    use llm_registry_db::{InMemoryAssetRepository, InMemoryEventStore};
     use semver::Version;
 
     fn create_test_asset() -> Asset {
@@ -441,21 +501,28 @@ mod tests {
             None,
         )
         .unwrap();
-        let checksum = Checksum::new(
-            HashAlgorithm::SHA256,
-            "a".repeat(64),
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+
+        Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
         )
-        .unwrap();
+        .unwrap()
+    }
 
-        Asset::new(AssetId::new(), AssetType::Model, metadata, storage, checksum).unwrap()
+    fn create_test_service() -> DefaultValidationService {
+        DefaultValidationService::new(
+            Arc::new(InMemoryAssetRepository::new()),
+            Arc::new(InMemoryEventStore::new()),
+        )
     }
 
     #[test]
     fn test_validate_schema_valid_asset() {
-        let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
-        };
+        let service = create_test_service();
 
         let asset = create_test_asset();
         let result = service.validate_schema(&asset);
@@ -465,10 +532,7 @@ mod tests {
 
     #[test]
     fn test_validate_schema_empty_name() {
-        let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
-        };
+        let service = create_test_service();
 
         let mut asset = create_test_asset();
         asset.metadata.name = String::new();
@@ -481,10 +545,7 @@ mod tests {
 
     #[test]
     fn test_validate_license_policy() {
-        let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
-        };
+        let service = create_test_service();
 
         let mut asset = create_test_asset();
         asset.metadata.license = Some("MIT".to_string());
@@ -493,96 +554,34 @@ mod tests {
         assert!(result.valid);
     }
 
-    // Mock implementations for testing
-    struct MockRepository;
-    struct MockEventStore;
+    #[tokio::test]
+    async fn test_validate_registered_asset() {
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let event_store = Arc::new(InMemoryEventStore::new());
+        let service = DefaultValidationService::new(repository.clone(), event_store.clone());
 
-    #[async_trait]
-    impl AssetRepository for MockRepository {
-        async fn create(&self, _: Asset) -> llm_registry_db::DbResult<Asset> {
-            unimplemented!()
-        }
-        async fn find_by_id(&self, _: &AssetId) -> llm_registry_db::DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn find_by_name_and_version(&self, _: &str, _: &semver::Version) -> llm_registry_db::DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn find_by_ids(&self, _: &[AssetId]) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn search(&self, _: &llm_registry_db::SearchQuery) -> llm_registry_db::DbResult<llm_registry_db::SearchResults> {
-            unimplemented!()
-        }
-        async fn update(&self, asset: Asset) -> llm_registry_db::DbResult<Asset> {
-            Ok(asset)
-        }
-        async fn delete(&self, _: &AssetId) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn list_versions(&self, _: &str) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn list_dependencies(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn list_reverse_dependencies(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn add_tag(&self, _: &AssetId, _: &str) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn remove_tag(&self, _: &AssetId, _: &str) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn get_tags(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<String>> {
-            Ok(vec![])
-        }
-        async fn list_all_tags(&self) -> llm_registry_db::DbResult<Vec<String>> {
-            Ok(vec![])
-        }
-        async fn add_dependency(&self, _: &AssetId, _: &AssetId, _: Option<&str>) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn remove_dependency(&self, _: &AssetId, _: &AssetId) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn count_assets(&self) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn count_by_type(&self, _: &AssetType) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn health_check(&self) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-    }
-
-    #[async_trait]
-    impl EventStore for MockEventStore {
-        async fn append(&self, event: RegistryEvent) -> llm_registry_db::DbResult<RegistryEvent> {
-            Ok(event)
-        }
-        async fn append_batch(&self, events: Vec<RegistryEvent>) -> llm_registry_db::DbResult<Vec<RegistryEvent>> {
-            Ok(events)
-        }
-        async fn query(&self, _: &llm_registry_db::EventQuery) -> llm_registry_db::DbResult<llm_registry_db::EventQueryResults> {
-            unimplemented!()
-        }
-        async fn get_asset_events(&self, _: &AssetId, _: i64) -> llm_registry_db::DbResult<Vec<RegistryEvent>> {
-            Ok(vec![])
-        }
-        async fn get_latest_event(&self, _: &AssetId) -> llm_registry_db::DbResult<Option<RegistryEvent>> {
-            Ok(None)
-        }
-        async fn count_events(&self) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn count_by_type(&self, _: &str) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn health_check(&self) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
+        let asset = create_test_asset();
+        let err = service
+            .validate_registered_asset(&asset.id, vec![], false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::NotFound(_)));
+
+        repository.create(asset.clone()).await.unwrap();
+        let result = service
+            .validate_registered_asset(&asset.id, vec!["size".to_string()], false)
+            .await
+            .unwrap();
+        assert!(result.valid);
+        assert_eq!(
+            event_store.count_by_type("policy_validated").await.unwrap(),
+            1
+        );
+
+        let err = service
+            .validate_registered_asset(&asset.id, vec!["unknown".to_string()], false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::InvalidInput(_)));
     }
 }
@@ -6,10 +6,10 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use llm_registry_core::{Asset, AssetId, AssetStatus, EventType, RegistryEvent};
-use llm_registry_db::{AssetRepository, EventStore};
+use llm_registry_db::{AssetRepository, UnitOfWork};
 use semver::{Version, VersionReq};
 use std::sync::Arc;
-use tracing::{debug, instrument, warn};
+use tracing::{debug, instrument};
 
 use crate::dto::{
     CheckVersionConflictRequest, ListVersionsRequest, ListVersionsResponse, VersionConflictResult,
@@ -21,10 +21,16 @@ use crate::error::{ServiceError, ServiceResult};
 #[async_trait]
 pub trait VersioningService: Send + Sync {
     /// List all versions of an asset
-    async fn list_versions(&self, request: ListVersionsRequest) -> ServiceResult<ListVersionsResponse>;
+    async fn list_versions(
+        &self,
+        request: ListVersionsRequest,
+    ) -> ServiceResult<ListVersionsResponse>;
 
     /// Check for version conflicts
-    async fn check_version_conflict(&self, request: CheckVersionConflictRequest) -> ServiceResult<VersionConflictResult>;
+    async fn check_version_conflict(
+        &self,
+        request: CheckVersionConflictRequest,
+    ) -> ServiceResult<VersionConflictResult>;
 
     /// Get the latest version of an asset
     async fn get_latest_version(&self, name: &str) -> ServiceResult<Option<Asset>>;
@@ -32,14 +38,33 @@ pub trait VersioningService: Send + Sync {
     /// Find assets matching a version requirement
     async fn find_by_version_req(&self, name: &str, req: &VersionReq) -> ServiceResult<Vec<Asset>>;
 
-    /// Deprecate a specific version
-    async fn deprecate_version(&self, asset_id: &AssetId, reason: Option<String>) -> ServiceResult<Asset>;
+    /// Resolve a version requirement to the highest matching active version
+    ///
+    /// Deprecated versions are only considered when `include_deprecated` is set.
+    async fn resolve_version(
+        &self,
+        name: &str,
+        req: &VersionReq,
+        include_deprecated: bool,
+    ) -> ServiceResult<Option<Asset>>;
+
+    /// Deprecate a specific version, optionally pointing at an alternative
+    /// version of the same asset
+    async fn deprecate_version(
+        &self,
+        asset_id: &AssetId,
+        reason: Option<String>,
+        alternative: Option<Version>,
+    ) -> ServiceResult<Asset>;
 
     /// Check if a version is deprecated
     async fn is_deprecated(&self, asset_id: &AssetId) -> ServiceResult<bool>;
 
     /// Get deprecation info
-    async fn get_deprecation_info(&self, asset_id: &AssetId) -> ServiceResult<Option<DeprecationInfo>>;
+    async fn get_deprecation_info(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Option<DeprecationInfo>>;
 }
 
 /// Information about a deprecated version
@@ -56,15 +81,18 @@ pub struct DeprecationInfo {
 /// Default implementation of VersioningService
 pub struct DefaultVersioningService {
     repository: Arc<dyn AssetRepository>,
-    event_store: Arc<dyn EventStore>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl DefaultVersioningService {
     /// Create a new versioning service
-    pub fn new(repository: Arc<dyn AssetRepository>, event_store: Arc<dyn EventStore>) -> Self {
+    ///
+    /// Deprecations are written through `unit_of_work` together with their
+    /// events.
+    pub fn new(repository: Arc<dyn AssetRepository>, unit_of_work: Arc<dyn UnitOfWork>) -> Self {
         Self {
             repository,
-            event_store,
+            unit_of_work,
         }
     }
 
@@ -97,7 +125,10 @@ impl DefaultVersioningService {
 #[async_trait]
 impl VersioningService for DefaultVersioningService {
     #[instrument(skip(self, request), fields(name = %request.name))]
-    async fn list_versions(&self, request: ListVersionsRequest) -> ServiceResult<ListVersionsResponse> {
+    async fn list_versions(
+        &self,
+        request: ListVersionsRequest,
+    ) -> ServiceResult<ListVersionsResponse> {
         debug!("Listing versions for asset: {}", request.name);
 
         // Get all versions from repository
@@ -112,7 +143,10 @@ impl VersioningService for DefaultVersioningService {
         assets = self.sort_versions_desc(assets);
 
         // Convert to VersionInfo
-        let versions: Vec<VersionInfo> = assets.iter().map(|a| self.asset_to_version_info(a)).collect();
+        let versions: Vec<VersionInfo> = assets
+            .iter()
+            .map(|a| self.asset_to_version_info(a))
+            .collect();
 
         // Find latest active version
         let latest = self
@@ -127,8 +161,14 @@ impl VersioningService for DefaultVersioningService {
     }
 
     #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
-    async fn check_version_conflict(&self, request: CheckVersionConflictRequest) -> ServiceResult<VersionConflictResult> {
-        debug!("Checking version conflict for {}@{}", request.name, request.version);
+    async fn check_version_conflict(
+        &self,
+        request: CheckVersionConflictRequest,
+    ) -> ServiceResult<VersionConflictResult> {
+        debug!(
+            "Checking version conflict for {}@{}",
+            request.name, request.version
+        );
 
         // Check if this exact version already exists
         match self
@@ -179,8 +219,30 @@ impl VersioningService for DefaultVersioningService {
         Ok(self.sort_versions_desc(matching))
     }
 
+    #[instrument(skip(self, req), fields(name = %name, requirement = %req))]
+    async fn resolve_version(
+        &self,
+        name: &str,
+        req: &VersionReq,
+        include_deprecated: bool,
+    ) -> ServiceResult<Option<Asset>> {
+        debug!("Resolving version requirement: {}", req);
+
+        let matching = self.find_by_version_req(name, req).await?;
+
+        Ok(matching.into_iter().find(|a| {
+            a.status == AssetStatus::Active
+                || (include_deprecated && a.status == AssetStatus::Deprecated)
+        }))
+    }
+
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn deprecate_version(&self, asset_id: &AssetId, reason: Option<String>) -> ServiceResult<Asset> {
+    async fn deprecate_version(
+        &self,
+        asset_id: &AssetId,
+        reason: Option<String>,
+        alternative: Option<Version>,
+    ) -> ServiceResult<Asset> {
         debug!("Deprecating version");
 
         // Fetch the asset
@@ -198,36 +260,71 @@ impl VersioningService for DefaultVersioningService {
             )));
         }
 
+        // The alternative must be another registered version of this asset
+        if let Some(ref alternative) = alternative {
+            if *alternative == asset.metadata.version {
+                return Err(ServiceError::InvalidInput(
+                    "An asset cannot be its own alternative".to_string(),
+                ));
+            }
+            if self
+                .repository
+                .find_by_name_and_version(&asset.metadata.name, alternative)
+                .await?
+                .is_none()
+            {
+                return Err(ServiceError::InvalidInput(format!(
+                    "Alternative version {} of {} does not exist",
+                    alternative, asset.metadata.name
+                )));
+            }
+        }
+
         let old_status = asset.status;
+        let before = asset.clone();
 
-        // Set status to deprecated
+        // Set status to deprecated, storing the reason and alternative in
+        // annotations if provided
         asset.set_status(AssetStatus::Deprecated);
-
-        // Update in repository
-        let updated = self.repository.update(asset).await?;
-
-        // Emit status change event
-        let event = RegistryEvent::new(EventType::AssetStatusChanged {
-            asset_id: *asset_id,
-            asset_name: updated.metadata.name.clone(),
-            old_status,
-            new_status: AssetStatus::Deprecated,
-        });
-
-        if let Err(e) = self.event_store.append(event).await {
-            warn!("Failed to emit status change event: {}", e);
-        }
-
-        // Store deprecation reason in annotations if provided
         if let Some(reason_text) = reason {
-            let mut updated_copy = updated.clone();
-            updated_copy
+            asset
                 .metadata
                 .add_annotation("deprecation_reason", reason_text);
-            return self.repository.update(updated_copy).await.map_err(Into::into);
         }
+        if let Some(alternative) = alternative {
+            asset
+                .metadata
+                .add_annotation("alternative_version", alternative.to_string());
+        }
+
+        // Record the status change, and the field-level update used to replay
+        // the asset's history, in the same transaction as the update
+        let changes = before.diff(&asset);
+        let events = vec![
+            RegistryEvent::new(EventType::AssetStatusChanged {
+                asset_id: *asset_id,
+                asset_name: asset.metadata.name.clone(),
+                old_status,
+                new_status: AssetStatus::Deprecated,
+            }),
+            RegistryEvent::new(EventType::AssetUpdated {
+                asset_id: *asset_id,
+                asset_name: asset.metadata.name.clone(),
+                updated_fields: changes.iter().map(|c| c.field.clone()).collect(),
+                changes,
+                revision: Some(before.revision + 1),
+            }),
+        ];
+
+        let mut tx = self.unit_of_work.begin().await?;
+        tx.update_asset(&asset).await?;
+        tx.append_events(&events).await?;
+        tx.commit().await?;
 
-        Ok(updated)
+        Ok(Asset {
+            revision: before.revision + 1,
+            ..asset
+        })
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
@@ -244,7 +341,10 @@ impl VersioningService for DefaultVersioningService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn get_deprecation_info(&self, asset_id: &AssetId) -> ServiceResult<Option<DeprecationInfo>> {
+    async fn get_deprecation_info(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Option<DeprecationInfo>> {
         debug!("Getting deprecation info");
 
         let asset = self
@@ -257,14 +357,9 @@ impl VersioningService for DefaultVersioningService {
             return Ok(None);
         }
 
-        let deprecated_at = asset
-            .deprecated_at
-            .unwrap_or_else(|| asset.updated_at);
+        let deprecated_at = asset.deprecated_at.unwrap_or_else(|| asset.updated_at);
 
-        let reason = asset
-            .metadata
-            .get_annotation("deprecation_reason")
-            .cloned();
+        let reason = asset.metadata.get_annotation("deprecation_reason").cloned();
 
         let alternative = asset
             .metadata
@@ -286,7 +381,10 @@ pub mod utils {
     /// Parse a version requirement string
     pub fn parse_version_req(req_str: &str) -> ServiceResult<VersionReq> {
         VersionReq::parse(req_str).map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid version requirement '{}': {}", req_str, e))
+            ServiceError::ValidationFailed(format!(
+                "Invalid version requirement '{}': {}",
+                req_str, e
+            ))
         })
     }
 
@@ -344,12 +442,83 @@ pub mod utils {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::register_model;
+    use crate::ServiceRegistry;
+
+    #[tokio::test]
+    async fn test_resolve_and_deprecate() {
+        let services = ServiceRegistry::in_memory();
+        let versioning = services.versioning();
+        register_model(&services, "llama", "1.2.0").await;
+        let v1_3 = register_model(&services, "llama", "1.3.0").await;
+        register_model(&services, "llama", "2.0.0").await;
+
+        let req = utils::parse_version_req("^1.2").unwrap();
+        let resolved = versioning
+            .resolve_version("llama", &req, false)
+            .await
+            .unwrap();
+        assert_eq!(resolved.unwrap().id, v1_3.id);
+
+        // Alternatives must be other registered versions of the asset
+        let missing = versioning
+            .deprecate_version(&v1_3.id, None, Some(Version::new(9, 9, 9)))
+            .await;
+        assert!(matches!(missing, Err(ServiceError::InvalidInput(_))));
+        let itself = versioning
+            .deprecate_version(&v1_3.id, None, Some(Version::new(1, 3, 0)))
+            .await;
+        assert!(matches!(itself, Err(ServiceError::InvalidInput(_))));
+
+        let deprecated = versioning
+            .deprecate_version(
+                &v1_3.id,
+                Some("Broken tokenizer".to_string()),
+                Some(Version::new(2, 0, 0)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(deprecated.status, AssetStatus::Deprecated);
+
+        let info = versioning
+            .get_deprecation_info(&v1_3.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.reason.as_deref(), Some("Broken tokenizer"));
+        assert_eq!(info.alternative, Some(Version::new(2, 0, 0)));
+
+        // Deprecated versions are skipped unless asked for
+        let resolved = versioning
+            .resolve_version("llama", &req, false)
+            .await
+            .unwrap();
+        assert_eq!(resolved.unwrap().metadata.version, Version::new(1, 2, 0));
+        let resolved = versioning
+            .resolve_version("llama", &req, true)
+            .await
+            .unwrap();
+        assert_eq!(resolved.unwrap().id, v1_3.id);
+
+        let none = utils::parse_version_req("^3").unwrap();
+        assert!(versioning
+            .resolve_version("llama", &none, true)
+            .await
+            .unwrap()
+            .is_none());
+    }
 
     #[test]
     fn test_parse_version_req() {
         let req = utils::parse_version_req("^1.0.0").unwrap();
-        assert!(utils::version_matches(&Version::parse("1.2.3").unwrap(), &req));
-        assert!(!utils::version_matches(&Version::parse("2.0.0").unwrap(), &req));
+        assert!(utils::version_matches(
+            &Version::parse("1.2.3").unwrap(),
+            &req
+        ));
+        assert!(!utils::version_matches(
+            &Version::parse("2.0.0").unwrap(),
+            &req
+        ));
     }
 
     #[test]
@@ -357,7 +526,10 @@ mod tests {
         let v1 = Version::parse("1.0.0").unwrap();
         let v2 = Version::parse("2.0.0").unwrap();
         assert_eq!(utils::compare_versions(&v1, &v2), std::cmp::Ordering::Less);
-        assert_eq!(utils::compare_versions(&v2, &v1), std::cmp::Ordering::Greater);
+        assert_eq!(
+            utils::compare_versions(&v2, &v1),
+            std::cmp::Ordering::Greater
+        );
         assert_eq!(utils::compare_versions(&v1, &v1), std::cmp::Ordering::Equal);
     }
 
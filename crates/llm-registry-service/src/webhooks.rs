@@ -0,0 +1,1152 @@
+//! Webhook service
+//!
+//! This module manages webhook subscriptions and delivers registry events to
+//! them over HTTP. [`WebhookPublisher`] is an outbox [`EventPublisher`], so
+//! webhooks see exactly the committed events that NATS does: it queues a
+//! delivery for every webhook whose filters match. [`WebhookDispatcher`]
+//! sends queued deliveries, signing each request with the webhook's secret
+//! and retrying failures with exponential backoff. Webhooks that keep failing
+//! are disabled until they are re-enabled through the API.
+//!
+//! Webhook URLs may not target loopback, link-local or private addresses
+//! unless their host is explicitly allowed, so that registry users cannot
+//! make the server call internal endpoints. URLs are checked when a webhook
+//! is saved and again by the dispatcher, which resolves host names itself
+//! and refuses to connect if any resolved address is internal. A name can
+//! therefore not be pointed at an internal address once it was accepted.
+//!
+//! Each request carries these headers:
+//!
+//! - `X-Registry-Event`: the event name
+//! - `X-Registry-Delivery`: the delivery ID, stable across retries
+//! - `X-Registry-Timestamp`: Unix time the request was signed
+//! - `X-Registry-Signature`: `sha256=` followed by the hex HMAC-SHA256 of
+//!   `"{timestamp}.{body}"` keyed with the webhook secret
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::StreamExt;
+use hmac::{Hmac, Mac};
+use llm_registry_core::{EventType, RegistryEvent};
+use llm_registry_db::{
+    AssetRepository, DbResult, DeliveryStatus, EventPublisher, Webhook, WebhookDelivery,
+    WebhookStore,
+};
+use sha2::Sha256;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+
+use crate::dto::{CreateWebhookRequest, UpdateWebhookRequest};
+use crate::error::{ServiceError, ServiceResult};
+
+/// Header carrying the event name
+pub const EVENT_HEADER: &str = "X-Registry-Event";
+
+/// Header carrying the delivery ID
+pub const DELIVERY_HEADER: &str = "X-Registry-Delivery";
+
+/// Header carrying the signing timestamp
+pub const TIMESTAMP_HEADER: &str = "X-Registry-Timestamp";
+
+/// Header carrying the request signature
+pub const SIGNATURE_HEADER: &str = "X-Registry-Signature";
+
+/// Minimum length of a webhook secret
+pub const MIN_SECRET_LENGTH: usize = 16;
+
+/// Maximum number of deliveries returned from the delivery log
+pub const MAX_DELIVERY_LOG_LIMIT: i64 = 1000;
+
+/// Sign a webhook request body
+///
+/// Returns the `X-Registry-Signature` header value. Receivers recompute it
+/// from the `X-Registry-Timestamp` header and the raw body, and should reject
+/// stale timestamps to prevent replays.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Trait for webhook subscription management
+#[async_trait]
+pub trait WebhookService: Send + Sync {
+    /// Create a webhook subscription
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> ServiceResult<Webhook>;
+
+    /// Get a webhook by ID
+    async fn get_webhook(&self, id: &str) -> ServiceResult<Webhook>;
+
+    /// List all webhooks
+    async fn list_webhooks(&self) -> ServiceResult<Vec<Webhook>>;
+
+    /// Update a webhook
+    async fn update_webhook(
+        &self,
+        id: &str,
+        request: UpdateWebhookRequest,
+    ) -> ServiceResult<Webhook>;
+
+    /// Delete a webhook and its delivery log
+    async fn delete_webhook(&self, id: &str) -> ServiceResult<()>;
+
+    /// List a webhook's deliveries, newest first
+    async fn list_deliveries(&self, id: &str, limit: i64) -> ServiceResult<Vec<WebhookDelivery>>;
+
+    /// Queue a delivery to be sent again, returning the new delivery
+    async fn replay_delivery(&self, id: &str, delivery_id: &str) -> ServiceResult<WebhookDelivery>;
+}
+
+/// Default implementation of WebhookService
+pub struct DefaultWebhookService {
+    store: Arc<dyn WebhookStore>,
+    allowed_hosts: Vec<String>,
+}
+
+impl DefaultWebhookService {
+    /// Create a new webhook service
+    pub fn new(store: Arc<dyn WebhookStore>) -> Self {
+        Self {
+            store,
+            allowed_hosts: Vec::new(),
+        }
+    }
+
+    /// Allow webhook URLs on these hosts even if they are internal
+    ///
+    /// Hosts are compared case-insensitively with the URL host, for example
+    /// `127.0.0.1`, `::1` or `localhost`.
+    pub fn with_allowed_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_hosts = hosts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn validate_url(&self, url: &str) -> ServiceResult<()> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| ServiceError::InvalidInput(format!("Invalid webhook URL: {}", e)))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(ServiceError::InvalidInput(format!(
+                "Webhook URL must use http or https: {}",
+                url
+            )));
+        }
+
+        let host = parsed.host().ok_or_else(|| {
+            ServiceError::InvalidInput(format!("Webhook URL must have a host: {}", url))
+        })?;
+        if !is_allowed_host(&self.allowed_hosts, &host.to_string()) && is_internal_host(&host) {
+            return Err(ServiceError::InvalidInput(format!(
+                "Webhook URL must not target a loopback, link-local or private address: {}",
+                url
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Whether a host is in the allow list, ignoring IPv6 brackets and case
+fn is_allowed_host(allowed_hosts: &[String], host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    allowed_hosts
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(host))
+}
+
+/// Whether a URL host is loopback, link-local, private or unspecified
+fn is_internal_host(host: &url::Host<&str>) -> bool {
+    match host {
+        url::Host::Domain(domain) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        url::Host::Ipv4(ip) => is_internal_ip(&IpAddr::V4(*ip)),
+        url::Host::Ipv6(ip) => is_internal_ip(&IpAddr::V6(*ip)),
+    }
+}
+
+fn is_internal_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_internal_ipv4(&ip),
+            None => is_internal_ipv6(ip),
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: &Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Shared address space (100.64.0.0/10)
+        || (first == 100 && second & 0xc0 == 64)
+}
+
+fn is_internal_ipv6(ip: &Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // Unique local (fc00::/7) and link-local (fe80::/10)
+        || first & 0xfe00 == 0xfc00
+        || first & 0xffc0 == 0xfe80
+}
+
+fn validate_secret(secret: &str) -> ServiceResult<()> {
+    if secret.chars().count() < MIN_SECRET_LENGTH {
+        return Err(ServiceError::InvalidInput(format!(
+            "Webhook secret must be at least {} characters",
+            MIN_SECRET_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+fn validate_filter(name: &str, values: &[String]) -> ServiceResult<()> {
+    if values.iter().any(|value| value.trim().is_empty()) {
+        return Err(ServiceError::InvalidInput(format!(
+            "Webhook {} must not contain empty entries",
+            name
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl WebhookService for DefaultWebhookService {
+    #[instrument(skip(self, request), fields(url = %request.url))]
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> ServiceResult<Webhook> {
+        self.validate_url(&request.url)?;
+        validate_secret(&request.secret)?;
+        validate_filter("event_types", &request.event_types)?;
+        validate_filter("asset_names", &request.asset_names)?;
+        validate_filter("tags", &request.tags)?;
+
+        let mut webhook = Webhook::new(request.url, request.secret);
+        webhook.description = request.description;
+        webhook.event_types = request.event_types;
+        webhook.asset_names = request.asset_names;
+        webhook.tags = request.tags;
+        self.store.create_webhook(&webhook).await?;
+
+        info!(webhook_id = %webhook.id, "Webhook created");
+        Ok(webhook)
+    }
+
+    async fn get_webhook(&self, id: &str) -> ServiceResult<Webhook> {
+        self.store
+            .get_webhook(id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Webhook not found: {}", id)))
+    }
+
+    async fn list_webhooks(&self) -> ServiceResult<Vec<Webhook>> {
+        Ok(self.store.list_webhooks().await?)
+    }
+
+    #[instrument(skip(self, request))]
+    async fn update_webhook(
+        &self,
+        id: &str,
+        request: UpdateWebhookRequest,
+    ) -> ServiceResult<Webhook> {
+        let mut webhook = self.get_webhook(id).await?;
+
+        if let Some(url) = request.url {
+            self.validate_url(&url)?;
+            webhook.url = url;
+        }
+        if let Some(secret) = request.secret {
+            validate_secret(&secret)?;
+            webhook.secret = secret;
+        }
+        if let Some(description) = request.description {
+            webhook.description = Some(description);
+        }
+        if let Some(event_types) = request.event_types {
+            validate_filter("event_types", &event_types)?;
+            webhook.event_types = event_types;
+        }
+        if let Some(asset_names) = request.asset_names {
+            validate_filter("asset_names", &asset_names)?;
+            webhook.asset_names = asset_names;
+        }
+        if let Some(tags) = request.tags {
+            validate_filter("tags", &tags)?;
+            webhook.tags = tags;
+        }
+        match request.enabled {
+            Some(true) if !webhook.enabled => {
+                webhook.enabled = true;
+                webhook.consecutive_failures = 0;
+                webhook.disabled_reason = None;
+            }
+            Some(false) => webhook.enabled = false,
+            _ => {}
+        }
+        webhook.updated_at = Utc::now();
+
+        self.store.update_webhook(&webhook).await?;
+        info!(webhook_id = %webhook.id, "Webhook updated");
+        Ok(webhook)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_webhook(&self, id: &str) -> ServiceResult<()> {
+        if !self.store.delete_webhook(id).await? {
+            return Err(ServiceError::NotFound(format!("Webhook not found: {}", id)));
+        }
+        info!(webhook_id = %id, "Webhook deleted");
+        Ok(())
+    }
+
+    async fn list_deliveries(&self, id: &str, limit: i64) -> ServiceResult<Vec<WebhookDelivery>> {
+        self.get_webhook(id).await?;
+        Ok(self
+            .store
+            .list_deliveries(id, limit.clamp(1, MAX_DELIVERY_LOG_LIMIT))
+            .await?)
+    }
+
+    #[instrument(skip(self))]
+    async fn replay_delivery(&self, id: &str, delivery_id: &str) -> ServiceResult<WebhookDelivery> {
+        self.get_webhook(id).await?;
+        let delivery = self
+            .store
+            .get_delivery(delivery_id)
+            .await?
+            .filter(|delivery| delivery.webhook_id == id)
+            .ok_or_else(|| {
+                ServiceError::NotFound(format!("Webhook delivery not found: {}", delivery_id))
+            })?;
+
+        let replay = delivery.replay();
+        self.store
+            .enqueue_deliveries(std::slice::from_ref(&replay))
+            .await?;
+        info!(webhook_id = %id, delivery_id = %delivery_id, "Webhook delivery replayed");
+        Ok(replay)
+    }
+}
+
+/// Outbox publisher queueing deliveries for matching webhooks
+///
+/// Deliveries are deduplicated per webhook and event, so events relayed
+/// more than once are delivered once.
+pub struct WebhookPublisher {
+    store: Arc<dyn WebhookStore>,
+    repository: Arc<dyn AssetRepository>,
+}
+
+impl WebhookPublisher {
+    /// Create a publisher looking up assets for name and tag filters
+    pub fn new(store: Arc<dyn WebhookStore>, repository: Arc<dyn AssetRepository>) -> Self {
+        Self { store, repository }
+    }
+
+    /// Name and tags of the asset an event is about
+    ///
+    /// Deleted assets are no longer in the repository, so only the name
+    /// carried by the event is known.
+    async fn event_asset(&self, event: &RegistryEvent) -> DbResult<Option<(String, Vec<String>)>> {
+        if let Some(asset_id) = event.asset_id() {
+            if let Some(asset) = self.repository.find_by_id(&asset_id).await? {
+                return Ok(Some((asset.metadata.name, asset.metadata.tags)));
+            }
+        }
+
+        let name = match &event.event_type {
+            EventType::AssetRegistered { asset_name, .. }
+            | EventType::AssetUpdated { asset_name, .. }
+            | EventType::AssetDeleted { asset_name, .. }
+            | EventType::AssetStatusChanged { asset_name, .. }
+            | EventType::AssetDownloaded { asset_name, .. } => Some(asset_name.clone()),
+            _ => None,
+        };
+        Ok(name.map(|name| (name, Vec::new())))
+    }
+}
+
+#[async_trait]
+impl EventPublisher for WebhookPublisher {
+    async fn publish(&self, event: &RegistryEvent) -> DbResult<()> {
+        let webhooks: Vec<Webhook> = self
+            .store
+            .list_webhooks()
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.enabled && webhook.accepts_event_type(event.event_name()))
+            .collect();
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let asset = if webhooks.iter().any(Webhook::has_asset_filter) {
+            self.event_asset(event).await?
+        } else {
+            None
+        };
+
+        let deliveries: Vec<WebhookDelivery> = webhooks
+            .iter()
+            .filter(|webhook| {
+                !webhook.has_asset_filter()
+                    || asset
+                        .as_ref()
+                        .is_some_and(|(name, tags)| webhook.accepts_asset(name, tags))
+            })
+            .map(|webhook| WebhookDelivery::new(&webhook.id, event))
+            .collect();
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let queued = self.store.enqueue_deliveries(&deliveries).await?;
+        debug!(
+            event_id = %event.event_id,
+            count = queued,
+            "Queued webhook deliveries"
+        );
+        Ok(())
+    }
+}
+
+/// Webhook dispatcher configuration
+#[derive(Debug, Clone)]
+pub struct WebhookDispatcherConfig {
+    /// Attempts before a delivery is marked failed
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, doubled for each later retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound on the retry delay
+    pub max_backoff: Duration,
+
+    /// Consecutive failed attempts after which a webhook is disabled
+    pub disable_after_failures: u32,
+
+    /// Timeout for each request
+    pub request_timeout: Duration,
+
+    /// Maximum number of deliveries claimed per pass
+    pub batch_size: i64,
+
+    /// Maximum number of requests in flight
+    pub concurrency: usize,
+
+    /// Delay between passes when no deliveries are due
+    pub poll_interval: Duration,
+
+    /// Hosts deliveries may target even though they are internal
+    pub allowed_hosts: Vec<String>,
+}
+
+impl Default for WebhookDispatcherConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            initial_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(3600),
+            disable_after_failures: 20,
+            request_timeout: Duration::from_secs(10),
+            batch_size: 50,
+            concurrency: 8,
+            poll_interval: Duration::from_secs(1),
+            allowed_hosts: Vec::new(),
+        }
+    }
+}
+
+impl WebhookDispatcherConfig {
+    /// Set the maximum number of attempts per delivery
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the initial and maximum retry delays
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Set the failure count after which a webhook is disabled
+    pub fn with_disable_after_failures(mut self, failures: u32) -> Self {
+        self.disable_after_failures = failures.max(1);
+        self
+    }
+
+    /// Set the request timeout
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set the batch size
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the maximum number of requests in flight
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the poll interval
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Allow deliveries to these hosts even if they are internal
+    ///
+    /// Use the same hosts as [`DefaultWebhookService::with_allowed_hosts`].
+    pub fn with_allowed_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_hosts = hosts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Delay before retrying a delivery that has failed `attempts` times
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// DNS resolver refusing host names that resolve to internal addresses
+///
+/// Resolving at connection time closes the gap between validating a
+/// webhook URL and delivering to it, during which the name's records may
+/// change. IP literals are not resolved and are checked by
+/// [`WebhookDispatcher::send`] instead.
+struct PublicResolver {
+    allowed_hosts: Arc<[String]>,
+}
+
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest_hyper::client::connect::dns::Name) -> reqwest::dns::Resolving {
+        let allowed_hosts = self.allowed_hosts.clone();
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+            let addrs = public_addrs(name.as_str(), addrs, &allowed_hosts)?;
+            let addrs: reqwest::dns::Addrs = Box::new(addrs.into_iter());
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(addrs)
+        })
+    }
+}
+
+/// Check the addresses a host name resolved to before connecting
+fn public_addrs(
+    host: &str,
+    addrs: Vec<SocketAddr>,
+    allowed_hosts: &[String],
+) -> Result<Vec<SocketAddr>, String> {
+    if !is_allowed_host(allowed_hosts, host) {
+        if let Some(addr) = addrs.iter().find(|addr| is_internal_ip(&addr.ip())) {
+            return Err(format!(
+                "{} resolves to internal address {}",
+                host,
+                addr.ip()
+            ));
+        }
+    }
+    Ok(addrs)
+}
+
+/// A failed delivery attempt
+struct AttemptFailure {
+    status_code: Option<u16>,
+    error: String,
+}
+
+/// Background task sending queued webhook deliveries
+pub struct WebhookDispatcher {
+    store: Arc<dyn WebhookStore>,
+    client: reqwest::Client,
+    config: WebhookDispatcherConfig,
+}
+
+impl WebhookDispatcher {
+    /// Create a dispatcher
+    pub fn new(
+        store: Arc<dyn WebhookStore>,
+        config: WebhookDispatcherConfig,
+    ) -> ServiceResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .user_agent(concat!("llm-registry-webhooks/", env!("CARGO_PKG_VERSION")))
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicResolver {
+                allowed_hosts: config.allowed_hosts.clone().into(),
+            }))
+            .build()
+            .map_err(|e| ServiceError::Internal(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            store,
+            client,
+            config,
+        })
+    }
+
+    /// Send one batch of due deliveries
+    ///
+    /// Returns the number of deliveries attempted.
+    #[instrument(skip(self))]
+    pub async fn dispatch_due(&self) -> ServiceResult<usize> {
+        // Claimed deliveries stay claimed until every request in the batch
+        // could have timed out
+        let lease = self
+            .config
+            .request_timeout
+            .saturating_mul(2)
+            .saturating_add(Duration::from_secs(30));
+        let due = self
+            .store
+            .claim_due_deliveries(Utc::now(), lease, self.config.batch_size)
+            .await?;
+        let count = due.len();
+
+        futures::stream::iter(due)
+            .for_each_concurrent(self.config.concurrency, |delivery| async move {
+                let delivery_id = delivery.id.clone();
+                if let Err(e) = self.attempt(delivery).await {
+                    warn!(delivery_id = %delivery_id, "Failed to record webhook delivery: {}", e);
+                }
+            })
+            .await;
+
+        Ok(count)
+    }
+
+    /// Attempt a delivery and record the outcome
+    async fn attempt(&self, mut delivery: WebhookDelivery) -> ServiceResult<()> {
+        // Deliveries of deleted webhooks are removed with them
+        let Some(webhook) = self.store.get_webhook(&delivery.webhook_id).await? else {
+            return Ok(());
+        };
+
+        let result = self.send(&webhook, &delivery).await;
+        let now = Utc::now();
+        delivery.attempts += 1;
+
+        let success = match result {
+            Ok(status_code) => {
+                delivery.status = DeliveryStatus::Succeeded;
+                delivery.last_status_code = Some(status_code);
+                delivery.last_error = None;
+                delivery.delivered_at = Some(now);
+                debug!(delivery_id = %delivery.id, "Webhook delivered");
+                true
+            }
+            Err(failure) => {
+                delivery.last_status_code = failure.status_code;
+                delivery.last_error = Some(failure.error);
+                if delivery.attempts >= self.config.max_attempts {
+                    delivery.status = DeliveryStatus::Failed;
+                    warn!(
+                        delivery_id = %delivery.id,
+                        webhook_id = %webhook.id,
+                        attempts = delivery.attempts,
+                        "Giving up on webhook delivery"
+                    );
+                } else {
+                    let backoff = self.config.backoff(delivery.attempts);
+                    delivery.next_attempt_at = now
+                        + chrono::Duration::from_std(backoff)
+                            .unwrap_or_else(|_| chrono::Duration::seconds(3600));
+                    debug!(
+                        delivery_id = %delivery.id,
+                        attempts = delivery.attempts,
+                        retry_in_ms = backoff.as_millis() as u64,
+                        "Webhook delivery failed, will retry"
+                    );
+                }
+                false
+            }
+        };
+
+        self.store.update_delivery(&delivery).await?;
+        let enabled = self
+            .store
+            .record_outcome(&webhook.id, success, self.config.disable_after_failures)
+            .await?;
+        if webhook.enabled && !enabled {
+            warn!(
+                webhook_id = %webhook.id,
+                "Webhook disabled after {} consecutive failed deliveries",
+                self.config.disable_after_failures
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Send a delivery, returning the response status on success
+    async fn send(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> Result<u16, AttemptFailure> {
+        self.check_url_host(&webhook.url)
+            .map_err(|error| AttemptFailure {
+                status_code: None,
+                error,
+            })?;
+
+        let body = serde_json::to_vec(&delivery.payload).map_err(|e| AttemptFailure {
+            status_code: None,
+            error: format!("Failed to serialize payload: {}", e),
+        })?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_payload(&webhook.secret, timestamp, &body);
+
+        let response = self
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &delivery.event_type)
+            .header(DELIVERY_HEADER, &delivery.id)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, signature)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| AttemptFailure {
+                status_code: None,
+                error: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(status.as_u16())
+        } else {
+            Err(AttemptFailure {
+                status_code: Some(status.as_u16()),
+                error: format!("Endpoint responded with {}", status),
+            })
+        }
+    }
+
+    /// Refuse URLs whose host is an internal IP literal
+    ///
+    /// Host names are checked by the client's resolver once resolved.
+    fn check_url_host(&self, url: &str) -> Result<(), String> {
+        let parsed = url::Url::parse(url).map_err(|e| format!("Invalid webhook URL: {}", e))?;
+        match parsed.host() {
+            Some(host @ (url::Host::Ipv4(_) | url::Host::Ipv6(_)))
+                if !is_allowed_host(&self.config.allowed_hosts, &host.to_string())
+                    && is_internal_host(&host) =>
+            {
+                Err(format!("Webhook URL targets internal address {}", host))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Send deliveries until the task is aborted
+    pub async fn run(self) {
+        info!(
+            max_attempts = self.config.max_attempts,
+            batch_size = self.config.batch_size,
+            "Starting webhook dispatcher"
+        );
+
+        loop {
+            match self.dispatch_due().await {
+                // A full batch means more deliveries are probably due
+                Ok(count) if count as i64 >= self.config.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => warn!("Webhook dispatch pass failed: {}", e),
+            }
+
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Spawn the dispatcher onto the Tokio runtime
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_core::Asset;
+    use llm_registry_db::{InMemoryAssetRepository, InMemoryWebhookStore};
+    use wiremock::matchers::{header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SECRET: &str = "0123456789abcdef";
+
+    fn test_asset(name: &str, tags: &[&str]) -> Asset {
+        let mut asset = llm_registry_core::test_support::test_asset(name, "1.0.0");
+        asset.metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+        asset
+    }
+
+    fn status_event(asset: &Asset) -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetStatusChanged {
+            asset_id: asset.id,
+            asset_name: asset.metadata.name.clone(),
+            old_status: llm_registry_core::AssetStatus::Active,
+            new_status: llm_registry_core::AssetStatus::Deprecated,
+        })
+    }
+
+    fn create_request(url: &str) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: url.to_string(),
+            secret: SECRET.to_string(),
+            description: None,
+            event_types: vec![],
+            asset_names: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn fast_config() -> WebhookDispatcherConfig {
+        WebhookDispatcherConfig::default()
+            .with_backoff(Duration::ZERO, Duration::ZERO)
+            .with_request_timeout(Duration::from_secs(5))
+            // The mock server listens on loopback
+            .with_allowed_hosts(["127.0.0.1"])
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_maximum() {
+        let config = WebhookDispatcherConfig::default()
+            .with_backoff(Duration::from_secs(10), Duration::from_secs(60));
+        assert_eq!(config.backoff(1), Duration::from_secs(10));
+        assert_eq!(config.backoff(2), Duration::from_secs(20));
+        assert_eq!(config.backoff(3), Duration::from_secs(40));
+        assert_eq!(config.backoff(4), Duration::from_secs(60));
+        assert_eq!(config.backoff(40), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_publisher_applies_filters() {
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let repository = Arc::new(InMemoryAssetRepository::new());
+        let service = DefaultWebhookService::new(store.clone());
+        let publisher = WebhookPublisher::new(store.clone(), repository.clone());
+
+        let llama = test_asset("llama-3", &["prod"]);
+        let mistral = test_asset("mistral", &["dev"]);
+        repository.create(llama.clone()).await.unwrap();
+        repository.create(mistral.clone()).await.unwrap();
+
+        let all = service
+            .create_webhook(create_request("https://example.com/all"))
+            .await
+            .unwrap();
+        let prod_llamas = service
+            .create_webhook(CreateWebhookRequest {
+                asset_names: vec!["llama-*".to_string()],
+                tags: vec!["prod".to_string()],
+                ..create_request("https://example.com/llamas")
+            })
+            .await
+            .unwrap();
+        let deletions = service
+            .create_webhook(CreateWebhookRequest {
+                event_types: vec!["asset_deleted".to_string()],
+                ..create_request("https://example.com/deletions")
+            })
+            .await
+            .unwrap();
+
+        let llama_event = status_event(&llama);
+        publisher.publish(&llama_event).await.unwrap();
+        publisher.publish(&status_event(&mistral)).await.unwrap();
+        // Relaying an event again does not deliver it twice
+        publisher.publish(&llama_event).await.unwrap();
+
+        assert_eq!(service.list_deliveries(&all.id, 10).await.unwrap().len(), 2);
+        let llama_deliveries = service.list_deliveries(&prod_llamas.id, 10).await.unwrap();
+        assert_eq!(llama_deliveries.len(), 1);
+        assert_eq!(llama_deliveries[0].event_id, llama_event.event_id);
+        assert!(service
+            .list_deliveries(&deletions.id, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_signs_deliveries() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let store = Arc::new(InMemoryWebhookStore::new());
+        // The mock server listens on loopback
+        let service = DefaultWebhookService::new(store.clone()).with_allowed_hosts(["127.0.0.1"]);
+        let webhook = service
+            .create_webhook(create_request(&server.uri()))
+            .await
+            .unwrap();
+        let event = status_event(&test_asset("model", &[]));
+        WebhookPublisher::new(store.clone(), Arc::new(InMemoryAssetRepository::new()))
+            .publish(&event)
+            .await
+            .unwrap();
+
+        let dispatcher = WebhookDispatcher::new(store.clone(), fast_config()).unwrap();
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 0);
+
+        let requests = server.received_requests().await.unwrap();
+        let request = &requests[0];
+        let header = |name: &str| {
+            request
+                .headers
+                .get(name)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        let timestamp: i64 = header(TIMESTAMP_HEADER).parse().unwrap();
+        assert_eq!(
+            header(SIGNATURE_HEADER),
+            sign_payload(SECRET, timestamp, &request.body)
+        );
+        assert_eq!(header(EVENT_HEADER), "asset_status_changed");
+
+        let deliveries = service.list_deliveries(&webhook.id, 10).await.unwrap();
+        assert_eq!(header(DELIVERY_HEADER), deliveries[0].id);
+        assert_eq!(deliveries[0].status, DeliveryStatus::Succeeded);
+        assert_eq!(deliveries[0].last_status_code, Some(204));
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["event_id"], event.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_failing_webhook_retried_then_disabled() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let store = Arc::new(InMemoryWebhookStore::new());
+        // The mock server listens on loopback
+        let service = DefaultWebhookService::new(store.clone()).with_allowed_hosts(["127.0.0.1"]);
+        let webhook = service
+            .create_webhook(create_request(&server.uri()))
+            .await
+            .unwrap();
+        WebhookPublisher::new(store.clone(), Arc::new(InMemoryAssetRepository::new()))
+            .publish(&status_event(&test_asset("model", &[])))
+            .await
+            .unwrap();
+
+        let dispatcher = WebhookDispatcher::new(
+            store.clone(),
+            fast_config()
+                .with_max_attempts(2)
+                .with_disable_after_failures(3),
+        )
+        .unwrap();
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 0);
+
+        let delivery = service.list_deliveries(&webhook.id, 10).await.unwrap()[0].clone();
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(delivery.last_status_code, Some(500));
+
+        // A replay fails once more, which disables the webhook
+        let replay = service
+            .replay_delivery(&webhook.id, &delivery.id)
+            .await
+            .unwrap();
+        assert_eq!(replay.replay_of.as_deref(), Some(delivery.id.as_str()));
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+        let disabled = service.get_webhook(&webhook.id).await.unwrap();
+        assert!(!disabled.enabled);
+        assert!(disabled.disabled_reason.is_some());
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 0);
+
+        // Re-enabling resets the failure count and resumes the replay
+        let enabled = service
+            .update_webhook(
+                &webhook.id,
+                UpdateWebhookRequest {
+                    enabled: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(enabled.consecutive_failures, 0);
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_validates_request() {
+        let service = DefaultWebhookService::new(Arc::new(InMemoryWebhookStore::new()));
+
+        for request in [
+            create_request("ftp://example.com/hook"),
+            create_request("not a url"),
+            CreateWebhookRequest {
+                secret: "short".to_string(),
+                ..create_request("https://example.com/hook")
+            },
+            CreateWebhookRequest {
+                tags: vec![" ".to_string()],
+                ..create_request("https://example.com/hook")
+            },
+        ] {
+            assert!(matches!(
+                service.create_webhook(request).await,
+                Err(ServiceError::InvalidInput(_))
+            ));
+        }
+
+        for url in [
+            "http://127.0.0.1:8080/hook",
+            "http://localhost/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://10.0.0.5/hook",
+            "http://192.168.1.10/hook",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(
+                matches!(
+                    service.create_webhook(create_request(url)).await,
+                    Err(ServiceError::InvalidInput(_))
+                ),
+                "{} was accepted",
+                url
+            );
+        }
+
+        let allowed = DefaultWebhookService::new(Arc::new(InMemoryWebhookStore::new()))
+            .with_allowed_hosts(["127.0.0.1", "::1"]);
+        allowed
+            .create_webhook(create_request("http://127.0.0.1:8080/hook"))
+            .await
+            .unwrap();
+        allowed
+            .create_webhook(create_request("http://[::1]/hook"))
+            .await
+            .unwrap();
+        assert!(allowed
+            .create_webhook(create_request("http://10.0.0.5/hook"))
+            .await
+            .is_err());
+        assert!(matches!(
+            service.get_webhook("missing").await,
+            Err(ServiceError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolved_internal_addresses_are_refused() {
+        let resolved = |ip: &str| vec![SocketAddr::new(ip.parse().unwrap(), 0)];
+        let host = "hooks.example.com";
+
+        for ip in [
+            "127.0.0.1",
+            "169.254.169.254",
+            "10.1.2.3",
+            "::1",
+            "fd00:ec2::254",
+        ] {
+            let err = public_addrs(host, resolved(ip), &[]).unwrap_err();
+            assert!(err.contains("internal address"), "{}: {}", ip, err);
+        }
+        let mixed = [resolved("93.184.216.34"), resolved("127.0.0.1")].concat();
+        assert!(public_addrs(host, mixed, &[]).is_err());
+
+        assert!(public_addrs(host, resolved("93.184.216.34"), &[]).is_ok());
+        let allowed = vec!["HOOKS.example.com".to_string()];
+        assert!(public_addrs(host, resolved("127.0.0.1"), &allowed).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_refuses_internal_targets_when_sending() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        let port = server.address().port();
+
+        // Both URLs were accepted when saved, as if allowed at the time or
+        // their host name resolved elsewhere
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let service = DefaultWebhookService::new(store.clone())
+            .with_allowed_hosts(["localhost", "127.0.0.1"]);
+        let by_name = service
+            .create_webhook(create_request(&format!("http://localhost:{}/hook", port)))
+            .await
+            .unwrap();
+        let by_address = service
+            .create_webhook(create_request(&server.uri()))
+            .await
+            .unwrap();
+        WebhookPublisher::new(store.clone(), Arc::new(InMemoryAssetRepository::new()))
+            .publish(&status_event(&test_asset("model", &[])))
+            .await
+            .unwrap();
+
+        let dispatcher = WebhookDispatcher::new(
+            store.clone(),
+            WebhookDispatcherConfig::default().with_backoff(Duration::ZERO, Duration::ZERO),
+        )
+        .unwrap();
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 2);
+        assert!(server.received_requests().await.unwrap().is_empty());
+
+        let delivery = service.list_deliveries(&by_name.id, 10).await.unwrap()[0].clone();
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        let error = delivery.last_error.unwrap();
+        assert!(error.contains("resolves to internal address"), "{}", error);
+        let delivery = service.list_deliveries(&by_address.id, 10).await.unwrap()[0].clone();
+        let error = delivery.last_error.unwrap();
+        assert!(error.contains("targets internal address"), "{}", error);
+
+        // Allowing the host lets the retries through
+        let dispatcher = WebhookDispatcher::new(
+            store.clone(),
+            fast_config().with_allowed_hosts(["localhost", "127.0.0.1"]),
+        )
+        .unwrap();
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 2);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+}
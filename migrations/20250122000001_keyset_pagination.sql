@@ -0,0 +1,15 @@
+-- Indexes for keyset pagination
+-- Migration: 20250122000001_keyset_pagination
+--
+-- Cursor pages seek to (sort key, id) and order by both columns, so the
+-- single-column sort indexes are replaced with composite ones.
+
+DROP INDEX IF EXISTS idx_assets_created_at;
+DROP INDEX IF EXISTS idx_assets_updated_at;
+DROP INDEX IF EXISTS idx_registry_events_timestamp;
+
+CREATE INDEX idx_assets_created_at ON assets(created_at, id);
+CREATE INDEX idx_assets_updated_at ON assets(updated_at, id);
+CREATE INDEX idx_assets_name_id ON assets(name, id);
+CREATE INDEX idx_assets_size_id ON assets((COALESCE(size_bytes, -1)), id);
+CREATE INDEX idx_registry_events_timestamp ON registry_events(timestamp, event_id);
@@ -0,0 +1,37 @@
+-- Tamper-evident event hash chain
+-- Migration: 20250124000001_event_chain
+--
+-- Each event stores the hash of its predecessor and a hash over its own
+-- persisted fields, so editing or deleting a row breaks every later link.
+-- Signed checkpoints of the chain head expose truncation and rewrites of the
+-- whole chain. Events recorded before this migration are not chained.
+
+ALTER TABLE registry_events
+    ADD COLUMN chain_sequence BIGINT,
+    ADD COLUMN chain_algorithm TEXT,
+    ADD COLUMN chain_prev_hash TEXT,
+    ADD COLUMN chain_hash TEXT;
+
+ALTER TABLE registry_events ADD CONSTRAINT chk_registry_events_chain_complete CHECK (
+    (chain_sequence IS NULL AND chain_algorithm IS NULL AND chain_prev_hash IS NULL AND chain_hash IS NULL)
+    OR (chain_sequence > 0 AND chain_algorithm IS NOT NULL AND chain_prev_hash IS NOT NULL AND chain_hash IS NOT NULL)
+);
+
+CREATE UNIQUE INDEX idx_registry_events_chain_sequence
+    ON registry_events (chain_sequence)
+    WHERE chain_sequence IS NOT NULL;
+
+-- Signed checkpoints of the chain head
+CREATE TABLE event_chain_checkpoints (
+    -- Sequence of the chain head at checkpoint time
+    sequence BIGINT PRIMARY KEY CHECK (sequence > 0),
+
+    -- Hash of the event at that sequence
+    hash TEXT NOT NULL,
+
+    -- Signing key and base64-encoded Ed25519 signature
+    key_id TEXT NOT NULL,
+    signature TEXT NOT NULL,
+
+    created_at TIMESTAMPTZ NOT NULL
+);